@@ -13,14 +13,74 @@ CREATE TABLE IF NOT EXISTS status_loan (
     description TEXT NOT NULL
 );
 
+CREATE TABLE IF NOT EXISTS adjustment_reason (
+    id INTEGER PRIMARY KEY,
+    description TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS price_change_source (
+    id INTEGER PRIMARY KEY,
+    description TEXT NOT NULL
+);
+
 CREATE TABLE IF NOT EXISTS unit_measurement (
     id INTEGER PRIMARY KEY,
     description TEXT NOT NULL,
-    abbreviation TEXT NOT NULL
+    abbreviation TEXT NOT NULL,
+    allows_fraction INTEGER NOT NULL DEFAULT 0,  -- Whether quantities like 1.5 can be sold
+    decimals INTEGER NOT NULL DEFAULT 0  -- Decimal places for fractional quantities
 );
 
 -- Core Tables
 
+CREATE TABLE IF NOT EXISTS app_setting (
+    key TEXT PRIMARY KEY,
+    value TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS supplier (
+    id TEXT PRIMARY KEY,  -- UUID as TEXT
+    name TEXT NOT NULL,
+    phone TEXT,
+    lead_time_days INTEGER NOT NULL DEFAULT 0,  -- Days from ordering to receiving
+    created_at TEXT DEFAULT (datetime('now'))
+);
+
+CREATE TABLE IF NOT EXISTS price_list (
+    id TEXT PRIMARY KEY,  -- UUID as TEXT
+    name TEXT NOT NULL UNIQUE,  -- e.g. Wholesale
+    discount_percent TEXT NOT NULL DEFAULT '0',  -- Off the regular price of products without a list price
+    created_at TEXT DEFAULT (datetime('now'))
+);
+
+CREATE TABLE IF NOT EXISTS customer (
+    id TEXT PRIMARY KEY,  -- UUID as TEXT
+    name TEXT NOT NULL,
+    phone TEXT,
+    rfc TEXT,  -- Tax profile, NULL when the customer does not invoice
+    legal_name TEXT,
+    tax_regime TEXT,  -- c_RegimenFiscal
+    zip_code TEXT,  -- Fiscal address ZIP code
+    cfdi_use TEXT,  -- c_UsoCFDI
+    price_list_id TEXT,  -- NULL for regular prices
+    created_at TEXT DEFAULT (datetime('now')),
+
+    FOREIGN KEY (price_list_id) REFERENCES price_list(id) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_customer_name ON customer(name);
+
+CREATE TABLE IF NOT EXISTS category (
+    id TEXT PRIMARY KEY,  -- UUID as TEXT
+    name TEXT NOT NULL,
+    parent_id TEXT,  -- NULL for top-level departments
+    created_at TEXT DEFAULT (datetime('now')),
+
+    FOREIGN KEY (parent_id) REFERENCES category(id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_category_parent ON category(parent_id);
+
 CREATE TABLE IF NOT EXISTS product (
     id TEXT PRIMARY KEY,  -- UUID as TEXT
     barcode TEXT UNIQUE,  -- Can be NULL for products without barcodes
@@ -29,16 +89,173 @@ CREATE TABLE IF NOT EXISTS product (
     cost_price TEXT,           -- Cost for profit calculation as Decimal
     min_amount REAL DEFAULT 0, -- Minimum stock alert (quantity)
     current_amount REAL DEFAULT 0, -- Current inventory (quantity)
-    unit_measurement_id INTEGER NOT NULL,
+    unit_measurement_id INTEGER NOT NULL,  -- Sale unit (stock is kept in this unit)
+    purchase_unit_id INTEGER,  -- NULL when bought in the sale unit
+    units_per_purchase REAL NOT NULL DEFAULT 1,  -- Sale units per purchase unit (e.g., 24 pcs per box)
+    category_id TEXT,  -- NULL for uncategorized products
+    supplier_id TEXT,  -- NULL when the supplier is not recorded
+    is_kit INTEGER NOT NULL DEFAULT 0,  -- 1 when stock comes from product_component
+    archived_at TEXT,  -- NULL while the product is active
     created_at TEXT DEFAULT (datetime('now')),
     updated_at TEXT DEFAULT (datetime('now')),
 
-    FOREIGN KEY (unit_measurement_id) REFERENCES unit_measurement(id)
+    FOREIGN KEY (unit_measurement_id) REFERENCES unit_measurement(id),
+    FOREIGN KEY (purchase_unit_id) REFERENCES unit_measurement(id),
+    FOREIGN KEY (category_id) REFERENCES category(id),
+    FOREIGN KEY (supplier_id) REFERENCES supplier(id)
 );
 
 CREATE INDEX IF NOT EXISTS idx_product_barcode ON product(barcode);
 CREATE INDEX IF NOT EXISTS idx_product_name ON product(full_name);
 CREATE INDEX IF NOT EXISTS idx_product_created ON product(created_at);
+CREATE INDEX IF NOT EXISTS idx_product_category ON product(category_id);
+CREATE INDEX IF NOT EXISTS idx_product_supplier ON product(supplier_id);
+
+CREATE TABLE IF NOT EXISTS product_price_history (
+    id TEXT PRIMARY KEY,  -- UUID as TEXT
+    product_id TEXT NOT NULL,
+    old_price TEXT NOT NULL,  -- Sale price before the change as Decimal
+    new_price TEXT NOT NULL,  -- Sale price after the change as Decimal
+    old_cost TEXT,  -- Cost before the change as Decimal, NULL if unknown
+    new_cost TEXT,  -- Cost after the change as Decimal, NULL if unknown
+    source_id INTEGER NOT NULL,  -- Manual edit, bulk update or receiving
+    changed_at TEXT DEFAULT (datetime('now')),
+
+    FOREIGN KEY (product_id) REFERENCES product(id) ON DELETE CASCADE,
+    FOREIGN KEY (source_id) REFERENCES price_change_source(id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_price_history_product ON product_price_history(product_id, changed_at);
+
+CREATE TABLE IF NOT EXISTS product_barcode (
+    barcode TEXT PRIMARY KEY,  -- Additional code (pack, supplier replacement, etc.)
+    product_id TEXT NOT NULL,
+    pack_quantity REAL NOT NULL DEFAULT 1,  -- Units of the product added per scan
+    created_at TEXT DEFAULT (datetime('now')),
+
+    FOREIGN KEY (product_id) REFERENCES product(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_barcode_product ON product_barcode(product_id);
+
+CREATE TABLE IF NOT EXISTS product_component (
+    kit_id TEXT NOT NULL,
+    component_id TEXT NOT NULL,
+    quantity REAL NOT NULL,  -- Component units per kit
+
+    PRIMARY KEY (kit_id, component_id),
+    FOREIGN KEY (kit_id) REFERENCES product(id) ON DELETE CASCADE,
+    FOREIGN KEY (component_id) REFERENCES product(id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_component_product ON product_component(component_id);
+
+CREATE TABLE IF NOT EXISTS product_tax_code (
+    product_id TEXT PRIMARY KEY,
+    sat_product_code TEXT NOT NULL,  -- c_ClaveProdServ
+    sat_unit_code TEXT,  -- c_ClaveUnidad, NULL to derive it from the sale unit
+    tax TEXT NOT NULL DEFAULT 'iva16',  -- iva16, iva0 or exempt
+
+    FOREIGN KEY (product_id) REFERENCES product(id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS price_list_item (
+    price_list_id TEXT NOT NULL,
+    product_id TEXT NOT NULL,
+    price TEXT NOT NULL,  -- Unit price on the list as Decimal
+
+    PRIMARY KEY (price_list_id, product_id),
+    FOREIGN KEY (price_list_id) REFERENCES price_list(id) ON DELETE CASCADE,
+    FOREIGN KEY (product_id) REFERENCES product(id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS product_price_tier (
+    product_id TEXT NOT NULL,
+    min_quantity REAL NOT NULL,  -- Quantity in the sale unit from which the price applies
+    unit_price TEXT NOT NULL,  -- Price per unit at this quantity as Decimal
+
+    PRIMARY KEY (product_id, min_quantity),
+    FOREIGN KEY (product_id) REFERENCES product(id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS product_lot (
+    id TEXT PRIMARY KEY,  -- UUID as TEXT
+    product_id TEXT NOT NULL,
+    lot_code TEXT,  -- Supplier batch number, NULL if not printed
+    expires_at TEXT,  -- Expiry date (YYYY-MM-DD), NULL for non-perishable lots
+    received_quantity REAL NOT NULL,  -- Quantity in sale units
+    remaining_quantity REAL NOT NULL,  -- Quantity left in sale units
+    condition_id INTEGER NOT NULL DEFAULT 1,  -- Only Good lots are sellable
+    received_at TEXT DEFAULT (datetime('now')),
+
+    FOREIGN KEY (product_id) REFERENCES product(id) ON DELETE CASCADE,
+    FOREIGN KEY (condition_id) REFERENCES item_condition(id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_lot_product ON product_lot(product_id);
+CREATE INDEX IF NOT EXISTS idx_lot_expires ON product_lot(expires_at);
+
+CREATE TABLE IF NOT EXISTS stock_adjustment (
+    id TEXT PRIMARY KEY,  -- UUID as TEXT
+    product_id TEXT NOT NULL,
+    product_name TEXT NOT NULL,  -- Denormalized for reports
+    quantity REAL NOT NULL,  -- Signed change in sale units (negative for losses)
+    unit_cost TEXT,  -- Cost price at the time as Decimal, NULL if unknown
+    total_cost TEXT NOT NULL DEFAULT '0',  -- unit_cost * quantity as Decimal
+    reason_id INTEGER NOT NULL,
+    notes TEXT,
+    adjusted_at TEXT DEFAULT (datetime('now')),
+
+    FOREIGN KEY (product_id) REFERENCES product(id),
+    FOREIGN KEY (reason_id) REFERENCES adjustment_reason(id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_adjustment_date ON stock_adjustment(adjusted_at);
+CREATE INDEX IF NOT EXISTS idx_adjustment_product ON stock_adjustment(product_id);
+
+CREATE TABLE IF NOT EXISTS inventory_count (
+    id TEXT PRIMARY KEY,  -- UUID as TEXT
+    name TEXT NOT NULL,
+    started_at TEXT DEFAULT (datetime('now')),
+    posted_at TEXT  -- NULL while the count is open
+);
+
+CREATE TABLE IF NOT EXISTS inventory_count_line (
+    count_id TEXT NOT NULL,
+    product_id TEXT NOT NULL,
+    product_name TEXT NOT NULL,  -- Denormalized at snapshot time
+    barcode TEXT,
+    expected_quantity REAL NOT NULL,  -- Stock when the count started
+    counted_quantity REAL,  -- NULL until counted
+    unit_cost TEXT,  -- Cost price when the count started as Decimal
+    approved INTEGER DEFAULT 1,  -- Boolean: 0 = variance rejected, 1 = post variance
+    counted_at TEXT,
+
+    PRIMARY KEY (count_id, product_id),
+    FOREIGN KEY (count_id) REFERENCES inventory_count(id) ON DELETE CASCADE,
+    FOREIGN KEY (product_id) REFERENCES product(id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS purchase_order (
+    id TEXT PRIMARY KEY,  -- UUID as TEXT
+    supplier_id TEXT,  -- NULL for products without supplier
+    created_at TEXT DEFAULT (datetime('now')),
+
+    FOREIGN KEY (supplier_id) REFERENCES supplier(id) ON DELETE SET NULL
+);
+
+CREATE TABLE IF NOT EXISTS purchase_order_line (
+    order_id TEXT NOT NULL,
+    product_id TEXT NOT NULL,
+    product_name TEXT NOT NULL,  -- Denormalized at order time
+    quantity REAL NOT NULL,  -- Quantity in sale units
+    purchase_quantity REAL NOT NULL,  -- Quantity in the product's purchase unit
+    unit_cost TEXT,  -- Cost per sale unit when ordered as Decimal
+
+    PRIMARY KEY (order_id, product_id),
+    FOREIGN KEY (order_id) REFERENCES purchase_order(id) ON DELETE CASCADE,
+    FOREIGN KEY (product_id) REFERENCES product(id) ON DELETE CASCADE
+);
 
 CREATE TABLE IF NOT EXISTS sale (
     id TEXT PRIMARY KEY,  -- UUID as TEXT
@@ -46,11 +263,43 @@ CREATE TABLE IF NOT EXISTS sale (
     paid_amount TEXT NOT NULL,   -- Paid amount as Decimal stored as TEXT
     change_amount TEXT DEFAULT '0',  -- Change as Decimal stored as TEXT
     is_loan INTEGER DEFAULT 0, -- Boolean: 0 = fully paid, 1 = loan/partial payment
-    sold_at TEXT DEFAULT (datetime('now'))
+    sold_at TEXT DEFAULT (datetime('now')),
+    customer_id TEXT,  -- NULL for walk-in customers
+    points_amount TEXT NOT NULL DEFAULT '0',  -- Part of paid_amount tendered in loyalty points
+
+    FOREIGN KEY (customer_id) REFERENCES customer(id)
 );
 
 CREATE INDEX IF NOT EXISTS idx_sale_date ON sale(sold_at);
 CREATE INDEX IF NOT EXISTS idx_sale_is_loan ON sale(is_loan);
+CREATE INDEX IF NOT EXISTS idx_sale_customer ON sale(customer_id);
+
+CREATE TABLE IF NOT EXISTS sale_tax_profile (
+    sale_id TEXT PRIMARY KEY,  -- Tax profile captured at checkout for invoicing
+    rfc TEXT NOT NULL,
+    legal_name TEXT NOT NULL,
+    tax_regime TEXT NOT NULL,
+    zip_code TEXT NOT NULL,
+    cfdi_use TEXT NOT NULL,
+
+    FOREIGN KEY (sale_id) REFERENCES sale(id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS loyalty_entry (
+    id TEXT PRIMARY KEY,  -- UUID as TEXT
+    customer_id TEXT NOT NULL,
+    sale_id TEXT,  -- NULL for expirations
+    points INTEGER NOT NULL,  -- Positive when earned, negative when redeemed or expired
+    kind TEXT NOT NULL,  -- earn, redeem or expire
+    expires_at TEXT,  -- Only for earned points, NULL when they never expire
+    created_at TEXT DEFAULT (datetime('now')),
+
+    FOREIGN KEY (customer_id) REFERENCES customer(id),
+    FOREIGN KEY (sale_id) REFERENCES sale(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_loyalty_entry_customer ON loyalty_entry(customer_id);
+CREATE INDEX IF NOT EXISTS idx_loyalty_entry_sale ON loyalty_entry(sale_id);
 
 CREATE TABLE IF NOT EXISTS operation (
     id TEXT PRIMARY KEY,  -- UUID as TEXT
@@ -70,6 +319,19 @@ CREATE INDEX IF NOT EXISTS idx_operation_sale ON operation(sale_id);
 CREATE INDEX IF NOT EXISTS idx_operation_product ON operation(product_id);
 CREATE INDEX IF NOT EXISTS idx_operation_date ON operation(recorded_at);
 
+-- Component stock deducted by a kit operation, as the kit was composed at sale time
+CREATE TABLE IF NOT EXISTS operation_component (
+    operation_id TEXT NOT NULL,
+    component_id TEXT NOT NULL,
+    quantity REAL NOT NULL,  -- Component units deducted
+
+    PRIMARY KEY (operation_id, component_id),
+    FOREIGN KEY (operation_id) REFERENCES operation(id) ON DELETE CASCADE,
+    FOREIGN KEY (component_id) REFERENCES product(id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_operation_component_product ON operation_component(component_id);
+
 CREATE TABLE IF NOT EXISTS loan (
     id TEXT PRIMARY KEY,  -- References sale.id
     total_debt TEXT NOT NULL,  -- Total debt as Decimal stored as TEXT
@@ -101,6 +363,30 @@ CREATE TABLE IF NOT EXISTS loan_payment (
 CREATE INDEX IF NOT EXISTS idx_loan_payment_loan ON loan_payment(loan_id);
 CREATE INDEX IF NOT EXISTS idx_loan_payment_date ON loan_payment(payment_date);
 
+CREATE TABLE IF NOT EXISTS invoice (
+    id TEXT PRIMARY KEY,  -- UUID as TEXT
+    series TEXT NOT NULL,
+    folio INTEGER NOT NULL,
+    uuid TEXT NOT NULL,  -- Fiscal folio assigned by the PAC
+    receiver_rfc TEXT NOT NULL,
+    total TEXT NOT NULL,  -- Total as Decimal stored as TEXT
+    data TEXT NOT NULL,  -- Invoice as JSON, for the PDF
+    xml TEXT NOT NULL,  -- Stamped CFDI
+    stamped_at TEXT NOT NULL,
+
+    UNIQUE (series, folio)
+);
+
+CREATE TABLE IF NOT EXISTS invoice_sale (
+    sale_id TEXT PRIMARY KEY,  -- A sale is invoiced at most once
+    invoice_id TEXT NOT NULL,
+
+    FOREIGN KEY (sale_id) REFERENCES sale(id),
+    FOREIGN KEY (invoice_id) REFERENCES invoice(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_invoice_sale_invoice ON invoice_sale(invoice_id);
+
 -- Insert default catalog data
 
 INSERT OR IGNORE INTO item_condition (id, description) VALUES
@@ -108,17 +394,33 @@ INSERT OR IGNORE INTO item_condition (id, description) VALUES
     (2, 'Damaged'),
     (3, 'Expired');
 
+INSERT OR IGNORE INTO adjustment_reason (id, description) VALUES
+    (1, 'Damaged'),
+    (2, 'Expired'),
+    (3, 'Theft'),
+    (4, 'Internal Use'),
+    (5, 'Count Correction');
+
+INSERT OR IGNORE INTO price_change_source (id, description) VALUES
+    (1, 'Manual Edit'),
+    (2, 'Bulk Update'),
+    (3, 'Receiving');
+
 INSERT OR IGNORE INTO status_loan (id, description) VALUES
     (1, 'Active'),
     (2, 'Partially Paid'),
     (3, 'Fully Paid'),
     (4, 'Cancelled');
 
-INSERT OR IGNORE INTO unit_measurement (id, description, abbreviation) VALUES
-    (1, 'Kilogram', 'kg'),
-    (2, 'Liter', 'lt'),
-    (3, 'Unit', 'unit'),
-    (4, 'Piece', 'pcs'),
-    (5, 'Box', 'box'),
-    (6, 'Can', 'can'),
-    (7, 'Bottle', 'bottle');
+-- Units are editable, so they are only seeded into an empty table
+INSERT INTO unit_measurement (id, description, abbreviation, allows_fraction, decimals)
+SELECT column1, column2, column3, column4, column5 FROM (VALUES
+    (1, 'Kilogram', 'kg', 1, 3),
+    (2, 'Liter', 'lt', 1, 3),
+    (3, 'Unit', 'unit', 0, 0),
+    (4, 'Piece', 'pcs', 0, 0),
+    (5, 'Box', 'box', 0, 0),
+    (6, 'Can', 'can', 0, 0),
+    (7, 'Bottle', 'bottle', 0, 0)
+)
+WHERE NOT EXISTS (SELECT 1 FROM unit_measurement);
@@ -0,0 +1,72 @@
+//! Customers API
+//!
+//! Business logic for customers, their tax profiles and price lists.
+
+use crate::models::{Customer, CustomerInput};
+use crate::repo::{CustomerRepository, PriceListRepository};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct CustomersApi {
+    customer_repo: Arc<dyn CustomerRepository>,
+    price_list_repo: Arc<dyn PriceListRepository>,
+}
+
+impl std::fmt::Debug for CustomersApi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CustomersApi").finish()
+    }
+}
+
+impl PartialEq for CustomersApi {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.customer_repo, &other.customer_repo)
+            && Arc::ptr_eq(&self.price_list_repo, &other.price_list_repo)
+    }
+}
+
+impl CustomersApi {
+    pub fn new(
+        customer_repo: Arc<dyn CustomerRepository>,
+        price_list_repo: Arc<dyn PriceListRepository>,
+    ) -> Self {
+        Self {
+            customer_repo,
+            price_list_repo,
+        }
+    }
+
+    /// List all customers
+    pub async fn list_customers(&self) -> Result<Vec<Customer>, String> {
+        self.customer_repo.list_all().await
+    }
+
+    /// Create a new customer with validation
+    pub async fn create_customer(&self, input: CustomerInput) -> Result<Customer, String> {
+        let input = input.normalized()?;
+        self.check_price_list(&input).await?;
+        self.customer_repo.create(input).await
+    }
+
+    /// Update a customer with validation
+    pub async fn update_customer(
+        &self,
+        id: &str,
+        input: CustomerInput,
+    ) -> Result<Customer, String> {
+        let input = input.normalized()?;
+        self.check_price_list(&input).await?;
+        self.customer_repo.update(id, input).await
+    }
+
+    /// Reject a price list that does not exist
+    async fn check_price_list(&self, input: &CustomerInput) -> Result<(), String> {
+        if let Some(id) = &input.price_list_id {
+            self.price_list_repo
+                .get_by_id(id)
+                .await?
+                .ok_or_else(|| format!("Price list not found: {}", id))?;
+        }
+        Ok(())
+    }
+}
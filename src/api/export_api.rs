@@ -0,0 +1,372 @@
+//! Export API
+//!
+//! Business logic for exporting sales, loans and inventory data for a date
+//! range as spreadsheet tables.
+
+use crate::models::StoreClock;
+use crate::repo::{
+    AdjustmentRepository, CatalogRepository, LoanRepository, LotRepository, ProductRepository,
+    SaleRepository,
+};
+use crate::utils::export::{ExportDataset, ExportTable, ExportValue};
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct ExportApi {
+    sale_repo: Arc<dyn SaleRepository>,
+    loan_repo: Arc<dyn LoanRepository>,
+    product_repo: Arc<dyn ProductRepository>,
+    lot_repo: Arc<dyn LotRepository>,
+    adjustment_repo: Arc<dyn AdjustmentRepository>,
+    catalog_repo: Arc<dyn CatalogRepository>,
+}
+
+impl std::fmt::Debug for ExportApi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExportApi").finish()
+    }
+}
+
+impl PartialEq for ExportApi {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.sale_repo, &other.sale_repo)
+            && Arc::ptr_eq(&self.loan_repo, &other.loan_repo)
+            && Arc::ptr_eq(&self.product_repo, &other.product_repo)
+            && Arc::ptr_eq(&self.lot_repo, &other.lot_repo)
+            && Arc::ptr_eq(&self.adjustment_repo, &other.adjustment_repo)
+            && Arc::ptr_eq(&self.catalog_repo, &other.catalog_repo)
+    }
+}
+
+impl ExportApi {
+    pub fn new(
+        sale_repo: Arc<dyn SaleRepository>,
+        loan_repo: Arc<dyn LoanRepository>,
+        product_repo: Arc<dyn ProductRepository>,
+        lot_repo: Arc<dyn LotRepository>,
+        adjustment_repo: Arc<dyn AdjustmentRepository>,
+        catalog_repo: Arc<dyn CatalogRepository>,
+    ) -> Self {
+        Self {
+            sale_repo,
+            loan_repo,
+            product_repo,
+            lot_repo,
+            adjustment_repo,
+            catalog_repo,
+        }
+    }
+
+    /// Rows of a dataset between two business days, oldest first
+    pub async fn export(
+        &self,
+        dataset: ExportDataset,
+        from: NaiveDate,
+        to: NaiveDate,
+        clock: &StoreClock,
+    ) -> Result<ExportTable, String> {
+        if dataset.uses_date_range() && from > to {
+            return Err("Start date must be on or before end date".to_string());
+        }
+
+        let (start, end) = clock.range_bounds(from, to);
+        let mut table = ExportTable::new(dataset);
+
+        match dataset {
+            ExportDataset::Sales => self.sales_rows(&mut table, &start, &end, clock).await?,
+            ExportDataset::Operations => {
+                self.operation_rows(&mut table, &start, &end, clock).await?
+            }
+            ExportDataset::Loans => self.loan_rows(&mut table, from, to, clock).await?,
+            ExportDataset::LoanPayments => {
+                self.payment_rows(&mut table, &start, &end, clock).await?
+            }
+            ExportDataset::StockMovements => {
+                self.movement_rows(&mut table, &start, &end, clock).await?
+            }
+            ExportDataset::InventoryValuation => self.valuation_rows(&mut table).await?,
+        }
+
+        Ok(table)
+    }
+
+    async fn sales_rows(
+        &self,
+        table: &mut ExportTable,
+        start: &str,
+        end: &str,
+        clock: &StoreClock,
+    ) -> Result<(), String> {
+        let mut sales = self.sale_repo.list_by_date_range(start, end).await?;
+        sales.sort_by_key(|s| s.sold_at);
+
+        for sale in sales {
+            table.push(vec![
+                sale.id.into(),
+                local_time(&sale.sold_at, clock),
+                business_date(sale.sold_at, clock),
+                sale.total_amount.into(),
+                sale.paid_amount.into(),
+                sale.change_amount.into(),
+                yes_no(sale.is_loan),
+                sale.points_amount.into(),
+            ]);
+        }
+        Ok(())
+    }
+
+    async fn operation_rows(
+        &self,
+        table: &mut ExportTable,
+        start: &str,
+        end: &str,
+        clock: &StoreClock,
+    ) -> Result<(), String> {
+        for operation in self.sale_repo.operations_between(start, end, None).await? {
+            table.push(vec![
+                operation.sale_id.into(),
+                local_time(&operation.recorded_at, clock),
+                business_date(operation.recorded_at, clock),
+                operation.product_id.into(),
+                operation.product_name.into(),
+                operation.quantity.into(),
+                operation.unit_abbreviation.into(),
+                operation.unit_price.into(),
+                operation.subtotal.into(),
+            ]);
+        }
+        Ok(())
+    }
+
+    /// Loans opened on the given business days, with their current balance
+    async fn loan_rows(
+        &self,
+        table: &mut ExportTable,
+        from: NaiveDate,
+        to: NaiveDate,
+        clock: &StoreClock,
+    ) -> Result<(), String> {
+        let statuses: HashMap<i32, String> = self
+            .catalog_repo
+            .get_loan_statuses()
+            .await?
+            .into_iter()
+            .map(|s| (s.id, s.description))
+            .collect();
+
+        let mut loans = self.loan_repo.list_all().await?;
+        loans.retain(|l| (from..=to).contains(&clock.business_date(l.created_at)));
+        loans.sort_by_key(|l| l.created_at);
+
+        for loan in loans {
+            table.push(vec![
+                loan.id.into(),
+                local_time(&loan.created_at, clock),
+                business_date(loan.created_at, clock),
+                loan.debtor_name.into(),
+                loan.debtor_phone.into(),
+                statuses.get(&loan.status_id).cloned().into(),
+                loan.total_debt.into(),
+                loan.paid_amount.into(),
+                loan.remaining_amount.into(),
+            ]);
+        }
+        Ok(())
+    }
+
+    async fn payment_rows(
+        &self,
+        table: &mut ExportTable,
+        start: &str,
+        end: &str,
+        clock: &StoreClock,
+    ) -> Result<(), String> {
+        let debtors: HashMap<String, String> = self
+            .loan_repo
+            .list_all()
+            .await?
+            .into_iter()
+            .map(|l| (l.id, l.debtor_name))
+            .collect();
+
+        for payment in self.loan_repo.payments_between(start, end).await? {
+            let debtor = debtors.get(&payment.loan_id).cloned();
+            table.push(vec![
+                payment.id.into(),
+                payment.loan_id.into(),
+                debtor.into(),
+                local_time(&payment.payment_date, clock),
+                business_date(payment.payment_date, clock),
+                payment.amount.into(),
+                payment.notes.into(),
+            ]);
+        }
+        Ok(())
+    }
+
+    /// Receipts, sales and adjustments merged into one ledger, oldest first
+    async fn movement_rows(
+        &self,
+        table: &mut ExportTable,
+        start: &str,
+        end: &str,
+        clock: &StoreClock,
+    ) -> Result<(), String> {
+        let reasons: HashMap<i32, String> = self
+            .catalog_repo
+            .get_adjustment_reasons()
+            .await?
+            .into_iter()
+            .map(|r| (r.id, r.description))
+            .collect();
+
+        let mut movements: Vec<(DateTime<Utc>, Vec<ExportValue>)> = Vec::new();
+
+        for received in self.lot_repo.list_received_between(start, end).await? {
+            let lot = received.lot;
+            movements.push((
+                lot.received_at,
+                movement_row(
+                    &lot.received_at,
+                    lot.product_id,
+                    received.product_name,
+                    "Receipt",
+                    lot.received_quantity,
+                    None,
+                    None,
+                    lot.lot_code,
+                    clock,
+                ),
+            ));
+        }
+
+        for operation in self.sale_repo.operations_between(start, end, None).await? {
+            movements.push((
+                operation.recorded_at,
+                movement_row(
+                    &operation.recorded_at,
+                    operation.product_id,
+                    operation.product_name,
+                    "Sale",
+                    -operation.quantity,
+                    None,
+                    None,
+                    Some(operation.sale_id),
+                    clock,
+                ),
+            ));
+        }
+
+        let adjustments = self.adjustment_repo.list_by_date_range(start, end).await?;
+        for adjustment in adjustments {
+            let reason = reasons
+                .get(&adjustment.reason_id)
+                .map(String::as_str)
+                .unwrap_or("Adjustment");
+            movements.push((
+                adjustment.adjusted_at,
+                movement_row(
+                    &adjustment.adjusted_at,
+                    adjustment.product_id,
+                    adjustment.product_name,
+                    reason,
+                    adjustment.quantity,
+                    adjustment.unit_cost,
+                    Some(adjustment.total_cost),
+                    adjustment.notes,
+                    clock,
+                ),
+            ));
+        }
+
+        movements.sort_by_key(|(moved_at, _)| *moved_at);
+        for (_, row) in movements {
+            table.push(row);
+        }
+        Ok(())
+    }
+
+    /// Current stock valued at cost and sale price; kits are valued through their components
+    async fn valuation_rows(&self, table: &mut ExportTable) -> Result<(), String> {
+        let units: HashMap<i32, String> = self
+            .catalog_repo
+            .get_units()
+            .await?
+            .into_iter()
+            .map(|u| (u.id, u.abbreviation))
+            .collect();
+
+        for product in self.product_repo.list_all().await? {
+            if product.is_kit {
+                continue;
+            }
+
+            // Parse the displayed amount so 0.1 kg is exactly 0.1
+            let stock: Decimal = product
+                .current_amount
+                .to_string()
+                .parse()
+                .unwrap_or_default();
+            let cost_value = product.cost_price.map(|cost| (cost * stock).round_dp(2));
+            let sale_value = (product.user_price * stock).round_dp(2);
+
+            table.push(vec![
+                product.id.into(),
+                product.barcode.into(),
+                product.full_name.into(),
+                units.get(&product.unit_measurement_id).cloned().into(),
+                product.current_amount.into(),
+                product.cost_price.into(),
+                cost_value.into(),
+                product.user_price.into(),
+                sale_value.into(),
+            ]);
+        }
+        Ok(())
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn movement_row(
+    moved_at: &DateTime<Utc>,
+    product_id: String,
+    product_name: String,
+    movement: &str,
+    quantity: f64,
+    unit_cost: Option<Decimal>,
+    total_cost: Option<Decimal>,
+    reference: Option<String>,
+    clock: &StoreClock,
+) -> Vec<ExportValue> {
+    vec![
+        local_time(moved_at, clock),
+        business_date(*moved_at, clock),
+        product_id.into(),
+        product_name.into(),
+        movement.into(),
+        quantity.into(),
+        unit_cost.into(),
+        total_cost.into(),
+        reference.into(),
+    ]
+}
+
+/// Store-local timestamp, e.g. "2026-10-18 14:05:00"
+fn local_time(instant: &DateTime<Utc>, clock: &StoreClock) -> ExportValue {
+    clock.format(instant, "%Y-%m-%d %H:%M:%S").into()
+}
+
+fn business_date(instant: DateTime<Utc>, clock: &StoreClock) -> ExportValue {
+    clock
+        .business_date(instant)
+        .format("%Y-%m-%d")
+        .to_string()
+        .into()
+}
+
+fn yes_no(value: bool) -> ExportValue {
+    if value { "yes" } else { "no" }.into()
+}
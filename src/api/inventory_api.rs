@@ -2,14 +2,37 @@
 //!
 //! Business logic for product and inventory management.
 
-use crate::models::{Product, ProductInput, UnitMeasurement};
-use crate::repo::{CatalogRepository, PaginatedResult, ProductRepository};
+use crate::models::pricing::round_to_ending;
+use crate::models::scale_barcode::find_scale_format;
+use crate::models::{
+    AdjustmentReason, BarcodeMatch, Category, CategoryInput, CategoryPath, ExpiringLot,
+    InventoryCount, InventoryCountLine, ItemCondition, KitComponent, KitComponentInput,
+    PriceChangeSource, PriceHistoryEntry, Product, ProductBarcode, ProductBarcodeInput,
+    ProductInput, ProductLot, RepriceInput, ScaleBarcodeFormat, StockAdjustment, StockReceiptInput,
+    StoreClock, Supplier, SupplierInput, UnitMeasurement, UnitMeasurementInput, WriteOffInput,
+};
+use crate::repo::{
+    AdjustmentRepository, CatalogRepository, CategoryRepository, InventoryCountRepository,
+    LotRepository, PaginatedResult, ProductRepository, SupplierRepository,
+};
+use crate::utils::ean13;
+use crate::utils::product_csv::{
+    build_product_input, mapped_value, write_products_csv, CsvTable, ProductColumn,
+};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct InventoryApi {
     product_repo: Arc<dyn ProductRepository>,
     catalog_repo: Arc<dyn CatalogRepository>,
+    category_repo: Arc<dyn CategoryRepository>,
+    lot_repo: Arc<dyn LotRepository>,
+    adjustment_repo: Arc<dyn AdjustmentRepository>,
+    count_repo: Arc<dyn InventoryCountRepository>,
+    supplier_repo: Arc<dyn SupplierRepository>,
 }
 
 impl std::fmt::Debug for InventoryApi {
@@ -22,6 +45,11 @@ impl PartialEq for InventoryApi {
     fn eq(&self, other: &Self) -> bool {
         Arc::ptr_eq(&self.product_repo, &other.product_repo)
             && Arc::ptr_eq(&self.catalog_repo, &other.catalog_repo)
+            && Arc::ptr_eq(&self.category_repo, &other.category_repo)
+            && Arc::ptr_eq(&self.lot_repo, &other.lot_repo)
+            && Arc::ptr_eq(&self.adjustment_repo, &other.adjustment_repo)
+            && Arc::ptr_eq(&self.count_repo, &other.count_repo)
+            && Arc::ptr_eq(&self.supplier_repo, &other.supplier_repo)
     }
 }
 
@@ -29,10 +57,20 @@ impl InventoryApi {
     pub fn new(
         product_repo: Arc<dyn ProductRepository>,
         catalog_repo: Arc<dyn CatalogRepository>,
+        category_repo: Arc<dyn CategoryRepository>,
+        lot_repo: Arc<dyn LotRepository>,
+        adjustment_repo: Arc<dyn AdjustmentRepository>,
+        count_repo: Arc<dyn InventoryCountRepository>,
+        supplier_repo: Arc<dyn SupplierRepository>,
     ) -> Self {
         Self {
             product_repo,
             catalog_repo,
+            category_repo,
+            lot_repo,
+            adjustment_repo,
+            count_repo,
+            supplier_repo,
         }
     }
 
@@ -58,10 +96,22 @@ impl InventoryApi {
         // Check for duplicate barcode if provided
         if let Some(ref barcode) = input.barcode {
             if !barcode.is_empty() && (self.product_repo.get_by_barcode(barcode).await?).is_some() {
-                return Err(format!("Product with barcode '{}' already exists", barcode));
+                return Err(format!(
+                    "Product with barcode '{}' already exists (it may be archived)",
+                    barcode
+                ));
             }
         }
 
+        if input.units_per_purchase <= 0.0 {
+            return Err("Units per purchase must be greater than zero".to_string());
+        }
+
+        self.validate_category_exists(input.category_id.as_deref())
+            .await?;
+        self.validate_supplier_exists(input.supplier_id.as_deref())
+            .await?;
+
         // Create product
         self.product_repo.create(input).await
     }
@@ -82,6 +132,179 @@ impl InventoryApi {
             .ok_or_else(|| format!("Product not found with barcode: {}", barcode))
     }
 
+    /// Resolve a scanned barcode to its product and pack quantity
+    ///
+    /// Archived products are not resolved, so they cannot be sold or counted.
+    pub async fn resolve_barcode(&self, barcode: &str) -> Result<BarcodeMatch, String> {
+        let barcode_match = self
+            .product_repo
+            .resolve_barcode(barcode.trim())
+            .await?
+            .ok_or_else(|| format!("Product not found with barcode: {}", barcode))?;
+
+        if barcode_match.product.is_archived() {
+            return Err(format!(
+                "Product '{}' is archived",
+                barcode_match.product.full_name
+            ));
+        }
+
+        Ok(barcode_match)
+    }
+
+    /// Resolve a barcode scanned at the register
+    ///
+    /// Codes registered on a product always win. Otherwise codes matching a
+    /// scale format are decoded: the PLU is looked up as a product barcode
+    /// (with or without leading zeros) and the embedded weight or price
+    /// becomes the quantity. Embedded prices are also charged as printed.
+    pub async fn resolve_sale_barcode(
+        &self,
+        barcode: &str,
+        scale_formats: &[ScaleBarcodeFormat],
+    ) -> Result<BarcodeMatch, String> {
+        let barcode = barcode.trim();
+        if let Some(barcode_match) = self.product_repo.resolve_barcode(barcode).await? {
+            if barcode_match.product.is_archived() {
+                return Err(format!(
+                    "Product '{}' is archived",
+                    barcode_match.product.full_name
+                ));
+            }
+            return Ok(barcode_match);
+        }
+
+        let scanned = match find_scale_format(scale_formats, barcode) {
+            Some(format) => format.decode(barcode)?,
+            None => None,
+        };
+        let Some(scanned) = scanned else {
+            return Err(format!("Product not found with barcode: {}", barcode));
+        };
+
+        let short_plu = scanned.plu.trim_start_matches('0');
+        let mut product = self.product_repo.get_by_barcode(&scanned.plu).await?;
+        if product.is_none() && !short_plu.is_empty() && short_plu != scanned.plu {
+            product = self.product_repo.get_by_barcode(short_plu).await?;
+        }
+        let product =
+            product.ok_or_else(|| format!("Product not found with PLU: {}", scanned.plu))?;
+
+        if product.is_archived() {
+            return Err(format!("Product '{}' is archived", product.full_name));
+        }
+
+        Ok(BarcodeMatch {
+            pack_quantity: scanned.quantity(product.user_price)?,
+            line_total: scanned.line_total(),
+            product,
+        })
+    }
+
+    /// List additional barcodes of a product
+    pub async fn list_product_barcodes(
+        &self,
+        product_id: &str,
+    ) -> Result<Vec<ProductBarcode>, String> {
+        self.product_repo.list_barcodes(product_id).await
+    }
+
+    /// Add an additional (e.g. pack-size) barcode to a product
+    pub async fn add_product_barcode(
+        &self,
+        product_id: &str,
+        input: ProductBarcodeInput,
+    ) -> Result<ProductBarcode, String> {
+        use crate::utils::validation::validate_barcode;
+
+        // Validate existence
+        let _ = self.get_product(product_id).await?;
+
+        let barcode = input.barcode.trim().to_string();
+        if barcode.is_empty() {
+            return Err("Barcode cannot be empty".to_string());
+        }
+        validate_barcode(&barcode)?;
+
+        if input.pack_quantity <= 0.0 {
+            return Err("Pack quantity must be greater than zero".to_string());
+        }
+
+        // Barcodes must be unique across own and additional codes
+        if let Some(existing) = self.product_repo.get_by_barcode(&barcode).await? {
+            return Err(format!(
+                "Barcode '{}' is already assigned to '{}'",
+                barcode, existing.full_name
+            ));
+        }
+
+        self.product_repo
+            .add_barcode(
+                product_id,
+                ProductBarcodeInput {
+                    barcode,
+                    pack_quantity: input.pack_quantity,
+                },
+            )
+            .await
+    }
+
+    /// Remove an additional barcode
+    pub async fn remove_product_barcode(&self, barcode: &str) -> Result<(), String> {
+        self.product_repo.remove_barcode(barcode).await
+    }
+
+    /// Next unused internal EAN-13 code
+    pub async fn next_internal_barcode(&self) -> Result<String, String> {
+        Ok(self.internal_barcodes(1).await?.remove(0))
+    }
+
+    /// Give every active product without a barcode an internal EAN-13 code
+    ///
+    /// Returns the number of products that received a code.
+    pub async fn generate_missing_barcodes(&self) -> Result<usize, String> {
+        let products: Vec<Product> = self
+            .product_repo
+            .list_all()
+            .await?
+            .into_iter()
+            .filter(|p| p.barcode.is_none())
+            .collect();
+        if products.is_empty() {
+            return Ok(0);
+        }
+
+        let codes = self.internal_barcodes(products.len()).await?;
+        let assignments: Vec<(String, String)> =
+            products.into_iter().map(|p| p.id).zip(codes).collect();
+        let assigned = assignments.len();
+
+        self.product_repo.assign_barcodes(assignments).await?;
+        Ok(assigned)
+    }
+
+    /// Allocate internal codes after the highest one in use, skipping any already taken
+    async fn internal_barcodes(&self, count: usize) -> Result<Vec<String>, String> {
+        let mut sequence = self
+            .product_repo
+            .last_barcode_with_prefix(ean13::INTERNAL_PREFIX)
+            .await?
+            .and_then(|code| ean13::internal_sequence(&code))
+            .unwrap_or(0);
+
+        let mut codes = Vec::with_capacity(count);
+        while codes.len() < count {
+            sequence += 1;
+            let code = ean13::internal_code(sequence)
+                .ok_or_else(|| "No internal barcodes left to assign".to_string())?;
+            if self.product_repo.get_by_barcode(&code).await?.is_none() {
+                codes.push(code);
+            }
+        }
+
+        Ok(codes)
+    }
+
     /// List all products
     pub async fn list_products(&self) -> Result<Vec<Product>, String> {
         self.product_repo.list_all().await
@@ -101,6 +324,9 @@ impl InventoryApi {
     }
 
     /// Update product with validation
+    ///
+    /// A changed stock amount is recorded as a count correction in the same
+    /// transaction, deducted from lots and valued at the saved cost.
     pub async fn update_product(&self, id: &str, input: ProductInput) -> Result<Product, String> {
         // Validate existence
         let _ = self.get_product(id).await?;
@@ -128,56 +354,530 @@ impl InventoryApi {
             }
         }
 
+        if input.units_per_purchase <= 0.0 {
+            return Err("Units per purchase must be greater than zero".to_string());
+        }
+
+        if input.current_amount < 0.0 {
+            return Err("Current amount cannot be negative".to_string());
+        }
+
+        self.validate_category_exists(input.category_id.as_deref())
+            .await?;
+        self.validate_supplier_exists(input.supplier_id.as_deref())
+            .await?;
+
         self.product_repo.update(id, input).await
     }
 
-    /// Delete product
+    /// Permanently delete a product
+    ///
+    /// Only products that were never sold nor adjusted can be deleted; the
+    /// rest must be archived so receipts and reports keep resolving them.
     pub async fn delete_product(&self, id: &str) -> Result<(), String> {
         // Validate existence
         let _ = self.get_product(id).await?;
 
+        if self.product_repo.has_history(id).await? {
+            return Err(
+                "This product has sales or stock adjustments; archive it instead".to_string(),
+            );
+        }
+        if self.product_repo.is_component(id).await? {
+            return Err("This product is part of a kit; remove it from the kit first".to_string());
+        }
+
         self.product_repo.delete(id).await
     }
 
-    /// Add stock to product
+    /// Archive a product, hiding it from sales and product lists
+    pub async fn archive_product(&self, id: &str) -> Result<Product, String> {
+        let product = self.get_product(id).await?;
+        if product.is_archived() {
+            return Err(format!(
+                "Product '{}' is already archived",
+                product.full_name
+            ));
+        }
+
+        self.product_repo.archive(id).await?;
+        self.get_product(id).await
+    }
+
+    /// Restore an archived product
+    pub async fn restore_product(&self, id: &str) -> Result<Product, String> {
+        let product = self.get_product(id).await?;
+        if !product.is_archived() {
+            return Err(format!("Product '{}' is not archived", product.full_name));
+        }
+
+        self.product_repo.restore(id).await?;
+        self.get_product(id).await
+    }
+
+    /// List archived products
+    pub async fn list_archived_products(&self) -> Result<Vec<Product>, String> {
+        self.product_repo.list_archived().await
+    }
+
+    /// Get a product that keeps its own stock (not a kit)
+    async fn get_stocked_product(&self, id: &str) -> Result<Product, String> {
+        let product = self.get_product(id).await?;
+        if product.is_kit {
+            return Err(format!(
+                "'{}' is a kit; adjust the stock of its components instead",
+                product.full_name
+            ));
+        }
+
+        Ok(product)
+    }
+
+    /// List the components of a kit
+    pub async fn list_kit_components(&self, kit_id: &str) -> Result<Vec<KitComponent>, String> {
+        self.product_repo.list_components(kit_id).await
+    }
+
+    /// Add a component to a kit, or change its quantity
+    ///
+    /// The first component turns the product into a kit, so it must not hold
+    /// stock of its own. Kits cannot contain other kits.
+    pub async fn set_kit_component(
+        &self,
+        kit_id: &str,
+        input: KitComponentInput,
+    ) -> Result<Vec<KitComponent>, String> {
+        if input.quantity <= 0.0 {
+            return Err("Component quantity must be positive".to_string());
+        }
+        if input.component_id == kit_id {
+            return Err("A kit cannot contain itself".to_string());
+        }
+
+        let kit = self.get_product(kit_id).await?;
+        if !kit.is_kit && kit.current_amount > 0.0 {
+            return Err(format!(
+                "'{}' still has {} in stock; sell or write it off before making it a kit",
+                kit.full_name, kit.current_amount
+            ));
+        }
+        if self.product_repo.is_component(kit_id).await? {
+            return Err(format!(
+                "'{}' is part of another kit and cannot be a kit itself",
+                kit.full_name
+            ));
+        }
+
+        let component = self.get_product(&input.component_id).await?;
+        if component.is_kit {
+            return Err(format!(
+                "'{}' is a kit and cannot be a component",
+                component.full_name
+            ));
+        }
+        if component.is_archived() {
+            return Err(format!("Product '{}' is archived", component.full_name));
+        }
+
+        self.product_repo.set_component(kit_id, input).await?;
+        self.list_kit_components(kit_id).await
+    }
+
+    /// Remove a component from a kit
+    pub async fn remove_kit_component(
+        &self,
+        kit_id: &str,
+        component_id: &str,
+    ) -> Result<Vec<KitComponent>, String> {
+        self.product_repo
+            .remove_component(kit_id, component_id)
+            .await?;
+        self.list_kit_components(kit_id).await
+    }
+
+    /// Add stock to product, received as a lot without code or expiry
     pub async fn add_stock(&self, id: &str, quantity: f64) -> Result<Product, String> {
         if quantity <= 0.0 {
             return Err("Quantity must be positive".to_string());
         }
 
-        let product = self.get_product(id).await?;
-        let new_amount = product.current_amount + quantity;
+        let _ = self.get_stocked_product(id).await?;
+
+        self.lot_repo
+            .receive(id, quantity, None, None, None)
+            .await?;
+        self.get_product(id).await
+    }
+
+    /// Receive a batch of stock counted in the product's purchase unit
+    ///
+    /// The quantity is converted to sale units (e.g. 2 boxes of 24 = 48 pcs)
+    /// and recorded as a lot with its optional code and expiry date.
+    pub async fn receive_stock(
+        &self,
+        id: &str,
+        input: StockReceiptInput,
+    ) -> Result<Product, String> {
+        if input.purchase_quantity <= 0.0 {
+            return Err("Quantity must be positive".to_string());
+        }
+
+        let product = self.get_stocked_product(id).await?;
+        let lot_code = input
+            .lot_code
+            .map(|code| code.trim().to_string())
+            .filter(|code| !code.is_empty());
+
+        // A receipt cost becomes the product's cost per sale unit
+        let price_change = match input.unit_cost {
+            Some(cost) if cost < Decimal::ZERO => {
+                return Err("Cost cannot be negative".to_string());
+            }
+            Some(cost) => Some(PriceHistoryEntry::new(
+                &product,
+                product.user_price,
+                Some(product.to_sale_unit_cost(cost)),
+                PriceChangeSource::RECEIVING,
+            ))
+            .filter(|entry| entry.is_change()),
+            None => None,
+        };
 
-        self.product_repo.update_stock(id, new_amount).await?;
+        self.lot_repo
+            .receive(
+                id,
+                product.to_sale_units(input.purchase_quantity),
+                lot_code,
+                input.expires_at,
+                price_change,
+            )
+            .await?;
         self.get_product(id).await
     }
 
-    /// Remove stock from product
-    pub async fn remove_stock(&self, id: &str, quantity: f64) -> Result<Product, String> {
+    /// List a product's price and cost changes, newest first
+    pub async fn list_price_history(
+        &self,
+        product_id: &str,
+    ) -> Result<Vec<PriceHistoryEntry>, String> {
+        self.product_repo.list_price_history(product_id).await
+    }
+
+    /// List all lots of a product
+    pub async fn list_product_lots(&self, product_id: &str) -> Result<Vec<ProductLot>, String> {
+        self.lot_repo.list_by_product(product_id).await
+    }
+
+    /// Get sellable lots expiring within the given number of days (including expired ones)
+    pub async fn get_expiring_lots(
+        &self,
+        days: i64,
+        today: NaiveDate,
+    ) -> Result<Vec<ExpiringLot>, String> {
+        if days < 0 {
+            return Err("Days cannot be negative".to_string());
+        }
+
+        let until = today + chrono::Duration::days(days);
+        self.lot_repo.list_expiring(until).await
+    }
+
+    /// Move part of a lot to the Damaged or Expired condition
+    ///
+    /// The quantity leaves sellable stock but the lot record is kept.
+    pub async fn move_lot_to_condition(
+        &self,
+        lot_id: &str,
+        quantity: f64,
+        condition_id: i32,
+    ) -> Result<ProductLot, String> {
+        if condition_id != ItemCondition::DAMAGED && condition_id != ItemCondition::EXPIRED {
+            return Err("Stock can only be moved to Damaged or Expired".to_string());
+        }
+
         if quantity <= 0.0 {
             return Err("Quantity must be positive".to_string());
         }
 
-        let product = self.get_product(id).await?;
-        let new_amount = product.current_amount - quantity;
+        let lot = self
+            .lot_repo
+            .get_by_id(lot_id)
+            .await?
+            .ok_or_else(|| format!("Lot not found: {}", lot_id))?;
+
+        if !lot.is_sellable() {
+            return Err("Only sellable lots can be moved".to_string());
+        }
+
+        if quantity > lot.remaining_quantity {
+            return Err(format!(
+                "Cannot move {}. Only {} left in this lot",
+                quantity, lot.remaining_quantity
+            ));
+        }
+
+        let product = self.get_product(&lot.product_id).await?;
+        let reason_id = if condition_id == ItemCondition::DAMAGED {
+            AdjustmentReason::DAMAGED
+        } else {
+            AdjustmentReason::EXPIRED
+        };
+        let adjustment = StockAdjustment::new(
+            &product.id,
+            &product.full_name,
+            -quantity,
+            product.cost_price,
+            reason_id,
+            lot.lot_code.as_ref().map(|code| format!("Lot {}", code)),
+        );
+
+        self.lot_repo
+            .move_to_condition(lot_id, quantity, condition_id, adjustment)
+            .await
+    }
+
+    /// Get shrinkage (stock adjustments valued at cost) for a period of business days, by reason
+    pub async fn get_shrinkage_report(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        clock: &StoreClock,
+    ) -> Result<ShrinkageReport, String> {
+        if end < start {
+            return Err("End date cannot be before start date".to_string());
+        }
+
+        let (start, end) = clock.range_bounds(start, end);
+
+        let adjustments = self
+            .adjustment_repo
+            .list_by_date_range(&start, &end)
+            .await?;
+        let reasons = self.catalog_repo.get_adjustment_reasons().await?;
 
-        if new_amount < 0.0 {
+        let by_reason = reasons
+            .iter()
+            .filter_map(|reason| {
+                let matching: Vec<&StockAdjustment> = adjustments
+                    .iter()
+                    .filter(|a| a.reason_id == reason.id)
+                    .collect();
+
+                if matching.is_empty() {
+                    return None;
+                }
+
+                Some(ShrinkageSummary {
+                    reason_id: reason.id,
+                    reason: reason.description.clone(),
+                    adjustment_count: matching.len(),
+                    total_quantity: matching.iter().map(|a| a.quantity).sum(),
+                    total_cost: matching.iter().map(|a| a.total_cost).sum(),
+                })
+            })
+            .collect();
+
+        let total_cost = adjustments.iter().map(|a| a.total_cost).sum();
+
+        Ok(ShrinkageReport {
+            by_reason,
+            adjustments,
+            total_cost,
+        })
+    }
+
+    /// Write off stock for a reason (damage, theft, internal use, ...)
+    ///
+    /// The removed quantity is valued at the product's current cost price.
+    pub async fn write_off_stock(&self, input: WriteOffInput) -> Result<StockAdjustment, String> {
+        if input.quantity <= 0.0 {
+            return Err("Quantity must be positive".to_string());
+        }
+
+        self.validate_adjustment_reason(input.reason_id).await?;
+
+        let product = self.get_stocked_product(&input.product_id).await?;
+        if input.quantity > product.current_amount {
             return Err("Insufficient stock".to_string());
         }
 
-        self.product_repo.update_stock(id, new_amount).await?;
-        self.get_product(id).await
+        let adjustment = StockAdjustment::new(
+            &product.id,
+            &product.full_name,
+            -input.quantity,
+            product.cost_price,
+            input.reason_id,
+            normalize_notes(input.notes),
+        );
+
+        self.adjustment_repo.create(adjustment).await
+    }
+
+    /// List all inventory counts, newest first
+    pub async fn list_counts(&self) -> Result<Vec<InventoryCount>, String> {
+        self.count_repo.list_all().await
+    }
+
+    /// Get an inventory count by ID
+    pub async fn get_count(&self, id: &str) -> Result<InventoryCount, String> {
+        self.count_repo
+            .get_by_id(id)
+            .await?
+            .ok_or_else(|| format!("Inventory count not found: {}", id))
+    }
+
+    /// Start a count, freezing the expected stock of every product
+    ///
+    /// Only one count can be open at a time.
+    pub async fn start_count(&self, name: &str) -> Result<InventoryCount, String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Count name cannot be empty".to_string());
+        }
+
+        let counts = self.count_repo.list_all().await?;
+        if let Some(open) = counts.iter().find(|c| c.is_open()) {
+            return Err(format!(
+                "Post or delete the open count \"{}\" first",
+                open.name
+            ));
+        }
+
+        self.count_repo.create(name).await
+    }
+
+    /// List the lines of a count
+    pub async fn list_count_lines(
+        &self,
+        count_id: &str,
+    ) -> Result<Vec<InventoryCountLine>, String> {
+        self.count_repo.list_lines(count_id).await
+    }
+
+    /// Set or clear the counted quantity of a product
+    pub async fn set_counted_quantity(
+        &self,
+        count_id: &str,
+        product_id: &str,
+        counted_quantity: Option<f64>,
+    ) -> Result<InventoryCountLine, String> {
+        self.get_open_count(count_id).await?;
+
+        if counted_quantity.is_some_and(|q| q < 0.0) {
+            return Err("Counted quantity cannot be negative".to_string());
+        }
+
+        self.count_repo
+            .set_counted(count_id, product_id, counted_quantity)
+            .await
+    }
+
+    /// Add a scanned barcode to the counted quantity of its product
+    ///
+    /// Pack barcodes add their pack quantity.
+    pub async fn record_count_scan(
+        &self,
+        count_id: &str,
+        barcode: &str,
+    ) -> Result<InventoryCountLine, String> {
+        self.get_open_count(count_id).await?;
+
+        let barcode_match = self.resolve_barcode(barcode).await?;
+        let line = self
+            .count_repo
+            .get_line(count_id, &barcode_match.product.id)
+            .await?
+            .ok_or_else(|| {
+                format!(
+                    "{} was added after this count started",
+                    barcode_match.product.full_name
+                )
+            })?;
+
+        let counted = line.counted_quantity.unwrap_or(0.0) + barcode_match.pack_quantity;
+        self.count_repo
+            .set_counted(count_id, &line.product_id, Some(counted))
+            .await
+    }
+
+    /// Approve or reject posting a line's variance
+    pub async fn set_count_line_approved(
+        &self,
+        count_id: &str,
+        product_id: &str,
+        approved: bool,
+    ) -> Result<(), String> {
+        self.get_open_count(count_id).await?;
+        self.count_repo
+            .set_approved(count_id, product_id, approved)
+            .await
+    }
+
+    /// Post the approved variances of a count as count corrections
+    ///
+    /// Each variance is applied on top of the current stock, so sales made
+    /// while counting are kept. Uncounted products are left untouched.
+    pub async fn post_count(&self, count_id: &str) -> Result<Vec<StockAdjustment>, String> {
+        let count = self.get_open_count(count_id).await?;
+        let lines = self.count_repo.list_lines(count_id).await?;
+
+        let notes = format!("Count {}", count.name);
+        let adjustments: Vec<StockAdjustment> = lines
+            .iter()
+            .filter(|line| line.is_postable())
+            .map(|line| {
+                StockAdjustment::new(
+                    &line.product_id,
+                    &line.product_name,
+                    line.variance().unwrap_or_default(),
+                    line.unit_cost,
+                    AdjustmentReason::COUNT_CORRECTION,
+                    Some(notes.clone()),
+                )
+            })
+            .collect();
+
+        self.count_repo.post(count_id, adjustments.clone()).await?;
+        Ok(adjustments)
     }
 
-    /// Set stock amount directly
+    /// Delete an open count without touching stock
+    pub async fn delete_count(&self, count_id: &str) -> Result<(), String> {
+        self.get_open_count(count_id).await?;
+        self.count_repo.delete(count_id).await
+    }
+
+    /// Get a count, rejecting counts that were already posted
+    async fn get_open_count(&self, count_id: &str) -> Result<InventoryCount, String> {
+        let count = self.get_count(count_id).await?;
+        if !count.is_open() {
+            return Err("Inventory count is already posted".to_string());
+        }
+
+        Ok(count)
+    }
+
+    /// Set stock amount directly, recording the difference as a count correction
     pub async fn set_stock(&self, id: &str, amount: f64) -> Result<Product, String> {
         if amount < 0.0 {
             return Err("Stock amount cannot be negative".to_string());
         }
 
-        let _ = self.get_product(id).await?;
+        let product = self.get_stocked_product(id).await?;
+        let difference = amount - product.current_amount;
+        if difference == 0.0 {
+            return Ok(product);
+        }
 
-        self.product_repo.update_stock(id, amount).await?;
+        let adjustment = StockAdjustment::new(
+            &product.id,
+            &product.full_name,
+            difference,
+            product.cost_price,
+            AdjustmentReason::COUNT_CORRECTION,
+            None,
+        );
+        self.adjustment_repo.create(adjustment).await?;
         self.get_product(id).await
     }
 
@@ -190,10 +890,11 @@ impl InventoryApi {
         self.product_repo.search(query).await
     }
 
-    /// Search products with pagination
+    /// Search products with pagination, optionally filtered by category
     pub async fn search_products_paginated(
         &self,
         query: &str,
+        category_id: Option<&str>,
         page: i64,
         page_size: i64,
     ) -> Result<PaginatedResult<Product>, String> {
@@ -201,30 +902,116 @@ impl InventoryApi {
             return Err("Page size must be between 1 and 100".to_string());
         }
 
-        if query.trim().is_empty() {
+        if query.trim().is_empty() && category_id.is_none() {
             return self.list_products_paginated(page, page_size).await;
         }
 
         self.product_repo
-            .search_paginated(query, page, page_size)
+            .search_paginated(query, category_id, page, page_size)
             .await
     }
 
+    /// List products in a category (including subcategories), or all
+    /// products when no category is given
+    pub async fn list_products_by_category(
+        &self,
+        category_id: Option<&str>,
+    ) -> Result<Vec<Product>, String> {
+        match category_id {
+            Some(category_id) => self.product_repo.list_by_category(category_id).await,
+            None => self.list_products().await,
+        }
+    }
+
     /// Get low stock products
     pub async fn get_low_stock_products(&self) -> Result<Vec<Product>, String> {
         self.product_repo.get_low_stock().await
     }
 
-    /// Get inventory statistics
-    pub async fn get_inventory_stats(&self) -> Result<InventoryStats, String> {
-        let products = self.product_repo.list_all().await?;
-        let low_stock = self.product_repo.get_low_stock().await?;
+    /// Preview a bulk price change without saving it
+    pub async fn preview_reprice(&self, input: &RepriceInput) -> Result<Vec<RepriceLine>, String> {
+        let products = self.select_reprice_products(input).await?;
+
+        Ok(products
+            .into_iter()
+            .map(|product| RepriceLine {
+                new_price: repriced(&product, input),
+                product_id: product.id,
+                product_name: product.full_name,
+                cost_price: product.cost_price,
+                old_price: product.user_price,
+            })
+            .collect())
+    }
+
+    /// Apply a bulk price change atomically, recording it in the price history
+    ///
+    /// Products the rule cannot price (e.g. a target margin without cost) are
+    /// left unchanged. Returns how many prices changed.
+    pub async fn apply_reprice(&self, input: &RepriceInput) -> Result<usize, String> {
+        let products = self.select_reprice_products(input).await?;
+
+        let entries: Vec<PriceHistoryEntry> = products
+            .iter()
+            .filter_map(|product| {
+                repriced(product, input).map(|new_price| {
+                    PriceHistoryEntry::new(
+                        product,
+                        new_price,
+                        product.cost_price,
+                        PriceChangeSource::BULK_UPDATE,
+                    )
+                })
+            })
+            .filter(|entry| entry.is_change())
+            .collect();
+
+        let changed = entries.len();
+        if changed > 0 {
+            self.product_repo.update_prices(entries).await?;
+        }
+
+        Ok(changed)
+    }
+
+    /// Products matching the category, supplier and search filters of a reprice
+    async fn select_reprice_products(&self, input: &RepriceInput) -> Result<Vec<Product>, String> {
+        let mut products = self
+            .list_products_by_category(input.category_id.as_deref())
+            .await?;
+
+        if let Some(ref supplier_id) = input.supplier_id {
+            products.retain(|p| p.supplier_id.as_ref() == Some(supplier_id));
+        }
+
+        if !input.query.trim().is_empty() {
+            let matches: HashSet<String> = self
+                .product_repo
+                .search(input.query.trim())
+                .await?
+                .into_iter()
+                .map(|p| p.id)
+                .collect();
+            products.retain(|p| matches.contains(&p.id));
+        }
+
+        Ok(products)
+    }
+
+    /// Get inventory statistics, optionally scoped to a category
+    pub async fn get_inventory_stats(
+        &self,
+        category_id: Option<&str>,
+    ) -> Result<InventoryStats, String> {
+        let products = self.list_products_by_category(category_id).await?;
 
         let total_products = products.len();
-        let low_stock_count = low_stock.len();
+        let low_stock_count = products.iter().filter(|p| p.is_low_stock()).count();
 
+        // Kit stock is already valued through its components
         let total_value = products
             .iter()
+            .filter(|p| !p.is_kit)
             .map(|p| {
                 p.user_price
                     * rust_decimal::Decimal::from_f64_retain(p.current_amount).unwrap_or_default()
@@ -233,8 +1020,8 @@ impl InventoryApi {
 
         let total_cost = products
             .iter()
-            .filter_map(|p| p.cost_price)
-            .zip(products.iter().map(|p| p.current_amount))
+            .filter(|p| !p.is_kit)
+            .filter_map(|p| p.cost_price.map(|cost| (cost, p.current_amount)))
             .map(|(cost, amount)| {
                 cost * rust_decimal::Decimal::from_f64_retain(amount).unwrap_or_default()
             })
@@ -247,10 +1034,374 @@ impl InventoryApi {
             total_cost,
         })
     }
+
+    /// Dry-run a CSV import, reporting what each row would do
+    ///
+    /// Rows are matched to existing products by barcode; matched products are
+    /// updated, keeping their stock, and the rest are created.
+    pub async fn preview_product_import(
+        &self,
+        table: &CsvTable,
+        mapping: &[Option<ProductColumn>],
+    ) -> Result<Vec<ImportRow>, String> {
+        if !mapping.contains(&Some(ProductColumn::Name))
+            && !mapping.contains(&Some(ProductColumn::Barcode))
+        {
+            return Err("Map at least a name or a barcode column".to_string());
+        }
+
+        let units = self.catalog_repo.get_units().await?;
+        let categories = self.list_categories().await?;
+
+        let mut seen_barcodes: HashMap<String, usize> = HashMap::new();
+        let mut rows = Vec::with_capacity(table.rows.len());
+
+        for (index, row) in table.rows.iter().enumerate() {
+            let line = index + 2; // Line 1 is the header
+            let barcode = mapped_value(row, mapping, ProductColumn::Barcode).map(str::to_string);
+            let name = mapped_value(row, mapping, ProductColumn::Name)
+                .unwrap_or_default()
+                .to_string();
+
+            let action = match self
+                .plan_import_row(row, mapping, barcode.as_deref(), &units, &categories)
+                .await
+            {
+                Ok(action) => action,
+                Err(err) => ImportAction::Error(err),
+            };
+
+            // The same barcode twice in the file would create a duplicate
+            let action = match barcode.as_ref().and_then(|b| seen_barcodes.get(b)) {
+                Some(first_line) => {
+                    ImportAction::Error(format!("Barcode already used on line {}", first_line))
+                }
+                None => action,
+            };
+            if let Some(ref barcode) = barcode {
+                seen_barcodes.entry(barcode.clone()).or_insert(line);
+            }
+
+            let name = match &action {
+                ImportAction::Create(input) | ImportAction::Update { input, .. } => {
+                    input.full_name.clone()
+                }
+                ImportAction::Error(_) => name,
+            };
+
+            rows.push(ImportRow {
+                line,
+                name,
+                barcode,
+                action,
+            });
+        }
+
+        Ok(rows)
+    }
+
+    /// Import products from CSV, creating or updating each valid row
+    ///
+    /// Rows are imported independently; rows with errors are skipped and
+    /// reported in the summary.
+    pub async fn import_products(
+        &self,
+        table: &CsvTable,
+        mapping: &[Option<ProductColumn>],
+    ) -> Result<ImportSummary, String> {
+        let rows = self.preview_product_import(table, mapping).await?;
+        let mut summary = ImportSummary::default();
+
+        for row in rows {
+            let result = match row.action {
+                ImportAction::Create(input) => self
+                    .create_product(input)
+                    .await
+                    .map(|_| summary.created += 1),
+                ImportAction::Update { product_id, input } => self
+                    .update_product(&product_id, input)
+                    .await
+                    .map(|_| summary.updated += 1),
+                ImportAction::Error(err) => Err(err),
+            };
+
+            if let Err(err) = result {
+                summary.errors.push((row.line, err));
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Export the full product catalog as CSV
+    pub async fn export_products_csv(&self) -> Result<String, String> {
+        let products = self.product_repo.list_all().await?;
+        let units = self.catalog_repo.get_units().await?;
+        let categories = self.list_categories().await?;
+
+        write_products_csv(&products, &units, &categories)
+    }
+
+    /// Decide whether a CSV row creates or updates a product
+    async fn plan_import_row(
+        &self,
+        row: &[String],
+        mapping: &[Option<ProductColumn>],
+        barcode: Option<&str>,
+        units: &[UnitMeasurement],
+        categories: &[CategoryPath],
+    ) -> Result<ImportAction, String> {
+        let existing = match barcode {
+            Some(barcode) => self.product_repo.get_by_barcode(barcode).await?,
+            None => None,
+        };
+
+        match existing {
+            // Additional barcodes identify packs, not the product record
+            Some(product) if product.barcode.as_deref() != barcode => Err(format!(
+                "Barcode is an additional code of '{}'",
+                product.full_name
+            )),
+            Some(product) => {
+                let input = build_product_input(row, mapping, Some(&product), units, categories)?;
+                Ok(ImportAction::Update {
+                    product_id: product.id,
+                    input,
+                })
+            }
+            None => {
+                let input = build_product_input(row, mapping, None, units, categories)?;
+                Ok(ImportAction::Create(input))
+            }
+        }
+    }
+
     /// Get all unit measurements
     pub async fn get_units(&self) -> Result<Vec<UnitMeasurement>, String> {
         self.catalog_repo.get_units().await
     }
+
+    /// Create a unit measurement with validation
+    pub async fn create_unit(
+        &self,
+        input: UnitMeasurementInput,
+    ) -> Result<UnitMeasurement, String> {
+        let input = self.validate_unit_input(None, input).await?;
+        self.catalog_repo.create_unit(input).await
+    }
+
+    /// Update a unit measurement with validation
+    ///
+    /// Fraction rules apply to quantities entered from now on; existing stock
+    /// and past sales are left untouched.
+    pub async fn update_unit(
+        &self,
+        id: i32,
+        input: UnitMeasurementInput,
+    ) -> Result<UnitMeasurement, String> {
+        self.catalog_repo
+            .get_unit(id)
+            .await?
+            .ok_or_else(|| format!("Unit not found: {}", id))?;
+
+        let input = self.validate_unit_input(Some(id), input).await?;
+        self.catalog_repo.update_unit(id, input).await
+    }
+
+    /// Delete a unit measurement no product uses
+    pub async fn delete_unit(&self, id: i32) -> Result<(), String> {
+        let unit = self
+            .catalog_repo
+            .get_unit(id)
+            .await?
+            .ok_or_else(|| format!("Unit not found: {}", id))?;
+
+        let products = self.catalog_repo.count_unit_products(id).await?;
+        if products > 0 {
+            return Err(format!(
+                "Cannot delete unit '{}': {} products use it",
+                unit.description, products
+            ));
+        }
+
+        self.catalog_repo.delete_unit(id).await
+    }
+
+    /// Validate unit data and ensure the abbreviation is unique
+    async fn validate_unit_input(
+        &self,
+        id: Option<i32>,
+        input: UnitMeasurementInput,
+    ) -> Result<UnitMeasurementInput, String> {
+        let input = input.validate()?;
+
+        let units = self.catalog_repo.get_units().await?;
+        if units
+            .iter()
+            .any(|u| Some(u.id) != id && u.abbreviation.eq_ignore_ascii_case(&input.abbreviation))
+        {
+            return Err(format!(
+                "A unit with abbreviation '{}' already exists",
+                input.abbreviation
+            ));
+        }
+
+        Ok(input)
+    }
+
+    /// Get all item conditions
+    pub async fn get_conditions(&self) -> Result<Vec<ItemCondition>, String> {
+        self.catalog_repo.get_conditions().await
+    }
+
+    /// Get all stock adjustment reasons
+    pub async fn get_adjustment_reasons(&self) -> Result<Vec<AdjustmentReason>, String> {
+        self.catalog_repo.get_adjustment_reasons().await
+    }
+
+    /// Get all price change sources
+    pub async fn get_price_change_sources(&self) -> Result<Vec<PriceChangeSource>, String> {
+        self.catalog_repo.get_price_change_sources().await
+    }
+
+    /// Validate that an adjustment reason exists in the catalog
+    async fn validate_adjustment_reason(&self, reason_id: i32) -> Result<(), String> {
+        let reasons = self.catalog_repo.get_adjustment_reasons().await?;
+        if reasons.iter().any(|r| r.id == reason_id) {
+            Ok(())
+        } else {
+            Err(format!("Unknown adjustment reason: {}", reason_id))
+        }
+    }
+
+    /// List all categories with their full paths, sorted by path
+    pub async fn list_categories(&self) -> Result<Vec<CategoryPath>, String> {
+        let categories = self.category_repo.list_all().await?;
+        Ok(Category::build_paths(&categories))
+    }
+
+    /// Create a new category with validation
+    pub async fn create_category(&self, input: CategoryInput) -> Result<Category, String> {
+        let input = self.validate_category_input(None, input).await?;
+        self.category_repo.create(input).await
+    }
+
+    /// Update a category with validation
+    pub async fn update_category(
+        &self,
+        id: &str,
+        input: CategoryInput,
+    ) -> Result<Category, String> {
+        // Validate existence
+        self.category_repo
+            .get_by_id(id)
+            .await?
+            .ok_or_else(|| format!("Category not found: {}", id))?;
+
+        let input = self.validate_category_input(Some(id), input).await?;
+        self.category_repo.update(id, input).await
+    }
+
+    /// Delete a category (its products become uncategorized)
+    pub async fn delete_category(&self, id: &str) -> Result<(), String> {
+        let categories = self.category_repo.list_all().await?;
+
+        if !categories.iter().any(|c| c.id == id) {
+            return Err(format!("Category not found: {}", id));
+        }
+
+        if categories
+            .iter()
+            .any(|c| c.parent_id.as_deref() == Some(id))
+        {
+            return Err("Cannot delete a category that has subcategories".to_string());
+        }
+
+        self.category_repo.delete(id).await
+    }
+
+    /// Validate category name and parent, rejecting cycles on update
+    async fn validate_category_input(
+        &self,
+        id: Option<&str>,
+        input: CategoryInput,
+    ) -> Result<CategoryInput, String> {
+        let name = input.name.trim().to_string();
+        if name.is_empty() {
+            return Err("Category name cannot be empty".to_string());
+        }
+
+        let parent_id = input.parent_id.filter(|p| !p.is_empty());
+
+        if let Some(ref parent_id) = parent_id {
+            let categories = self.category_repo.list_all().await?;
+
+            if !categories.iter().any(|c| &c.id == parent_id) {
+                return Err(format!("Parent category not found: {}", parent_id));
+            }
+
+            // A category cannot be moved under itself or one of its descendants
+            if let Some(id) = id {
+                if Category::subtree_ids(&categories, id).contains(parent_id) {
+                    return Err(
+                        "A category cannot be placed under itself or its subcategories".to_string(),
+                    );
+                }
+            }
+        }
+
+        Ok(CategoryInput { name, parent_id })
+    }
+
+    /// List all suppliers
+    pub async fn list_suppliers(&self) -> Result<Vec<Supplier>, String> {
+        self.supplier_repo.list_all().await
+    }
+
+    /// Create a new supplier with validation
+    pub async fn create_supplier(&self, input: SupplierInput) -> Result<Supplier, String> {
+        let input = validate_supplier_input(input)?;
+        self.supplier_repo.create(input).await
+    }
+
+    /// Update a supplier with validation
+    pub async fn update_supplier(
+        &self,
+        id: &str,
+        input: SupplierInput,
+    ) -> Result<Supplier, String> {
+        let input = validate_supplier_input(input)?;
+        self.supplier_repo.update(id, input).await
+    }
+
+    /// Delete a supplier (its products are left without supplier)
+    pub async fn delete_supplier(&self, id: &str) -> Result<(), String> {
+        self.supplier_repo.delete(id).await
+    }
+
+    /// Ensure an optional supplier reference points to an existing supplier
+    async fn validate_supplier_exists(&self, supplier_id: Option<&str>) -> Result<(), String> {
+        if let Some(supplier_id) = supplier_id {
+            let suppliers = self.supplier_repo.list_all().await?;
+            if !suppliers.iter().any(|s| s.id == supplier_id) {
+                return Err(format!("Supplier not found: {}", supplier_id));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ensure an optional category reference points to an existing category
+    async fn validate_category_exists(&self, category_id: Option<&str>) -> Result<(), String> {
+        if let Some(category_id) = category_id {
+            self.category_repo
+                .get_by_id(category_id)
+                .await?
+                .ok_or_else(|| format!("Category not found: {}", category_id))?;
+        }
+
+        Ok(())
+    }
 }
 
 /// Inventory statistics
@@ -261,3 +1412,484 @@ pub struct InventoryStats {
     pub total_value: rust_decimal::Decimal,
     pub total_cost: rust_decimal::Decimal,
 }
+
+/// Shrinkage totals for one adjustment reason
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShrinkageSummary {
+    pub reason_id: i32,
+    pub reason: String,
+    pub adjustment_count: usize,
+    pub total_quantity: f64, // Net signed quantity (negative for losses)
+    pub total_cost: Decimal, // Net signed value at cost
+}
+
+/// Shrinkage report for a period
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShrinkageReport {
+    pub by_reason: Vec<ShrinkageSummary>,
+    pub adjustments: Vec<StockAdjustment>,
+    pub total_cost: Decimal,
+}
+
+/// What importing a CSV row will do
+#[derive(Debug, Clone)]
+pub enum ImportAction {
+    Create(ProductInput),
+    Update {
+        product_id: String,
+        input: ProductInput,
+    },
+    Error(String),
+}
+
+/// Dry-run result for one CSV row
+#[derive(Debug, Clone)]
+pub struct ImportRow {
+    pub line: usize, // Line number in the file, counting the header
+    pub name: String,
+    pub barcode: Option<String>,
+    pub action: ImportAction,
+}
+
+/// Outcome of a CSV import
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ImportSummary {
+    pub created: usize,
+    pub updated: usize,
+    pub errors: Vec<(usize, String)>, // (line, error)
+}
+
+/// Old and new price of one product in a bulk update
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepriceLine {
+    pub product_id: String,
+    pub product_name: String,
+    pub cost_price: Option<Decimal>,
+    pub old_price: Decimal,
+    pub new_price: Option<Decimal>, // None when the rule cannot price the product
+}
+
+impl RepriceLine {
+    /// Check if applying the update changes this product's price
+    pub fn is_change(&self) -> bool {
+        self.new_price.is_some_and(|price| price != self.old_price)
+    }
+}
+
+/// New price of a product under a bulk update, rounded to the allowed endings
+fn repriced(product: &Product, input: &RepriceInput) -> Option<Decimal> {
+    input
+        .rule
+        .apply(product.user_price, product.cost_price)
+        .map(|price| round_to_ending(price, &input.endings))
+        .filter(|price| *price > Decimal::ZERO)
+}
+
+/// Trim supplier fields, treating a blank phone as absent
+fn validate_supplier_input(input: SupplierInput) -> Result<SupplierInput, String> {
+    let name = input.name.trim().to_string();
+    if name.is_empty() {
+        return Err("Supplier name cannot be empty".to_string());
+    }
+
+    let phone = input
+        .phone
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty());
+
+    if input.lead_time_days < 0 {
+        return Err("Lead time cannot be negative".to_string());
+    }
+
+    Ok(SupplierInput {
+        name,
+        phone,
+        lead_time_days: input.lead_time_days,
+    })
+}
+
+/// Trim notes, treating blank notes as absent
+fn normalize_notes(notes: Option<String>) -> Option<String> {
+    notes
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::pricing::PriceRule;
+    use crate::utils::test_store::{cash_sale, product_input, sale_item, TestStore};
+    use rust_decimal_macros::dec;
+
+    /// Ten units at $10 with a $6 cost
+    fn costed_input(name: &str, barcode: Option<&str>) -> ProductInput {
+        ProductInput {
+            barcode: barcode.map(str::to_string),
+            cost_price: Some(dec!(6)),
+            ..product_input(name, dec!(10), 10.0)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_internal_barcodes_skip_foreign_and_taken_codes() {
+        let api = TestStore::new().await.inventory;
+        let first = ean13::internal_code(1).unwrap();
+        // A digit code with a wrong check digit is the highest, so the sequence restarts
+        let fifth = ean13::internal_code(5).unwrap();
+        let check = fifth[12..].parse::<u32>().unwrap();
+        let invalid = format!("{}{}", &fifth[..12], (check + 1) % 10);
+
+        for (name, barcode) in [
+            ("Internal", first.as_str()),
+            ("Text code", "200XYZ0000001"),
+            ("Misprint", invalid.as_str()),
+        ] {
+            api.product_repo
+                .create(costed_input(name, Some(barcode)))
+                .await
+                .unwrap();
+        }
+
+        let next = api.next_internal_barcode().await.unwrap();
+        assert_eq!(next, ean13::internal_code(2).unwrap());
+
+        api.product_repo
+            .create(costed_input("No code", None))
+            .await
+            .unwrap();
+        assert_eq!(api.generate_missing_barcodes().await.unwrap(), 1);
+        assert!(api
+            .product_repo
+            .get_by_barcode(&next)
+            .await
+            .unwrap()
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn test_pack_barcode_resolves_to_pack_quantity() {
+        let api = TestStore::new().await.inventory;
+        let soda = api
+            .create_product(costed_input("Soda", Some("7501000000017")))
+            .await
+            .unwrap();
+        let pack_code = ean13::internal_code(12).unwrap();
+        api.add_product_barcode(
+            &soda.id,
+            ProductBarcodeInput {
+                barcode: pack_code.clone(),
+                pack_quantity: 12.0,
+            },
+        )
+        .await
+        .unwrap();
+
+        let single = api
+            .resolve_sale_barcode("7501000000017", &[])
+            .await
+            .unwrap();
+        assert_eq!(
+            (single.product.id.as_str(), single.pack_quantity),
+            (soda.id.as_str(), 1.0)
+        );
+
+        let pack = api.resolve_sale_barcode(&pack_code, &[]).await.unwrap();
+        assert_eq!(pack.product.id, soda.id);
+        assert_eq!(pack.pack_quantity, 12.0);
+        assert_eq!(pack.line_total, None);
+
+        let err = api
+            .resolve_sale_barcode("7509999999994", &[])
+            .await
+            .unwrap_err();
+        assert_eq!(err, "Product not found with barcode: 7509999999994");
+        assert!(api.resolve_barcode("7509999999994").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_write_offs_are_valued_at_cost() {
+        let api = TestStore::new().await.inventory;
+        let milk = api
+            .create_product(costed_input("Milk", None))
+            .await
+            .unwrap();
+
+        let adjustment = api
+            .write_off_stock(WriteOffInput {
+                product_id: milk.id.clone(),
+                quantity: 2.5,
+                reason_id: AdjustmentReason::THEFT,
+                notes: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(adjustment.quantity, -2.5);
+        assert_eq!(adjustment.unit_cost, Some(dec!(6)));
+        assert_eq!(adjustment.total_cost, dec!(-15));
+        assert_eq!(api.get_product(&milk.id).await.unwrap().current_amount, 7.5);
+
+        let err = api
+            .write_off_stock(WriteOffInput {
+                product_id: milk.id.clone(),
+                quantity: 8.0,
+                reason_id: AdjustmentReason::THEFT,
+                notes: None,
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err, "Insufficient stock");
+    }
+
+    #[tokio::test]
+    async fn test_lot_condition_move_is_limited_to_remaining_quantity() {
+        let api = TestStore::new().await.inventory;
+        let milk = api
+            .create_product(costed_input("Milk", None))
+            .await
+            .unwrap();
+        api.receive_stock(
+            &milk.id,
+            StockReceiptInput {
+                purchase_quantity: 5.0,
+                lot_code: Some("A1".to_string()),
+                expires_at: None,
+                unit_cost: None,
+            },
+        )
+        .await
+        .unwrap();
+        let lot = api.list_product_lots(&milk.id).await.unwrap().remove(0);
+
+        let err = api
+            .move_lot_to_condition(&lot.id, 6.0, ItemCondition::DAMAGED)
+            .await
+            .unwrap_err();
+        assert_eq!(err, "Cannot move 6. Only 5 left in this lot");
+
+        let damaged = api
+            .move_lot_to_condition(&lot.id, 2.0, ItemCondition::DAMAGED)
+            .await
+            .unwrap();
+        assert_eq!(
+            (damaged.remaining_quantity, damaged.condition_id),
+            (2.0, ItemCondition::DAMAGED)
+        );
+        let lot = api.lot_repo.get_by_id(&lot.id).await.unwrap().unwrap();
+        assert_eq!(lot.remaining_quantity, 3.0);
+        assert_eq!(
+            api.get_product(&milk.id).await.unwrap().current_amount,
+            13.0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_price_changes_record_old_and_new_values() {
+        let api = TestStore::new().await.inventory;
+        let milk = api
+            .create_product(costed_input("Milk", None))
+            .await
+            .unwrap();
+
+        let changed = api
+            .apply_reprice(&RepriceInput {
+                category_id: None,
+                supplier_id: None,
+                query: String::new(),
+                rule: PriceRule::Percentage(dec!(10)),
+                endings: Vec::new(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(changed, 1);
+
+        api.receive_stock(
+            &milk.id,
+            StockReceiptInput {
+                purchase_quantity: 5.0,
+                lot_code: None,
+                expires_at: None,
+                unit_cost: Some(dec!(8)),
+            },
+        )
+        .await
+        .unwrap();
+
+        let product = api.get_product(&milk.id).await.unwrap();
+        assert_eq!(
+            (product.user_price, product.cost_price),
+            (dec!(11), Some(dec!(8)))
+        );
+
+        let history = api.list_price_history(&milk.id).await.unwrap();
+        let change = |source_id: i32| {
+            let entry = history.iter().find(|e| e.source_id == source_id).unwrap();
+            (
+                entry.old_price,
+                entry.new_price,
+                entry.old_cost,
+                entry.new_cost,
+            )
+        };
+        assert_eq!(history.len(), 2);
+        assert_eq!(
+            change(PriceChangeSource::BULK_UPDATE),
+            (dec!(10), dec!(11), Some(dec!(6)), Some(dec!(6)))
+        );
+        assert_eq!(
+            change(PriceChangeSource::RECEIVING),
+            (dec!(11), dec!(11), Some(dec!(6)), Some(dec!(8)))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_products_with_history_are_archived_not_deleted() {
+        let fx = TestStore::new().await;
+        let create =
+            |name: &str, stock: f64| fx.products.create(product_input(name, dec!(10), stock));
+        let sold = create("Sold", 5.0).await.unwrap();
+        let component = create("Component", 5.0).await.unwrap();
+        let kit = create("Kit", 0.0).await.unwrap();
+        let unused = create("Unused", 5.0).await.unwrap();
+
+        fx.sell(cash_sale(vec![sale_item(&sold, 1.0, dec!(10))], None))
+            .await
+            .unwrap();
+        fx.inventory
+            .set_kit_component(
+                &kit.id,
+                KitComponentInput {
+                    component_id: component.id.clone(),
+                    quantity: 2.0,
+                },
+            )
+            .await
+            .unwrap();
+
+        let err = fx.inventory.delete_product(&sold.id).await.unwrap_err();
+        assert_eq!(
+            err,
+            "This product has sales or stock adjustments; archive it instead"
+        );
+        let err = fx
+            .inventory
+            .delete_product(&component.id)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err,
+            "This product is part of a kit; remove it from the kit first"
+        );
+        fx.inventory.delete_product(&unused.id).await.unwrap();
+
+        let archived = fx.inventory.archive_product(&sold.id).await.unwrap();
+        assert!(archived.is_archived());
+        assert!(fx.inventory.archive_product(&component.id).await.is_ok());
+
+        // Nor can a kit built from an archived product
+        let err = fx
+            .sell(cash_sale(vec![sale_item(&kit, 1.0, dec!(10))], None))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err,
+            "Kit 'Kit' contains archived product 'Component' and cannot be sold"
+        );
+
+        let err = fx
+            .sell(cash_sale(vec![sale_item(&sold, 1.0, dec!(10))], None))
+            .await
+            .unwrap_err();
+        assert_eq!(err, "Product 'Sold' is archived and cannot be sold");
+    }
+
+    #[tokio::test]
+    async fn test_stock_edits_go_through_lots() {
+        let api = TestStore::new().await.inventory;
+        let milk = api
+            .create_product(costed_input("Milk", None))
+            .await
+            .unwrap();
+        api.add_stock(&milk.id, 5.0).await.unwrap();
+        let lot = api.list_product_lots(&milk.id).await.unwrap().remove(0);
+
+        // Editing the product from 15 down to 3 consumes the lot
+        let edited = api
+            .update_product(
+                &milk.id,
+                ProductInput {
+                    current_amount: 3.0,
+                    ..costed_input("Fresh milk", None)
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(edited.full_name, "Fresh milk");
+        assert_eq!(edited.current_amount, 3.0);
+        let lot = api.lot_repo.get_by_id(&lot.id).await.unwrap().unwrap();
+        assert_eq!(lot.remaining_quantity, 0.0);
+
+        let err = api
+            .update_product(
+                &milk.id,
+                ProductInput {
+                    current_amount: -1.0,
+                    ..costed_input("Fresh milk", None)
+                },
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err, "Current amount cannot be negative");
+    }
+
+    #[tokio::test]
+    async fn test_stock_edits_are_valued_in_shrinkage() {
+        let api = TestStore::new().await.inventory;
+        let milk = api
+            .create_product(costed_input("Milk", None))
+            .await
+            .unwrap();
+
+        // Three units lost while the cost goes up to $8
+        api.update_product(
+            &milk.id,
+            ProductInput {
+                cost_price: Some(dec!(8)),
+                current_amount: 7.0,
+                ..costed_input("Milk", None)
+            },
+        )
+        .await
+        .unwrap();
+
+        let clock = StoreClock::default();
+        let today = clock.today();
+        let report = api
+            .get_shrinkage_report(today, today, &clock)
+            .await
+            .unwrap();
+        assert_eq!(report.adjustments.len(), 1);
+        assert_eq!(
+            report.adjustments[0].reason_id,
+            AdjustmentReason::COUNT_CORRECTION
+        );
+        assert_eq!(report.adjustments[0].quantity, -3.0);
+        assert_eq!(report.total_cost, dec!(-24));
+
+        // Saving without touching stock records nothing
+        api.update_product(
+            &milk.id,
+            ProductInput {
+                current_amount: 7.0,
+                ..costed_input("Whole milk", None)
+            },
+        )
+        .await
+        .unwrap();
+        let report = api
+            .get_shrinkage_report(today, today, &clock)
+            .await
+            .unwrap();
+        assert_eq!(report.adjustments.len(), 1);
+    }
+}
@@ -0,0 +1,245 @@
+//! Invoicing API
+//!
+//! Business logic for issuing CFDI invoices: one per sale for customers who
+//! request it, and a daily global invoice for the rest of the day's sales.
+
+use crate::models::invoice::PAYMENT_FORMS;
+use crate::models::{
+    Invoice, InvoiceConcept, InvoiceIssuer, InvoiceReceiver, InvoiceRecord, Operation,
+    PaymentMethod, ProductTaxCode, StoreClock,
+};
+use crate::pac::PacClient;
+use crate::repo::{InvoiceRepository, SaleRepository};
+use crate::utils::cfdi::write_cfdi;
+use chrono::{NaiveDate, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Payment form of sales on credit ("Por definir")
+const PAYMENT_FORM_TO_BE_DEFINED: &str = "99";
+
+#[derive(Clone)]
+pub struct InvoicingApi {
+    invoice_repo: Arc<dyn InvoiceRepository>,
+    sale_repo: Arc<dyn SaleRepository>,
+    pac: Arc<dyn PacClient>,
+}
+
+impl std::fmt::Debug for InvoicingApi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InvoicingApi").finish()
+    }
+}
+
+impl PartialEq for InvoicingApi {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.invoice_repo, &other.invoice_repo)
+            && Arc::ptr_eq(&self.sale_repo, &other.sale_repo)
+            && Arc::ptr_eq(&self.pac, &other.pac)
+    }
+}
+
+impl InvoicingApi {
+    pub fn new(
+        invoice_repo: Arc<dyn InvoiceRepository>,
+        sale_repo: Arc<dyn SaleRepository>,
+        pac: Arc<dyn PacClient>,
+    ) -> Self {
+        Self {
+            invoice_repo,
+            sale_repo,
+            pac,
+        }
+    }
+
+    /// Get the SAT codes of a product, if set
+    pub async fn get_tax_code(&self, product_id: &str) -> Result<Option<ProductTaxCode>, String> {
+        self.invoice_repo.get_tax_code(product_id).await
+    }
+
+    /// Save the SAT codes of a product
+    pub async fn set_tax_code(&self, code: ProductTaxCode) -> Result<ProductTaxCode, String> {
+        code.validate()?;
+        self.invoice_repo.set_tax_code(code.clone()).await?;
+        Ok(code)
+    }
+
+    /// Get the invoice that includes a sale, if any
+    pub async fn get_sale_invoice(&self, sale_id: &str) -> Result<Option<InvoiceRecord>, String> {
+        self.invoice_repo.get_by_sale(sale_id).await
+    }
+
+    /// Issue and stamp an invoice for one sale
+    pub async fn issue_sale_invoice(
+        &self,
+        sale_id: &str,
+        receiver: InvoiceReceiver,
+        payment_form: &str,
+        issuer: &InvoiceIssuer,
+        clock: &StoreClock,
+    ) -> Result<InvoiceRecord, String> {
+        validate_issuer(issuer)?;
+        receiver.validate()?;
+
+        let sale = self
+            .sale_repo
+            .get_by_id(sale_id)
+            .await?
+            .ok_or_else(|| "Sale not found".to_string())?;
+        if self.invoice_repo.get_by_sale(sale_id).await?.is_some() {
+            return Err("Sale has already been invoiced".to_string());
+        }
+
+        let operations = self.sale_repo.get_operations(sale_id).await?;
+        if operations.is_empty() {
+            return Err("Sale has no items to invoice".to_string());
+        }
+        let codes = self.tax_codes_for(&operations).await?;
+
+        // Sales on credit are paid later, so the payment form is not known yet
+        let (payment_method, payment_form) = if sale.is_loan {
+            (
+                PaymentMethod::InstallmentPayments,
+                PAYMENT_FORM_TO_BE_DEFINED,
+            )
+        } else {
+            validate_payment_form(payment_form)?;
+            (PaymentMethod::SinglePayment, payment_form)
+        };
+
+        let invoice = Invoice {
+            series: issuer.series.clone(),
+            folio: self.invoice_repo.next_folio(&issuer.series).await?,
+            issued_at: Utc::now(),
+            payment_form: payment_form.to_string(),
+            payment_method,
+            receiver,
+            global_day: None,
+            concepts: operations
+                .iter()
+                .map(|op| InvoiceConcept::from_operation(op, codes.get(&op.product_id)))
+                .collect(),
+        };
+
+        self.stamp_and_save(invoice, vec![sale.id], issuer, clock)
+            .await
+    }
+
+    /// Issue and stamp the global invoice of a business day
+    ///
+    /// Covers the day's paid sales that were not invoiced to a customer, one
+    /// line per ticket, issued to the general public.
+    pub async fn issue_global_invoice(
+        &self,
+        date: NaiveDate,
+        payment_form: &str,
+        issuer: &InvoiceIssuer,
+        clock: &StoreClock,
+    ) -> Result<InvoiceRecord, String> {
+        validate_issuer(issuer)?;
+        validate_payment_form(payment_form)?;
+
+        let (start, end) = clock.day_bounds(date);
+        let mut sales = self.sale_repo.list_by_date_range(&start, &end).await?;
+        sales.retain(|s| !s.is_loan);
+
+        let sale_ids: Vec<String> = sales.iter().map(|s| s.id.clone()).collect();
+        let invoiced = self.invoice_repo.invoiced_sale_ids(&sale_ids).await?;
+        sales.retain(|s| !invoiced.contains(&s.id));
+
+        // Sales with a tax profile wait to be invoiced to their customer
+        let mut public_sales = Vec::with_capacity(sales.len());
+        for sale in sales {
+            if self.sale_repo.get_tax_profile(&sale.id).await?.is_none() {
+                public_sales.push(sale);
+            }
+        }
+        let mut sales = public_sales;
+        sales.sort_by_key(|s| s.sold_at);
+        if sales.is_empty() {
+            return Err("No sales left to invoice on this day".to_string());
+        }
+
+        let mut tickets = Vec::with_capacity(sales.len());
+        for sale in sales {
+            let operations = self.sale_repo.get_operations(&sale.id).await?;
+            tickets.push((sale, operations));
+        }
+        let all_operations: Vec<Operation> = tickets
+            .iter()
+            .flat_map(|(_, ops)| ops.iter().cloned())
+            .collect();
+        let codes = self.tax_codes_for(&all_operations).await?;
+
+        let invoice = Invoice {
+            series: issuer.series.clone(),
+            folio: self.invoice_repo.next_folio(&issuer.series).await?,
+            issued_at: Utc::now(),
+            payment_form: payment_form.to_string(),
+            payment_method: PaymentMethod::SinglePayment,
+            receiver: InvoiceReceiver::general_public(&issuer.zip_code),
+            global_day: Some(date),
+            concepts: tickets
+                .iter()
+                .map(|(sale, ops)| InvoiceConcept::for_ticket(sale, ops, &codes))
+                .collect(),
+        };
+        let sale_ids = tickets.into_iter().map(|(sale, _)| sale.id).collect();
+
+        self.stamp_and_save(invoice, sale_ids, issuer, clock).await
+    }
+
+    /// SAT codes of the products sold, by product id
+    async fn tax_codes_for(
+        &self,
+        operations: &[Operation],
+    ) -> Result<HashMap<String, ProductTaxCode>, String> {
+        let mut product_ids: Vec<String> =
+            operations.iter().map(|op| op.product_id.clone()).collect();
+        product_ids.sort();
+        product_ids.dedup();
+
+        Ok(self
+            .invoice_repo
+            .list_tax_codes(&product_ids)
+            .await?
+            .into_iter()
+            .map(|code| (code.product_id.clone(), code))
+            .collect())
+    }
+
+    async fn stamp_and_save(
+        &self,
+        invoice: Invoice,
+        sale_ids: Vec<String>,
+        issuer: &InvoiceIssuer,
+        clock: &StoreClock,
+    ) -> Result<InvoiceRecord, String> {
+        let xml = write_cfdi(&invoice, issuer, clock);
+        let stamp = self.pac.stamp(&xml).await?;
+
+        let record = InvoiceRecord {
+            id: uuid::Uuid::new_v4().to_string(),
+            uuid: stamp.uuid,
+            invoice,
+            xml: stamp.xml,
+            stamped_at: stamp.stamped_at,
+        };
+        self.invoice_repo.create(&record, &sale_ids).await?;
+        Ok(record)
+    }
+}
+
+fn validate_issuer(issuer: &InvoiceIssuer) -> Result<(), String> {
+    if !issuer.is_configured() {
+        return Err("Set up the store's invoicing data in Settings first".to_string());
+    }
+    issuer.validate()
+}
+
+fn validate_payment_form(payment_form: &str) -> Result<(), String> {
+    if !PAYMENT_FORMS.iter().any(|(code, _)| *code == payment_form) {
+        return Err(format!("Unknown payment form: {}", payment_form));
+    }
+    Ok(())
+}
@@ -2,8 +2,9 @@
 //!
 //! Business logic for loan management and payment processing.
 
-use crate::models::{Loan, LoanInput, LoanPayment, LoanPaymentInput, StatusLoan};
+use crate::models::{Loan, LoanInput, LoanPayment, LoanPaymentInput, StatusLoan, StoreClock};
 use crate::repo::{LoanRepository, PaginatedResult, SaleRepository};
+use chrono::NaiveDate;
 use rust_decimal::Decimal;
 use std::sync::Arc;
 
@@ -148,6 +149,16 @@ impl LoansApi {
             .await
     }
 
+    /// Get loan payments received on a business day
+    pub async fn get_payments_on(
+        &self,
+        date: NaiveDate,
+        clock: &StoreClock,
+    ) -> Result<Vec<LoanPayment>, String> {
+        let (start, end) = clock.day_bounds(date);
+        self.loan_repo.payments_between(&start, &end).await
+    }
+
     /// Get loan statistics
     pub async fn get_loan_stats(&self) -> Result<LoanStats, String> {
         let loans = self.loan_repo.list_all().await?;
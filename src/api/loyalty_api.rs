@@ -0,0 +1,173 @@
+//! Loyalty API
+//!
+//! Business logic for customers' points ledgers.
+
+use crate::models::loyalty::{expired_points, points_balance};
+use crate::models::{PointsEntry, PointsKind, Sale, SalePoints};
+use crate::repo::LoyaltyRepository;
+use chrono::Utc;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct LoyaltyApi {
+    loyalty_repo: Arc<dyn LoyaltyRepository>,
+}
+
+impl std::fmt::Debug for LoyaltyApi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LoyaltyApi").finish()
+    }
+}
+
+impl PartialEq for LoyaltyApi {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.loyalty_repo, &other.loyalty_repo)
+    }
+}
+
+impl LoyaltyApi {
+    pub fn new(loyalty_repo: Arc<dyn LoyaltyRepository>) -> Self {
+        Self { loyalty_repo }
+    }
+
+    /// Points ledger of a customer, oldest first. Points past their expiry
+    /// are shown as an expiration, which is only recorded with the next sale
+    pub async fn get_ledger(&self, customer_id: &str) -> Result<Vec<PointsEntry>, String> {
+        let mut entries = self.loyalty_repo.list_entries(customer_id).await?;
+
+        let expired = expired_points(&entries, Utc::now());
+        if expired > 0 {
+            entries.push(PointsEntry::expire(customer_id, expired));
+        }
+
+        Ok(entries)
+    }
+
+    /// Current points balance of a customer
+    pub async fn get_balance(&self, customer_id: &str) -> Result<i64, String> {
+        Ok(points_balance(&self.get_ledger(customer_id).await?))
+    }
+
+    /// Points earned and redeemed in a sale and the balance right after it,
+    /// None when it moved no points
+    pub async fn get_sale_points(&self, sale: &Sale) -> Result<Option<SalePoints>, String> {
+        let Some(customer_id) = &sale.customer_id else {
+            return Ok(None);
+        };
+
+        let ledger = self.loyalty_repo.list_entries(customer_id).await?;
+        let Some(last) = ledger
+            .iter()
+            .rposition(|e| e.sale_id.as_deref() == Some(sale.id.as_str()))
+        else {
+            return Ok(None);
+        };
+
+        let total = |kind: PointsKind| -> i64 {
+            ledger
+                .iter()
+                .filter(|e| e.sale_id.as_deref() == Some(sale.id.as_str()) && e.kind == kind)
+                .map(|e| e.points.abs())
+                .sum()
+        };
+
+        Ok(Some(SalePoints {
+            earned: total(PointsKind::Earn),
+            redeemed: total(PointsKind::Redeem),
+            balance: points_balance(&ledger[..=last]),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CustomerInput, LoyaltySettings};
+    use crate::repo::{CustomerRepository, ProductRepository};
+    use crate::utils::test_store::{cash_sale, product_input, sale_item, TestStore};
+    use chrono::Duration;
+    use rust_decimal_macros::dec;
+
+    #[tokio::test]
+    async fn test_sale_points_and_expirations() {
+        let store = TestStore::new().await;
+        let pool = &store.pool;
+        let loyalty_api = LoyaltyApi::new(store.loyalty.clone());
+        let loyalty = LoyaltySettings {
+            amount_per_point: dec!(10),
+            ..LoyaltySettings::default()
+        };
+
+        let soda = store
+            .products
+            .create(product_input("Soda", dec!(20), 10.0))
+            .await
+            .unwrap();
+        let customer = store
+            .customers
+            .create(CustomerInput {
+                name: "Ana".to_string(),
+                phone: None,
+                tax_profile: None,
+                price_list_id: None,
+            })
+            .await
+            .unwrap();
+
+        // 5 points already past their expiry
+        let mut old = PointsEntry::earn(&customer.id, 5, Some(Utc::now() - Duration::days(1)));
+        old.created_at = Utc::now() - Duration::days(400);
+        sqlx::query(
+            "INSERT INTO loyalty_entry (id, customer_id, points, kind, expires_at, created_at) \
+             VALUES (?, ?, ?, 'earn', ?, ?)",
+        )
+        .bind(&old.id)
+        .bind(&customer.id)
+        .bind(old.points)
+        .bind(old.expires_at.map(|at| at.to_rfc3339()))
+        .bind(old.created_at.to_rfc3339())
+        .execute(pool)
+        .await
+        .unwrap();
+
+        // Reading the ledger shows the expiration without recording it
+        assert_eq!(loyalty_api.get_balance(&customer.id).await.unwrap(), 0);
+        let recorded: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM loyalty_entry")
+            .fetch_one(pool)
+            .await
+            .unwrap();
+        assert_eq!(recorded, 1);
+
+        let sell = |quantity: f64| {
+            cash_sale(
+                vec![sale_item(&soda, quantity, dec!(20))],
+                Some(customer.id.clone()),
+            )
+        };
+        let first = store
+            .sales
+            .process_sale(sell(1.0), &loyalty, Utc::now().date_naive())
+            .await
+            .unwrap();
+        let second = store
+            .sales
+            .process_sale(sell(2.0), &loyalty, Utc::now().date_naive())
+            .await
+            .unwrap();
+
+        // The expiration is settled with the first sale but isn't part of it
+        let ledger = loyalty_api.get_ledger(&customer.id).await.unwrap();
+        let expiration = ledger
+            .iter()
+            .find(|e| e.kind == PointsKind::Expire)
+            .unwrap();
+        assert_eq!(expiration.points, -5);
+        assert_eq!(expiration.sale_id, None);
+
+        // Each sale shows the balance right after it
+        let points = loyalty_api.get_sale_points(&first).await.unwrap().unwrap();
+        assert_eq!((points.earned, points.balance), (2, 2));
+        let points = loyalty_api.get_sale_points(&second).await.unwrap().unwrap();
+        assert_eq!((points.earned, points.balance), (4, 6));
+    }
+}
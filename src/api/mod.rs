@@ -2,10 +2,29 @@
 //!
 //! Core business logic layer that orchestrates repository operations.
 
+pub mod customers_api;
+pub mod export_api;
 pub mod inventory_api;
+pub mod invoicing_api;
 pub mod loans_api;
+pub mod loyalty_api;
+pub mod price_lists_api;
+pub mod purchasing_api;
+pub mod reports_api;
 pub mod sales_api;
+pub mod settings_api;
 
-pub use inventory_api::{InventoryApi, InventoryStats};
+pub use customers_api::CustomersApi;
+pub use export_api::ExportApi;
+pub use inventory_api::{
+    ImportAction, ImportRow, ImportSummary, InventoryApi, InventoryStats, RepriceLine,
+    ShrinkageReport, ShrinkageSummary,
+};
+pub use invoicing_api::InvoicingApi;
 pub use loans_api::{LoanStats, LoanWithPayments, LoansApi};
+pub use loyalty_api::LoyaltyApi;
+pub use price_lists_api::PriceListsApi;
+pub use purchasing_api::PurchasingApi;
+pub use reports_api::{ProductAnalytics, ReportsApi};
 pub use sales_api::{SaleWithOperations, SalesApi, SalesStats};
+pub use settings_api::SettingsApi;
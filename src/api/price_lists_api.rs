@@ -0,0 +1,185 @@
+//! Price Lists API
+//!
+//! Business logic for customer price lists and quantity price tiers.
+
+use crate::models::price_list::validate_tiers;
+use crate::models::{PriceBook, PriceList, PriceListInput, PriceListItem, PriceTier};
+use crate::repo::{CustomerRepository, PriceListRepository, ProductRepository};
+use rust_decimal::Decimal;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PriceListsApi {
+    price_list_repo: Arc<dyn PriceListRepository>,
+    product_repo: Arc<dyn ProductRepository>,
+    customer_repo: Arc<dyn CustomerRepository>,
+}
+
+impl std::fmt::Debug for PriceListsApi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PriceListsApi").finish()
+    }
+}
+
+impl PartialEq for PriceListsApi {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.price_list_repo, &other.price_list_repo)
+            && Arc::ptr_eq(&self.product_repo, &other.product_repo)
+            && Arc::ptr_eq(&self.customer_repo, &other.customer_repo)
+    }
+}
+
+/// Build the price book of a price list (None for regular prices) with the given tiers
+pub(crate) async fn load_price_book(
+    price_list_repo: &dyn PriceListRepository,
+    price_list_id: Option<&str>,
+    tiers: Vec<PriceTier>,
+) -> Result<PriceBook, String> {
+    let (price_list, list_prices) = match price_list_id {
+        Some(id) => {
+            let list = price_list_repo.get_by_id(id).await?;
+            let prices = price_list_repo
+                .list_items(id)
+                .await?
+                .into_iter()
+                .map(|item| (item.product_id, item.price))
+                .collect();
+            (list, prices)
+        }
+        None => (None, Default::default()),
+    };
+
+    Ok(PriceBook {
+        price_list,
+        list_prices,
+        tiers,
+    })
+}
+
+impl PriceListsApi {
+    pub fn new(
+        price_list_repo: Arc<dyn PriceListRepository>,
+        product_repo: Arc<dyn ProductRepository>,
+        customer_repo: Arc<dyn CustomerRepository>,
+    ) -> Self {
+        Self {
+            price_list_repo,
+            product_repo,
+            customer_repo,
+        }
+    }
+
+    /// List all price lists
+    pub async fn list_price_lists(&self) -> Result<Vec<PriceList>, String> {
+        self.price_list_repo.list_all().await
+    }
+
+    /// Create a new price list with validation
+    pub async fn create_price_list(&self, input: PriceListInput) -> Result<PriceList, String> {
+        let input = input.normalized()?;
+        self.check_name_available(&input.name, None).await?;
+        self.price_list_repo.create(input).await
+    }
+
+    /// Update a price list with validation
+    pub async fn update_price_list(
+        &self,
+        id: &str,
+        input: PriceListInput,
+    ) -> Result<PriceList, String> {
+        let input = input.normalized()?;
+        self.check_name_available(&input.name, Some(id)).await?;
+        self.price_list_repo.update(id, input).await
+    }
+
+    /// Delete a price list, its customers go back to regular prices
+    pub async fn delete_price_list(&self, id: &str) -> Result<(), String> {
+        self.price_list_repo.delete(id).await
+    }
+
+    /// Product prices on a list
+    pub async fn list_prices(&self, price_list_id: &str) -> Result<Vec<PriceListItem>, String> {
+        self.price_list_repo.list_items(price_list_id).await
+    }
+
+    /// Set the price of a product on a list
+    pub async fn set_list_price(
+        &self,
+        price_list_id: &str,
+        product_id: &str,
+        price: Decimal,
+    ) -> Result<(), String> {
+        if price <= Decimal::ZERO {
+            return Err("List price must be greater than zero".to_string());
+        }
+        self.product_repo
+            .get_by_id(product_id)
+            .await?
+            .ok_or_else(|| format!("Product not found: {}", product_id))?;
+
+        self.price_list_repo
+            .set_item_price(price_list_id, product_id, price)
+            .await
+    }
+
+    /// Remove a product from a list, it goes back to the list's discount
+    pub async fn remove_list_price(
+        &self,
+        price_list_id: &str,
+        product_id: &str,
+    ) -> Result<(), String> {
+        self.price_list_repo
+            .remove_item(price_list_id, product_id)
+            .await
+    }
+
+    /// Quantity tiers of a product, lowest quantity first
+    pub async fn get_tiers(&self, product_id: &str) -> Result<Vec<PriceTier>, String> {
+        self.price_list_repo
+            .list_tiers(&[product_id.to_string()])
+            .await
+    }
+
+    /// Replace the quantity tiers of a product with validation
+    pub async fn set_tiers(&self, product_id: &str, tiers: Vec<PriceTier>) -> Result<(), String> {
+        let product = self
+            .product_repo
+            .get_by_id(product_id)
+            .await?
+            .ok_or_else(|| format!("Product not found: {}", product_id))?;
+        let tiers = validate_tiers(&product, tiers)?;
+
+        self.price_list_repo.set_tiers(product_id, &tiers).await
+    }
+
+    /// Prices that apply to a customer at checkout, regular prices without one
+    pub async fn get_price_book(&self, customer_id: Option<&str>) -> Result<PriceBook, String> {
+        let price_list_id = match customer_id {
+            Some(id) => {
+                self.customer_repo
+                    .get_by_id(id)
+                    .await?
+                    .ok_or_else(|| format!("Customer not found: {}", id))?
+                    .price_list_id
+            }
+            None => None,
+        };
+        let tiers = self.price_list_repo.list_all_tiers().await?;
+
+        load_price_book(&*self.price_list_repo, price_list_id.as_deref(), tiers).await
+    }
+
+    /// Reject a name already used by another list
+    async fn check_name_available(&self, name: &str, id: Option<&str>) -> Result<(), String> {
+        let taken = self
+            .price_list_repo
+            .list_all()
+            .await?
+            .iter()
+            .any(|l| l.name.eq_ignore_ascii_case(name) && Some(l.id.as_str()) != id);
+        if taken {
+            return Err(format!("A price list named '{}' already exists", name));
+        }
+        Ok(())
+    }
+}
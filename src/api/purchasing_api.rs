@@ -0,0 +1,155 @@
+//! Purchasing API
+//!
+//! Business logic for reorder suggestions and draft purchase orders.
+
+use crate::models::{PurchaseOrder, PurchaseOrderLine, ReorderPolicy, ReorderSuggestion};
+use crate::repo::{ProductRepository, PurchaseOrderRepository, SaleRepository, SupplierRepository};
+use chrono::{Duration, Utc};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PurchasingApi {
+    product_repo: Arc<dyn ProductRepository>,
+    supplier_repo: Arc<dyn SupplierRepository>,
+    sale_repo: Arc<dyn SaleRepository>,
+    purchase_order_repo: Arc<dyn PurchaseOrderRepository>,
+}
+
+impl std::fmt::Debug for PurchasingApi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PurchasingApi").finish()
+    }
+}
+
+impl PartialEq for PurchasingApi {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.product_repo, &other.product_repo)
+            && Arc::ptr_eq(&self.supplier_repo, &other.supplier_repo)
+            && Arc::ptr_eq(&self.sale_repo, &other.sale_repo)
+            && Arc::ptr_eq(&self.purchase_order_repo, &other.purchase_order_repo)
+    }
+}
+
+impl PurchasingApi {
+    pub fn new(
+        product_repo: Arc<dyn ProductRepository>,
+        supplier_repo: Arc<dyn SupplierRepository>,
+        sale_repo: Arc<dyn SaleRepository>,
+        purchase_order_repo: Arc<dyn PurchaseOrderRepository>,
+    ) -> Self {
+        Self {
+            product_repo,
+            supplier_repo,
+            sale_repo,
+            purchase_order_repo,
+        }
+    }
+
+    /// Products that should be reordered, ordered by supplier then name
+    pub async fn get_reorder_suggestions(
+        &self,
+        policy: &ReorderPolicy,
+    ) -> Result<Vec<ReorderSuggestion>, String> {
+        policy.validate()?;
+
+        let since = Utc::now() - Duration::days(policy.window_days);
+        let units_sold: HashMap<String, f64> = self
+            .sale_repo
+            .units_sold_since(since)
+            .await?
+            .into_iter()
+            .collect();
+
+        let lead_times: HashMap<String, i64> = self
+            .supplier_repo
+            .list_all()
+            .await?
+            .into_iter()
+            .map(|supplier| (supplier.id, supplier.lead_time_days))
+            .collect();
+
+        let mut suggestions: Vec<ReorderSuggestion> = self
+            .product_repo
+            .list_all()
+            .await?
+            .iter()
+            .filter(|product| !product.is_kit)
+            .filter_map(|product| {
+                let lead_time_days = product
+                    .supplier_id
+                    .as_ref()
+                    .and_then(|id| lead_times.get(id))
+                    .copied()
+                    .unwrap_or(0);
+                let sold = units_sold.get(&product.id).copied().unwrap_or(0.0);
+
+                ReorderSuggestion::calculate(product, sold, lead_time_days, policy)
+            })
+            .collect();
+
+        suggestions.sort_by(|a, b| {
+            a.supplier_id
+                .cmp(&b.supplier_id)
+                .then_with(|| a.product_name.cmp(&b.product_name))
+        });
+        Ok(suggestions)
+    }
+
+    /// Turn suggestions into draft purchase orders, one per supplier
+    pub async fn create_purchase_orders(
+        &self,
+        suggestions: Vec<ReorderSuggestion>,
+    ) -> Result<Vec<PurchaseOrder>, String> {
+        if suggestions.is_empty() {
+            return Err("Select at least one product to order".to_string());
+        }
+
+        let mut by_supplier: BTreeMap<Option<String>, Vec<PurchaseOrderLine>> = BTreeMap::new();
+        for suggestion in suggestions {
+            if suggestion.purchase_quantity <= 0.0 || suggestion.suggested_quantity <= 0.0 {
+                return Err(format!(
+                    "Order quantity must be positive: {}",
+                    suggestion.product_name
+                ));
+            }
+
+            by_supplier
+                .entry(suggestion.supplier_id)
+                .or_default()
+                .push(PurchaseOrderLine {
+                    order_id: String::new(),
+                    product_id: suggestion.product_id,
+                    product_name: suggestion.product_name,
+                    quantity: suggestion.suggested_quantity,
+                    purchase_quantity: suggestion.purchase_quantity,
+                    unit_cost: suggestion.unit_cost,
+                });
+        }
+
+        let mut orders = Vec::new();
+        for (supplier_id, lines) in by_supplier {
+            orders.push(self.purchase_order_repo.create(supplier_id, lines).await?);
+        }
+
+        Ok(orders)
+    }
+
+    /// List draft purchase orders, newest first
+    pub async fn list_purchase_orders(&self) -> Result<Vec<PurchaseOrder>, String> {
+        self.purchase_order_repo.list_all().await
+    }
+
+    /// Get the lines of a purchase order
+    pub async fn get_purchase_order_lines(
+        &self,
+        order_id: &str,
+    ) -> Result<Vec<PurchaseOrderLine>, String> {
+        self.purchase_order_repo.list_lines(order_id).await
+    }
+
+    /// Delete a purchase order
+    pub async fn delete_purchase_order(&self, id: &str) -> Result<(), String> {
+        self.purchase_order_repo.delete(id).await
+    }
+}
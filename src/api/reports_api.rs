@@ -0,0 +1,311 @@
+//! Reports API
+//!
+//! Business logic for end-of-day summaries, the sales dashboard and product
+//! analytics built from sales, loans and inventory.
+
+use crate::api::{InventoryApi, LoansApi, SalesApi};
+use crate::models::analytics::{classify_abc, idle_products, slow_movers};
+use crate::models::dashboard::{comparison_ranges, trend_ranges};
+use crate::models::report::scope_sales;
+use crate::models::{
+    DailyReport, Dashboard, DashboardPeriod, IdleProduct, ProductPerformance, SlowMover, StoreClock,
+};
+use chrono::{Duration, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Slow movers listed in the product analytics
+const SLOW_MOVER_LIMIT: usize = 20;
+
+#[derive(Clone)]
+pub struct ReportsApi {
+    sales_api: Arc<SalesApi>,
+    loans_api: Arc<LoansApi>,
+    inventory_api: Arc<InventoryApi>,
+}
+
+impl std::fmt::Debug for ReportsApi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReportsApi").finish()
+    }
+}
+
+impl PartialEq for ReportsApi {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.sales_api, &other.sales_api)
+            && Arc::ptr_eq(&self.loans_api, &other.loans_api)
+            && Arc::ptr_eq(&self.inventory_api, &other.inventory_api)
+    }
+}
+
+impl ReportsApi {
+    pub fn new(
+        sales_api: Arc<SalesApi>,
+        loans_api: Arc<LoansApi>,
+        inventory_api: Arc<InventoryApi>,
+    ) -> Self {
+        Self {
+            sales_api,
+            loans_api,
+            inventory_api,
+        }
+    }
+
+    /// Build the end-of-day summary for a business day
+    ///
+    /// With a category (including subcategories) the sales figures, top
+    /// products and low stock only cover its products; loan payments and
+    /// balances stay store-wide.
+    pub async fn daily_report(
+        &self,
+        date: NaiveDate,
+        category_id: Option<&str>,
+        clock: &StoreClock,
+    ) -> Result<DailyReport, String> {
+        let mut sales = self.sales_api.get_sales_on(date, clock).await?;
+        let product_sales = self
+            .sales_api
+            .get_product_sales(date, date, category_id, clock)
+            .await?;
+        let payments = self.loans_api.get_payments_on(date, clock).await?;
+        let loan_stats = self.loans_api.get_loan_stats().await?;
+        let mut low_stock = self.inventory_api.get_low_stock_products().await?;
+
+        if let Some(category_id) = category_id {
+            let lines = self
+                .sales_api
+                .get_category_operations_on(date, category_id, clock)
+                .await?;
+            sales = scope_sales(&sales, &lines);
+
+            let in_category: HashSet<String> = self
+                .inventory_api
+                .list_products_by_category(Some(category_id))
+                .await?
+                .into_iter()
+                .map(|p| p.id)
+                .collect();
+            low_stock.retain(|p| in_category.contains(&p.id));
+        }
+
+        Ok(DailyReport::build(
+            date,
+            &sales,
+            product_sales,
+            &payments,
+            loan_stats.total_remaining,
+            low_stock,
+            clock,
+        ))
+    }
+
+    /// Period-to-date figures, the previous period and the trend for the
+    /// dashboard, optionally only for a category (including subcategories)
+    pub async fn dashboard(
+        &self,
+        period: DashboardPeriod,
+        category_id: Option<&str>,
+        clock: &StoreClock,
+    ) -> Result<Dashboard, String> {
+        let today = clock.today();
+        let (current, previous) = comparison_ranges(period, today);
+
+        // One query for the comparison and every trend bar
+        let mut ranges = vec![current, previous];
+        ranges.extend(trend_ranges(period, today));
+        let mut totals = self
+            .sales_api
+            .get_period_totals(&ranges, category_id, clock)
+            .await?
+            .into_iter();
+
+        let (Some(current), Some(previous)) = (totals.next(), totals.next()) else {
+            return Err("Failed to get sales totals: missing periods".to_string());
+        };
+        let trend = totals.collect();
+
+        Ok(Dashboard {
+            period,
+            current,
+            previous,
+            trend,
+        })
+    }
+
+    /// Product performance between two business days, optionally only for a
+    /// category (including subcategories)
+    pub async fn product_analytics(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        category_id: Option<&str>,
+        clock: &StoreClock,
+    ) -> Result<ProductAnalytics, String> {
+        let sales = self
+            .sales_api
+            .get_product_sales(from, to, category_id, clock)
+            .await?;
+        let products = self
+            .inventory_api
+            .list_products_by_category(category_id)
+            .await?;
+        let period_days = (to - from).num_days() + 1;
+
+        let total_revenue = sales.iter().map(|s| s.revenue).sum();
+        let total_units = sales.iter().map(|s| s.quantity).sum();
+        let slow_movers = slow_movers(&products, &sales, period_days, SLOW_MOVER_LIMIT);
+
+        Ok(ProductAnalytics {
+            products: classify_abc(sales),
+            slow_movers,
+            total_revenue,
+            total_units,
+        })
+    }
+
+    /// Active products without sales in the last `days` days, optionally only
+    /// for a category (including subcategories)
+    pub async fn idle_products(
+        &self,
+        days: i64,
+        category_id: Option<&str>,
+    ) -> Result<Vec<IdleProduct>, String> {
+        if days < 1 {
+            return Err("Days must be at least 1".to_string());
+        }
+
+        let products = self
+            .inventory_api
+            .list_products_by_category(category_id)
+            .await?;
+        let last_sold = self.sales_api.get_last_sold_per_product().await?;
+
+        Ok(idle_products(
+            &products,
+            &last_sold,
+            Utc::now() - Duration::days(days),
+        ))
+    }
+}
+
+/// Product analytics for a period
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProductAnalytics {
+    pub products: Vec<ProductPerformance>, // Ranked by revenue with ABC classes
+    pub slow_movers: Vec<SlowMover>,
+    pub total_revenue: Decimal,
+    pub total_units: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CategoryInput, Product, ProductInput, SaleInput};
+    use crate::repo::{CategoryRepository, ProductRepository};
+    use crate::utils::test_store::{cash_sale, product_input, sale_item, TestStore};
+    use rust_decimal_macros::dec;
+
+    fn categorized_input(name: &str, price: Decimal, category_id: &str) -> ProductInput {
+        ProductInput {
+            category_id: Some(category_id.to_string()),
+            ..product_input(name, price, 100.0)
+        }
+    }
+
+    /// Cash sale of each product at its regular price
+    fn sale(lines: &[(&Product, f64)]) -> SaleInput {
+        let items = lines
+            .iter()
+            .map(|(product, quantity)| sale_item(product, *quantity, product.user_price))
+            .collect();
+        cash_sale(items, None)
+    }
+
+    #[tokio::test]
+    async fn test_reports_scoped_to_category() {
+        let store = TestStore::new().await;
+        let reports = ReportsApi::new(
+            store.sales.clone(),
+            store.loans.clone(),
+            store.inventory.clone(),
+        );
+        let (categories, products) = (&store.categories, &store.products);
+        let clock = StoreClock::default();
+        let today = clock.today();
+
+        // Dairy > Yogurt, and Snacks
+        let category = |name: &str, parent_id: Option<String>| CategoryInput {
+            name: name.to_string(),
+            parent_id,
+        };
+        let dairy = categories.create(category("Dairy", None)).await.unwrap();
+        let yogurts = categories
+            .create(category("Yogurt", Some(dairy.id.clone())))
+            .await
+            .unwrap();
+        let snacks = categories.create(category("Snacks", None)).await.unwrap();
+
+        let milk = products
+            .create(categorized_input("Milk", dec!(20), &dairy.id))
+            .await
+            .unwrap();
+        let yogurt = products
+            .create(categorized_input("Yogurt", dec!(10), &yogurts.id))
+            .await
+            .unwrap();
+        let chips = products
+            .create(categorized_input("Chips", dec!(15), &snacks.id))
+            .await
+            .unwrap();
+
+        for input in [
+            sale(&[(&milk, 1.0), (&chips, 2.0)]),
+            sale(&[(&yogurt, 3.0)]),
+            sale(&[(&chips, 1.0)]),
+        ] {
+            store.sell(input).await.unwrap();
+        }
+
+        let all = reports.daily_report(today, None, &clock).await.unwrap();
+        assert_eq!((all.ticket_count, all.revenue), (3, dec!(95)));
+
+        // Dairy includes its Yogurt subcategory and only their lines count
+        let report = reports
+            .daily_report(today, Some(&dairy.id), &clock)
+            .await
+            .unwrap();
+        assert_eq!((report.ticket_count, report.revenue), (2, dec!(50)));
+        let names: Vec<&str> = report
+            .top_products
+            .iter()
+            .map(|p| p.product_name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Yogurt", "Milk"]);
+
+        let dashboard = reports
+            .dashboard(DashboardPeriod::Day, Some(&dairy.id), &clock)
+            .await
+            .unwrap();
+        assert_eq!(
+            (dashboard.current.tickets, dashboard.current.revenue),
+            (2, dec!(50))
+        );
+        let dashboard = reports
+            .dashboard(DashboardPeriod::Day, None, &clock)
+            .await
+            .unwrap();
+        assert_eq!(
+            (dashboard.current.tickets, dashboard.current.revenue),
+            (3, dec!(95))
+        );
+
+        let analytics = reports
+            .product_analytics(today, today, Some(&snacks.id), &clock)
+            .await
+            .unwrap();
+        assert_eq!(analytics.products.len(), 1);
+        assert_eq!(analytics.total_revenue, dec!(45));
+        assert_eq!(analytics.total_units, 3.0);
+    }
+}
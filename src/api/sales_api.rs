@@ -2,15 +2,32 @@
 //!
 //! Business logic for processing sales transactions.
 
-use crate::models::{Operation, Sale, SaleInput};
-use crate::repo::{ProductRepository, SaleRepository};
+use crate::api::price_lists_api::load_price_book;
+use crate::models::customer::normalize_tax_profile;
+use crate::models::loyalty::{expired_points, points_balance};
+use crate::models::scale_barcode::label_quantity;
+use crate::models::{
+    Category, InvoiceReceiver, LoyaltySettings, Operation, PeriodTotals, PointsEntry, ProductSales,
+    Sale, SaleFilter, SaleInput, StoreClock,
+};
+use crate::repo::{
+    CatalogRepository, CategoryRepository, CustomerRepository, LoyaltyRepository, PaginatedResult,
+    PriceListRepository, ProductRepository, SaleRepository,
+};
+use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct SalesApi {
     sale_repo: Arc<dyn SaleRepository>,
     product_repo: Arc<dyn ProductRepository>,
+    catalog_repo: Arc<dyn CatalogRepository>,
+    customer_repo: Arc<dyn CustomerRepository>,
+    category_repo: Arc<dyn CategoryRepository>,
+    loyalty_repo: Arc<dyn LoyaltyRepository>,
+    price_list_repo: Arc<dyn PriceListRepository>,
 }
 
 impl std::fmt::Debug for SalesApi {
@@ -23,6 +40,11 @@ impl PartialEq for SalesApi {
     fn eq(&self, other: &Self) -> bool {
         Arc::ptr_eq(&self.sale_repo, &other.sale_repo)
             && Arc::ptr_eq(&self.product_repo, &other.product_repo)
+            && Arc::ptr_eq(&self.catalog_repo, &other.catalog_repo)
+            && Arc::ptr_eq(&self.customer_repo, &other.customer_repo)
+            && Arc::ptr_eq(&self.category_repo, &other.category_repo)
+            && Arc::ptr_eq(&self.loyalty_repo, &other.loyalty_repo)
+            && Arc::ptr_eq(&self.price_list_repo, &other.price_list_repo)
     }
 }
 
@@ -30,20 +52,58 @@ impl SalesApi {
     pub fn new(
         sale_repo: Arc<dyn SaleRepository>,
         product_repo: Arc<dyn ProductRepository>,
+        catalog_repo: Arc<dyn CatalogRepository>,
+        customer_repo: Arc<dyn CustomerRepository>,
+        category_repo: Arc<dyn CategoryRepository>,
+        loyalty_repo: Arc<dyn LoyaltyRepository>,
+        price_list_repo: Arc<dyn PriceListRepository>,
     ) -> Self {
         Self {
             sale_repo,
             product_repo,
+            catalog_repo,
+            customer_repo,
+            category_repo,
+            loyalty_repo,
+            price_list_repo,
         }
     }
 
     /// Process a new sale with validation
-    pub async fn process_sale(&self, input: SaleInput) -> Result<Sale, String> {
+    pub async fn process_sale(
+        &self,
+        mut input: SaleInput,
+        loyalty: &LoyaltySettings,
+        today: NaiveDate,
+    ) -> Result<Sale, String> {
         // Validate sale has items
         if input.items.is_empty() {
             return Err("Sale must have at least one item".to_string());
         }
 
+        // Customer prices come from their price list, if any
+        let price_list_id = match &input.customer_id {
+            Some(customer_id) => {
+                self.customer_repo
+                    .get_by_id(customer_id)
+                    .await?
+                    .ok_or_else(|| format!("Customer not found: {}", customer_id))?
+                    .price_list_id
+            }
+            None => None,
+        };
+        let product_ids: Vec<String> = input.items.iter().map(|i| i.product_id.clone()).collect();
+        let tiers = self.price_list_repo.list_tiers(&product_ids).await?;
+        let prices =
+            load_price_book(&*self.price_list_repo, price_list_id.as_deref(), tiers).await?;
+
+        let units = self.catalog_repo.get_units().await?;
+        let excluded_categories = self.excluded_categories(loyalty).await?;
+        let mut eligible_amount = Decimal::ZERO;
+        // Stock needed per physical product across the cart: product ID to
+        // (name, stock, quantity), with kits counted as their components
+        let mut demand: HashMap<String, (String, f64, f64)> = HashMap::new();
+
         // Validate all products exist and have sufficient stock
         for item in &input.items {
             let product = self
@@ -52,6 +112,13 @@ impl SalesApi {
                 .await?
                 .ok_or_else(|| format!("Product not found: {}", item.product_id))?;
 
+            if product.is_archived() {
+                return Err(format!(
+                    "Product '{}' is archived and cannot be sold",
+                    product.full_name
+                ));
+            }
+
             if item.quantity <= 0.0 {
                 return Err(format!(
                     "Invalid quantity for product '{}': must be positive",
@@ -59,18 +126,81 @@ impl SalesApi {
                 ));
             }
 
-            if product.current_amount < item.quantity {
+            // Whole-number units cannot be sold in fractions, except by a
+            // scale label, whose quantity comes from its printed amount
+            if let Some(unit) = units.iter().find(|u| u.id == product.unit_measurement_id) {
+                if item.line_total.is_none() {
+                    unit.check_quantity(item.quantity).map_err(|e| {
+                        format!("Invalid quantity for '{}': {}", product.full_name, e)
+                    })?;
+                }
+            }
+
+            if product.is_kit {
+                for component in self.product_repo.list_components(&product.id).await? {
+                    if component.component_archived {
+                        return Err(format!(
+                            "Kit '{}' contains archived product '{}' and cannot be sold",
+                            product.full_name, component.component_name
+                        ));
+                    }
+                    demand
+                        .entry(component.component_id)
+                        .or_insert((component.component_name, component.component_stock, 0.0))
+                        .2 += component.quantity * item.quantity;
+                }
+            } else {
+                demand
+                    .entry(product.id.clone())
+                    .or_insert((product.full_name.clone(), product.current_amount, 0.0))
+                    .2 += item.quantity;
+            }
+
+            // Validate price matches the resolved price (security check).
+            // Scale labels are charged their printed amount at the regular price.
+            let expected_price = match item.line_total {
+                Some(line_total) if line_total <= Decimal::ZERO => {
+                    return Err(format!(
+                        "Invalid label amount for '{}': must be positive",
+                        product.full_name
+                    ));
+                }
+                Some(line_total) => {
+                    // The quantity must be what the printed amount buys
+                    let quantity = Decimal::from_f64_retain(item.quantity)
+                        .unwrap_or_default()
+                        .round_dp(3);
+                    if quantity != label_quantity(line_total, product.user_price) {
+                        return Err(format!(
+                            "Label amount ${} does not match {} of '{}'",
+                            line_total, item.quantity, product.full_name
+                        ));
+                    }
+                    product.user_price
+                }
+                None => prices.unit_price(&product, item.quantity),
+            };
+            if item.unit_price != expected_price {
                 return Err(format!(
-                    "Insufficient stock for '{}': available {}, requested {}",
-                    product.full_name, product.current_amount, item.quantity
+                    "Price mismatch for '{}': expected ${}, got ${}",
+                    product.full_name, expected_price, item.unit_price
                 ));
             }
 
-            // Validate price matches (security check)
-            if item.unit_price != product.user_price {
+            if !product
+                .category_id
+                .as_ref()
+                .is_some_and(|id| excluded_categories.contains(id))
+            {
+                eligible_amount += item.subtotal();
+            }
+        }
+
+        for (name, available, requested) in demand.values() {
+            if available < requested {
                 return Err(format!(
-                    "Price mismatch for '{}': expected ${}, got ${}",
-                    product.full_name, product.user_price, item.unit_price
+                    "Insufficient stock for '{}': available {}, requested {}",
+                    name, available, requested
                 ));
             }
         }
@@ -85,14 +215,118 @@ impl SalesApi {
         // Validate payment based on whether it's a loan
         if !input.is_loan() {
             // Cash sales must be paid in full
-            if input.paid_amount < total {
+            if input.tendered_amount() < total {
                 return Err("Cash sales must be paid in full".to_string());
             }
         }
         // Loans can have partial or zero payment - no validation needed
 
+        // Invoice data must be complete at checkout, not fixed up later
+        if let Some(profile) = input.tax_profile.take() {
+            let profile = normalize_tax_profile(profile);
+            profile
+                .validate()
+                .map_err(|e| format!("Invalid tax profile: {}", e))?;
+            input.tax_profile = Some(profile);
+        }
+
+        let points = self
+            .loyalty_entries(&input, loyalty, eligible_amount)
+            .await?;
+
         // Create the sale (repository handles stock deduction)
-        self.sale_repo.create(input).await
+        self.sale_repo.create(input, &points, today).await
+    }
+
+    /// Categories that don't earn points, including subcategories of excluded ones
+    async fn excluded_categories(&self, loyalty: &LoyaltySettings) -> Result<Vec<String>, String> {
+        if loyalty.excluded_category_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let categories = self.category_repo.list_all().await?;
+        Ok(loyalty
+            .excluded_category_ids
+            .iter()
+            .flat_map(|id| Category::subtree_ids(&categories, id))
+            .collect())
+    }
+
+    /// Ledger entries of a sale: points past their expiry, points redeemed and
+    /// points earned
+    async fn loyalty_entries(
+        &self,
+        input: &SaleInput,
+        loyalty: &LoyaltySettings,
+        eligible_amount: Decimal,
+    ) -> Result<Vec<PointsEntry>, String> {
+        let redeems = input.redeemed_points != 0 || input.points_amount != Decimal::ZERO;
+        let customer_id = match &input.customer_id {
+            Some(customer_id) if loyalty.is_enabled() => customer_id,
+            _ if redeems => {
+                return Err(
+                    "Points can only be redeemed by a customer of the loyalty program".to_string(),
+                )
+            }
+            _ => return Ok(Vec::new()),
+        };
+
+        if input.redeemed_points < 0 {
+            return Err("Redeemed points cannot be negative".to_string());
+        }
+
+        // Validate points value matches (security check)
+        let points_value = loyalty.value_of(input.redeemed_points);
+        if input.points_amount != points_value {
+            return Err(format!(
+                "Points value mismatch: expected ${}, got ${}",
+                points_value, input.points_amount
+            ));
+        }
+        if input.points_amount > input.total_amount() {
+            return Err("Points cannot pay more than the sale total".to_string());
+        }
+
+        let now = Utc::now();
+        let ledger = self.loyalty_repo.list_entries(customer_id).await?;
+        let expired = expired_points(&ledger, now);
+        let mut entries = Vec::new();
+        if expired > 0 {
+            entries.push(PointsEntry::expire(customer_id, expired));
+        }
+
+        if input.redeemed_points > 0 {
+            let balance = points_balance(&ledger) - expired;
+            if balance < input.redeemed_points {
+                return Err(format!(
+                    "Not enough points: balance {}, requested {}",
+                    balance, input.redeemed_points
+                ));
+            }
+            entries.push(PointsEntry::redeem(customer_id, input.redeemed_points));
+        }
+
+        // Only sales paid in full earn, and not on the part paid with points
+        if !input.is_loan() {
+            let earned = loyalty.points_for(eligible_amount - input.points_amount);
+            if earned > 0 {
+                entries.push(PointsEntry::earn(
+                    customer_id,
+                    earned,
+                    loyalty.expiry_from(now),
+                ));
+            }
+        }
+
+        Ok(entries)
+    }
+
+    /// Tax profile captured when the sale was made, if any
+    pub async fn get_sale_tax_profile(
+        &self,
+        sale_id: &str,
+    ) -> Result<Option<InvoiceReceiver>, String> {
+        self.sale_repo.get_tax_profile(sale_id).await
     }
 
     /// Get sale by ID with operations
@@ -113,11 +347,98 @@ impl SalesApi {
         self.sale_repo.list_all().await
     }
 
+    /// Search past sales with filters and pagination
+    pub async fn search_sales_paginated(
+        &self,
+        filter: SaleFilter,
+        clock: &StoreClock,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PaginatedResult<Sale>, String> {
+        filter.validate()?;
+        self.sale_repo
+            .search_paginated(&filter, clock, page.max(1), page_size)
+            .await
+    }
+
     /// Get sales within date range
     pub async fn get_sales_by_date(&self, start: &str, end: &str) -> Result<Vec<Sale>, String> {
         self.sale_repo.list_by_date_range(start, end).await
     }
 
+    /// Get sales made on a business day
+    pub async fn get_sales_on(
+        &self,
+        date: NaiveDate,
+        clock: &StoreClock,
+    ) -> Result<Vec<Sale>, String> {
+        let (start, end) = clock.day_bounds(date);
+        self.sale_repo.list_by_date_range(&start, &end).await
+    }
+
+    /// Sale lines of a category's products (including subcategories) sold on a
+    /// business day
+    pub async fn get_category_operations_on(
+        &self,
+        date: NaiveDate,
+        category_id: &str,
+        clock: &StoreClock,
+    ) -> Result<Vec<Operation>, String> {
+        let (start, end) = clock.day_bounds(date);
+        self.sale_repo
+            .operations_between(&start, &end, Some(category_id))
+            .await
+    }
+
+    /// Units, revenue and cost per product sold between two business days,
+    /// optionally only for a category (including subcategories)
+    pub async fn get_product_sales(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        category_id: Option<&str>,
+        clock: &StoreClock,
+    ) -> Result<Vec<ProductSales>, String> {
+        if from > to {
+            return Err("Start date must be before end date".to_string());
+        }
+        let (start, end) = clock.range_bounds(from, to);
+        self.sale_repo
+            .product_sales_between(&start, &end, category_id)
+            .await
+    }
+
+    /// Tickets and revenue for each range of business days [start, end),
+    /// optionally only for a category (including subcategories)
+    pub async fn get_period_totals(
+        &self,
+        ranges: &[(NaiveDate, NaiveDate)],
+        category_id: Option<&str>,
+        clock: &StoreClock,
+    ) -> Result<Vec<PeriodTotals>, String> {
+        let bounds: Vec<(String, String)> = ranges
+            .iter()
+            .map(|(start, end)| (clock.day_start(*start), clock.day_start(*end)))
+            .collect();
+        let totals = self.sale_repo.totals_by_range(&bounds, category_id).await?;
+
+        Ok(ranges
+            .iter()
+            .zip(totals)
+            .map(|((start, end), (tickets, revenue))| PeriodTotals {
+                start: *start,
+                end: *end,
+                tickets,
+                revenue,
+            })
+            .collect())
+    }
+
+    /// Most recent sale time of every product ever sold
+    pub async fn get_last_sold_per_product(&self) -> Result<Vec<(String, DateTime<Utc>)>, String> {
+        self.sale_repo.last_sold_per_product().await
+    }
+
     /// Get sales for a customer
     pub async fn get_customer_sales(&self, customer_name: &str) -> Result<Vec<Sale>, String> {
         self.sale_repo.get_by_customer(customer_name).await
@@ -134,7 +455,7 @@ impl SalesApi {
 
         let loan_sales = sales.iter().filter(|s| s.is_loan).count();
 
-        let total_cash_received = sales.iter().map(|s| s.paid_amount).sum();
+        let total_cash_received = sales.iter().map(|s| s.cash_received()).sum();
 
         Ok(SalesStats {
             total_sales,
@@ -145,18 +466,14 @@ impl SalesApi {
         })
     }
 
-    /// Get today's sales
-    pub async fn get_today_sales(&self) -> Result<Vec<Sale>, String> {
-        let today = chrono::Utc::now().date_naive();
-        let start = format!("{}T00:00:00Z", today);
-        let end = format!("{}T23:59:59Z", today);
-
-        self.sale_repo.list_by_date_range(&start, &end).await
+    /// Get the current business day's sales
+    pub async fn get_today_sales(&self, clock: &StoreClock) -> Result<Vec<Sale>, String> {
+        self.get_sales_on(clock.today(), clock).await
     }
 
-    /// Get sales statistics for today
-    pub async fn get_today_stats(&self) -> Result<SalesStats, String> {
-        let sales = self.get_today_sales().await?;
+    /// Get sales statistics for the current business day
+    pub async fn get_today_stats(&self, clock: &StoreClock) -> Result<SalesStats, String> {
+        let sales = self.get_today_sales(clock).await?;
 
         let total_sales = sales.len();
         let total_revenue = sales.iter().map(|s| s.total_amount).sum();
@@ -165,7 +482,7 @@ impl SalesApi {
 
         let loan_sales = sales.iter().filter(|s| s.is_loan).count();
 
-        let total_cash_received = sales.iter().map(|s| s.paid_amount).sum();
+        let total_cash_received = sales.iter().map(|s| s.cash_received()).sum();
 
         Ok(SalesStats {
             total_sales,
@@ -193,3 +510,186 @@ pub struct SalesStats {
     pub cash_sales: usize,
     pub loan_sales: usize,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        CustomerInput, KitComponentInput, PriceListInput, ProductInput, ScaleBarcodeFormat,
+    };
+    use crate::utils::test_store::{cash_sale, product_input, sale_item, TestStore};
+    use rust_decimal_macros::dec;
+
+    #[tokio::test]
+    async fn test_price_label_is_charged_as_printed() {
+        let fx = TestStore::new().await;
+        let ham = fx
+            .products
+            .create(ProductInput {
+                barcode: Some("123".to_string()),
+                ..product_input("Ham", dec!(89.90), 5.0)
+            })
+            .await
+            .unwrap();
+
+        // A wholesale customer would otherwise get 10% off the regular price
+        let wholesale = fx
+            .price_lists
+            .create(PriceListInput {
+                name: "Wholesale".to_string(),
+                discount_percent: dec!(10),
+            })
+            .await
+            .unwrap();
+        let customer = fx
+            .customers
+            .create(CustomerInput {
+                name: "Deli".to_string(),
+                phone: None,
+                tax_profile: None,
+                price_list_id: Some(wholesale.id),
+            })
+            .await
+            .unwrap();
+
+        let format = ScaleBarcodeFormat::parse("22PPPPP$$$$$C").unwrap();
+        let scanned = fx
+            .inventory
+            .resolve_sale_barcode("2200123045508", &[format])
+            .await
+            .unwrap();
+        assert_eq!(scanned.pack_quantity, 0.506);
+        assert_eq!(scanned.line_total, Some(dec!(45.50)));
+
+        // A fraction of a piece-sold product, charged at the printed amount
+        let mut label = sale_item(&ham, scanned.pack_quantity, ham.user_price);
+        label.line_total = scanned.line_total;
+        let sale = fx
+            .sell(cash_sale(vec![label.clone()], Some(customer.id.clone())))
+            .await
+            .unwrap();
+        assert_eq!(sale.total_amount, dec!(45.50));
+
+        // The printed amount only buys the quantity on the label
+        let mut heavier = label.clone();
+        heavier.quantity = 10.0;
+        let err = fx
+            .sell(cash_sale(vec![heavier], Some(customer.id.clone())))
+            .await
+            .unwrap_err();
+        assert_eq!(err, "Label amount $45.50 does not match 10 of 'Ham'");
+
+        // The label keeps the regular price it was printed with
+        label.unit_price = dec!(80.91);
+        assert!(fx
+            .sell(cash_sale(vec![label], Some(customer.id)))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_stock_is_checked_across_the_cart() {
+        let fx = TestStore::new().await;
+        let soda = fx
+            .products
+            .create(product_input("Soda", dec!(20), 10.0))
+            .await
+            .unwrap();
+        let six_pack = fx
+            .products
+            .create(product_input("Soda 6-pack", dec!(100), 0.0))
+            .await
+            .unwrap();
+        fx.products
+            .set_component(
+                &six_pack.id,
+                KitComponentInput {
+                    component_id: soda.id.clone(),
+                    quantity: 6.0,
+                },
+            )
+            .await
+            .unwrap();
+
+        // 6 sodas in the pack plus 5 loose ones exceed the 10 in stock
+        let err = fx
+            .sell(cash_sale(
+                vec![
+                    sale_item(&six_pack, 1.0, dec!(100)),
+                    sale_item(&soda, 5.0, dec!(20)),
+                ],
+                None,
+            ))
+            .await
+            .unwrap_err();
+        assert!(err.contains("Insufficient stock for 'Soda'"), "{}", err);
+
+        // So do two lines of the same product
+        assert!(fx
+            .sell(cash_sale(
+                vec![
+                    sale_item(&soda, 6.0, dec!(20)),
+                    sale_item(&soda, 5.0, dec!(20))
+                ],
+                None
+            ))
+            .await
+            .is_err());
+
+        fx.sell(cash_sale(
+            vec![
+                sale_item(&six_pack, 1.0, dec!(100)),
+                sale_item(&soda, 4.0, dec!(20)),
+            ],
+            None,
+        ))
+        .await
+        .unwrap();
+        let soda = fx.products.get_by_id(&soda.id).await.unwrap().unwrap();
+        assert_eq!(soda.current_amount, 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_kit_velocity_uses_components_at_sale_time() {
+        let fx = TestStore::new().await;
+        let soda = fx
+            .products
+            .create(product_input("Soda", dec!(20), 20.0))
+            .await
+            .unwrap();
+        let pack = fx
+            .products
+            .create(product_input("Soda pack", dec!(100), 0.0))
+            .await
+            .unwrap();
+        let component = |quantity| KitComponentInput {
+            component_id: soda.id.clone(),
+            quantity,
+        };
+        fx.products
+            .set_component(&pack.id, component(6.0))
+            .await
+            .unwrap();
+
+        let since = Utc::now() - chrono::Duration::days(1);
+        fx.sell(cash_sale(vec![sale_item(&pack, 2.0, dec!(100))], None))
+            .await
+            .unwrap();
+
+        // Repacking as a 4-pack leaves the 12 sodas already sold untouched
+        fx.products
+            .set_component(&pack.id, component(4.0))
+            .await
+            .unwrap();
+        let sold: HashMap<String, f64> = fx
+            .sales
+            .sale_repo
+            .units_sold_since(since)
+            .await
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(sold[&soda.id], 12.0);
+        assert_eq!(sold[&pack.id], 2.0);
+    }
+}
@@ -0,0 +1,264 @@
+//! Settings API
+//!
+//! Business logic for store-wide configuration.
+
+use crate::models::pricing::{format_price_endings, parse_price_endings};
+use crate::models::scale_barcode::{format_scale_formats, parse_scale_formats};
+use crate::models::{
+    InvoiceIssuer, LoyaltySettings, ReorderPolicy, ScaleBarcodeFormat, StoreClock,
+};
+use crate::repo::SettingsRepository;
+use rust_decimal::Decimal;
+use std::sync::Arc;
+
+/// Setting key for the allowed price endings
+const PRICE_ENDINGS_KEY: &str = "price_endings";
+
+/// Setting key for the scale barcode formats
+const SCALE_FORMATS_KEY: &str = "scale_barcode_formats";
+
+/// Setting keys for the reorder policy
+const REORDER_WINDOW_KEY: &str = "reorder_window_days";
+const REORDER_COVERAGE_KEY: &str = "reorder_coverage_days";
+const REORDER_SAFETY_KEY: &str = "reorder_safety_factor";
+
+/// Setting keys for the store timezone and business-day cutoff
+const TIMEZONE_KEY: &str = "store_timezone";
+const DAY_CUTOFF_KEY: &str = "business_day_cutoff_hour";
+
+/// Setting keys for the invoice issuer
+const ISSUER_RFC_KEY: &str = "invoice_issuer_rfc";
+const ISSUER_NAME_KEY: &str = "invoice_issuer_legal_name";
+const ISSUER_REGIME_KEY: &str = "invoice_issuer_tax_regime";
+const ISSUER_ZIP_KEY: &str = "invoice_issuer_zip_code";
+const ISSUER_SERIES_KEY: &str = "invoice_series";
+const ISSUER_CERTIFICATE_KEY: &str = "invoice_certificate_number";
+
+/// Setting keys for the loyalty program
+const LOYALTY_AMOUNT_KEY: &str = "loyalty_amount_per_point";
+const LOYALTY_VALUE_KEY: &str = "loyalty_point_value";
+const LOYALTY_EXPIRY_KEY: &str = "loyalty_expiry_days";
+const LOYALTY_EXCLUDED_KEY: &str = "loyalty_excluded_categories";
+
+#[derive(Clone)]
+pub struct SettingsApi {
+    settings_repo: Arc<dyn SettingsRepository>,
+}
+
+impl std::fmt::Debug for SettingsApi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SettingsApi").finish()
+    }
+}
+
+impl PartialEq for SettingsApi {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.settings_repo, &other.settings_repo)
+    }
+}
+
+impl SettingsApi {
+    pub fn new(settings_repo: Arc<dyn SettingsRepository>) -> Self {
+        Self { settings_repo }
+    }
+
+    /// Get the price endings prices are rounded to (empty when not configured)
+    pub async fn get_price_endings(&self) -> Result<Vec<Decimal>, String> {
+        match self.settings_repo.get(PRICE_ENDINGS_KEY).await? {
+            Some(value) => parse_price_endings(&value),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Save the price endings, e.g. ".50, .90"
+    pub async fn set_price_endings(&self, value: &str) -> Result<Vec<Decimal>, String> {
+        let endings = parse_price_endings(value)?;
+        self.settings_repo
+            .set(PRICE_ENDINGS_KEY, &format_price_endings(&endings))
+            .await?;
+        Ok(endings)
+    }
+
+    /// Get the price- and weight-embedded barcode formats (empty when not configured)
+    pub async fn get_scale_formats(&self) -> Result<Vec<ScaleBarcodeFormat>, String> {
+        match self.settings_repo.get(SCALE_FORMATS_KEY).await? {
+            Some(value) => parse_scale_formats(&value),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Save the scale barcode formats, e.g. "20PPPPPWWWWWC, 22PPPPP$$$$$C"
+    pub async fn set_scale_formats(&self, value: &str) -> Result<Vec<ScaleBarcodeFormat>, String> {
+        let formats = parse_scale_formats(value)?;
+        self.settings_repo
+            .set(SCALE_FORMATS_KEY, &format_scale_formats(&formats))
+            .await?;
+        Ok(formats)
+    }
+
+    /// Get the reorder policy, falling back to defaults for unset values
+    pub async fn get_reorder_policy(&self) -> Result<ReorderPolicy, String> {
+        let defaults = ReorderPolicy::default();
+
+        Ok(ReorderPolicy {
+            window_days: self
+                .get_parsed(REORDER_WINDOW_KEY, defaults.window_days)
+                .await?,
+            coverage_days: self
+                .get_parsed(REORDER_COVERAGE_KEY, defaults.coverage_days)
+                .await?,
+            safety_factor: self
+                .get_parsed(REORDER_SAFETY_KEY, defaults.safety_factor)
+                .await?,
+        })
+    }
+
+    /// Save the reorder policy
+    pub async fn set_reorder_policy(&self, policy: ReorderPolicy) -> Result<ReorderPolicy, String> {
+        policy.validate()?;
+
+        self.settings_repo
+            .set(REORDER_WINDOW_KEY, &policy.window_days.to_string())
+            .await?;
+        self.settings_repo
+            .set(REORDER_COVERAGE_KEY, &policy.coverage_days.to_string())
+            .await?;
+        self.settings_repo
+            .set(REORDER_SAFETY_KEY, &policy.safety_factor.to_string())
+            .await?;
+        Ok(policy)
+    }
+
+    /// Get the store timezone and business-day cutoff, falling back to defaults
+    pub async fn get_store_clock(&self) -> Result<StoreClock, String> {
+        let defaults = StoreClock::default();
+        let timezone = self
+            .settings_repo
+            .get(TIMEZONE_KEY)
+            .await?
+            .unwrap_or_else(|| defaults.timezone_name().to_string());
+        let day_cutoff_hour = self
+            .get_parsed(DAY_CUTOFF_KEY, defaults.day_cutoff_hour)
+            .await?;
+
+        StoreClock::new(&timezone, day_cutoff_hour)
+    }
+
+    /// Save the store timezone and business-day cutoff
+    pub async fn set_store_clock(&self, clock: StoreClock) -> Result<StoreClock, String> {
+        self.settings_repo
+            .set(TIMEZONE_KEY, clock.timezone_name())
+            .await?;
+        self.settings_repo
+            .set(DAY_CUTOFF_KEY, &clock.day_cutoff_hour.to_string())
+            .await?;
+        Ok(clock)
+    }
+
+    /// Get the store's invoicing data (empty when not configured)
+    pub async fn get_invoice_issuer(&self) -> Result<InvoiceIssuer, String> {
+        Ok(InvoiceIssuer {
+            rfc: self.get_text(ISSUER_RFC_KEY).await?,
+            legal_name: self.get_text(ISSUER_NAME_KEY).await?,
+            tax_regime: self.get_text(ISSUER_REGIME_KEY).await?,
+            zip_code: self.get_text(ISSUER_ZIP_KEY).await?,
+            series: self.get_text(ISSUER_SERIES_KEY).await?,
+            certificate_number: self.get_text(ISSUER_CERTIFICATE_KEY).await?,
+        })
+    }
+
+    /// Save the store's invoicing data; an empty RFC turns invoicing off
+    pub async fn set_invoice_issuer(&self, issuer: InvoiceIssuer) -> Result<InvoiceIssuer, String> {
+        let issuer = if !issuer.is_configured() {
+            InvoiceIssuer::default()
+        } else {
+            InvoiceIssuer {
+                rfc: issuer.rfc.trim().to_uppercase(),
+                legal_name: issuer.legal_name.trim().to_string(),
+                tax_regime: issuer.tax_regime,
+                zip_code: issuer.zip_code.trim().to_string(),
+                series: issuer.series.trim().to_uppercase(),
+                certificate_number: issuer.certificate_number.trim().to_string(),
+            }
+        };
+        if issuer.is_configured() {
+            issuer.validate()?;
+        }
+
+        for (key, value) in [
+            (ISSUER_RFC_KEY, &issuer.rfc),
+            (ISSUER_NAME_KEY, &issuer.legal_name),
+            (ISSUER_REGIME_KEY, &issuer.tax_regime),
+            (ISSUER_ZIP_KEY, &issuer.zip_code),
+            (ISSUER_SERIES_KEY, &issuer.series),
+            (ISSUER_CERTIFICATE_KEY, &issuer.certificate_number),
+        ] {
+            self.settings_repo.set(key, value).await?;
+        }
+        Ok(issuer)
+    }
+
+    /// Get the loyalty program rules, falling back to defaults (program off)
+    pub async fn get_loyalty_settings(&self) -> Result<LoyaltySettings, String> {
+        let defaults = LoyaltySettings::default();
+
+        Ok(LoyaltySettings {
+            amount_per_point: self
+                .get_parsed(LOYALTY_AMOUNT_KEY, defaults.amount_per_point)
+                .await?,
+            point_value: self
+                .get_parsed(LOYALTY_VALUE_KEY, defaults.point_value)
+                .await?,
+            expiry_days: self
+                .get_parsed(LOYALTY_EXPIRY_KEY, defaults.expiry_days)
+                .await?,
+            excluded_category_ids: self
+                .get_text(LOYALTY_EXCLUDED_KEY)
+                .await?
+                .split(',')
+                .filter(|id| !id.is_empty())
+                .map(str::to_string)
+                .collect(),
+        })
+    }
+
+    /// Save the loyalty program rules
+    pub async fn set_loyalty_settings(
+        &self,
+        settings: LoyaltySettings,
+    ) -> Result<LoyaltySettings, String> {
+        settings.validate()?;
+
+        self.settings_repo
+            .set(LOYALTY_AMOUNT_KEY, &settings.amount_per_point.to_string())
+            .await?;
+        self.settings_repo
+            .set(LOYALTY_VALUE_KEY, &settings.point_value.to_string())
+            .await?;
+        self.settings_repo
+            .set(LOYALTY_EXPIRY_KEY, &settings.expiry_days.to_string())
+            .await?;
+        self.settings_repo
+            .set(
+                LOYALTY_EXCLUDED_KEY,
+                &settings.excluded_category_ids.join(","),
+            )
+            .await?;
+        Ok(settings)
+    }
+
+    /// Read a text setting, empty when unset
+    async fn get_text(&self, key: &str) -> Result<String, String> {
+        Ok(self.settings_repo.get(key).await?.unwrap_or_default())
+    }
+
+    /// Read a setting as a number, using the default when unset
+    async fn get_parsed<T: std::str::FromStr>(&self, key: &str, default: T) -> Result<T, String> {
+        match self.settings_repo.get(key).await? {
+            Some(value) => value
+                .parse()
+                .map_err(|_| format!("Invalid value for setting {}: {}", key, value)),
+            None => Ok(default),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! Customers Handler
+//!
+//! UI event handlers for customer management.
+
+use crate::api::CustomersApi;
+use crate::models::{Customer, CustomerInput};
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CustomersHandler {
+    api: Arc<CustomersApi>,
+}
+
+impl CustomersHandler {
+    pub fn new(api: Arc<CustomersApi>) -> Self {
+        Self { api }
+    }
+
+    /// Load all customers
+    pub async fn load_customers(&self) -> Result<Vec<Customer>, String> {
+        self.api.list_customers().await
+    }
+
+    /// Create a new customer
+    pub async fn create_customer(&self, input: CustomerInput) -> Result<Customer, String> {
+        self.api.create_customer(input).await
+    }
+
+    /// Update an existing customer
+    pub async fn update_customer(
+        &self,
+        id: String,
+        input: CustomerInput,
+    ) -> Result<Customer, String> {
+        self.api.update_customer(&id, input).await
+    }
+}
@@ -0,0 +1,42 @@
+//! Export Handler
+//!
+//! UI event handlers for CSV and XLSX exports.
+
+use crate::api::{ExportApi, SettingsApi};
+use crate::utils::export::{ExportDataset, ExportFormat};
+use chrono::NaiveDate;
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExportHandler {
+    api: Arc<ExportApi>,
+    settings_api: Arc<SettingsApi>, // Store clock for business days
+}
+
+impl ExportHandler {
+    pub fn new(api: Arc<ExportApi>, settings_api: Arc<SettingsApi>) -> Self {
+        Self { api, settings_api }
+    }
+
+    /// Export a dataset between two dates such as "2026-10-01", returning the file contents
+    pub async fn export(
+        &self,
+        dataset: ExportDataset,
+        from: String,
+        to: String,
+        format: ExportFormat,
+    ) -> Result<Vec<u8>, String> {
+        let clock = self.settings_api.get_store_clock().await?;
+        let table = self
+            .api
+            .export(dataset, parse_date(&from)?, parse_date(&to)?, &clock)
+            .await?;
+        table.write(format)
+    }
+}
+
+/// Parse a date input value
+fn parse_date(date: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+        .map_err(|_| format!("Invalid date: {}", date))
+}
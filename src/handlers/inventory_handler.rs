@@ -2,19 +2,31 @@
 //!
 //! UI event handlers for inventory management.
 
-use crate::api::{InventoryApi, InventoryStats};
-use crate::models::{Product, ProductInput, UnitMeasurement};
+use crate::api::{
+    ImportRow, ImportSummary, InventoryApi, InventoryStats, RepriceLine, SettingsApi,
+    ShrinkageReport,
+};
+use crate::models::{
+    AdjustmentReason, BarcodeMatch, Category, CategoryInput, CategoryPath, ExpiringLot,
+    InventoryCount, InventoryCountLine, ItemCondition, KitComponent, KitComponentInput,
+    PriceChangeSource, PriceHistoryEntry, Product, ProductBarcode, ProductBarcodeInput,
+    ProductInput, ProductLot, RepriceInput, StockAdjustment, StockReceiptInput, Supplier,
+    SupplierInput, UnitMeasurement, UnitMeasurementInput, WriteOffInput,
+};
 use crate::repo::PaginatedResult;
+use crate::utils::product_csv::{CsvTable, ProductColumn};
+use chrono::NaiveDate;
 use std::sync::Arc;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct InventoryHandler {
     api: Arc<InventoryApi>,
+    settings_api: Arc<SettingsApi>, // Scale barcode formats and the store clock
 }
 
 impl InventoryHandler {
-    pub fn new(api: Arc<InventoryApi>) -> Self {
-        Self { api }
+    pub fn new(api: Arc<InventoryApi>, settings_api: Arc<SettingsApi>) -> Self {
+        Self { api, settings_api }
     }
 
     /// Load all products
@@ -22,6 +34,11 @@ impl InventoryHandler {
         self.api.list_products().await
     }
 
+    /// Load a single product
+    pub async fn get_product(&self, id: String) -> Result<Product, String> {
+        self.api.get_product(&id).await
+    }
+
     /// Load products with pagination
     pub async fn load_products_paginated(
         &self,
@@ -41,25 +58,51 @@ impl InventoryHandler {
         self.api.update_product(&id, input).await
     }
 
-    /// Delete a product
+    /// Permanently delete a product that was never sold
     pub async fn delete_product(&self, id: String) -> Result<(), String> {
         self.api.delete_product(&id).await
     }
 
+    /// Archive a product
+    pub async fn archive_product(&self, id: String) -> Result<Product, String> {
+        self.api.archive_product(&id).await
+    }
+
+    /// Restore an archived product
+    pub async fn restore_product(&self, id: String) -> Result<Product, String> {
+        self.api.restore_product(&id).await
+    }
+
+    /// Load archived products
+    pub async fn load_archived_products(&self) -> Result<Vec<Product>, String> {
+        self.api.list_archived_products().await
+    }
+
     /// Search products by name or barcode
     pub async fn search_products(&self, query: String) -> Result<Vec<Product>, String> {
         self.api.search_products(&query).await
     }
 
-    /// Search products with pagination
+    /// Search products with pagination, optionally filtered by category
     pub async fn search_products_paginated(
         &self,
         query: String,
+        category_id: Option<String>,
         page: i64,
         page_size: i64,
     ) -> Result<PaginatedResult<Product>, String> {
         self.api
-            .search_products_paginated(&query, page, page_size)
+            .search_products_paginated(&query, category_id.as_deref(), page, page_size)
+            .await
+    }
+
+    /// Load products in a category (including subcategories), or all products
+    pub async fn load_products_by_category(
+        &self,
+        category_id: Option<String>,
+    ) -> Result<Vec<Product>, String> {
+        self.api
+            .list_products_by_category(category_id.as_deref())
             .await
     }
 
@@ -68,14 +111,153 @@ impl InventoryHandler {
         self.api.get_low_stock_products().await
     }
 
+    /// Get the components of a kit
+    pub async fn get_kit_components(&self, kit_id: String) -> Result<Vec<KitComponent>, String> {
+        self.api.list_kit_components(&kit_id).await
+    }
+
+    /// Add a component to a kit, or change its quantity
+    pub async fn set_kit_component(
+        &self,
+        kit_id: String,
+        input: KitComponentInput,
+    ) -> Result<Vec<KitComponent>, String> {
+        self.api.set_kit_component(&kit_id, input).await
+    }
+
+    /// Remove a component from a kit
+    pub async fn remove_kit_component(
+        &self,
+        kit_id: String,
+        component_id: String,
+    ) -> Result<Vec<KitComponent>, String> {
+        self.api.remove_kit_component(&kit_id, &component_id).await
+    }
+
     /// Add stock to a product
     pub async fn add_stock(&self, id: String, quantity: f64) -> Result<Product, String> {
         self.api.add_stock(&id, quantity).await
     }
 
-    /// Remove stock from a product
-    pub async fn remove_stock(&self, id: String, quantity: f64) -> Result<Product, String> {
-        self.api.remove_stock(&id, quantity).await
+    /// Receive a batch of stock in the product's purchase unit
+    pub async fn receive_stock(
+        &self,
+        id: String,
+        input: StockReceiptInput,
+    ) -> Result<Product, String> {
+        self.api.receive_stock(&id, input).await
+    }
+
+    /// Get all lots of a product
+    pub async fn get_lots(&self, product_id: String) -> Result<Vec<ProductLot>, String> {
+        self.api.list_product_lots(&product_id).await
+    }
+
+    /// Get the price and cost changes of a product, newest first
+    pub async fn get_price_history(
+        &self,
+        product_id: String,
+    ) -> Result<Vec<PriceHistoryEntry>, String> {
+        self.api.list_price_history(&product_id).await
+    }
+
+    /// Get lots expiring within the given number of days
+    pub async fn get_expiring_lots(&self, days: i64) -> Result<Vec<ExpiringLot>, String> {
+        let clock = self.settings_api.get_store_clock().await?;
+        self.api.get_expiring_lots(days, clock.today()).await
+    }
+
+    /// Move part of a lot to the Damaged or Expired condition
+    pub async fn move_lot(
+        &self,
+        lot_id: String,
+        quantity: f64,
+        condition_id: i32,
+    ) -> Result<ProductLot, String> {
+        self.api
+            .move_lot_to_condition(&lot_id, quantity, condition_id)
+            .await
+    }
+
+    /// Write off stock with a reason
+    pub async fn write_off_stock(&self, input: WriteOffInput) -> Result<StockAdjustment, String> {
+        self.api.write_off_stock(input).await
+    }
+
+    /// Get the shrinkage report for a period
+    pub async fn get_shrinkage_report(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<ShrinkageReport, String> {
+        let clock = self.settings_api.get_store_clock().await?;
+        self.api.get_shrinkage_report(start, end, &clock).await
+    }
+
+    /// Load all inventory counts
+    pub async fn get_counts(&self) -> Result<Vec<InventoryCount>, String> {
+        self.api.list_counts().await
+    }
+
+    /// Get an inventory count
+    pub async fn get_count(&self, id: String) -> Result<InventoryCount, String> {
+        self.api.get_count(&id).await
+    }
+
+    /// Start a new inventory count
+    pub async fn start_count(&self, name: String) -> Result<InventoryCount, String> {
+        self.api.start_count(&name).await
+    }
+
+    /// Get the lines of an inventory count
+    pub async fn get_count_lines(
+        &self,
+        count_id: String,
+    ) -> Result<Vec<InventoryCountLine>, String> {
+        self.api.list_count_lines(&count_id).await
+    }
+
+    /// Set or clear the counted quantity of a product
+    pub async fn set_counted_quantity(
+        &self,
+        count_id: String,
+        product_id: String,
+        counted_quantity: Option<f64>,
+    ) -> Result<InventoryCountLine, String> {
+        self.api
+            .set_counted_quantity(&count_id, &product_id, counted_quantity)
+            .await
+    }
+
+    /// Count one scan of a barcode
+    pub async fn record_count_scan(
+        &self,
+        count_id: String,
+        barcode: String,
+    ) -> Result<InventoryCountLine, String> {
+        self.api.record_count_scan(&count_id, &barcode).await
+    }
+
+    /// Approve or reject posting a line's variance
+    pub async fn set_count_line_approved(
+        &self,
+        count_id: String,
+        product_id: String,
+        approved: bool,
+    ) -> Result<(), String> {
+        self.api
+            .set_count_line_approved(&count_id, &product_id, approved)
+            .await
+    }
+
+    /// Post the approved variances of an inventory count
+    pub async fn post_count(&self, count_id: String) -> Result<Vec<StockAdjustment>, String> {
+        self.api.post_count(&count_id).await
+    }
+
+    /// Delete an open inventory count
+    pub async fn delete_count(&self, count_id: String) -> Result<(), String> {
+        self.api.delete_count(&count_id).await
     }
 
     /// Set stock amount directly
@@ -83,18 +265,171 @@ impl InventoryHandler {
         self.api.set_stock(&id, amount).await
     }
 
-    /// Get inventory statistics
-    pub async fn get_stats(&self) -> Result<InventoryStats, String> {
-        self.api.get_inventory_stats().await
+    /// Preview a bulk price change
+    pub async fn preview_reprice(&self, input: RepriceInput) -> Result<Vec<RepriceLine>, String> {
+        self.api.preview_reprice(&input).await
+    }
+
+    /// Apply a bulk price change, returning how many prices changed
+    pub async fn apply_reprice(&self, input: RepriceInput) -> Result<usize, String> {
+        self.api.apply_reprice(&input).await
+    }
+
+    /// Get inventory statistics, optionally scoped to a category
+    pub async fn get_stats(&self, category_id: Option<String>) -> Result<InventoryStats, String> {
+        self.api.get_inventory_stats(category_id.as_deref()).await
     }
 
-    /// Get product by barcode (for quick lookup)
-    pub async fn scan_barcode(&self, barcode: String) -> Result<Product, String> {
-        self.api.get_product_by_barcode(&barcode).await
+    /// Resolve a scanned barcode to its product and quantity
+    ///
+    /// Pack barcodes give their pack quantity; scale barcodes give the
+    /// embedded weight, or the embedded price converted to a quantity.
+    pub async fn scan_barcode(&self, barcode: String) -> Result<BarcodeMatch, String> {
+        let scale_formats = self.settings_api.get_scale_formats().await?;
+        self.api
+            .resolve_sale_barcode(&barcode, &scale_formats)
+            .await
+    }
+
+    /// Get additional barcodes of a product
+    pub async fn get_barcodes(&self, product_id: String) -> Result<Vec<ProductBarcode>, String> {
+        self.api.list_product_barcodes(&product_id).await
+    }
+
+    /// Add an additional barcode to a product
+    pub async fn add_barcode(
+        &self,
+        product_id: String,
+        input: ProductBarcodeInput,
+    ) -> Result<ProductBarcode, String> {
+        self.api.add_product_barcode(&product_id, input).await
+    }
+
+    /// Remove an additional barcode
+    pub async fn remove_barcode(&self, barcode: String) -> Result<(), String> {
+        self.api.remove_product_barcode(&barcode).await
+    }
+
+    /// Get the next unused internal EAN-13 code
+    pub async fn next_internal_barcode(&self) -> Result<String, String> {
+        self.api.next_internal_barcode().await
+    }
+
+    /// Assign internal EAN-13 codes to products without a barcode
+    pub async fn generate_missing_barcodes(&self) -> Result<usize, String> {
+        self.api.generate_missing_barcodes().await
+    }
+
+    /// Preview a CSV import without saving anything
+    pub async fn preview_import(
+        &self,
+        table: CsvTable,
+        mapping: Vec<Option<ProductColumn>>,
+    ) -> Result<Vec<ImportRow>, String> {
+        self.api.preview_product_import(&table, &mapping).await
+    }
+
+    /// Import products from a CSV file
+    pub async fn import_products(
+        &self,
+        table: CsvTable,
+        mapping: Vec<Option<ProductColumn>>,
+    ) -> Result<ImportSummary, String> {
+        self.api.import_products(&table, &mapping).await
+    }
+
+    /// Export the product catalog as CSV
+    pub async fn export_products_csv(&self) -> Result<String, String> {
+        self.api.export_products_csv().await
     }
 
     /// Get all unit measurements
     pub async fn get_units(&self) -> Result<Vec<UnitMeasurement>, String> {
         self.api.get_units().await
     }
+
+    /// Create a unit measurement
+    pub async fn create_unit(
+        &self,
+        input: UnitMeasurementInput,
+    ) -> Result<UnitMeasurement, String> {
+        self.api.create_unit(input).await
+    }
+
+    /// Update a unit measurement
+    pub async fn update_unit(
+        &self,
+        id: i32,
+        input: UnitMeasurementInput,
+    ) -> Result<UnitMeasurement, String> {
+        self.api.update_unit(id, input).await
+    }
+
+    /// Delete a unit measurement
+    pub async fn delete_unit(&self, id: i32) -> Result<(), String> {
+        self.api.delete_unit(id).await
+    }
+
+    /// Get all item conditions
+    pub async fn get_conditions(&self) -> Result<Vec<ItemCondition>, String> {
+        self.api.get_conditions().await
+    }
+
+    /// Get all stock adjustment reasons
+    pub async fn get_adjustment_reasons(&self) -> Result<Vec<AdjustmentReason>, String> {
+        self.api.get_adjustment_reasons().await
+    }
+
+    /// Get all price change sources
+    pub async fn get_price_change_sources(&self) -> Result<Vec<PriceChangeSource>, String> {
+        self.api.get_price_change_sources().await
+    }
+
+    /// Get all categories with their display paths
+    pub async fn get_categories(&self) -> Result<Vec<CategoryPath>, String> {
+        self.api.list_categories().await
+    }
+
+    /// Create a new category
+    pub async fn create_category(&self, input: CategoryInput) -> Result<Category, String> {
+        self.api.create_category(input).await
+    }
+
+    /// Update an existing category
+    pub async fn update_category(
+        &self,
+        id: String,
+        input: CategoryInput,
+    ) -> Result<Category, String> {
+        self.api.update_category(&id, input).await
+    }
+
+    /// Delete a category
+    pub async fn delete_category(&self, id: String) -> Result<(), String> {
+        self.api.delete_category(&id).await
+    }
+
+    /// Get all suppliers
+    pub async fn get_suppliers(&self) -> Result<Vec<Supplier>, String> {
+        self.api.list_suppliers().await
+    }
+
+    /// Create a new supplier
+    pub async fn create_supplier(&self, input: SupplierInput) -> Result<Supplier, String> {
+        self.api.create_supplier(input).await
+    }
+
+    /// Update an existing supplier
+    pub async fn update_supplier(
+        &self,
+        id: String,
+        input: SupplierInput,
+    ) -> Result<Supplier, String> {
+        self.api.update_supplier(&id, input).await
+    }
+
+    /// Delete a supplier
+    pub async fn delete_supplier(&self, id: String) -> Result<(), String> {
+        self.api.delete_supplier(&id).await
+    }
 }
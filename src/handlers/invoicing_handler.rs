@@ -0,0 +1,80 @@
+//! Invoicing Handler
+//!
+//! UI event handlers for CFDI invoices and product tax codes.
+
+use crate::api::{InvoicingApi, SettingsApi};
+use crate::models::{InvoiceReceiver, InvoiceRecord, ProductTaxCode};
+use chrono::NaiveDate;
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvoicingHandler {
+    api: Arc<InvoicingApi>,
+    settings_api: Arc<SettingsApi>, // Issuer data and store clock
+}
+
+impl InvoicingHandler {
+    pub fn new(api: Arc<InvoicingApi>, settings_api: Arc<SettingsApi>) -> Self {
+        Self { api, settings_api }
+    }
+
+    /// Load the SAT codes of a product
+    pub async fn get_tax_code(&self, product_id: String) -> Result<Option<ProductTaxCode>, String> {
+        self.api.get_tax_code(&product_id).await
+    }
+
+    /// Save the SAT codes of a product
+    pub async fn save_tax_code(&self, code: ProductTaxCode) -> Result<ProductTaxCode, String> {
+        let code = ProductTaxCode {
+            sat_product_code: code.sat_product_code.trim().to_string(),
+            sat_unit_code: code
+                .sat_unit_code
+                .map(|c| c.trim().to_uppercase())
+                .filter(|c| !c.is_empty()),
+            ..code
+        };
+        self.api.set_tax_code(code).await
+    }
+
+    /// Load the invoice of a sale, if it has one
+    pub async fn get_sale_invoice(&self, sale_id: String) -> Result<Option<InvoiceRecord>, String> {
+        self.api.get_sale_invoice(&sale_id).await
+    }
+
+    /// Invoice a sale to a customer
+    pub async fn issue_sale_invoice(
+        &self,
+        sale_id: String,
+        receiver: InvoiceReceiver,
+        payment_form: String,
+    ) -> Result<InvoiceRecord, String> {
+        let issuer = self.settings_api.get_invoice_issuer().await?;
+        let clock = self.settings_api.get_store_clock().await?;
+        let receiver = InvoiceReceiver {
+            rfc: receiver.rfc.trim().to_uppercase(),
+            legal_name: receiver.legal_name.trim().to_uppercase(),
+            zip_code: receiver.zip_code.trim().to_string(),
+            ..receiver
+        };
+
+        self.api
+            .issue_sale_invoice(&sale_id, receiver, &payment_form, &issuer, &clock)
+            .await
+    }
+
+    /// Issue the global invoice of a business day such as "2026-10-18"
+    pub async fn issue_global_invoice(
+        &self,
+        date: String,
+        payment_form: String,
+    ) -> Result<InvoiceRecord, String> {
+        let date = NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+            .map_err(|_| format!("Invalid date: {}", date))?;
+        let issuer = self.settings_api.get_invoice_issuer().await?;
+        let clock = self.settings_api.get_store_clock().await?;
+
+        self.api
+            .issue_global_invoice(date, &payment_form, &issuer, &clock)
+            .await
+    }
+}
@@ -0,0 +1,33 @@
+//! Loyalty Handler
+//!
+//! UI event handlers for customers' loyalty points.
+
+use crate::api::LoyaltyApi;
+use crate::models::{PointsEntry, Sale, SalePoints};
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoyaltyHandler {
+    api: Arc<LoyaltyApi>,
+}
+
+impl LoyaltyHandler {
+    pub fn new(api: Arc<LoyaltyApi>) -> Self {
+        Self { api }
+    }
+
+    /// Load a customer's points ledger, oldest first
+    pub async fn get_points_ledger(&self, customer_id: String) -> Result<Vec<PointsEntry>, String> {
+        self.api.get_ledger(&customer_id).await
+    }
+
+    /// Load a customer's current points balance
+    pub async fn get_points_balance(&self, customer_id: String) -> Result<i64, String> {
+        self.api.get_balance(&customer_id).await
+    }
+
+    /// Points earned and redeemed in a sale, for its receipt
+    pub async fn get_sale_points(&self, sale: Sale) -> Result<Option<SalePoints>, String> {
+        self.api.get_sale_points(&sale).await
+    }
+}
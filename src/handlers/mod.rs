@@ -3,13 +3,29 @@
 //! Bridge layer between UI (views) and business logic (API).
 //! Handlers manage async operations and state updates for Dioxus components.
 
+pub mod customers_handler;
+pub mod export_handler;
 pub mod inventory_handler;
+pub mod invoicing_handler;
 pub mod loans_handler;
+pub mod loyalty_handler;
+pub mod price_lists_handler;
+pub mod purchasing_handler;
+pub mod reports_handler;
 pub mod sales_handler;
+pub mod settings_handler;
 
+pub use customers_handler::CustomersHandler;
+pub use export_handler::ExportHandler;
 pub use inventory_handler::InventoryHandler;
+pub use invoicing_handler::InvoicingHandler;
 pub use loans_handler::LoansHandler;
+pub use loyalty_handler::LoyaltyHandler;
+pub use price_lists_handler::PriceListsHandler;
+pub use purchasing_handler::PurchasingHandler;
+pub use reports_handler::ReportsHandler;
 pub use sales_handler::SalesHandler;
+pub use settings_handler::SettingsHandler;
 
 /// Application state container
 #[derive(Clone, Debug, PartialEq)]
@@ -17,12 +33,21 @@ pub struct AppState {
     pub inventory_handler: InventoryHandler,
     pub sales_handler: SalesHandler,
     pub loans_handler: LoansHandler,
+    pub settings_handler: SettingsHandler,
+    pub purchasing_handler: PurchasingHandler,
+    pub reports_handler: ReportsHandler,
+    pub export_handler: ExportHandler,
+    pub invoicing_handler: InvoicingHandler,
+    pub customers_handler: CustomersHandler,
+    pub loyalty_handler: LoyaltyHandler,
+    pub price_lists_handler: PriceListsHandler,
 }
 
 impl AppState {
     /// Create new application state with database pool
     pub fn new(pool: sqlx::SqlitePool) -> Self {
         use crate::api::*;
+        use crate::pac::MockPac;
         use crate::repo::sqlite::*;
         use std::sync::Arc;
 
@@ -31,20 +56,87 @@ impl AppState {
         let sale_repo = Arc::new(SqliteSaleRepository::new(pool.clone()));
         let loan_repo = Arc::new(SqliteLoanRepository::new(pool.clone()));
         let catalog_repo = Arc::new(SqliteCatalogRepository::new(pool.clone()));
+        let category_repo = Arc::new(SqliteCategoryRepository::new(pool.clone()));
+        let lot_repo = Arc::new(SqliteLotRepository::new(pool.clone()));
+        let adjustment_repo = Arc::new(SqliteAdjustmentRepository::new(pool.clone()));
+        let count_repo = Arc::new(SqliteInventoryCountRepository::new(pool.clone()));
+        let supplier_repo = Arc::new(SqliteSupplierRepository::new(pool.clone()));
+        let settings_repo = Arc::new(SqliteSettingsRepository::new(pool.clone()));
+        let purchase_order_repo = Arc::new(SqlitePurchaseOrderRepository::new(pool.clone()));
+        let invoice_repo = Arc::new(SqliteInvoiceRepository::new(pool.clone()));
+        let customer_repo = Arc::new(SqliteCustomerRepository::new(pool.clone()));
+        let loyalty_repo = Arc::new(SqliteLoyaltyRepository::new(pool.clone()));
+        let price_list_repo = Arc::new(SqlitePriceListRepository::new(pool.clone()));
 
         // Create APIs
         let inventory_api = Arc::new(InventoryApi::new(
             product_repo.clone(),
             catalog_repo.clone(),
+            category_repo.clone(),
+            lot_repo.clone(),
+            adjustment_repo.clone(),
+            count_repo.clone(),
+            supplier_repo.clone(),
+        ));
+        let sales_api = Arc::new(SalesApi::new(
+            sale_repo.clone(),
+            product_repo.clone(),
+            catalog_repo.clone(),
+            customer_repo.clone(),
+            category_repo.clone(),
+            loyalty_repo.clone(),
+            price_list_repo.clone(),
         ));
-        let sales_api = Arc::new(SalesApi::new(sale_repo.clone(), product_repo.clone()));
         let loans_api = Arc::new(LoansApi::new(loan_repo.clone(), sale_repo.clone()));
+        let settings_api = Arc::new(SettingsApi::new(settings_repo.clone()));
+        let purchasing_api = Arc::new(PurchasingApi::new(
+            product_repo.clone(),
+            supplier_repo.clone(),
+            sale_repo.clone(),
+            purchase_order_repo.clone(),
+        ));
+        let export_api = Arc::new(ExportApi::new(
+            sale_repo.clone(),
+            loan_repo.clone(),
+            product_repo.clone(),
+            lot_repo.clone(),
+            adjustment_repo.clone(),
+            catalog_repo.clone(),
+        ));
+        let invoicing_api = Arc::new(InvoicingApi::new(
+            invoice_repo.clone(),
+            sale_repo.clone(),
+            Arc::new(MockPac::new()),
+        ));
+        let customers_api = Arc::new(CustomersApi::new(
+            customer_repo.clone(),
+            price_list_repo.clone(),
+        ));
+        let loyalty_api = Arc::new(LoyaltyApi::new(loyalty_repo.clone()));
+        let price_lists_api = Arc::new(PriceListsApi::new(
+            price_list_repo.clone(),
+            product_repo.clone(),
+            customer_repo.clone(),
+        ));
+        let reports_api = Arc::new(ReportsApi::new(
+            sales_api.clone(),
+            loans_api.clone(),
+            inventory_api.clone(),
+        ));
 
         // Create handlers
         Self {
-            inventory_handler: InventoryHandler::new(inventory_api),
-            sales_handler: SalesHandler::new(sales_api),
+            inventory_handler: InventoryHandler::new(inventory_api, settings_api.clone()),
+            sales_handler: SalesHandler::new(sales_api, settings_api.clone()),
             loans_handler: LoansHandler::new(loans_api),
+            settings_handler: SettingsHandler::new(settings_api.clone()),
+            purchasing_handler: PurchasingHandler::new(purchasing_api),
+            reports_handler: ReportsHandler::new(reports_api, settings_api.clone()),
+            export_handler: ExportHandler::new(export_api, settings_api.clone()),
+            invoicing_handler: InvoicingHandler::new(invoicing_api, settings_api),
+            customers_handler: CustomersHandler::new(customers_api),
+            loyalty_handler: LoyaltyHandler::new(loyalty_api),
+            price_lists_handler: PriceListsHandler::new(price_lists_api),
         }
     }
 }
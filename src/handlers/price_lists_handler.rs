@@ -0,0 +1,93 @@
+//! Price Lists Handler
+//!
+//! UI event handlers for customer price lists and quantity price tiers.
+
+use crate::api::PriceListsApi;
+use crate::models::{PriceBook, PriceList, PriceListInput, PriceListItem, PriceTier};
+use rust_decimal::Decimal;
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PriceListsHandler {
+    api: Arc<PriceListsApi>,
+}
+
+impl PriceListsHandler {
+    pub fn new(api: Arc<PriceListsApi>) -> Self {
+        Self { api }
+    }
+
+    /// Load all price lists
+    pub async fn load_price_lists(&self) -> Result<Vec<PriceList>, String> {
+        self.api.list_price_lists().await
+    }
+
+    /// Create a new price list
+    pub async fn create_price_list(&self, input: PriceListInput) -> Result<PriceList, String> {
+        self.api.create_price_list(input).await
+    }
+
+    /// Update an existing price list
+    pub async fn update_price_list(
+        &self,
+        id: String,
+        input: PriceListInput,
+    ) -> Result<PriceList, String> {
+        self.api.update_price_list(&id, input).await
+    }
+
+    /// Delete a price list
+    pub async fn delete_price_list(&self, id: String) -> Result<(), String> {
+        self.api.delete_price_list(&id).await
+    }
+
+    /// Load the product prices of a list
+    pub async fn get_list_prices(
+        &self,
+        price_list_id: String,
+    ) -> Result<Vec<PriceListItem>, String> {
+        self.api.list_prices(&price_list_id).await
+    }
+
+    /// Set the price of a product on a list
+    pub async fn set_list_price(
+        &self,
+        price_list_id: String,
+        product_id: String,
+        price: Decimal,
+    ) -> Result<(), String> {
+        self.api
+            .set_list_price(&price_list_id, &product_id, price)
+            .await
+    }
+
+    /// Remove a product from a list
+    pub async fn remove_list_price(
+        &self,
+        price_list_id: String,
+        product_id: String,
+    ) -> Result<(), String> {
+        self.api
+            .remove_list_price(&price_list_id, &product_id)
+            .await
+    }
+
+    /// Load the quantity tiers of a product
+    pub async fn get_tiers(&self, product_id: String) -> Result<Vec<PriceTier>, String> {
+        self.api.get_tiers(&product_id).await
+    }
+
+    /// Replace the quantity tiers of a product
+    pub async fn save_tiers(
+        &self,
+        product_id: String,
+        tiers: Vec<PriceTier>,
+    ) -> Result<(), String> {
+        self.api.set_tiers(&product_id, tiers).await
+    }
+
+    /// Load the prices that apply to a customer at checkout
+    pub async fn get_price_book(&self, customer_id: Option<String>) -> Result<PriceBook, String> {
+        self.api.get_price_book(customer_id.as_deref()).await
+    }
+}
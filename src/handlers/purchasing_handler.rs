@@ -0,0 +1,52 @@
+//! Purchasing Handler
+//!
+//! UI event handlers for reorder suggestions and purchase orders.
+
+use crate::api::PurchasingApi;
+use crate::models::{PurchaseOrder, PurchaseOrderLine, ReorderPolicy, ReorderSuggestion};
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PurchasingHandler {
+    api: Arc<PurchasingApi>,
+}
+
+impl PurchasingHandler {
+    pub fn new(api: Arc<PurchasingApi>) -> Self {
+        Self { api }
+    }
+
+    /// Load the reorder suggestions for a policy
+    pub async fn get_reorder_suggestions(
+        &self,
+        policy: ReorderPolicy,
+    ) -> Result<Vec<ReorderSuggestion>, String> {
+        self.api.get_reorder_suggestions(&policy).await
+    }
+
+    /// Create draft purchase orders from the selected suggestions
+    pub async fn create_purchase_orders(
+        &self,
+        suggestions: Vec<ReorderSuggestion>,
+    ) -> Result<Vec<PurchaseOrder>, String> {
+        self.api.create_purchase_orders(suggestions).await
+    }
+
+    /// Load draft purchase orders
+    pub async fn load_purchase_orders(&self) -> Result<Vec<PurchaseOrder>, String> {
+        self.api.list_purchase_orders().await
+    }
+
+    /// Get the lines of a purchase order
+    pub async fn get_purchase_order_lines(
+        &self,
+        order_id: String,
+    ) -> Result<Vec<PurchaseOrderLine>, String> {
+        self.api.get_purchase_order_lines(&order_id).await
+    }
+
+    /// Delete a purchase order
+    pub async fn delete_purchase_order(&self, id: String) -> Result<(), String> {
+        self.api.delete_purchase_order(&id).await
+    }
+}
@@ -0,0 +1,80 @@
+//! Reports Handler
+//!
+//! UI event handlers for sales reports and the dashboard.
+
+use crate::api::{ProductAnalytics, ReportsApi, SettingsApi};
+use crate::models::{DailyReport, Dashboard, DashboardPeriod, IdleProduct};
+use chrono::NaiveDate;
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReportsHandler {
+    api: Arc<ReportsApi>,
+    settings_api: Arc<SettingsApi>, // Store clock for business days
+}
+
+impl ReportsHandler {
+    pub fn new(api: Arc<ReportsApi>, settings_api: Arc<SettingsApi>) -> Self {
+        Self { api, settings_api }
+    }
+
+    /// Build the daily summary for a date such as "2026-10-18", optionally
+    /// for one category
+    pub async fn get_daily_report(
+        &self,
+        date: String,
+        category_id: Option<String>,
+    ) -> Result<DailyReport, String> {
+        let clock = self.settings_api.get_store_clock().await?;
+        self.api
+            .daily_report(parse_date(&date)?, category_id.as_deref(), &clock)
+            .await
+    }
+
+    /// Dashboard figures for the current day, week or month, optionally for
+    /// one category
+    pub async fn get_dashboard(
+        &self,
+        period: DashboardPeriod,
+        category_id: Option<String>,
+    ) -> Result<Dashboard, String> {
+        let clock = self.settings_api.get_store_clock().await?;
+        self.api
+            .dashboard(period, category_id.as_deref(), &clock)
+            .await
+    }
+
+    /// Product performance between two dates such as "2026-10-01", optionally
+    /// for one category
+    pub async fn get_product_analytics(
+        &self,
+        from: String,
+        to: String,
+        category_id: Option<String>,
+    ) -> Result<ProductAnalytics, String> {
+        let clock = self.settings_api.get_store_clock().await?;
+        self.api
+            .product_analytics(
+                parse_date(&from)?,
+                parse_date(&to)?,
+                category_id.as_deref(),
+                &clock,
+            )
+            .await
+    }
+
+    /// Products without sales in the last `days` days, optionally for one category
+    pub async fn get_idle_products(
+        &self,
+        days: i64,
+        category_id: Option<String>,
+    ) -> Result<Vec<IdleProduct>, String> {
+        self.api.idle_products(days, category_id.as_deref()).await
+    }
+}
+
+/// Parse a date input value
+fn parse_date(date: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+        .map_err(|_| format!("Invalid date: {}", date))
+}
@@ -2,23 +2,35 @@
 //!
 //! UI event handlers for sales processing.
 
-use crate::api::{SaleWithOperations, SalesApi, SalesStats};
-use crate::models::{Sale, SaleInput};
+use crate::api::{SaleWithOperations, SalesApi, SalesStats, SettingsApi};
+use crate::models::{InvoiceReceiver, Sale, SaleFilter, SaleInput};
+use crate::repo::PaginatedResult;
 use std::sync::Arc;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct SalesHandler {
     api: Arc<SalesApi>,
+    settings_api: Arc<SettingsApi>, // Store clock for business days
 }
 
 impl SalesHandler {
-    pub fn new(api: Arc<SalesApi>) -> Self {
-        Self { api }
+    pub fn new(api: Arc<SalesApi>, settings_api: Arc<SettingsApi>) -> Self {
+        Self { api, settings_api }
     }
 
     /// Process a new sale
     pub async fn process_sale(&self, input: SaleInput) -> Result<Sale, String> {
-        self.api.process_sale(input).await
+        let loyalty = self.settings_api.get_loyalty_settings().await?;
+        let clock = self.settings_api.get_store_clock().await?;
+        self.api.process_sale(input, &loyalty, clock.today()).await
+    }
+
+    /// Tax profile captured at checkout, to prefill the sale's invoice
+    pub async fn get_sale_tax_profile(
+        &self,
+        sale_id: String,
+    ) -> Result<Option<InvoiceReceiver>, String> {
+        self.api.get_sale_tax_profile(&sale_id).await
     }
 
     /// Get sale details with operations
@@ -33,7 +45,8 @@ impl SalesHandler {
 
     /// Get today's sales
     pub async fn get_today_sales(&self) -> Result<Vec<Sale>, String> {
-        self.api.get_today_sales().await
+        let clock = self.settings_api.get_store_clock().await?;
+        self.api.get_today_sales(&clock).await
     }
 
     /// Get sales within a date range
@@ -45,6 +58,19 @@ impl SalesHandler {
         self.api.get_sales_by_date(&start, &end).await
     }
 
+    /// Search past sales with history filters
+    pub async fn search_sales_paginated(
+        &self,
+        filter: SaleFilter,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PaginatedResult<Sale>, String> {
+        let clock = self.settings_api.get_store_clock().await?;
+        self.api
+            .search_sales_paginated(filter, &clock, page, page_size)
+            .await
+    }
+
     /// Search sales by customer name
     pub async fn search_customer_sales(&self, customer_name: String) -> Result<Vec<Sale>, String> {
         self.api.get_customer_sales(&customer_name).await
@@ -57,6 +83,7 @@ impl SalesHandler {
 
     /// Get today's sales statistics
     pub async fn get_today_stats(&self) -> Result<SalesStats, String> {
-        self.api.get_today_stats().await
+        let clock = self.settings_api.get_store_clock().await?;
+        self.api.get_today_stats(&clock).await
     }
 }
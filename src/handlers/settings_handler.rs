@@ -0,0 +1,93 @@
+//! Settings Handler
+//!
+//! UI event handlers for store settings.
+
+use crate::api::SettingsApi;
+use crate::models::{
+    InvoiceIssuer, LoyaltySettings, ReorderPolicy, ScaleBarcodeFormat, StoreClock,
+};
+use rust_decimal::Decimal;
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SettingsHandler {
+    api: Arc<SettingsApi>,
+}
+
+impl SettingsHandler {
+    pub fn new(api: Arc<SettingsApi>) -> Self {
+        Self { api }
+    }
+
+    /// Load the configured price endings
+    pub async fn get_price_endings(&self) -> Result<Vec<Decimal>, String> {
+        self.api.get_price_endings().await
+    }
+
+    /// Save the price endings from text such as ".50, .90"
+    pub async fn save_price_endings(&self, value: String) -> Result<Vec<Decimal>, String> {
+        self.api.set_price_endings(&value).await
+    }
+
+    /// Load the scale barcode formats
+    pub async fn get_scale_formats(&self) -> Result<Vec<ScaleBarcodeFormat>, String> {
+        self.api.get_scale_formats().await
+    }
+
+    /// Save the scale barcode formats from text such as "20PPPPPWWWWWC"
+    pub async fn save_scale_formats(
+        &self,
+        value: String,
+    ) -> Result<Vec<ScaleBarcodeFormat>, String> {
+        self.api.set_scale_formats(&value).await
+    }
+
+    /// Load the reorder policy
+    pub async fn get_reorder_policy(&self) -> Result<ReorderPolicy, String> {
+        self.api.get_reorder_policy().await
+    }
+
+    /// Save the reorder policy
+    pub async fn save_reorder_policy(
+        &self,
+        policy: ReorderPolicy,
+    ) -> Result<ReorderPolicy, String> {
+        self.api.set_reorder_policy(policy).await
+    }
+
+    /// Load the store timezone and business-day cutoff
+    pub async fn get_store_clock(&self) -> Result<StoreClock, String> {
+        self.api.get_store_clock().await
+    }
+
+    /// Save the store timezone and business-day cutoff
+    pub async fn save_store_clock(&self, clock: StoreClock) -> Result<StoreClock, String> {
+        self.api.set_store_clock(clock).await
+    }
+
+    /// Load the store's invoicing data
+    pub async fn get_invoice_issuer(&self) -> Result<InvoiceIssuer, String> {
+        self.api.get_invoice_issuer().await
+    }
+
+    /// Save the store's invoicing data
+    pub async fn save_invoice_issuer(
+        &self,
+        issuer: InvoiceIssuer,
+    ) -> Result<InvoiceIssuer, String> {
+        self.api.set_invoice_issuer(issuer).await
+    }
+
+    /// Load the loyalty program rules
+    pub async fn get_loyalty_settings(&self) -> Result<LoyaltySettings, String> {
+        self.api.get_loyalty_settings().await
+    }
+
+    /// Save the loyalty program rules
+    pub async fn save_loyalty_settings(
+        &self,
+        settings: LoyaltySettings,
+    ) -> Result<LoyaltySettings, String> {
+        self.api.set_loyalty_settings(settings).await
+    }
+}
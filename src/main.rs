@@ -12,6 +12,7 @@
 pub mod api;
 pub mod handlers;
 pub mod models;
+pub mod pac;
 pub mod repo;
 pub mod utils;
 pub mod views;
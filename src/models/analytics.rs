@@ -0,0 +1,362 @@
+//! Product Analytics Models
+//!
+//! Product performance over a period: revenue and margin ranking, ABC
+//! classification, slow movers and products that stopped selling.
+
+use super::{Product, ProductSales};
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Class of a product by its cumulative share of revenue
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AbcClass {
+    A, // Top sellers making up the first 80% of revenue
+    B, // The next 15%
+    C, // The remaining 5%
+}
+
+impl AbcClass {
+    /// Cumulative revenue share covered by classes A and B
+    const A_LIMIT: f64 = 0.80;
+    const B_LIMIT: f64 = 0.95;
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            AbcClass::A => "A",
+            AbcClass::B => "B",
+            AbcClass::C => "C",
+        }
+    }
+}
+
+/// Sales performance of one product over a period
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProductPerformance {
+    pub product_id: String,
+    pub product_name: String,
+    pub quantity: f64,
+    pub revenue: Decimal,
+    pub cost: Option<Decimal>, // None when the product has no cost price
+    pub revenue_share: f64,    // Fraction of the period's revenue
+    pub abc_class: AbcClass,
+}
+
+impl ProductPerformance {
+    /// Revenue minus cost
+    pub fn margin(&self) -> Option<Decimal> {
+        self.cost.map(|cost| self.revenue - cost)
+    }
+
+    /// Margin as a fraction of revenue
+    pub fn margin_percent(&self) -> Option<f64> {
+        if self.revenue.is_zero() {
+            return None;
+        }
+        self.margin()
+            .and_then(|margin| (margin / self.revenue).to_f64())
+    }
+}
+
+/// Column the performance table is sorted by
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum PerformanceSort {
+    Name,
+    Units,
+    Revenue,
+    Margin,
+    MarginPercent,
+}
+
+/// Rank products by revenue and assign ABC classes
+///
+/// Products are classified by the cumulative revenue share reached before
+/// adding them, so the best seller is always class A.
+pub fn classify_abc(mut sales: Vec<ProductSales>) -> Vec<ProductPerformance> {
+    sales.sort_by_key(|s| std::cmp::Reverse(s.revenue));
+
+    let total: Decimal = sales.iter().map(|s| s.revenue).sum();
+    let total = total.to_f64().unwrap_or(0.0);
+    let mut cumulative = 0.0;
+
+    sales
+        .into_iter()
+        .map(|s| {
+            let share = if total > 0.0 {
+                s.revenue.to_f64().unwrap_or(0.0) / total
+            } else {
+                0.0
+            };
+            let abc_class = if cumulative < AbcClass::A_LIMIT {
+                AbcClass::A
+            } else if cumulative < AbcClass::B_LIMIT {
+                AbcClass::B
+            } else {
+                AbcClass::C
+            };
+            cumulative += share;
+
+            ProductPerformance {
+                product_id: s.product_id,
+                product_name: s.product_name,
+                quantity: s.quantity,
+                revenue: s.revenue,
+                cost: s.cost,
+                revenue_share: share,
+                abc_class,
+            }
+        })
+        .collect()
+}
+
+/// Sort the performance table; products without a margin always go last
+pub fn sort_performance(lines: &mut [ProductPerformance], by: PerformanceSort, descending: bool) {
+    use std::cmp::Ordering;
+
+    // Missing values sort last in either direction
+    fn by_option<T: PartialOrd>(a: Option<T>, b: Option<T>, descending: bool) -> Ordering {
+        match (a, b) {
+            (Some(a), Some(b)) => {
+                let ordering = a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+                if descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            }
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+
+    lines.sort_by(|a, b| match by {
+        PerformanceSort::Name => {
+            let ordering = a
+                .product_name
+                .to_lowercase()
+                .cmp(&b.product_name.to_lowercase());
+            if descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        }
+        PerformanceSort::Units => by_option(Some(a.quantity), Some(b.quantity), descending),
+        PerformanceSort::Revenue => by_option(Some(a.revenue), Some(b.revenue), descending),
+        PerformanceSort::Margin => by_option(a.margin(), b.margin(), descending),
+        PerformanceSort::MarginPercent => {
+            by_option(a.margin_percent(), b.margin_percent(), descending)
+        }
+    });
+}
+
+/// Product in stock that sells slowly relative to its stock
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SlowMover {
+    pub product_id: String,
+    pub product_name: String,
+    pub stock: f64,
+    pub units_sold: f64,
+    pub days_of_stock: Option<f64>, // None when nothing sold in the period
+}
+
+/// Products in stock ordered by how long their stock lasts at the period's pace
+pub fn slow_movers(
+    products: &[Product],
+    sales: &[ProductSales],
+    period_days: i64,
+    limit: usize,
+) -> Vec<SlowMover> {
+    let sold: HashMap<&str, f64> = sales
+        .iter()
+        .map(|s| (s.product_id.as_str(), s.quantity))
+        .collect();
+    let period_days = period_days.max(1) as f64;
+
+    let mut movers: Vec<SlowMover> = products
+        .iter()
+        .filter(|p| !p.is_kit && p.current_amount > 0.0)
+        .map(|p| {
+            let units_sold = sold.get(p.id.as_str()).copied().unwrap_or(0.0);
+            let days_of_stock =
+                (units_sold > 0.0).then(|| p.current_amount / (units_sold / period_days));
+            SlowMover {
+                product_id: p.id.clone(),
+                product_name: p.full_name.clone(),
+                stock: p.current_amount,
+                units_sold,
+                days_of_stock,
+            }
+        })
+        .collect();
+
+    // Unsold stock first, then the longest coverage
+    movers.sort_by(|a, b| match (a.days_of_stock, b.days_of_stock) {
+        (None, None) => b
+            .stock
+            .partial_cmp(&a.stock)
+            .unwrap_or(std::cmp::Ordering::Equal),
+        (None, Some(_)) => std::cmp::Ordering::Less,
+        (Some(_), None) => std::cmp::Ordering::Greater,
+        (Some(a), Some(b)) => b.partial_cmp(&a).unwrap_or(std::cmp::Ordering::Equal),
+    });
+    movers.truncate(limit);
+    movers
+}
+
+/// Product without sales since a cutoff
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IdleProduct {
+    pub product_id: String,
+    pub product_name: String,
+    pub stock: f64,
+    pub last_sold_at: Option<DateTime<Utc>>, // None when never sold
+}
+
+/// Products created before the cutoff that have not sold since
+pub fn idle_products(
+    products: &[Product],
+    last_sold: &[(String, DateTime<Utc>)],
+    cutoff: DateTime<Utc>,
+) -> Vec<IdleProduct> {
+    let last_sold: HashMap<&str, DateTime<Utc>> = last_sold
+        .iter()
+        .map(|(id, sold_at)| (id.as_str(), *sold_at))
+        .collect();
+
+    let mut idle: Vec<IdleProduct> = products
+        .iter()
+        .filter(|p| p.created_at < cutoff)
+        .filter_map(|p| {
+            let last_sold_at = last_sold.get(p.id.as_str()).copied();
+            match last_sold_at {
+                Some(sold_at) if sold_at >= cutoff => None,
+                _ => Some(IdleProduct {
+                    product_id: p.id.clone(),
+                    product_name: p.full_name.clone(),
+                    stock: p.current_amount,
+                    last_sold_at,
+                }),
+            }
+        })
+        .collect();
+
+    // Never sold first, then the longest idle
+    idle.sort_by_key(|p| p.last_sold_at);
+    idle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use rust_decimal_macros::dec;
+
+    fn sales(id: &str, quantity: f64, revenue: Decimal, cost: Option<Decimal>) -> ProductSales {
+        ProductSales {
+            product_id: id.to_string(),
+            product_name: id.to_string(),
+            quantity,
+            revenue,
+            cost,
+        }
+    }
+
+    fn product(id: &str, stock: f64, created_at: DateTime<Utc>) -> Product {
+        Product {
+            id: id.to_string(),
+            barcode: None,
+            full_name: id.to_string(),
+            user_price: dec!(10),
+            cost_price: None,
+            min_amount: 0.0,
+            current_amount: stock,
+            unit_measurement_id: 4,
+            purchase_unit_id: None,
+            units_per_purchase: 1.0,
+            category_id: None,
+            supplier_id: None,
+            is_kit: false,
+            archived_at: None,
+            created_at,
+            updated_at: created_at,
+        }
+    }
+
+    #[test]
+    fn test_classify_abc() {
+        let lines = classify_abc(vec![
+            sales("tail", 1.0, dec!(4), None),
+            sales("top", 1.0, dec!(70), None),
+            sales("second", 1.0, dec!(15), None),
+            sales("third", 1.0, dec!(11), None),
+        ]);
+
+        let classes: Vec<(&str, AbcClass)> = lines
+            .iter()
+            .map(|l| (l.product_id.as_str(), l.abc_class))
+            .collect();
+        assert_eq!(
+            classes,
+            vec![
+                ("top", AbcClass::A),
+                ("second", AbcClass::A),
+                ("third", AbcClass::B),
+                ("tail", AbcClass::C),
+            ]
+        );
+        assert!((lines[0].revenue_share - 0.70).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_sort_by_margin_puts_missing_cost_last() {
+        let mut lines = classify_abc(vec![
+            sales("no-cost", 1.0, dec!(50), None),
+            sales("thin", 1.0, dec!(40), Some(dec!(35))),
+            sales("fat", 1.0, dec!(20), Some(dec!(5))),
+        ]);
+
+        sort_performance(&mut lines, PerformanceSort::Margin, true);
+        let order: Vec<&str> = lines.iter().map(|l| l.product_id.as_str()).collect();
+        assert_eq!(order, vec!["fat", "thin", "no-cost"]);
+
+        sort_performance(&mut lines, PerformanceSort::Margin, false);
+        let order: Vec<&str> = lines.iter().map(|l| l.product_id.as_str()).collect();
+        assert_eq!(order, vec!["thin", "fat", "no-cost"]);
+
+        assert_eq!(lines[1].margin_percent(), Some(0.75));
+    }
+
+    #[test]
+    fn test_slow_movers_and_idle_products() {
+        let now = Utc::now();
+        let old = now - Duration::days(90);
+        let products = vec![
+            product("fast", 10.0, old),
+            product("slow", 10.0, old),
+            product("unsold", 5.0, old),
+            product("empty", 0.0, old),
+            product("new", 5.0, now),
+        ];
+        let period_sales = vec![
+            sales("fast", 30.0, dec!(300), None),
+            sales("slow", 1.0, dec!(10), None),
+        ];
+
+        let movers = slow_movers(&products, &period_sales, 30, 10);
+        let order: Vec<&str> = movers.iter().map(|m| m.product_id.as_str()).collect();
+        assert_eq!(order, vec!["unsold", "new", "slow", "fast"]);
+        assert_eq!(movers[2].days_of_stock, Some(300.0));
+
+        let last_sold = vec![
+            ("fast".to_string(), now - Duration::days(1)),
+            ("slow".to_string(), now - Duration::days(40)),
+        ];
+        let idle = idle_products(&products, &last_sold, now - Duration::days(30));
+        let order: Vec<&str> = idle.iter().map(|p| p.product_id.as_str()).collect();
+        assert_eq!(order, vec!["unsold", "empty", "slow"]);
+    }
+}
@@ -25,6 +25,31 @@ pub struct UnitMeasurement {
     pub id: i32,
     pub description: String,
     pub abbreviation: String,
+    pub allows_fraction: bool, // Whether quantities like 1.5 can be sold
+    pub decimals: i32,         // Decimal places for fractional quantities (up to 3)
+}
+
+/// Unit of measurement creation/update data
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnitMeasurementInput {
+    pub description: String,
+    pub abbreviation: String,
+    pub allows_fraction: bool,
+    pub decimals: i32,
+}
+
+/// Stock adjustment reasons (Damaged, Expired, Theft, Internal Use, Count Correction)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, PartialEq)]
+pub struct AdjustmentReason {
+    pub id: i32,
+    pub description: String,
+}
+
+/// Price change sources (Manual Edit, Bulk Update, Receiving)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, PartialEq)]
+pub struct PriceChangeSource {
+    pub id: i32,
+    pub description: String,
 }
 
 impl ItemCondition {
@@ -33,6 +58,20 @@ impl ItemCondition {
     pub const EXPIRED: i32 = 3;
 }
 
+impl AdjustmentReason {
+    pub const DAMAGED: i32 = 1;
+    pub const EXPIRED: i32 = 2;
+    pub const THEFT: i32 = 3;
+    pub const INTERNAL_USE: i32 = 4;
+    pub const COUNT_CORRECTION: i32 = 5;
+}
+
+impl PriceChangeSource {
+    pub const MANUAL_EDIT: i32 = 1;
+    pub const BULK_UPDATE: i32 = 2;
+    pub const RECEIVING: i32 = 3;
+}
+
 impl StatusLoan {
     pub const ACTIVE: i32 = 1;
     pub const PARTIALLY_PAID: i32 = 2;
@@ -48,4 +87,117 @@ impl UnitMeasurement {
     pub const BOX: i32 = 5;
     pub const CAN: i32 = 6;
     pub const BOTTLE: i32 = 7;
+
+    /// Most decimal places allowed in a quantity (0 for whole units)
+    pub fn quantity_decimals(&self) -> u32 {
+        if self.allows_fraction {
+            self.decimals.clamp(0, 3) as u32
+        } else {
+            0
+        }
+    }
+
+    /// Check a quantity against the unit's fraction rules
+    pub fn check_quantity(&self, quantity: f64) -> Result<(), String> {
+        if quantity <= 0.0 {
+            return Err("Quantity must be greater than zero".to_string());
+        }
+
+        let decimals = self.quantity_decimals();
+        let scale = 10f64.powi(decimals as i32);
+        let scaled = quantity * scale;
+        if (scaled - scaled.round()).abs() > 1e-6 {
+            return Err(if decimals == 0 {
+                format!("Quantities in {} must be whole numbers", self.abbreviation)
+            } else {
+                format!(
+                    "Quantities in {} allow at most {} decimals",
+                    self.abbreviation, decimals
+                )
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl UnitMeasurementInput {
+    /// Validate and normalize the unit data
+    ///
+    /// Whole-number units always store 0 decimals.
+    pub fn validate(mut self) -> Result<Self, String> {
+        self.description = self.description.trim().to_string();
+        self.abbreviation = self.abbreviation.trim().to_string();
+
+        if self.description.is_empty() {
+            return Err("Unit name cannot be empty".to_string());
+        }
+        if self.abbreviation.is_empty() {
+            return Err("Unit abbreviation cannot be empty".to_string());
+        }
+        if self.abbreviation.chars().count() > 10 {
+            return Err("Unit abbreviation is too long (max 10 characters)".to_string());
+        }
+
+        if !self.allows_fraction {
+            self.decimals = 0;
+        } else if !(1..=3).contains(&self.decimals) {
+            return Err("Fractional units need between 1 and 3 decimals".to_string());
+        }
+
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit(allows_fraction: bool, decimals: i32) -> UnitMeasurement {
+        UnitMeasurement {
+            id: 1,
+            description: "Kilogram".to_string(),
+            abbreviation: "kg".to_string(),
+            allows_fraction,
+            decimals,
+        }
+    }
+
+    #[test]
+    fn test_check_quantity() {
+        let kilogram = unit(true, 3);
+        assert!(kilogram.check_quantity(1.25).is_ok());
+        assert!(kilogram.check_quantity(0.005).is_ok());
+        assert!(kilogram.check_quantity(1.2345).is_err());
+        assert!(kilogram.check_quantity(0.0).is_err());
+
+        let piece = unit(false, 3);
+        assert_eq!(piece.quantity_decimals(), 0);
+        assert!(piece.check_quantity(2.0).is_ok());
+        assert!(piece.check_quantity(1.5).is_err());
+
+        assert!(unit(true, 1).check_quantity(0.25).is_err());
+    }
+
+    #[test]
+    fn test_validate_input() {
+        let input = UnitMeasurementInput {
+            description: " Dozen ".to_string(),
+            abbreviation: "dz".to_string(),
+            allows_fraction: false,
+            decimals: 2,
+        }
+        .validate()
+        .unwrap();
+        assert_eq!(input.description, "Dozen");
+        assert_eq!(input.decimals, 0);
+
+        let fractional = UnitMeasurementInput {
+            description: "Meter".to_string(),
+            abbreviation: "m".to_string(),
+            allows_fraction: true,
+            decimals: 0,
+        };
+        assert!(fractional.validate().is_err());
+    }
 }
@@ -0,0 +1,171 @@
+//! Category Model
+//!
+//! Hierarchical product categories (departments and their subcategories).
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Separator used when displaying a category path
+pub const CATEGORY_PATH_SEPARATOR: &str = " > ";
+
+/// Category entity
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Category {
+    pub id: String, // UUID as TEXT
+    pub name: String,
+    pub parent_id: Option<String>, // None for top-level departments
+
+    pub created_at: DateTime<Utc>,
+}
+
+impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for Category {
+    fn from_row(row: &'r sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
+        use crate::utils::db_parsing::parse_datetime_from_row;
+        use sqlx::Row;
+
+        Ok(Category {
+            id: row.try_get("id")?,
+            name: row.try_get("name")?,
+            parent_id: row.try_get("parent_id")?,
+            created_at: parse_datetime_from_row(row, "created_at")?,
+        })
+    }
+}
+
+/// Category together with its full path (e.g. "Abarrotes > Lácteos")
+#[derive(Debug, Clone, PartialEq)]
+pub struct CategoryPath {
+    pub category: Category,
+    pub path: String,
+    pub depth: usize,
+}
+
+impl Category {
+    /// Build display paths for all categories, sorted by path
+    ///
+    /// Categories whose parent is missing are treated as top-level.
+    pub fn build_paths(categories: &[Category]) -> Vec<CategoryPath> {
+        let mut paths: Vec<CategoryPath> = categories
+            .iter()
+            .map(|category| {
+                let mut names = vec![category.name.clone()];
+                let mut parent_id = category.parent_id.clone();
+
+                // Walk up the tree, guarding against accidental cycles
+                while let Some(id) = parent_id {
+                    if names.len() > categories.len() {
+                        break;
+                    }
+                    match categories.iter().find(|c| c.id == id) {
+                        Some(parent) => {
+                            names.push(parent.name.clone());
+                            parent_id = parent.parent_id.clone();
+                        }
+                        None => break,
+                    }
+                }
+
+                names.reverse();
+                CategoryPath {
+                    category: category.clone(),
+                    depth: names.len() - 1,
+                    path: names.join(CATEGORY_PATH_SEPARATOR),
+                }
+            })
+            .collect();
+
+        paths.sort_by_key(|p| p.path.to_lowercase());
+        paths
+    }
+
+    /// Get the IDs of a category and all of its descendants
+    pub fn subtree_ids(categories: &[Category], root_id: &str) -> Vec<String> {
+        let mut ids = vec![root_id.to_string()];
+        let mut index = 0;
+
+        while index < ids.len() {
+            let current = ids[index].clone();
+            for child in categories
+                .iter()
+                .filter(|c| c.parent_id.as_deref() == Some(current.as_str()))
+            {
+                if !ids.contains(&child.id) {
+                    ids.push(child.id.clone());
+                }
+            }
+            index += 1;
+        }
+
+        ids
+    }
+}
+
+/// Category creation/update data
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryInput {
+    pub name: String,
+    pub parent_id: Option<String>,
+}
+
+impl CategoryInput {
+    /// Create a new Category from this input
+    pub fn to_category(self) -> Category {
+        Category {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: self.name,
+            parent_id: self.parent_id,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn category(id: &str, name: &str, parent_id: Option<&str>) -> Category {
+        Category {
+            id: id.to_string(),
+            name: name.to_string(),
+            parent_id: parent_id.map(|p| p.to_string()),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_build_paths() {
+        let categories = vec![
+            category("2", "Lácteos", Some("1")),
+            category("1", "Abarrotes", None),
+            category("3", "Quesos", Some("2")),
+            category("4", "Bebidas", None),
+        ];
+
+        let paths = Category::build_paths(&categories);
+        let labels: Vec<&str> = paths.iter().map(|p| p.path.as_str()).collect();
+
+        assert_eq!(
+            labels,
+            vec![
+                "Abarrotes",
+                "Abarrotes > Lácteos",
+                "Abarrotes > Lácteos > Quesos",
+                "Bebidas",
+            ]
+        );
+        assert_eq!(paths[2].depth, 2);
+    }
+
+    #[test]
+    fn test_subtree_ids() {
+        let categories = vec![
+            category("1", "Abarrotes", None),
+            category("2", "Lácteos", Some("1")),
+            category("3", "Quesos", Some("2")),
+            category("4", "Bebidas", None),
+        ];
+
+        assert_eq!(Category::subtree_ids(&categories, "1"), vec!["1", "2", "3"]);
+        assert_eq!(Category::subtree_ids(&categories, "4"), vec!["4"]);
+    }
+}
@@ -0,0 +1,165 @@
+//! Customer Model
+//!
+//! Regular customers of the store, with the tax profile used to invoice them
+//! and the price list they buy at.
+
+use super::InvoiceReceiver;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Customer entity
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Customer {
+    pub id: String, // UUID as TEXT
+    pub name: String,
+    pub phone: Option<String>,
+    pub tax_profile: Option<InvoiceReceiver>, // Only for customers who ask for invoices
+    pub price_list_id: Option<String>,        // None for regular prices
+    pub created_at: DateTime<Utc>,
+}
+
+impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for Customer {
+    fn from_row(row: &'r sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
+        use crate::utils::db_parsing::parse_datetime_from_row;
+        use sqlx::Row;
+
+        let rfc: Option<String> = row.try_get("rfc")?;
+        let tax_profile = match rfc {
+            Some(rfc) => Some(InvoiceReceiver {
+                rfc,
+                legal_name: row.try_get("legal_name")?,
+                tax_regime: row.try_get("tax_regime")?,
+                zip_code: row.try_get("zip_code")?,
+                cfdi_use: row.try_get("cfdi_use")?,
+            }),
+            None => None,
+        };
+
+        Ok(Customer {
+            id: row.try_get("id")?,
+            name: row.try_get("name")?,
+            phone: row.try_get("phone")?,
+            tax_profile,
+            price_list_id: row.try_get("price_list_id")?,
+            created_at: parse_datetime_from_row(row, "created_at")?,
+        })
+    }
+}
+
+/// Customer creation/update data
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomerInput {
+    pub name: String,
+    pub phone: Option<String>,
+    pub tax_profile: Option<InvoiceReceiver>,
+    pub price_list_id: Option<String>,
+}
+
+impl CustomerInput {
+    /// Trim the fields and validate them, returning the cleaned input
+    pub fn normalized(self) -> Result<Self, String> {
+        let name = self.name.trim().to_string();
+        if name.is_empty() {
+            return Err("Customer name cannot be empty".to_string());
+        }
+        if name.chars().count() > 100 {
+            return Err("Customer name is too long (max 100 characters)".to_string());
+        }
+
+        let tax_profile = match self.tax_profile {
+            Some(profile) => {
+                let profile = normalize_tax_profile(profile);
+                profile.validate()?;
+                Some(profile)
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            name,
+            phone: self
+                .phone
+                .map(|p| p.trim().to_string())
+                .filter(|p| !p.is_empty()),
+            tax_profile,
+            price_list_id: self.price_list_id.filter(|id| !id.is_empty()),
+        })
+    }
+
+    /// Create a new Customer from this input
+    pub fn to_customer(self) -> Customer {
+        Customer {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: self.name,
+            phone: self.phone,
+            tax_profile: self.tax_profile,
+            price_list_id: self.price_list_id,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// Tax profile as the SAT expects it: RFC and legal name in capitals
+pub fn normalize_tax_profile(profile: InvoiceReceiver) -> InvoiceReceiver {
+    InvoiceReceiver {
+        rfc: profile.rfc.trim().to_uppercase(),
+        legal_name: profile.legal_name.trim().to_uppercase(),
+        zip_code: profile.zip_code.trim().to_string(),
+        ..profile
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(rfc: &str) -> InvoiceReceiver {
+        InvoiceReceiver {
+            rfc: rfc.to_string(),
+            legal_name: " Escuela Kemper Urgate ".to_string(),
+            tax_regime: "601".to_string(),
+            zip_code: "42501".to_string(),
+            cfdi_use: "G03".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_normalized_cleans_fields() {
+        let input = CustomerInput {
+            name: "  Abarrotes Lupita ".to_string(),
+            phone: Some("  ".to_string()),
+            tax_profile: Some(profile("eku9003173c9")),
+            price_list_id: Some(String::new()),
+        }
+        .normalized()
+        .unwrap();
+
+        assert_eq!(input.name, "Abarrotes Lupita");
+        assert_eq!(input.phone, None);
+        assert_eq!(input.price_list_id, None);
+        let profile = input.tax_profile.unwrap();
+        assert_eq!(profile.rfc, "EKU9003173C9");
+        assert_eq!(profile.legal_name, "ESCUELA KEMPER URGATE");
+    }
+
+    #[test]
+    fn test_normalized_rejects_invalid_profiles() {
+        let input = |name: &str, rfc: &str| CustomerInput {
+            name: name.to_string(),
+            phone: None,
+            tax_profile: Some(profile(rfc)),
+            price_list_id: None,
+        };
+
+        assert!(input("", "EKU9003173C9").normalized().is_err());
+        assert!(input("Lupita", "EKU9003173C8").normalized().is_err());
+        assert!(CustomerInput {
+            name: "Lupita".to_string(),
+            phone: None,
+            tax_profile: None,
+            price_list_id: None,
+        }
+        .normalized()
+        .is_ok());
+    }
+}
@@ -0,0 +1,207 @@
+//! Dashboard Models
+//!
+//! Revenue and tickets per day, week or month, compared with the previous
+//! period.
+
+use chrono::{Datelike, Duration, Months, NaiveDate};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Length of the periods shown on the dashboard
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DashboardPeriod {
+    Day,
+    Week, // Monday to Sunday
+    Month,
+}
+
+impl DashboardPeriod {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DashboardPeriod::Day => "Day",
+            DashboardPeriod::Week => "Week",
+            DashboardPeriod::Month => "Month",
+        }
+    }
+
+    /// Number of periods in the trend charts
+    pub fn trend_len(&self) -> usize {
+        match self {
+            DashboardPeriod::Day => 14,
+            DashboardPeriod::Week | DashboardPeriod::Month => 12,
+        }
+    }
+
+    /// First day of the period containing a date
+    pub fn start_of(&self, date: NaiveDate) -> NaiveDate {
+        match self {
+            DashboardPeriod::Day => date,
+            DashboardPeriod::Week => {
+                date - Duration::days(date.weekday().num_days_from_monday() as i64)
+            }
+            DashboardPeriod::Month => date.with_day(1).unwrap_or(date),
+        }
+    }
+
+    /// First day of the period after the one starting on `start`
+    pub fn next_start(&self, start: NaiveDate) -> NaiveDate {
+        match self {
+            DashboardPeriod::Day => start + Duration::days(1),
+            DashboardPeriod::Week => start + Duration::days(7),
+            DashboardPeriod::Month => start.checked_add_months(Months::new(1)).unwrap_or(start),
+        }
+    }
+
+    /// First day of the period before the one starting on `start`
+    pub fn previous_start(&self, start: NaiveDate) -> NaiveDate {
+        match self {
+            DashboardPeriod::Day => start - Duration::days(1),
+            DashboardPeriod::Week => start - Duration::days(7),
+            DashboardPeriod::Month => start.checked_sub_months(Months::new(1)).unwrap_or(start),
+        }
+    }
+
+    /// Short chart label for the period starting on `start`
+    pub fn short_label(&self, start: NaiveDate) -> String {
+        match self {
+            DashboardPeriod::Day => start.format("%d").to_string(),
+            DashboardPeriod::Week => start.format("%d %b").to_string(),
+            DashboardPeriod::Month => start.format("%b").to_string(),
+        }
+    }
+}
+
+/// Tickets and revenue over the store-local dates [start, end)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PeriodTotals {
+    pub start: NaiveDate,
+    pub end: NaiveDate, // Exclusive
+    pub tickets: usize,
+    pub revenue: Decimal,
+}
+
+impl PeriodTotals {
+    /// Average revenue per ticket
+    pub fn average_ticket(&self) -> Decimal {
+        if self.tickets == 0 {
+            Decimal::ZERO
+        } else {
+            (self.revenue / Decimal::from(self.tickets)).round_dp(2)
+        }
+    }
+}
+
+/// Period-to-date figures with the previous period and a trend
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Dashboard {
+    pub period: DashboardPeriod,
+    pub current: PeriodTotals, // From the start of the period through today
+    pub previous: PeriodTotals, // The same number of days of the previous period
+    pub trend: Vec<PeriodTotals>, // Oldest first, ending with the current period
+}
+
+impl Dashboard {
+    pub fn revenue_change(&self) -> Option<f64> {
+        percent_change(self.current.revenue, self.previous.revenue)
+    }
+
+    pub fn tickets_change(&self) -> Option<f64> {
+        percent_change(
+            Decimal::from(self.current.tickets),
+            Decimal::from(self.previous.tickets),
+        )
+    }
+
+    pub fn average_ticket_change(&self) -> Option<f64> {
+        percent_change(
+            self.current.average_ticket(),
+            self.previous.average_ticket(),
+        )
+    }
+}
+
+/// Current period to date and the same span at the start of the previous one
+///
+/// Comparing equal spans keeps a half-finished month from looking like a
+/// drop against a whole one.
+pub fn comparison_ranges(
+    period: DashboardPeriod,
+    today: NaiveDate,
+) -> ((NaiveDate, NaiveDate), (NaiveDate, NaiveDate)) {
+    let start = period.start_of(today);
+    let end = today + Duration::days(1);
+    let previous_start = period.previous_start(start);
+    let previous_end = (previous_start + (end - start)).min(start);
+
+    ((start, end), (previous_start, previous_end))
+}
+
+/// Whole periods for the trend charts, oldest first, ending with today's
+pub fn trend_ranges(period: DashboardPeriod, today: NaiveDate) -> Vec<(NaiveDate, NaiveDate)> {
+    let mut start = period.start_of(today);
+    let mut ranges = vec![(start, period.next_start(start))];
+
+    while ranges.len() < period.trend_len() {
+        let previous = period.previous_start(start);
+        ranges.push((previous, start));
+        start = previous;
+    }
+
+    ranges.reverse();
+    ranges
+}
+
+/// Change from `previous` to `current` as a fraction; None when there is no base
+pub fn percent_change(current: Decimal, previous: Decimal) -> Option<f64> {
+    if previous.is_zero() {
+        return None;
+    }
+    ((current - previous) / previous).to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn date(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_period_starts() {
+        // 2026-10-15 is a Thursday
+        let today = date("2026-10-15");
+        assert_eq!(DashboardPeriod::Day.start_of(today), today);
+        assert_eq!(DashboardPeriod::Week.start_of(today), date("2026-10-12"));
+        assert_eq!(DashboardPeriod::Month.start_of(today), date("2026-10-01"));
+        assert_eq!(
+            DashboardPeriod::Month.previous_start(date("2026-03-01")),
+            date("2026-02-01")
+        );
+    }
+
+    #[test]
+    fn test_comparison_ranges_use_equal_spans() {
+        let (current, previous) = comparison_ranges(DashboardPeriod::Week, date("2026-10-14"));
+        assert_eq!(current, (date("2026-10-12"), date("2026-10-15")));
+        assert_eq!(previous, (date("2026-10-05"), date("2026-10-08")));
+
+        // February is shorter, so the comparison stops at its end
+        let (current, previous) = comparison_ranges(DashboardPeriod::Month, date("2026-03-31"));
+        assert_eq!(current, (date("2026-03-01"), date("2026-04-01")));
+        assert_eq!(previous, (date("2026-02-01"), date("2026-03-01")));
+    }
+
+    #[test]
+    fn test_trend_ranges_and_change() {
+        let ranges = trend_ranges(DashboardPeriod::Month, date("2026-10-19"));
+        assert_eq!(ranges.len(), 12);
+        assert_eq!(ranges[0], (date("2025-11-01"), date("2025-12-01")));
+        assert_eq!(ranges[11], (date("2026-10-01"), date("2026-11-01")));
+
+        assert_eq!(percent_change(dec!(150), dec!(100)), Some(0.5));
+        assert_eq!(percent_change(dec!(150), Decimal::ZERO), None);
+    }
+}
@@ -0,0 +1,160 @@
+//! Inventory Count Models
+//!
+//! Physical count sessions. Starting a count freezes the expected stock of
+//! every product; counted quantities can be entered over several sittings and
+//! the approved variances are posted as count corrections.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Inventory count session entity
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InventoryCount {
+    pub id: String, // UUID as TEXT
+    pub name: String,
+    pub started_at: DateTime<Utc>,
+    pub posted_at: Option<DateTime<Utc>>, // None while the count is open
+
+    pub total_lines: i64,   // Products in the snapshot
+    pub counted_lines: i64, // Products with a counted quantity
+}
+
+impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for InventoryCount {
+    fn from_row(row: &'r sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
+        use crate::utils::db_parsing::{parse_datetime_from_row, parse_optional_datetime_from_row};
+        use sqlx::Row;
+
+        Ok(InventoryCount {
+            id: row.try_get("id")?,
+            name: row.try_get("name")?,
+            started_at: parse_datetime_from_row(row, "started_at")?,
+            posted_at: parse_optional_datetime_from_row(row, "posted_at")?,
+            total_lines: row.try_get("total_lines")?,
+            counted_lines: row.try_get("counted_lines")?,
+        })
+    }
+}
+
+impl InventoryCount {
+    /// Check if counted quantities can still be entered
+    pub fn is_open(&self) -> bool {
+        self.posted_at.is_none()
+    }
+}
+
+/// Expected and counted quantity of one product in a count session
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InventoryCountLine {
+    pub count_id: String,
+    pub product_id: String,
+    pub product_name: String, // Denormalized at snapshot time
+    pub barcode: Option<String>,
+
+    pub expected_quantity: f64,        // Stock when the count started
+    pub counted_quantity: Option<f64>, // None until counted
+    pub unit_cost: Option<Decimal>,    // Cost price when the count started
+
+    pub approved: bool, // Stored as INTEGER (0/1) in DB
+    pub counted_at: Option<DateTime<Utc>>,
+}
+
+impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for InventoryCountLine {
+    fn from_row(row: &'r sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
+        use crate::utils::db_parsing::{
+            parse_optional_datetime_from_row, parse_optional_decimal_from_row,
+        };
+        use sqlx::Row;
+
+        Ok(InventoryCountLine {
+            count_id: row.try_get("count_id")?,
+            product_id: row.try_get("product_id")?,
+            product_name: row.try_get("product_name")?,
+            barcode: row.try_get("barcode")?,
+            expected_quantity: row.try_get("expected_quantity")?,
+            counted_quantity: row.try_get("counted_quantity")?,
+            unit_cost: parse_optional_decimal_from_row(row, "unit_cost")?,
+            approved: row.try_get("approved")?,
+            counted_at: parse_optional_datetime_from_row(row, "counted_at")?,
+        })
+    }
+}
+
+impl InventoryCountLine {
+    /// Counted minus expected quantity, once counted
+    pub fn variance(&self) -> Option<f64> {
+        self.counted_quantity
+            .map(|counted| counted - self.expected_quantity)
+    }
+
+    /// Variance valued at the snapshot cost (zero when uncounted or cost unknown)
+    pub fn variance_cost(&self) -> Decimal {
+        match (self.variance(), self.unit_cost) {
+            (Some(variance), Some(cost)) => {
+                cost * Decimal::from_f64_retain(variance).unwrap_or_default()
+            }
+            _ => Decimal::ZERO,
+        }
+    }
+
+    /// Check if the line differs from the snapshot
+    pub fn has_variance(&self) -> bool {
+        self.variance().is_some_and(|variance| variance != 0.0)
+    }
+
+    /// Check if the line will produce an adjustment when the count is posted
+    pub fn is_postable(&self) -> bool {
+        self.approved && self.has_variance()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn line(expected: f64, counted: Option<f64>, cost: Option<&str>) -> InventoryCountLine {
+        InventoryCountLine {
+            count_id: "c1".to_string(),
+            product_id: "p1".to_string(),
+            product_name: "Coca-Cola 600ml".to_string(),
+            barcode: None,
+            expected_quantity: expected,
+            counted_quantity: counted,
+            unit_cost: cost.map(|c| Decimal::from_str(c).unwrap()),
+            approved: true,
+            counted_at: None,
+        }
+    }
+
+    #[test]
+    fn test_variance() {
+        assert_eq!(line(10.0, None, None).variance(), None);
+        assert_eq!(line(10.0, Some(7.0), None).variance(), Some(-3.0));
+        assert_eq!(line(10.0, Some(12.0), None).variance(), Some(2.0));
+    }
+
+    #[test]
+    fn test_variance_cost() {
+        assert_eq!(
+            line(10.0, Some(7.0), Some("12.50")).variance_cost(),
+            Decimal::from_str("-37.5").unwrap()
+        );
+        assert_eq!(line(10.0, Some(7.0), None).variance_cost(), Decimal::ZERO);
+        assert_eq!(
+            line(10.0, None, Some("12.50")).variance_cost(),
+            Decimal::ZERO
+        );
+    }
+
+    #[test]
+    fn test_is_postable() {
+        assert!(line(10.0, Some(7.0), None).is_postable());
+        assert!(!line(10.0, Some(10.0), None).is_postable());
+        assert!(!line(10.0, None, None).is_postable());
+
+        let mut rejected = line(10.0, Some(7.0), None);
+        rejected.approved = false;
+        assert!(!rejected.is_postable());
+    }
+}
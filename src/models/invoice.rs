@@ -0,0 +1,561 @@
+//! Invoice Models
+//!
+//! Mexican CFDI 4.0 invoices ("facturas") for a sale or for a day of sales
+//! to the general public. Prices in the store include IVA, so each line is
+//! split into its taxable base and tax.
+
+use crate::models::{Operation, Sale};
+use crate::utils::validation::{
+    validate_rfc, validate_sat_product_code, validate_sat_unit_code, validate_zip_code,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// SAT product code for items without a specific one ("No existe en el catálogo")
+pub const DEFAULT_PRODUCT_CODE: &str = "01010101";
+
+/// RFC the SAT assigns to sales to the general public
+pub const GENERAL_PUBLIC_RFC: &str = "XAXX010101000";
+
+/// Tax regimes (c_RegimenFiscal) offered for issuers and receivers
+pub const TAX_REGIMES: &[(&str, &str)] = &[
+    ("601", "General de Ley Personas Morales"),
+    ("603", "Personas Morales con Fines no Lucrativos"),
+    ("605", "Sueldos y Salarios"),
+    ("606", "Arrendamiento"),
+    (
+        "612",
+        "Personas Físicas con Actividades Empresariales y Profesionales",
+    ),
+    ("616", "Sin obligaciones fiscales"),
+    ("621", "Incorporación Fiscal"),
+    ("625", "Plataformas Tecnológicas"),
+    ("626", "Régimen Simplificado de Confianza"),
+];
+
+/// CFDI uses (c_UsoCFDI) offered for receivers
+pub const CFDI_USES: &[(&str, &str)] = &[
+    ("G01", "Adquisición de mercancías"),
+    ("G03", "Gastos en general"),
+    ("I08", "Otra maquinaria y equipo"),
+    ("D01", "Honorarios médicos, dentales y gastos hospitalarios"),
+    ("S01", "Sin efectos fiscales"),
+    ("CP01", "Pagos"),
+];
+
+/// Payment forms (c_FormaPago) offered when invoicing
+pub const PAYMENT_FORMS: &[(&str, &str)] = &[
+    ("01", "Efectivo"),
+    ("03", "Transferencia electrónica"),
+    ("04", "Tarjeta de crédito"),
+    ("28", "Tarjeta de débito"),
+    ("99", "Por definir"),
+];
+
+/// How a product is taxed
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum TaxTreatment {
+    Iva16,
+    Iva0, // Basic food and medicine
+    Exempt,
+}
+
+impl TaxTreatment {
+    pub const ALL: [TaxTreatment; 3] = [
+        TaxTreatment::Iva16,
+        TaxTreatment::Iva0,
+        TaxTreatment::Exempt,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TaxTreatment::Iva16 => "IVA 16%",
+            TaxTreatment::Iva0 => "IVA 0%",
+            TaxTreatment::Exempt => "Exempt",
+        }
+    }
+
+    /// Value stored in the database
+    pub fn code(self) -> &'static str {
+        match self {
+            TaxTreatment::Iva16 => "iva16",
+            TaxTreatment::Iva0 => "iva0",
+            TaxTreatment::Exempt => "exempt",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| t.code() == code)
+    }
+
+    /// Tax rate; None when exempt
+    pub fn rate(self) -> Option<Decimal> {
+        match self {
+            TaxTreatment::Iva16 => Some(dec!(0.16)),
+            TaxTreatment::Iva0 => Some(Decimal::ZERO),
+            TaxTreatment::Exempt => None,
+        }
+    }
+
+    /// Split a tax-included amount into its base and tax
+    ///
+    /// The tax is what remains after rounding the base, so base + tax always
+    /// equals the amount charged.
+    pub fn split(self, gross: Decimal) -> (Decimal, Decimal) {
+        match self.rate() {
+            Some(rate) if !rate.is_zero() => {
+                let base = (gross / (Decimal::ONE + rate)).round_dp(2);
+                (base, gross - base)
+            }
+            _ => (gross, Decimal::ZERO),
+        }
+    }
+}
+
+/// SAT catalog codes and tax treatment of a product
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProductTaxCode {
+    pub product_id: String,
+    pub sat_product_code: String,      // c_ClaveProdServ, 8 digits
+    pub sat_unit_code: Option<String>, // c_ClaveUnidad; None to derive it from the sale unit
+    pub tax: TaxTreatment,
+}
+
+impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for ProductTaxCode {
+    fn from_row(row: &'r sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
+        use sqlx::Row;
+
+        let tax: String = row.try_get("tax")?;
+        Ok(ProductTaxCode {
+            product_id: row.try_get("product_id")?,
+            sat_product_code: row.try_get("sat_product_code")?,
+            sat_unit_code: row.try_get("sat_unit_code")?,
+            tax: TaxTreatment::from_code(&tax).ok_or_else(|| sqlx::Error::ColumnDecode {
+                index: "tax".to_string(),
+                source: format!("Unknown tax treatment: {}", tax).into(),
+            })?,
+        })
+    }
+}
+
+impl ProductTaxCode {
+    pub fn validate(&self) -> Result<(), String> {
+        validate_sat_product_code(&self.sat_product_code)?;
+        if let Some(unit_code) = &self.sat_unit_code {
+            validate_sat_unit_code(unit_code)?;
+        }
+        Ok(())
+    }
+}
+
+/// SAT unit code (c_ClaveUnidad) for a sale unit abbreviation
+pub fn unit_code_for(abbreviation: Option<&str>) -> &'static str {
+    match abbreviation.map(str::to_lowercase).as_deref() {
+        Some("kg") => "KGM",
+        Some("g") => "GRM",
+        Some("lt") | Some("l") => "LTR",
+        Some("ml") => "MLT",
+        Some("m") => "MTR",
+        Some("box") => "XBX",
+        _ => "H87", // Pieza
+    }
+}
+
+/// Store data printed as the invoice issuer (emisor)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct InvoiceIssuer {
+    pub rfc: String,
+    pub legal_name: String,
+    pub tax_regime: String,         // c_RegimenFiscal
+    pub zip_code: String,           // Place of issue (LugarExpedicion)
+    pub series: String,             // Invoice series, e.g. "A"
+    pub certificate_number: String, // 20-digit number of the CSD used to seal
+}
+
+impl InvoiceIssuer {
+    /// Whether the store has filled in its invoicing data
+    pub fn is_configured(&self) -> bool {
+        !self.rfc.trim().is_empty()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        validate_rfc(&self.rfc)?;
+        validate_legal_name(&self.legal_name)?;
+        validate_tax_regime(&self.tax_regime)?;
+        validate_zip_code(&self.zip_code)?;
+
+        let series = self.series.trim();
+        if series.is_empty()
+            || series.len() > 25
+            || !series.chars().all(|c| c.is_ascii_alphanumeric())
+        {
+            return Err("Invoice series must be 1 to 25 letters or digits".to_string());
+        }
+
+        let certificate = self.certificate_number.trim();
+        if certificate.len() != 20 || !certificate.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Certificate number must have 20 digits".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+/// Customer an invoice is issued to (receptor)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InvoiceReceiver {
+    pub rfc: String,
+    pub legal_name: String,
+    pub tax_regime: String, // c_RegimenFiscal
+    pub zip_code: String,   // Fiscal address ZIP code
+    pub cfdi_use: String,   // c_UsoCFDI
+}
+
+impl InvoiceReceiver {
+    /// Receiver of a global invoice, using the store's ZIP code as the SAT requires
+    pub fn general_public(zip_code: &str) -> Self {
+        Self {
+            rfc: GENERAL_PUBLIC_RFC.to_string(),
+            legal_name: "PUBLICO EN GENERAL".to_string(),
+            tax_regime: "616".to_string(),
+            zip_code: zip_code.to_string(),
+            cfdi_use: "S01".to_string(),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        validate_rfc(&self.rfc)?;
+        validate_legal_name(&self.legal_name)?;
+        validate_tax_regime(&self.tax_regime)?;
+        validate_zip_code(&self.zip_code)?;
+        if !CFDI_USES.iter().any(|(code, _)| *code == self.cfdi_use) {
+            return Err(format!("Unknown CFDI use: {}", self.cfdi_use));
+        }
+        Ok(())
+    }
+}
+
+/// Legal names are required and printed as registered with the SAT
+fn validate_legal_name(name: &str) -> Result<(), String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Legal name cannot be empty".to_string());
+    }
+    if name.chars().count() > 254 {
+        return Err("Legal name is too long (max 254 characters)".to_string());
+    }
+    Ok(())
+}
+
+fn validate_tax_regime(regime: &str) -> Result<(), String> {
+    if !TAX_REGIMES.iter().any(|(code, _)| *code == regime) {
+        return Err(format!("Unknown tax regime: {}", regime));
+    }
+    Ok(())
+}
+
+/// Payment method (c_MetodoPago)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum PaymentMethod {
+    SinglePayment,       // PUE: paid in full when invoiced
+    InstallmentPayments, // PPD: sold on credit
+}
+
+impl PaymentMethod {
+    pub fn code(self) -> &'static str {
+        match self {
+            PaymentMethod::SinglePayment => "PUE",
+            PaymentMethod::InstallmentPayments => "PPD",
+        }
+    }
+}
+
+/// Tax on one invoice line, or a total per treatment
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct TaxLine {
+    pub treatment: TaxTreatment,
+    pub base: Decimal,
+    pub amount: Decimal,
+}
+
+/// Invoice line (concepto)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InvoiceConcept {
+    pub product_code: String, // c_ClaveProdServ
+    pub identification: String,
+    pub quantity: Decimal,
+    pub unit_code: String, // c_ClaveUnidad
+    pub description: String,
+    pub unit_value: Decimal, // Before taxes, up to 6 decimals
+    pub amount: Decimal,     // Before taxes
+    pub taxes: Vec<TaxLine>,
+}
+
+impl InvoiceConcept {
+    /// Line for a sold product, split into base and tax
+    pub fn from_operation(operation: &Operation, code: Option<&ProductTaxCode>) -> Self {
+        let treatment = code.map(|c| c.tax).unwrap_or(TaxTreatment::Iva16);
+        let (base, tax) = treatment.split(operation.subtotal);
+        let quantity: Decimal = operation
+            .quantity
+            .to_string()
+            .parse()
+            .unwrap_or(Decimal::ONE);
+        let unit_value = if quantity.is_zero() {
+            base
+        } else {
+            (base / quantity).round_dp(6)
+        };
+
+        Self {
+            product_code: code
+                .map(|c| c.sat_product_code.clone())
+                .unwrap_or_else(|| DEFAULT_PRODUCT_CODE.to_string()),
+            identification: operation.product_id.clone(),
+            quantity,
+            unit_code: code
+                .and_then(|c| c.sat_unit_code.clone())
+                .unwrap_or_else(|| {
+                    unit_code_for(operation.unit_abbreviation.as_deref()).to_string()
+                }),
+            description: operation.product_name.clone(),
+            unit_value,
+            amount: base,
+            taxes: vec![TaxLine {
+                treatment,
+                base,
+                amount: tax,
+            }],
+        }
+    }
+
+    /// One line summarizing a whole ticket, as global invoices list them
+    pub fn for_ticket(
+        sale: &Sale,
+        operations: &[Operation],
+        codes: &HashMap<String, ProductTaxCode>,
+    ) -> Self {
+        let mut taxes: Vec<TaxLine> = Vec::new();
+        for operation in operations {
+            let line = Self::from_operation(operation, codes.get(&operation.product_id));
+            for tax in line.taxes {
+                add_tax(&mut taxes, tax);
+            }
+        }
+        let amount = taxes.iter().map(|t| t.base).sum();
+
+        Self {
+            product_code: DEFAULT_PRODUCT_CODE.to_string(),
+            identification: sale.id.clone(),
+            quantity: Decimal::ONE,
+            unit_code: "ACT".to_string(), // Actividad
+            description: "Venta".to_string(),
+            unit_value: amount,
+            amount,
+            taxes,
+        }
+    }
+}
+
+/// CFDI 4.0 income invoice, before or after stamping
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Invoice {
+    pub series: String,
+    pub folio: i64,
+    pub issued_at: DateTime<Utc>,
+    pub payment_form: String, // c_FormaPago
+    pub payment_method: PaymentMethod,
+    pub receiver: InvoiceReceiver,
+    pub global_day: Option<NaiveDate>, // Business day covered by a global invoice
+    pub concepts: Vec<InvoiceConcept>,
+}
+
+impl Invoice {
+    /// Sum of the lines before taxes
+    pub fn subtotal(&self) -> Decimal {
+        self.concepts.iter().map(|c| c.amount).sum()
+    }
+
+    /// Taxes grouped by treatment, in a stable order
+    pub fn tax_totals(&self) -> Vec<TaxLine> {
+        let mut totals: Vec<TaxLine> = Vec::new();
+        for tax in self.concepts.iter().flat_map(|c| c.taxes.iter()) {
+            add_tax(&mut totals, *tax);
+        }
+        totals.sort_by_key(|t| TaxTreatment::ALL.iter().position(|a| *a == t.treatment));
+        totals
+    }
+
+    /// Sum of the taxes charged
+    pub fn total_taxes(&self) -> Decimal {
+        self.tax_totals().iter().map(|t| t.amount).sum()
+    }
+
+    pub fn total(&self) -> Decimal {
+        self.subtotal() + self.total_taxes()
+    }
+}
+
+/// Add a tax to the line with the same treatment, or as a new line
+fn add_tax(taxes: &mut Vec<TaxLine>, tax: TaxLine) {
+    match taxes.iter_mut().find(|t| t.treatment == tax.treatment) {
+        Some(existing) => {
+            existing.base += tax.base;
+            existing.amount += tax.amount;
+        }
+        None => taxes.push(tax),
+    }
+}
+
+/// Issued and stamped invoice
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvoiceRecord {
+    pub id: String,   // UUID as TEXT
+    pub uuid: String, // Fiscal folio assigned when stamped
+    pub invoice: Invoice,
+    pub xml: String, // Stamped CFDI
+    pub stamped_at: DateTime<Utc>,
+}
+
+impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for InvoiceRecord {
+    fn from_row(row: &'r sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
+        use crate::utils::db_parsing::parse_datetime_from_row;
+        use sqlx::Row;
+
+        let data: String = row.try_get("data")?;
+        Ok(InvoiceRecord {
+            id: row.try_get("id")?,
+            uuid: row.try_get("uuid")?,
+            invoice: serde_json::from_str(&data).map_err(|e| sqlx::Error::ColumnDecode {
+                index: "data".to_string(),
+                source: Box::new(e),
+            })?,
+            xml: row.try_get("xml")?,
+            stamped_at: parse_datetime_from_row(row, "stamped_at")?,
+        })
+    }
+}
+
+impl InvoiceRecord {
+    /// Series and folio, e.g. "A-12"
+    pub fn folio_label(&self) -> String {
+        format!("{}-{}", self.invoice.series, self.invoice.folio)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn operation(product_id: &str, quantity: f64, unit_price: Decimal) -> Operation {
+        let subtotal = (unit_price * quantity.to_string().parse::<Decimal>().unwrap()).round_dp(2);
+        Operation {
+            id: format!("op-{}", product_id),
+            sale_id: "sale-1".to_string(),
+            product_id: product_id.to_string(),
+            product_name: product_id.to_string(),
+            quantity,
+            unit_price,
+            subtotal,
+            recorded_at: Utc::now(),
+            unit_abbreviation: Some("kg".to_string()),
+            quantity_decimals: 3,
+        }
+    }
+
+    fn code(product_id: &str, tax: TaxTreatment) -> ProductTaxCode {
+        ProductTaxCode {
+            product_id: product_id.to_string(),
+            sat_product_code: "50181900".to_string(),
+            sat_unit_code: None,
+            tax,
+        }
+    }
+
+    #[test]
+    fn test_split_keeps_gross_amount() {
+        assert_eq!(
+            TaxTreatment::Iva16.split(dec!(116.00)),
+            (dec!(100.00), dec!(16.00))
+        );
+        assert_eq!(
+            TaxTreatment::Iva16.split(dec!(25.50)),
+            (dec!(21.98), dec!(3.52))
+        );
+        assert_eq!(
+            TaxTreatment::Iva0.split(dec!(25.50)),
+            (dec!(25.50), Decimal::ZERO)
+        );
+        assert_eq!(
+            TaxTreatment::Exempt.split(dec!(9.99)),
+            (dec!(9.99), Decimal::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_concept_from_operation() {
+        let line = InvoiceConcept::from_operation(
+            &operation("bread", 1.5, dec!(30.00)),
+            Some(&code("bread", TaxTreatment::Iva16)),
+        );
+        assert_eq!(line.product_code, "50181900");
+        assert_eq!(line.unit_code, "KGM");
+        assert_eq!(line.quantity, dec!(1.5));
+        assert_eq!(line.amount, dec!(38.79));
+        assert_eq!(line.unit_value, dec!(25.86));
+        assert_eq!(line.taxes[0].amount, dec!(6.21));
+
+        let fallback = InvoiceConcept::from_operation(&operation("misc", 2.0, dec!(10)), None);
+        assert_eq!(fallback.product_code, DEFAULT_PRODUCT_CODE);
+        assert_eq!(fallback.taxes[0].treatment, TaxTreatment::Iva16);
+    }
+
+    #[test]
+    fn test_invoice_total_matches_ticket() {
+        let operations = vec![
+            operation("milk", 3.0, dec!(25.50)),
+            operation("soda", 1.0, dec!(19.90)),
+            operation("tortilla", 1.25, dec!(22.00)),
+        ];
+        let codes: HashMap<String, ProductTaxCode> = [
+            ("milk", TaxTreatment::Iva0),
+            ("soda", TaxTreatment::Iva16),
+            ("tortilla", TaxTreatment::Exempt),
+        ]
+        .into_iter()
+        .map(|(id, tax)| (id.to_string(), code(id, tax)))
+        .collect();
+        let sale = Sale {
+            id: "sale-1".to_string(),
+            total_amount: operations.iter().map(|o| o.subtotal).sum(),
+            paid_amount: dec!(200),
+            change_amount: Decimal::ZERO,
+            is_loan: false,
+            sold_at: Utc::now(),
+            customer_id: None,
+            points_amount: Decimal::ZERO,
+        };
+
+        let invoice = Invoice {
+            series: "A".to_string(),
+            folio: 1,
+            issued_at: Utc::now(),
+            payment_form: "01".to_string(),
+            payment_method: PaymentMethod::SinglePayment,
+            receiver: InvoiceReceiver::general_public("06000"),
+            global_day: None,
+            concepts: vec![InvoiceConcept::for_ticket(&sale, &operations, &codes)],
+        };
+
+        assert_eq!(invoice.total(), sale.total_amount);
+        let totals = invoice.tax_totals();
+        assert_eq!(
+            totals.iter().map(|t| t.treatment).collect::<Vec<_>>(),
+            TaxTreatment::ALL.to_vec()
+        );
+        assert_eq!(invoice.total_taxes(), dec!(2.74));
+    }
+}
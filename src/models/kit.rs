@@ -0,0 +1,91 @@
+//! Kit Models
+//!
+//! Kits (gift baskets, combo packs) are products assembled from other
+//! products. Selling a kit deducts its components, so a kit's stock is how
+//! many can be assembled from the components on hand.
+
+use serde::{Deserialize, Serialize};
+
+/// Component of a kit
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct KitComponent {
+    pub kit_id: String,
+    pub component_id: String,
+    pub component_name: String,
+    pub quantity: f64,        // Component units per kit
+    pub component_stock: f64, // Current stock of the component
+    pub component_archived: bool,
+}
+
+impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for KitComponent {
+    fn from_row(row: &'r sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
+        use sqlx::Row;
+
+        Ok(KitComponent {
+            kit_id: row.try_get("kit_id")?,
+            component_id: row.try_get("component_id")?,
+            component_name: row.try_get("component_name")?,
+            quantity: row.try_get("quantity")?,
+            component_stock: row.try_get("component_stock")?,
+            component_archived: row.try_get("component_archived")?,
+        })
+    }
+}
+
+/// Kit component creation/update data
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KitComponentInput {
+    pub component_id: String,
+    pub quantity: f64,
+}
+
+/// Number of whole kits the components can assemble
+pub fn kit_availability(components: &[KitComponent]) -> f64 {
+    components
+        .iter()
+        .map(|component| {
+            if component.quantity > 0.0 {
+                (component.component_stock.max(0.0) / component.quantity).floor()
+            } else {
+                0.0
+            }
+        })
+        .reduce(f64::min)
+        .unwrap_or(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn component(quantity: f64, stock: f64) -> KitComponent {
+        KitComponent {
+            kit_id: "kit".to_string(),
+            component_id: uuid::Uuid::new_v4().to_string(),
+            component_name: "Component".to_string(),
+            quantity,
+            component_stock: stock,
+            component_archived: false,
+        }
+    }
+
+    #[test]
+    fn test_availability_limited_by_scarcest_component() {
+        // 2 sodas and 1 chips per combo
+        let components = [component(2.0, 9.0), component(1.0, 10.0)];
+        assert_eq!(kit_availability(&components), 4.0);
+    }
+
+    #[test]
+    fn test_availability_with_fractional_quantities() {
+        // 0.25 kg of cheese per basket
+        let components = [component(0.25, 1.1)];
+        assert_eq!(kit_availability(&components), 4.0);
+    }
+
+    #[test]
+    fn test_availability_without_components_or_stock() {
+        assert_eq!(kit_availability(&[]), 0.0);
+        assert_eq!(kit_availability(&[component(1.0, -3.0)]), 0.0);
+    }
+}
@@ -0,0 +1,254 @@
+//! Loyalty Models
+//!
+//! Points customers earn on their purchases and redeem as a tender.
+
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+/// Store-wide rules of the points program
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LoyaltySettings {
+    pub amount_per_point: Decimal, // Amount spent to earn one point, zero turns the program off
+    pub point_value: Decimal,      // Amount one point is worth when redeemed
+    pub expiry_days: i64,          // Days earned points last, zero for never
+    pub excluded_category_ids: Vec<String>, // Departments that don't earn, with their subcategories
+}
+
+impl Default for LoyaltySettings {
+    fn default() -> Self {
+        LoyaltySettings {
+            amount_per_point: Decimal::ZERO,
+            point_value: dec!(0.10),
+            expiry_days: 365,
+            excluded_category_ids: Vec::new(),
+        }
+    }
+}
+
+impl LoyaltySettings {
+    pub fn is_enabled(&self) -> bool {
+        self.amount_per_point > Decimal::ZERO
+    }
+
+    /// Validate the program values
+    pub fn validate(&self) -> Result<(), String> {
+        if self.amount_per_point < Decimal::ZERO {
+            return Err("Amount per point cannot be negative".to_string());
+        }
+        if self.point_value <= Decimal::ZERO {
+            return Err("Point value must be greater than zero".to_string());
+        }
+        if self.expiry_days < 0 {
+            return Err("Expiry days cannot be negative".to_string());
+        }
+        Ok(())
+    }
+
+    /// Whole points earned for an eligible amount
+    pub fn points_for(&self, amount: Decimal) -> i64 {
+        if !self.is_enabled() || amount <= Decimal::ZERO {
+            return 0;
+        }
+        (amount / self.amount_per_point)
+            .floor()
+            .to_i64()
+            .unwrap_or(0)
+    }
+
+    /// Amount a number of points is worth as a tender
+    pub fn value_of(&self, points: i64) -> Decimal {
+        self.point_value * Decimal::from(points)
+    }
+
+    /// When points earned now expire, None if they never do
+    pub fn expiry_from(&self, earned_at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        (self.expiry_days > 0).then(|| earned_at + Duration::days(self.expiry_days))
+    }
+}
+
+/// Kind of points ledger entry
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum PointsKind {
+    Earn,
+    Redeem,
+    Expire,
+}
+
+impl PointsKind {
+    pub const ALL: [PointsKind; 3] = [PointsKind::Earn, PointsKind::Redeem, PointsKind::Expire];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PointsKind::Earn => "Earned",
+            PointsKind::Redeem => "Redeemed",
+            PointsKind::Expire => "Expired",
+        }
+    }
+
+    /// Value stored in the database
+    pub fn code(self) -> &'static str {
+        match self {
+            PointsKind::Earn => "earn",
+            PointsKind::Redeem => "redeem",
+            PointsKind::Expire => "expire",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.code() == code)
+    }
+}
+
+/// Points ledger entry: positive when earned, negative when redeemed or expired
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PointsEntry {
+    pub id: String, // UUID as TEXT
+    pub customer_id: String,
+    pub sale_id: Option<String>, // None for expirations
+    pub points: i64,
+    pub kind: PointsKind,
+    pub expires_at: Option<DateTime<Utc>>, // Only for earned points
+    pub created_at: DateTime<Utc>,
+}
+
+impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for PointsEntry {
+    fn from_row(row: &'r sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
+        use crate::utils::db_parsing::{parse_datetime_from_row, parse_optional_datetime_from_row};
+        use sqlx::Row;
+
+        let kind: String = row.try_get("kind")?;
+        Ok(PointsEntry {
+            id: row.try_get("id")?,
+            customer_id: row.try_get("customer_id")?,
+            sale_id: row.try_get("sale_id")?,
+            points: row.try_get("points")?,
+            kind: PointsKind::from_code(&kind).ok_or_else(|| sqlx::Error::ColumnDecode {
+                index: "kind".to_string(),
+                source: format!("Unknown points entry kind: {}", kind).into(),
+            })?,
+            expires_at: parse_optional_datetime_from_row(row, "expires_at")?,
+            created_at: parse_datetime_from_row(row, "created_at")?,
+        })
+    }
+}
+
+impl PointsEntry {
+    fn new(customer_id: &str, points: i64, kind: PointsKind) -> Self {
+        PointsEntry {
+            id: uuid::Uuid::new_v4().to_string(),
+            customer_id: customer_id.to_string(),
+            sale_id: None,
+            points,
+            kind,
+            expires_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn earn(customer_id: &str, points: i64, expires_at: Option<DateTime<Utc>>) -> Self {
+        PointsEntry {
+            expires_at,
+            ..Self::new(customer_id, points, PointsKind::Earn)
+        }
+    }
+
+    pub fn redeem(customer_id: &str, points: i64) -> Self {
+        Self::new(customer_id, -points, PointsKind::Redeem)
+    }
+
+    pub fn expire(customer_id: &str, points: i64) -> Self {
+        Self::new(customer_id, -points, PointsKind::Expire)
+    }
+}
+
+/// Current points of a ledger
+pub fn points_balance(entries: &[PointsEntry]) -> i64 {
+    entries.iter().map(|e| e.points).sum()
+}
+
+/// Earned points past their expiry that were not redeemed or expired yet
+///
+/// Redemptions and expirations consume the soonest-expiring points first.
+pub fn expired_points(entries: &[PointsEntry], now: DateTime<Utc>) -> i64 {
+    let mut lots: Vec<(i64, Option<DateTime<Utc>>)> = entries
+        .iter()
+        .filter(|e| e.kind == PointsKind::Earn)
+        .map(|e| (e.points, e.expires_at))
+        .collect();
+    // Points that never expire go last
+    lots.sort_by_key(|(_, expires_at)| (expires_at.is_none(), *expires_at));
+
+    let mut consumed: i64 = entries
+        .iter()
+        .filter(|e| e.kind != PointsKind::Earn)
+        .map(|e| -e.points)
+        .sum();
+
+    let mut expired = 0;
+    for (points, expires_at) in lots {
+        let used = consumed.min(points);
+        consumed -= used;
+        if expires_at.is_some_and(|at| at <= now) {
+            expired += points - used;
+        }
+    }
+    expired
+}
+
+/// Points activity of one sale, for its receipt
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SalePoints {
+    pub earned: i64,
+    pub redeemed: i64,
+    pub balance: i64, // Customer's balance now
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(days: i64) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-01-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+            + Duration::days(days)
+    }
+
+    #[test]
+    fn test_points_for_and_value_of() {
+        let settings = LoyaltySettings {
+            amount_per_point: dec!(10),
+            ..LoyaltySettings::default()
+        };
+
+        assert_eq!(settings.points_for(dec!(99.90)), 9);
+        assert_eq!(settings.points_for(dec!(-5)), 0);
+        assert_eq!(settings.value_of(25), dec!(2.50));
+        assert_eq!(LoyaltySettings::default().points_for(dec!(1000)), 0);
+    }
+
+    #[test]
+    fn test_expired_points_consume_soonest_expiring_first() {
+        let entries = vec![
+            PointsEntry::earn("c", 50, Some(at(10))),
+            PointsEntry::earn("c", 30, Some(at(40))),
+            PointsEntry::earn("c", 20, None),
+            PointsEntry::redeem("c", 35),
+        ];
+
+        // Redemption used 35 of the first 50
+        assert_eq!(expired_points(&entries, at(5)), 0);
+        assert_eq!(expired_points(&entries, at(10)), 15);
+        assert_eq!(expired_points(&entries, at(50)), 45);
+
+        // Once recorded, expired points are not counted again
+        let mut settled = entries.clone();
+        settled.push(PointsEntry::expire("c", 15));
+        assert_eq!(expired_points(&settled, at(20)), 0);
+        assert_eq!(expired_points(&settled, at(50)), 30);
+        assert_eq!(points_balance(&settled), 50);
+    }
+}
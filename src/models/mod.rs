@@ -2,12 +2,56 @@
 //!
 //! Database entities matching the SQLite schema.
 
+pub mod analytics;
 pub mod catalogs;
+pub mod category;
+pub mod customer;
+pub mod dashboard;
+pub mod inventory_count;
+pub mod invoice;
+pub mod kit;
 pub mod loan;
+pub mod loyalty;
+pub mod price_history;
+pub mod price_list;
+pub mod pricing;
 pub mod product;
+pub mod product_barcode;
+pub mod product_lot;
+pub mod reorder;
+pub mod report;
 pub mod sale;
+pub mod scale_barcode;
+pub mod stock_adjustment;
+pub mod store_clock;
+pub mod supplier;
 
-pub use catalogs::{ItemCondition, StatusLoan, UnitMeasurement};
+pub use analytics::{AbcClass, IdleProduct, PerformanceSort, ProductPerformance, SlowMover};
+pub use catalogs::{
+    AdjustmentReason, ItemCondition, PriceChangeSource, StatusLoan, UnitMeasurement,
+    UnitMeasurementInput,
+};
+pub use category::{Category, CategoryInput, CategoryPath};
+pub use customer::{Customer, CustomerInput};
+pub use dashboard::{Dashboard, DashboardPeriod, PeriodTotals};
+pub use inventory_count::{InventoryCount, InventoryCountLine};
+pub use invoice::{
+    Invoice, InvoiceConcept, InvoiceIssuer, InvoiceReceiver, InvoiceRecord, PaymentMethod,
+    ProductTaxCode, TaxTreatment,
+};
+pub use kit::{KitComponent, KitComponentInput};
 pub use loan::{Loan, LoanInput, LoanPayment, LoanPaymentInput};
+pub use loyalty::{LoyaltySettings, PointsEntry, PointsKind, SalePoints};
+pub use price_history::PriceHistoryEntry;
+pub use price_list::{PriceBook, PriceList, PriceListInput, PriceListItem, PriceTier};
+pub use pricing::{PriceRule, RepriceInput};
 pub use product::{Product, ProductInput};
-pub use sale::{Operation, Sale, SaleInput, SaleItemInput};
+pub use product_barcode::{BarcodeMatch, ProductBarcode, ProductBarcodeInput};
+pub use product_lot::{ExpiringLot, ProductLot, StockReceiptInput};
+pub use reorder::{PurchaseOrder, PurchaseOrderLine, ReorderPolicy, ReorderSuggestion};
+pub use report::{DailyReport, HourlySales, ProductSales};
+pub use sale::{Operation, PaymentFilter, Sale, SaleFilter, SaleInput, SaleItemInput};
+pub use scale_barcode::ScaleBarcodeFormat;
+pub use stock_adjustment::{StockAdjustment, WriteOffInput};
+pub use store_clock::StoreClock;
+pub use supplier::{Supplier, SupplierInput};
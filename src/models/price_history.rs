@@ -0,0 +1,69 @@
+//! Price History Model
+//!
+//! Record of every change to a product's sale price or cost.
+
+use super::Product;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Price history entry
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PriceHistoryEntry {
+    pub id: String, // UUID as TEXT
+    pub product_id: String,
+
+    pub old_price: Decimal, // Stored as TEXT in DB
+    pub new_price: Decimal, // Stored as TEXT in DB
+    pub old_cost: Option<Decimal>,
+    pub new_cost: Option<Decimal>,
+
+    pub source_id: i32, // Manual edit, bulk update or receiving
+    pub changed_at: DateTime<Utc>,
+}
+
+impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for PriceHistoryEntry {
+    fn from_row(row: &'r sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
+        use crate::utils::db_parsing::{
+            parse_datetime_from_row, parse_decimal_from_row, parse_optional_decimal_from_row,
+        };
+        use sqlx::Row;
+
+        Ok(PriceHistoryEntry {
+            id: row.try_get("id")?,
+            product_id: row.try_get("product_id")?,
+            old_price: parse_decimal_from_row(row, "old_price")?,
+            new_price: parse_decimal_from_row(row, "new_price")?,
+            old_cost: parse_optional_decimal_from_row(row, "old_cost")?,
+            new_cost: parse_optional_decimal_from_row(row, "new_cost")?,
+            source_id: row.try_get("source_id")?,
+            changed_at: parse_datetime_from_row(row, "changed_at")?,
+        })
+    }
+}
+
+impl PriceHistoryEntry {
+    /// Build an entry changing a product's price and cost
+    pub fn new(
+        product: &Product,
+        new_price: Decimal,
+        new_cost: Option<Decimal>,
+        source_id: i32,
+    ) -> Self {
+        PriceHistoryEntry {
+            id: uuid::Uuid::new_v4().to_string(),
+            product_id: product.id.clone(),
+            old_price: product.user_price,
+            new_price,
+            old_cost: product.cost_price,
+            new_cost,
+            source_id,
+            changed_at: Utc::now(),
+        }
+    }
+
+    /// Check if the entry actually changes the price or the cost
+    pub fn is_change(&self) -> bool {
+        self.old_price != self.new_price || self.old_cost != self.new_cost
+    }
+}
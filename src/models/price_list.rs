@@ -0,0 +1,244 @@
+//! Price List Model
+//!
+//! Special prices that replace `Product.user_price`: price lists assigned to
+//! customers (e.g. wholesale for small shops) and quantity break tiers that
+//! apply to everyone.
+
+use super::Product;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Price list entity
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PriceList {
+    pub id: String, // UUID as TEXT
+    pub name: String,
+    pub discount_percent: Decimal, // Off the regular price of products without a list price
+    pub created_at: DateTime<Utc>,
+}
+
+impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for PriceList {
+    fn from_row(row: &'r sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
+        use crate::utils::db_parsing::{parse_datetime_from_row, parse_decimal_from_row};
+        use sqlx::Row;
+
+        Ok(PriceList {
+            id: row.try_get("id")?,
+            name: row.try_get("name")?,
+            discount_percent: parse_decimal_from_row(row, "discount_percent")?,
+            created_at: parse_datetime_from_row(row, "created_at")?,
+        })
+    }
+}
+
+/// Price list creation/update data
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceListInput {
+    pub name: String,
+    pub discount_percent: Decimal,
+}
+
+impl PriceListInput {
+    /// Trim the name and validate the fields, returning the cleaned input
+    pub fn normalized(self) -> Result<Self, String> {
+        let name = self.name.trim().to_string();
+        if name.is_empty() {
+            return Err("Price list name cannot be empty".to_string());
+        }
+        if self.discount_percent < Decimal::ZERO || self.discount_percent >= Decimal::ONE_HUNDRED {
+            return Err("Discount must be between 0% and 100%".to_string());
+        }
+        Ok(Self { name, ..self })
+    }
+
+    /// Create a new PriceList from this input
+    pub fn to_price_list(self) -> PriceList {
+        PriceList {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: self.name,
+            discount_percent: self.discount_percent,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// Price of a product on a price list
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PriceListItem {
+    pub price_list_id: String,
+    pub product_id: String,
+    pub product_name: String, // From the product table, for display
+    pub price: Decimal,
+}
+
+impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for PriceListItem {
+    fn from_row(row: &'r sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
+        use crate::utils::db_parsing::parse_decimal_from_row;
+        use sqlx::Row;
+
+        Ok(PriceListItem {
+            price_list_id: row.try_get("price_list_id")?,
+            product_id: row.try_get("product_id")?,
+            product_name: row.try_get("product_name")?,
+            price: parse_decimal_from_row(row, "price")?,
+        })
+    }
+}
+
+/// Quantity break: unit price when buying at least `min_quantity`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PriceTier {
+    pub product_id: String,
+    pub min_quantity: f64, // In the product's sale unit
+    pub unit_price: Decimal,
+}
+
+impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for PriceTier {
+    fn from_row(row: &'r sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
+        use crate::utils::db_parsing::parse_decimal_from_row;
+        use sqlx::Row;
+
+        Ok(PriceTier {
+            product_id: row.try_get("product_id")?,
+            min_quantity: row.try_get("min_quantity")?,
+            unit_price: parse_decimal_from_row(row, "unit_price")?,
+        })
+    }
+}
+
+/// Validate the quantity tiers of a product, returning them sorted by quantity
+pub fn validate_tiers(
+    product: &Product,
+    mut tiers: Vec<PriceTier>,
+) -> Result<Vec<PriceTier>, String> {
+    tiers.sort_by(|a, b| a.min_quantity.total_cmp(&b.min_quantity));
+    for tier in &tiers {
+        if tier.min_quantity <= 1.0 {
+            return Err("Tier quantities must be greater than 1".to_string());
+        }
+        if tier.unit_price <= Decimal::ZERO || tier.unit_price >= product.user_price {
+            return Err(format!(
+                "Tier price for {} must be between $0 and the regular price ${}",
+                tier.min_quantity, product.user_price
+            ));
+        }
+    }
+    if tiers
+        .windows(2)
+        .any(|w| w[0].min_quantity == w[1].min_quantity)
+    {
+        return Err("Each tier needs a different quantity".to_string());
+    }
+    Ok(tiers)
+}
+
+/// Special prices that apply to a customer: their price list and all quantity tiers
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PriceBook {
+    pub price_list: Option<PriceList>,
+    pub list_prices: HashMap<String, Decimal>, // By product ID
+    pub tiers: Vec<PriceTier>,
+}
+
+impl PriceBook {
+    /// Unit price of a product for a quantity: the lowest of the regular or
+    /// list price and the best quantity tier reached
+    pub fn unit_price(&self, product: &Product, quantity: f64) -> Decimal {
+        let base = match (self.list_prices.get(&product.id), &self.price_list) {
+            (Some(price), _) => *price,
+            (None, Some(list)) if list.discount_percent > Decimal::ZERO => (product.user_price
+                * (Decimal::ONE_HUNDRED - list.discount_percent)
+                / Decimal::ONE_HUNDRED)
+                .round_dp(2),
+            _ => product.user_price,
+        };
+
+        self.tiers
+            .iter()
+            .filter(|t| t.product_id == product.id && t.min_quantity <= quantity)
+            .map(|t| t.unit_price)
+            .fold(base, Decimal::min)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ProductInput;
+    use rust_decimal_macros::dec;
+
+    fn product(id: &str, price: Decimal) -> Product {
+        ProductInput {
+            barcode: None,
+            full_name: id.to_string(),
+            user_price: price,
+            cost_price: None,
+            min_amount: 0.0,
+            current_amount: 0.0,
+            unit_measurement_id: 4,
+            purchase_unit_id: None,
+            units_per_purchase: 1.0,
+            category_id: None,
+            supplier_id: None,
+        }
+        .to_product()
+    }
+
+    fn tier(product: &Product, min_quantity: f64, unit_price: Decimal) -> PriceTier {
+        PriceTier {
+            product_id: product.id.clone(),
+            min_quantity,
+            unit_price,
+        }
+    }
+
+    #[test]
+    fn test_unit_price_uses_lowest_applicable_price() {
+        let soda = product("soda", dec!(20));
+        let chips = product("chips", dec!(15));
+        let mut book = PriceBook {
+            tiers: vec![tier(&soda, 12.0, dec!(17)), tier(&soda, 24.0, dec!(16))],
+            ..PriceBook::default()
+        };
+
+        assert_eq!(book.unit_price(&soda, 11.0), dec!(20));
+        assert_eq!(book.unit_price(&soda, 12.0), dec!(17));
+        assert_eq!(book.unit_price(&soda, 30.0), dec!(16));
+
+        // Wholesale list: explicit price for soda, 10% off everything else
+        book.price_list = Some(
+            PriceListInput {
+                name: "Wholesale".to_string(),
+                discount_percent: dec!(10),
+            }
+            .to_price_list(),
+        );
+        book.list_prices.insert(soda.id.clone(), dec!(16.50));
+
+        assert_eq!(book.unit_price(&soda, 1.0), dec!(16.50));
+        assert_eq!(book.unit_price(&soda, 24.0), dec!(16));
+        assert_eq!(book.unit_price(&chips, 1.0), dec!(13.50));
+    }
+
+    #[test]
+    fn test_validate_tiers() {
+        let soda = product("soda", dec!(20));
+
+        let tiers = validate_tiers(
+            &soda,
+            vec![tier(&soda, 24.0, dec!(16)), tier(&soda, 12.0, dec!(17))],
+        )
+        .unwrap();
+        assert_eq!(tiers[0].min_quantity, 12.0);
+
+        assert!(validate_tiers(&soda, vec![tier(&soda, 1.0, dec!(17))]).is_err());
+        assert!(validate_tiers(&soda, vec![tier(&soda, 12.0, dec!(20))]).is_err());
+        assert!(validate_tiers(
+            &soda,
+            vec![tier(&soda, 12.0, dec!(17)), tier(&soda, 12.0, dec!(16))]
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,155 @@
+//! Pricing Models
+//!
+//! Bulk repricing rules and retail price endings (e.g. prices ending in .50
+//! or .90).
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// How a bulk update changes the sale price
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum PriceRule {
+    Percentage(Decimal),   // 8 raises prices 8%, -10 lowers them 10%
+    FixedAmount(Decimal),  // 2.50 adds $2.50 to every price
+    TargetMargin(Decimal), // 30 prices at cost plus 30% of cost
+}
+
+impl PriceRule {
+    /// New price before rounding
+    ///
+    /// Returns None when the rule needs a cost the product does not have.
+    pub fn apply(&self, price: Decimal, cost: Option<Decimal>) -> Option<Decimal> {
+        match *self {
+            PriceRule::Percentage(percent) => {
+                Some(price * (Decimal::ONE + percent / Decimal::from(100)))
+            }
+            PriceRule::FixedAmount(amount) => Some(price + amount),
+            PriceRule::TargetMargin(margin) => cost
+                .filter(|c| *c > Decimal::ZERO)
+                .map(|c| c * (Decimal::ONE + margin / Decimal::from(100))),
+        }
+    }
+}
+
+/// Bulk repricing request: which products and how to change them
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RepriceInput {
+    pub category_id: Option<String>, // Includes subcategories
+    pub supplier_id: Option<String>,
+    pub query: String, // Name or barcode, empty for all
+    pub rule: PriceRule,
+    pub endings: Vec<Decimal>, // Allowed cents, empty to only round to cents
+}
+
+/// Round a price up to the closest allowed ending
+///
+/// With endings .50 and .90, 23.17 becomes 23.50, 23.60 becomes 23.90 and
+/// 23.95 becomes 24.50. Without endings the price is rounded to cents.
+pub fn round_to_ending(price: Decimal, endings: &[Decimal]) -> Decimal {
+    let price = price.round_dp(2);
+    let whole = price.floor();
+
+    endings
+        .iter()
+        .flat_map(|ending| [whole + ending, whole + Decimal::ONE + ending])
+        .filter(|candidate| *candidate >= price)
+        .min()
+        .unwrap_or(price)
+}
+
+/// Parse configured price endings such as ".50, .90"
+pub fn parse_price_endings(value: &str) -> Result<Vec<Decimal>, String> {
+    let mut endings = Vec::new();
+
+    for part in value.split([',', '/', ' ']) {
+        let part = part.trim();
+        if part.is_empty() {
+            continue;
+        }
+
+        let normalized = if part.starts_with('.') {
+            format!("0{}", part)
+        } else {
+            part.to_string()
+        };
+        let ending = Decimal::from_str(&normalized)
+            .map_err(|_| format!("Invalid price ending: {}", part))?;
+
+        if ending < Decimal::ZERO || ending >= Decimal::ONE {
+            return Err(format!(
+                "Price ending must be between .00 and .99: {}",
+                part
+            ));
+        }
+
+        endings.push(ending.round_dp(2));
+    }
+
+    endings.sort();
+    endings.dedup();
+    Ok(endings)
+}
+
+/// Format price endings for display (".50, .90")
+pub fn format_price_endings(endings: &[Decimal]) -> String {
+    endings
+        .iter()
+        .map(|ending| format!(".{:02}", (ending * Decimal::from(100)).round()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_price_rules() {
+        assert_eq!(
+            PriceRule::Percentage(dec!(8)).apply(dec!(25), None),
+            Some(dec!(27))
+        );
+        assert_eq!(
+            PriceRule::FixedAmount(dec!(-1.50)).apply(dec!(25), None),
+            Some(dec!(23.50))
+        );
+        assert_eq!(
+            PriceRule::TargetMargin(dec!(30)).apply(dec!(25), Some(dec!(20))),
+            Some(dec!(26))
+        );
+        assert_eq!(
+            PriceRule::TargetMargin(dec!(30)).apply(dec!(25), None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_round_to_ending() {
+        let endings = [dec!(0.50), dec!(0.90)];
+
+        assert_eq!(round_to_ending(dec!(23.17), &endings), dec!(23.50));
+        assert_eq!(round_to_ending(dec!(23.60), &endings), dec!(23.90));
+        assert_eq!(round_to_ending(dec!(23.95), &endings), dec!(24.50));
+        assert_eq!(round_to_ending(dec!(23.50), &endings), dec!(23.50));
+        assert_eq!(round_to_ending(dec!(23.456), &[]), dec!(23.46));
+    }
+
+    #[test]
+    fn test_parse_price_endings() {
+        assert_eq!(
+            parse_price_endings(".90, .50 / 0.50").unwrap(),
+            vec![dec!(0.50), dec!(0.90)]
+        );
+        assert_eq!(parse_price_endings("").unwrap(), Vec::<Decimal>::new());
+        assert!(parse_price_endings("1.50").is_err());
+        assert!(parse_price_endings("abc").is_err());
+    }
+
+    #[test]
+    fn test_format_price_endings() {
+        assert_eq!(format_price_endings(&[dec!(0.5), dec!(0.90)]), ".50, .90");
+        assert_eq!(format_price_endings(&[dec!(0)]), ".00");
+    }
+}
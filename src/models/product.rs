@@ -17,9 +17,16 @@ pub struct Product {
 
     pub cost_price: Option<Decimal>, // Stored as TEXT in DB
 
-    pub min_amount: f64,     // Minimum stock threshold
-    pub current_amount: f64, // Current inventory
-    pub unit_measurement_id: i32,
+    pub min_amount: f64,               // Minimum stock threshold
+    pub current_amount: f64,           // Current inventory
+    pub unit_measurement_id: i32,      // Sale unit; stock is kept in this unit
+    pub purchase_unit_id: Option<i32>, // None when bought in the sale unit
+    pub units_per_purchase: f64,       // Sale units per purchase unit (e.g., 24 pcs per box)
+    pub category_id: Option<String>,   // None for uncategorized products
+    pub supplier_id: Option<String>,   // None when the supplier is not recorded
+    pub is_kit: bool,                  // Stock is computed from the kit's components
+
+    pub archived_at: Option<DateTime<Utc>>, // None while the product is active
 
     pub created_at: DateTime<Utc>,
 
@@ -30,7 +37,8 @@ pub struct Product {
 impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for Product {
     fn from_row(row: &'r sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
         use crate::utils::db_parsing::{
-            parse_datetime_from_row, parse_decimal_from_row, parse_optional_decimal_from_row,
+            parse_datetime_from_row, parse_decimal_from_row, parse_optional_datetime_from_row,
+            parse_optional_decimal_from_row,
         };
         use sqlx::Row;
 
@@ -43,6 +51,12 @@ impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for Product {
             min_amount: row.try_get("min_amount")?,
             current_amount: row.try_get("current_amount")?,
             unit_measurement_id: row.try_get("unit_measurement_id")?,
+            purchase_unit_id: row.try_get("purchase_unit_id")?,
+            units_per_purchase: row.try_get("units_per_purchase")?,
+            category_id: row.try_get("category_id")?,
+            supplier_id: row.try_get("supplier_id")?,
+            is_kit: row.try_get("is_kit")?,
+            archived_at: parse_optional_datetime_from_row(row, "archived_at")?,
             created_at: parse_datetime_from_row(row, "created_at")?,
             updated_at: parse_datetime_from_row(row, "updated_at")?,
         })
@@ -55,6 +69,37 @@ impl Product {
         self.current_amount <= self.min_amount
     }
 
+    /// Check if the product was archived (hidden from sales and lists)
+    pub fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
+    }
+
+    /// Convert a quantity in purchase units to sale units
+    pub fn to_sale_units(&self, purchase_quantity: f64) -> f64 {
+        match self.purchase_unit_id {
+            Some(_) => purchase_quantity * self.units_per_purchase,
+            None => purchase_quantity,
+        }
+    }
+
+    /// Convert a cost per purchase unit to a cost per sale unit
+    pub fn to_sale_unit_cost(&self, purchase_cost: Decimal) -> Decimal {
+        match self.purchase_unit_id {
+            Some(_) => Decimal::from_f64_retain(self.units_per_purchase)
+                .filter(|units| !units.is_zero())
+                .map(|units| (purchase_cost / units).round_dp(4))
+                .unwrap_or(purchase_cost),
+            None => purchase_cost,
+        }
+    }
+
+    /// Current stock expressed in purchase units, if the product has one
+    pub fn stock_in_purchase_units(&self) -> Option<f64> {
+        self.purchase_unit_id
+            .filter(|_| self.units_per_purchase > 0.0)
+            .map(|_| self.current_amount / self.units_per_purchase)
+    }
+
     /// Calculate profit margin percentage
     pub fn profit_margin(&self) -> Option<Decimal> {
         self.cost_price.map(|cost| {
@@ -82,6 +127,10 @@ pub struct ProductInput {
     pub min_amount: f64,
     pub current_amount: f64,
     pub unit_measurement_id: i32,
+    pub purchase_unit_id: Option<i32>,
+    pub units_per_purchase: f64,
+    pub category_id: Option<String>,
+    pub supplier_id: Option<String>,
 }
 
 impl ProductInput {
@@ -97,6 +146,12 @@ impl ProductInput {
             min_amount: self.min_amount,
             current_amount: self.current_amount,
             unit_measurement_id: self.unit_measurement_id,
+            purchase_unit_id: self.purchase_unit_id,
+            units_per_purchase: self.units_per_purchase,
+            category_id: self.category_id,
+            supplier_id: self.supplier_id,
+            is_kit: false,
+            archived_at: None,
             created_at: now,
             updated_at: now,
         }
@@ -0,0 +1,60 @@
+//! Product Barcode Model
+//!
+//! Additional barcodes for a product, e.g. a 6-pack code or a supplier's
+//! replacement code. Each code maps to a pack quantity of the base unit.
+
+use super::Product;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Additional barcode entity
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProductBarcode {
+    pub barcode: String,
+    pub product_id: String,
+    pub pack_quantity: f64, // Units added per scan (1 for single items)
+
+    pub created_at: DateTime<Utc>,
+}
+
+impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for ProductBarcode {
+    fn from_row(row: &'r sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
+        use crate::utils::db_parsing::parse_datetime_from_row;
+        use sqlx::Row;
+
+        Ok(ProductBarcode {
+            barcode: row.try_get("barcode")?,
+            product_id: row.try_get("product_id")?,
+            pack_quantity: row.try_get("pack_quantity")?,
+            created_at: parse_datetime_from_row(row, "created_at")?,
+        })
+    }
+}
+
+/// Additional barcode creation data
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductBarcodeInput {
+    pub barcode: String,
+    pub pack_quantity: f64,
+}
+
+impl ProductBarcodeInput {
+    /// Create a new ProductBarcode for the given product
+    pub fn to_product_barcode(self, product_id: &str) -> ProductBarcode {
+        ProductBarcode {
+            barcode: self.barcode,
+            product_id: product_id.to_string(),
+            pack_quantity: self.pack_quantity,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// Product resolved from a scanned barcode
+#[derive(Debug, Clone, PartialEq)]
+pub struct BarcodeMatch {
+    pub product: Product,
+    pub pack_quantity: f64,
+    pub line_total: Option<Decimal>, // Amount printed on a price-embedded scale label
+}
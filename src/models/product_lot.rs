@@ -0,0 +1,82 @@
+//! Product Lot Model
+//!
+//! Batches of received stock with optional lot code and expiry date.
+//! Lots in a condition other than Good are kept for the record but are no
+//! longer part of the sellable quantity.
+
+use super::ItemCondition;
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Product lot entity
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProductLot {
+    pub id: String, // UUID as TEXT
+    pub product_id: String,
+    pub lot_code: Option<String>,
+    pub expires_at: Option<NaiveDate>, // None for non-perishable lots
+
+    pub received_quantity: f64,  // In sale units
+    pub remaining_quantity: f64, // In sale units
+    pub condition_id: i32,       // Good, Damaged or Expired
+
+    pub received_at: DateTime<Utc>,
+}
+
+impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for ProductLot {
+    fn from_row(row: &'r sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
+        use crate::utils::db_parsing::parse_datetime_from_row;
+        use sqlx::Row;
+
+        Ok(ProductLot {
+            id: row.try_get("id")?,
+            product_id: row.try_get("product_id")?,
+            lot_code: row.try_get("lot_code")?,
+            expires_at: row.try_get("expires_at")?,
+            received_quantity: row.try_get("received_quantity")?,
+            remaining_quantity: row.try_get("remaining_quantity")?,
+            condition_id: row.try_get("condition_id")?,
+            received_at: parse_datetime_from_row(row, "received_at")?,
+        })
+    }
+}
+
+impl ProductLot {
+    /// Check if the lot still counts as sellable stock
+    pub fn is_sellable(&self) -> bool {
+        self.condition_id == ItemCondition::GOOD && self.remaining_quantity > 0.0
+    }
+
+    /// Days until the lot expires (negative once expired)
+    pub fn days_until_expiry(&self, today: NaiveDate) -> Option<i64> {
+        self.expires_at.map(|date| (date - today).num_days())
+    }
+}
+
+/// Lot together with its product name, for reports
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpiringLot {
+    pub lot: ProductLot,
+    pub product_name: String,
+}
+
+impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for ExpiringLot {
+    fn from_row(row: &'r sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
+        use sqlx::Row;
+
+        Ok(ExpiringLot {
+            lot: ProductLot::from_row(row)?,
+            product_name: row.try_get("product_name")?,
+        })
+    }
+}
+
+/// Stock receipt data (quantity in the product's purchase unit)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockReceiptInput {
+    pub purchase_quantity: f64,
+    pub lot_code: Option<String>,
+    pub expires_at: Option<NaiveDate>,
+    pub unit_cost: Option<Decimal>, // Cost per purchase unit, updates the product cost
+}
@@ -0,0 +1,269 @@
+//! Reorder Models
+//!
+//! Reorder suggestions computed from sales velocity, supplier lead time and a
+//! safety-stock factor, and the draft purchase orders they turn into.
+
+use super::Product;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Parameters of the reorder calculation
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct ReorderPolicy {
+    pub window_days: i64,   // Sales history used for the daily average
+    pub coverage_days: i64, // Days of sales an order should last after arriving
+    pub safety_factor: f64, // Multiplier on expected demand (1.0 = no safety stock)
+}
+
+impl Default for ReorderPolicy {
+    fn default() -> Self {
+        ReorderPolicy {
+            window_days: 30,
+            coverage_days: 7,
+            safety_factor: 1.5,
+        }
+    }
+}
+
+impl ReorderPolicy {
+    /// Validate the policy values
+    pub fn validate(&self) -> Result<(), String> {
+        if self.window_days < 1 {
+            return Err("Sales window must be at least one day".to_string());
+        }
+        if self.coverage_days < 0 {
+            return Err("Coverage days cannot be negative".to_string());
+        }
+        if self.safety_factor < 1.0 {
+            return Err("Safety factor must be at least 1".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Suggested order for one product
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReorderSuggestion {
+    pub product_id: String,
+    pub product_name: String,
+    pub supplier_id: Option<String>,
+    pub unit_cost: Option<Decimal>, // Cost per sale unit
+
+    pub current_stock: f64,
+    pub avg_daily_sales: f64,
+    pub lead_time_days: i64,
+    pub reorder_point: f64, // Stock level at which to order
+
+    pub suggested_quantity: f64, // In sale units
+    pub purchase_quantity: f64,  // In purchase units, rounded up
+    pub units_per_purchase: f64, // Sale units per purchase unit
+}
+
+impl ReorderSuggestion {
+    /// Compute the suggestion for a product, None when no order is needed
+    ///
+    /// The reorder point is the demand expected during the supplier's lead
+    /// time times the safety factor (never below the product's minimum). When
+    /// stock is at or below it, the order brings stock up to the demand for
+    /// the lead time plus the coverage days, times the safety factor.
+    pub fn calculate(
+        product: &Product,
+        units_sold: f64,
+        lead_time_days: i64,
+        policy: &ReorderPolicy,
+    ) -> Option<Self> {
+        let avg_daily_sales = units_sold.max(0.0) / policy.window_days.max(1) as f64;
+
+        let reorder_point = (avg_daily_sales * lead_time_days as f64 * policy.safety_factor)
+            .max(product.min_amount);
+        if product.current_amount > reorder_point {
+            return None;
+        }
+
+        let target_stock = (avg_daily_sales
+            * (lead_time_days + policy.coverage_days) as f64
+            * policy.safety_factor)
+            .max(product.min_amount);
+        let needed = target_stock - product.current_amount;
+        if needed <= 0.0 {
+            return None;
+        }
+
+        // Order whole purchase units (whole units for items sold by the piece)
+        let units_per_purchase = match product.purchase_unit_id {
+            Some(_) if product.units_per_purchase > 0.0 => product.units_per_purchase,
+            _ => 1.0,
+        };
+        let purchase_quantity = (needed / units_per_purchase).ceil();
+
+        Some(ReorderSuggestion {
+            product_id: product.id.clone(),
+            product_name: product.full_name.clone(),
+            supplier_id: product.supplier_id.clone(),
+            unit_cost: product.cost_price,
+            current_stock: product.current_amount,
+            avg_daily_sales,
+            lead_time_days,
+            reorder_point,
+            suggested_quantity: purchase_quantity * units_per_purchase,
+            purchase_quantity,
+            units_per_purchase,
+        })
+    }
+
+    /// Change the order quantity, keeping the sale units in step
+    pub fn set_purchase_quantity(&mut self, purchase_quantity: f64) {
+        self.purchase_quantity = purchase_quantity;
+        self.suggested_quantity = purchase_quantity * self.units_per_purchase;
+    }
+}
+
+/// Draft purchase order entity
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PurchaseOrder {
+    pub id: String,                  // UUID as TEXT
+    pub supplier_id: Option<String>, // None for products without supplier
+    pub supplier_name: Option<String>,
+    pub created_at: DateTime<Utc>,
+
+    pub line_count: i64,
+    pub total_cost: Decimal, // Known costs only
+}
+
+impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for PurchaseOrder {
+    fn from_row(row: &'r sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
+        use crate::utils::db_parsing::{parse_datetime_from_row, parse_decimal_from_row};
+        use sqlx::Row;
+
+        Ok(PurchaseOrder {
+            id: row.try_get("id")?,
+            supplier_id: row.try_get("supplier_id")?,
+            supplier_name: row.try_get("supplier_name")?,
+            created_at: parse_datetime_from_row(row, "created_at")?,
+            line_count: row.try_get("line_count")?,
+            total_cost: parse_decimal_from_row(row, "total_cost")?,
+        })
+    }
+}
+
+/// Product line of a purchase order
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PurchaseOrderLine {
+    pub order_id: String,
+    pub product_id: String,
+    pub product_name: String,       // Denormalized at order time
+    pub quantity: f64,              // In sale units
+    pub purchase_quantity: f64,     // In the product's purchase unit
+    pub unit_cost: Option<Decimal>, // Cost per sale unit when ordered
+}
+
+impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for PurchaseOrderLine {
+    fn from_row(row: &'r sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
+        use crate::utils::db_parsing::parse_optional_decimal_from_row;
+        use sqlx::Row;
+
+        Ok(PurchaseOrderLine {
+            order_id: row.try_get("order_id")?,
+            product_id: row.try_get("product_id")?,
+            product_name: row.try_get("product_name")?,
+            quantity: row.try_get("quantity")?,
+            purchase_quantity: row.try_get("purchase_quantity")?,
+            unit_cost: parse_optional_decimal_from_row(row, "unit_cost")?,
+        })
+    }
+}
+
+impl PurchaseOrderLine {
+    /// Line cost, when the unit cost is known
+    pub fn total_cost(&self) -> Option<Decimal> {
+        self.unit_cost
+            .map(|cost| cost * Decimal::from_f64_retain(self.quantity).unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ProductInput;
+
+    fn product(current: f64, min: f64, units_per_purchase: Option<f64>) -> Product {
+        ProductInput {
+            barcode: None,
+            full_name: "Coca-Cola 600ml".to_string(),
+            user_price: Decimal::from(18),
+            cost_price: Some(Decimal::from(12)),
+            min_amount: min,
+            current_amount: current,
+            unit_measurement_id: 4,
+            purchase_unit_id: units_per_purchase.map(|_| 4),
+            units_per_purchase: units_per_purchase.unwrap_or(1.0),
+            category_id: None,
+            supplier_id: None,
+        }
+        .to_product()
+    }
+
+    #[test]
+    fn test_no_suggestion_above_reorder_point() {
+        // 2 per day, 3 days lead time, 1.5 factor: reorder point is 9
+        let policy = ReorderPolicy::default();
+        assert!(
+            ReorderSuggestion::calculate(&product(10.0, 0.0, None), 60.0, 3, &policy).is_none()
+        );
+    }
+
+    #[test]
+    fn test_suggestion_covers_lead_time_and_coverage() {
+        let policy = ReorderPolicy::default();
+        let suggestion =
+            ReorderSuggestion::calculate(&product(5.0, 0.0, None), 60.0, 3, &policy).unwrap();
+
+        assert_eq!(suggestion.avg_daily_sales, 2.0);
+        assert_eq!(suggestion.reorder_point, 9.0);
+        // Target is 2/day * (3 + 7) days * 1.5 = 30
+        assert_eq!(suggestion.suggested_quantity, 25.0);
+        assert_eq!(suggestion.purchase_quantity, 25.0);
+    }
+
+    #[test]
+    fn test_suggestion_rounds_up_to_purchase_units() {
+        let policy = ReorderPolicy::default();
+        let suggestion =
+            ReorderSuggestion::calculate(&product(5.0, 0.0, Some(24.0)), 60.0, 3, &policy).unwrap();
+
+        assert_eq!(suggestion.purchase_quantity, 2.0);
+        assert_eq!(suggestion.suggested_quantity, 48.0);
+
+        let mut edited = suggestion.clone();
+        edited.set_purchase_quantity(3.0);
+        assert_eq!(edited.suggested_quantity, 72.0);
+    }
+
+    #[test]
+    fn test_minimum_stock_without_sales() {
+        let policy = ReorderPolicy::default();
+        let suggestion =
+            ReorderSuggestion::calculate(&product(2.0, 6.0, None), 0.0, 3, &policy).unwrap();
+
+        assert_eq!(suggestion.suggested_quantity, 4.0);
+        assert!(ReorderSuggestion::calculate(&product(8.0, 6.0, None), 0.0, 3, &policy).is_none());
+    }
+
+    #[test]
+    fn test_policy_validation() {
+        assert!(ReorderPolicy::default().validate().is_ok());
+        assert!(ReorderPolicy {
+            window_days: 0,
+            ..ReorderPolicy::default()
+        }
+        .validate()
+        .is_err());
+        assert!(ReorderPolicy {
+            safety_factor: 0.5,
+            ..ReorderPolicy::default()
+        }
+        .validate()
+        .is_err());
+    }
+}
@@ -0,0 +1,303 @@
+//! Report Models
+//!
+//! Aggregated sales figures for end-of-day summaries.
+
+use super::{LoanPayment, Operation, Product, Sale, StoreClock};
+use chrono::{NaiveDate, Timelike};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Units and revenue of one product over a period
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProductSales {
+    pub product_id: String,
+    pub product_name: String,
+    pub quantity: f64,
+    pub revenue: Decimal,
+    pub cost: Option<Decimal>, // At the current cost price; None when it is not set
+}
+
+impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for ProductSales {
+    fn from_row(row: &'r sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
+        use crate::utils::db_parsing::{parse_decimal_from_row, parse_optional_decimal_from_row};
+        use sqlx::Row;
+
+        Ok(ProductSales {
+            product_id: row.try_get("product_id")?,
+            product_name: row.try_get("product_name")?,
+            quantity: row.try_get("quantity")?,
+            revenue: parse_decimal_from_row(row, "revenue")?,
+            cost: parse_optional_decimal_from_row(row, "cost")?,
+        })
+    }
+}
+
+/// Tickets and revenue within one store-local hour
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HourlySales {
+    pub hour: u32, // 0-23, store-local
+    pub tickets: usize,
+    pub revenue: Decimal,
+}
+
+/// End-of-day summary for one business day
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DailyReport {
+    pub date: NaiveDate,
+
+    pub ticket_count: usize,
+    pub revenue: Decimal,
+    pub cash_sales: usize,
+    pub cash_revenue: Decimal,
+    pub credit_sales: usize,
+    pub credit_revenue: Decimal,
+    pub credit_outstanding: Decimal, // Part of today's credit sales not paid at the till
+
+    pub payments_collected: Decimal, // Loan payments received during the day
+    pub payment_count: usize,
+    pub total_outstanding: Decimal, // All loans, as of generating the report
+
+    pub top_products: Vec<ProductSales>,
+    pub hourly: Vec<HourlySales>, // Only hours with sales
+    pub low_stock: Vec<Product>,
+}
+
+impl DailyReport {
+    /// Maximum number of products listed as top sellers
+    pub const TOP_PRODUCTS: usize = 10;
+
+    /// Build the summary from the day's sales and loan payments
+    pub fn build(
+        date: NaiveDate,
+        sales: &[Sale],
+        mut product_sales: Vec<ProductSales>,
+        payments: &[LoanPayment],
+        total_outstanding: Decimal,
+        low_stock: Vec<Product>,
+        clock: &StoreClock,
+    ) -> Self {
+        let (credit, cash): (Vec<&Sale>, Vec<&Sale>) = sales.iter().partition(|s| s.is_loan);
+
+        product_sales.sort_by_key(|p| std::cmp::Reverse(p.revenue));
+        product_sales.truncate(Self::TOP_PRODUCTS);
+
+        DailyReport {
+            date,
+            ticket_count: sales.len(),
+            revenue: sales.iter().map(|s| s.total_amount).sum(),
+            cash_sales: cash.len(),
+            cash_revenue: cash.iter().map(|s| s.total_amount).sum(),
+            credit_sales: credit.len(),
+            credit_revenue: credit.iter().map(|s| s.total_amount).sum(),
+            credit_outstanding: credit.iter().map(|s| s.remaining_amount()).sum(),
+            payments_collected: payments.iter().map(|p| p.amount).sum(),
+            payment_count: payments.len(),
+            total_outstanding,
+            top_products: product_sales,
+            hourly: hourly_distribution(sales, clock),
+            low_stock,
+        }
+    }
+
+    /// Average revenue per ticket
+    pub fn average_ticket(&self) -> Decimal {
+        if self.ticket_count == 0 {
+            Decimal::ZERO
+        } else {
+            (self.revenue / Decimal::from(self.ticket_count)).round_dp(2)
+        }
+    }
+
+    /// Money taken in during the day: cash at the till plus loan payments
+    pub fn cash_collected(&self) -> Decimal {
+        self.revenue - self.credit_outstanding + self.payments_collected
+    }
+}
+
+/// Sales cut down to some of their lines, e.g. those of one category
+///
+/// Each sale keeps the total of its lines found in `operations` and what was
+/// paid for them in proportion, so credit sales keep their share of the
+/// balance. Sales without any of the lines are left out.
+pub fn scope_sales(sales: &[Sale], operations: &[Operation]) -> Vec<Sale> {
+    sales
+        .iter()
+        .filter_map(|sale| {
+            let lines: Vec<&Operation> =
+                operations.iter().filter(|o| o.sale_id == sale.id).collect();
+            if lines.is_empty() {
+                return None;
+            }
+
+            let total: Decimal = lines.iter().map(|o| o.subtotal).sum();
+            let share = |amount: Decimal| {
+                if sale.total_amount.is_zero() {
+                    Decimal::ZERO
+                } else {
+                    (amount * total / sale.total_amount).round_dp(2)
+                }
+            };
+
+            Some(Sale {
+                total_amount: total,
+                paid_amount: share(sale.paid_amount.min(sale.total_amount)),
+                change_amount: Decimal::ZERO,
+                points_amount: share(sale.points_amount),
+                ..sale.clone()
+            })
+        })
+        .collect()
+}
+
+/// Group sales by store-local hour in business-day order, keeping only hours with sales
+pub fn hourly_distribution(sales: &[Sale], clock: &StoreClock) -> Vec<HourlySales> {
+    let mut hours: Vec<HourlySales> = Vec::new();
+
+    for sale in sales {
+        let hour = clock.local(sale.sold_at).hour();
+        match hours.iter_mut().find(|h| h.hour == hour) {
+            Some(bucket) => {
+                bucket.tickets += 1;
+                bucket.revenue += sale.total_amount;
+            }
+            None => hours.push(HourlySales {
+                hour,
+                tickets: 1,
+                revenue: sale.total_amount,
+            }),
+        }
+    }
+
+    hours.sort_by_key(|h| clock.hour_position(h.hour));
+    hours
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    fn sale(sold_at: &str, total: Decimal, paid: Decimal) -> Sale {
+        Sale {
+            id: sold_at.to_string(),
+            total_amount: total,
+            paid_amount: paid,
+            change_amount: Decimal::ZERO,
+            is_loan: paid < total,
+            sold_at: DateTime::parse_from_rfc3339(sold_at)
+                .unwrap()
+                .with_timezone(&Utc),
+            customer_id: None,
+            points_amount: Decimal::ZERO,
+        }
+    }
+
+    fn line(sale: &Sale, subtotal: Decimal) -> Operation {
+        Operation {
+            id: uuid::Uuid::new_v4().to_string(),
+            sale_id: sale.id.clone(),
+            product_id: "p".to_string(),
+            product_name: "p".to_string(),
+            quantity: 1.0,
+            unit_price: subtotal,
+            subtotal,
+            recorded_at: sale.sold_at,
+            unit_abbreviation: None,
+            quantity_decimals: 3,
+        }
+    }
+
+    fn product_sales(name: &str, revenue: Decimal) -> ProductSales {
+        ProductSales {
+            product_id: name.to_string(),
+            product_name: name.to_string(),
+            quantity: 1.0,
+            revenue,
+            cost: None,
+        }
+    }
+
+    #[test]
+    fn test_hourly_distribution_uses_store_time() {
+        let sales = vec![
+            sale("2026-10-18T15:10:00+00:00", dec!(10), dec!(10)),
+            sale("2026-10-18T15:50:00+00:00", dec!(5), dec!(5)),
+            sale("2026-10-18T14:05:00+00:00", dec!(7), dec!(7)),
+        ];
+
+        let hours = hourly_distribution(&sales, &StoreClock::default());
+
+        // UTC-6: 14:05 and 15:xx UTC are 8 am and 9 am in the store
+        assert_eq!(hours.len(), 2);
+        assert_eq!((hours[0].hour, hours[0].tickets), (8, 1));
+        assert_eq!((hours[1].hour, hours[1].tickets), (9, 2));
+        assert_eq!(hours[1].revenue, dec!(15));
+    }
+
+    #[test]
+    fn test_hourly_distribution_puts_late_night_last() {
+        let sales = vec![
+            sale("2026-10-19T07:30:00+00:00", dec!(10), dec!(10)),
+            sale("2026-10-18T15:10:00+00:00", dec!(5), dec!(5)),
+        ];
+        let clock = StoreClock::new("America/Mexico_City", 3).unwrap();
+
+        let hours = hourly_distribution(&sales, &clock);
+
+        // 1:30 am belongs to the end of the previous business day
+        let order: Vec<u32> = hours.iter().map(|h| h.hour).collect();
+        assert_eq!(order, vec![9, 1]);
+    }
+
+    #[test]
+    fn test_daily_report_totals() {
+        let date = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+        let sales = vec![
+            sale("2026-10-18T15:10:00+00:00", dec!(100), dec!(100)),
+            sale("2026-10-18T16:00:00+00:00", dec!(50), dec!(20)),
+        ];
+        let products = (1..=12)
+            .map(|i| product_sales(&format!("p{}", i), Decimal::from(i)))
+            .collect();
+
+        let report = DailyReport::build(
+            date,
+            &sales,
+            products,
+            &[],
+            dec!(500),
+            Vec::new(),
+            &StoreClock::default(),
+        );
+
+        assert_eq!(report.ticket_count, 2);
+        assert_eq!(report.revenue, dec!(150));
+        assert_eq!((report.cash_sales, report.cash_revenue), (1, dec!(100)));
+        assert_eq!((report.credit_sales, report.credit_revenue), (1, dec!(50)));
+        assert_eq!(report.credit_outstanding, dec!(30));
+        assert_eq!(report.cash_collected(), dec!(120));
+        assert_eq!(report.average_ticket(), dec!(75));
+        assert_eq!(report.top_products.len(), DailyReport::TOP_PRODUCTS);
+        assert_eq!(report.top_products[0].product_name, "p12");
+    }
+
+    #[test]
+    fn test_scope_sales_to_lines() {
+        let cash = sale("2026-10-18T15:10:00+00:00", dec!(100), dec!(200));
+        let credit = sale("2026-10-18T16:00:00+00:00", dec!(50), dec!(20));
+        let other = sale("2026-10-18T17:00:00+00:00", dec!(30), dec!(30));
+        let lines = vec![line(&cash, dec!(40)), line(&credit, dec!(25))];
+
+        let scoped = scope_sales(&[cash, credit, other], &lines);
+
+        assert_eq!(scoped.len(), 2);
+        assert_eq!(scoped[0].total_amount, dec!(40));
+        assert_eq!(scoped[0].remaining_amount(), Decimal::ZERO);
+
+        // Half the credit sale's lines, half of what it still owes
+        assert_eq!(scoped[1].total_amount, dec!(25));
+        assert_eq!(scoped[1].paid_amount, dec!(10));
+        assert_eq!(scoped[1].remaining_amount(), dec!(15));
+    }
+}
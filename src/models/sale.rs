@@ -2,7 +2,9 @@
 //!
 //! Represents sales transactions and their line items.
 
-use chrono::{DateTime, Utc};
+use super::{InvoiceReceiver, StoreClock};
+use crate::utils::formatting::format_quantity;
+use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
@@ -20,6 +22,10 @@ pub struct Sale {
     pub is_loan: bool, // Stored as INTEGER (0/1) in DB
 
     pub sold_at: DateTime<Utc>,
+
+    pub customer_id: Option<String>, // None for walk-in customers
+
+    pub points_amount: Decimal, // Part of paid_amount tendered in loyalty points
 }
 
 impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for Sale {
@@ -34,6 +40,8 @@ impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for Sale {
             change_amount: parse_decimal_from_row(row, "change_amount")?,
             is_loan: row.try_get("is_loan")?,
             sold_at: parse_datetime_from_row(row, "sold_at")?,
+            customer_id: row.try_get("customer_id")?,
+            points_amount: parse_decimal_from_row(row, "points_amount")?,
         })
     }
 }
@@ -44,6 +52,11 @@ impl Sale {
         self.paid_amount >= self.total_amount
     }
 
+    /// Amount paid in money rather than points
+    pub fn cash_received(&self) -> Decimal {
+        self.paid_amount - self.points_amount
+    }
+
     /// Get remaining amount to be paid
     pub fn remaining_amount(&self) -> Decimal {
         if self.total_amount > self.paid_amount {
@@ -68,6 +81,10 @@ pub struct Operation {
     pub subtotal: Decimal,
 
     pub recorded_at: DateTime<Utc>,
+
+    // Sale unit of the product, for receipts
+    pub unit_abbreviation: Option<String>,
+    pub quantity_decimals: u32,
 }
 
 impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for Operation {
@@ -84,15 +101,37 @@ impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for Operation {
             unit_price: parse_decimal_from_row(row, "unit_price")?,
             subtotal: parse_decimal_from_row(row, "subtotal")?,
             recorded_at: parse_datetime_from_row(row, "recorded_at")?,
+            unit_abbreviation: row.try_get("unit_abbreviation")?,
+            quantity_decimals: row
+                .try_get::<Option<i64>, _>("quantity_decimals")?
+                .map_or(Operation::DEFAULT_DECIMALS, |d| d as u32),
         })
     }
 }
 
+impl Operation {
+    /// Decimals shown when the product's unit is unknown
+    const DEFAULT_DECIMALS: u32 = 3;
+
+    /// Quantity with the unit's decimals and abbreviation, e.g. "1.250 kg"
+    pub fn formatted_quantity(&self) -> String {
+        let quantity = format_quantity(self.quantity, self.quantity_decimals);
+        match &self.unit_abbreviation {
+            Some(unit) => format!("{} {}", quantity, unit),
+            None => quantity,
+        }
+    }
+}
+
 /// Input for creating a new sale
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SaleInput {
     pub items: Vec<SaleItemInput>,
     pub paid_amount: Decimal,
+    pub customer_id: Option<String>,
+    pub tax_profile: Option<InvoiceReceiver>, // Captured at checkout to invoice the sale
+    pub redeemed_points: i64,
+    pub points_amount: Decimal, // Value of the redeemed points
 }
 
 /// Input for a sale line item
@@ -102,11 +141,15 @@ pub struct SaleItemInput {
     pub product_name: String,
     pub quantity: f64,
     pub unit_price: Decimal,
+    #[serde(default)]
+    pub line_total: Option<Decimal>, // Amount printed on a scale label, charged as is
 }
 
 impl SaleItemInput {
     pub fn subtotal(&self) -> Decimal {
-        self.unit_price * Decimal::from_f64_retain(self.quantity).unwrap_or_default()
+        self.line_total.unwrap_or_else(|| {
+            self.unit_price * Decimal::from_f64_retain(self.quantity).unwrap_or_default()
+        })
     }
 }
 
@@ -116,11 +159,17 @@ impl SaleInput {
         self.items.iter().map(|item| item.subtotal()).sum()
     }
 
+    /// Money and points tendered together
+    pub fn tendered_amount(&self) -> Decimal {
+        self.paid_amount + self.points_amount
+    }
+
     /// Calculate change
     pub fn change_amount(&self) -> Decimal {
         let total = self.total_amount();
-        if self.paid_amount > total {
-            self.paid_amount - total
+        let tendered = self.tendered_amount();
+        if tendered > total {
+            tendered - total
         } else {
             Decimal::ZERO
         }
@@ -128,7 +177,7 @@ impl SaleInput {
 
     /// Check if this sale should be a loan
     pub fn is_loan(&self) -> bool {
-        self.paid_amount < self.total_amount()
+        self.tendered_amount() < self.total_amount()
     }
 
     /// Convert to Sale entity
@@ -136,10 +185,12 @@ impl SaleInput {
         Sale {
             id: uuid::Uuid::new_v4().to_string(),
             total_amount: self.total_amount(),
-            paid_amount: self.paid_amount,
+            paid_amount: self.tendered_amount(),
             change_amount: self.change_amount(),
             is_loan: self.is_loan(),
             sold_at: Utc::now(),
+            customer_id: self.customer_id.clone(),
+            points_amount: self.points_amount,
         }
     }
 
@@ -157,7 +208,125 @@ impl SaleInput {
                 unit_price: item.unit_price,
                 subtotal: item.subtotal(),
                 recorded_at: now,
+                unit_abbreviation: None,
+                quantity_decimals: Operation::DEFAULT_DECIMALS,
             })
             .collect()
     }
 }
+
+/// Payment type shown in the sales history
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PaymentFilter {
+    #[default]
+    All,
+    Cash,
+    Loan,
+}
+
+impl PaymentFilter {
+    /// Required value of the sale's is_loan flag, if any
+    pub fn is_loan(&self) -> Option<bool> {
+        match self {
+            PaymentFilter::All => None,
+            PaymentFilter::Cash => Some(false),
+            PaymentFilter::Loan => Some(true),
+        }
+    }
+}
+
+/// Filters for browsing past sales
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SaleFilter {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub min_total: Option<Decimal>,
+    pub max_total: Option<Decimal>,
+    pub payment: PaymentFilter,
+    pub product: String, // Product name or barcode
+}
+
+impl SaleFilter {
+    /// Check that the ranges are well formed
+    pub fn validate(&self) -> Result<(), String> {
+        if let (Some(from), Some(to)) = (self.from, self.to) {
+            if from > to {
+                return Err("Start date must be before end date".to_string());
+            }
+        }
+
+        if self.min_total.is_some_and(|min| min < Decimal::ZERO)
+            || self.max_total.is_some_and(|max| max < Decimal::ZERO)
+        {
+            return Err("Amounts cannot be negative".to_string());
+        }
+
+        if let (Some(min), Some(max)) = (self.min_total, self.max_total) {
+            if min > max {
+                return Err("Minimum amount must not exceed maximum amount".to_string());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Business-day range as UTC timestamps, the end being exclusive
+    pub fn sold_at_bounds(&self, clock: &StoreClock) -> (Option<String>, Option<String>) {
+        let start = self.from.map(|from| clock.day_start(from));
+        let end = self
+            .to
+            .and_then(|to| to.succ_opt())
+            .map(|after| clock.day_start(after));
+        (start, end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn date(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_sale_filter_validate() {
+        assert!(SaleFilter::default().validate().is_ok());
+
+        let reversed_dates = SaleFilter {
+            from: Some(date("2026-10-18")),
+            to: Some(date("2026-10-01")),
+            ..Default::default()
+        };
+        assert!(reversed_dates.validate().is_err());
+
+        let reversed_amounts = SaleFilter {
+            min_total: Some(dec!(100)),
+            max_total: Some(dec!(50)),
+            ..Default::default()
+        };
+        assert!(reversed_amounts.validate().is_err());
+
+        let negative = SaleFilter {
+            min_total: Some(dec!(-1)),
+            ..Default::default()
+        };
+        assert!(negative.validate().is_err());
+    }
+
+    #[test]
+    fn test_sold_at_bounds_cover_whole_local_days() {
+        let filter = SaleFilter {
+            from: Some(date("2026-10-18")),
+            to: Some(date("2026-10-18")),
+            ..Default::default()
+        };
+
+        let clock = StoreClock::default();
+        let (start, end) = filter.sold_at_bounds(&clock);
+        assert_eq!(start.as_deref(), Some("2026-10-18T06:00:00+00:00"));
+        assert_eq!(end.as_deref(), Some("2026-10-19T06:00:00+00:00"));
+        assert_eq!(SaleFilter::default().sold_at_bounds(&clock), (None, None));
+    }
+}
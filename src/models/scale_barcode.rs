@@ -0,0 +1,295 @@
+//! Scale Barcode Formats
+//!
+//! Variable-measure EAN-13 codes printed by deli and produce scales. They
+//! start with a 2x prefix and embed the item's PLU plus its weight or price.
+//! Formats are written as 13-character patterns:
+//!
+//! - digits: fixed prefix, e.g. `20`
+//! - `P`: PLU digits
+//! - `W`: weight in grams (kilograms with three decimals)
+//! - `$`: price in cents
+//! - `C`: EAN-13 check digit
+//!
+//! `20PPPPPWWWWWC` reads 2000123012506 as PLU 00123 weighing 1.250 kg.
+
+use crate::utils::ean13;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// What the value digits of a scale barcode hold
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum EmbeddedValue {
+    Weight, // Kilograms, three decimals
+    Price,  // Currency, two decimals
+}
+
+impl EmbeddedValue {
+    fn symbol(&self) -> char {
+        match self {
+            EmbeddedValue::Weight => 'W',
+            EmbeddedValue::Price => '$',
+        }
+    }
+
+    fn decimals(&self) -> u32 {
+        match self {
+            EmbeddedValue::Weight => 3,
+            EmbeddedValue::Price => 2,
+        }
+    }
+}
+
+/// Layout of a price- or weight-embedded barcode
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ScaleBarcodeFormat {
+    pub prefix: String,
+    pub plu_digits: usize,
+    pub value: EmbeddedValue,
+}
+
+/// Values decoded from a scale barcode
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScaleBarcode {
+    pub plu: String, // With leading zeros, as printed
+    pub value: EmbeddedValue,
+    pub amount: Decimal, // Kilograms or currency
+}
+
+impl ScaleBarcodeFormat {
+    /// Parse a pattern such as `20PPPPPWWWWWC`
+    pub fn parse(pattern: &str) -> Result<Self, String> {
+        let pattern = pattern.trim().to_uppercase();
+        let invalid = || format!("Invalid scale barcode format: {}", pattern);
+
+        if pattern.len() != 13 || !pattern.is_ascii() || !pattern.ends_with('C') {
+            return Err(format!(
+                "Scale barcode format must be 13 characters ending in C: {}",
+                pattern
+            ));
+        }
+
+        let body = &pattern[..12];
+        let prefix: String = body.chars().take_while(|c| c.is_ascii_digit()).collect();
+        if !prefix.starts_with('2') {
+            return Err(format!(
+                "Scale barcode format must start with prefix 2: {}",
+                pattern
+            ));
+        }
+
+        let rest = &body[prefix.len()..];
+        let plu_digits = rest.chars().take_while(|c| *c == 'P').count();
+        let value_part = &rest[plu_digits..];
+        let value = match value_part.chars().next() {
+            Some('W') => EmbeddedValue::Weight,
+            Some('$') => EmbeddedValue::Price,
+            _ => return Err(invalid()),
+        };
+
+        if plu_digits == 0 || !value_part.chars().all(|c| c == value.symbol()) {
+            return Err(invalid());
+        }
+
+        Ok(ScaleBarcodeFormat {
+            prefix,
+            plu_digits,
+            value,
+        })
+    }
+
+    /// Pattern text of the format
+    pub fn pattern(&self) -> String {
+        format!(
+            "{}{}{}C",
+            self.prefix,
+            "P".repeat(self.plu_digits),
+            self.value.symbol().to_string().repeat(self.value_digits())
+        )
+    }
+
+    fn value_digits(&self) -> usize {
+        12 - self.prefix.len() - self.plu_digits
+    }
+
+    /// Whether a scanned code belongs to this format (check digit not verified)
+    pub fn matches(&self, code: &str) -> bool {
+        code.len() == 13
+            && code.chars().all(|c| c.is_ascii_digit())
+            && code.starts_with(&self.prefix)
+    }
+
+    /// Decode a scanned code
+    ///
+    /// Fails when the code has this format's prefix but a wrong check digit;
+    /// returns None when the code does not belong to the format.
+    pub fn decode(&self, code: &str) -> Result<Option<ScaleBarcode>, String> {
+        if !self.matches(code) {
+            return Ok(None);
+        }
+        if !ean13::is_valid(code) {
+            return Err(format!("Invalid check digit in barcode: {}", code));
+        }
+
+        let plu_start = self.prefix.len();
+        let value_start = plu_start + self.plu_digits;
+        let raw: i64 = code[value_start..12]
+            .parse()
+            .map_err(|_| format!("Invalid value in barcode: {}", code))?;
+
+        Ok(Some(ScaleBarcode {
+            plu: code[plu_start..value_start].to_string(),
+            value: self.value,
+            amount: Decimal::new(raw, self.value.decimals()),
+        }))
+    }
+}
+
+impl ScaleBarcode {
+    /// Quantity to sell at the given unit price
+    ///
+    /// Weights are sold as is; embedded prices are divided by the unit price
+    /// and rounded to three decimals.
+    pub fn quantity(&self, unit_price: Decimal) -> Result<f64, String> {
+        use rust_decimal::prelude::ToPrimitive;
+
+        let quantity = match self.value {
+            EmbeddedValue::Weight => self.amount,
+            EmbeddedValue::Price => {
+                if unit_price <= Decimal::ZERO {
+                    return Err("Product has no price to convert the barcode amount".to_string());
+                }
+                label_quantity(self.amount, unit_price)
+            }
+        };
+
+        if quantity <= Decimal::ZERO {
+            return Err("Scanned barcode has no quantity".to_string());
+        }
+
+        quantity
+            .to_f64()
+            .ok_or_else(|| "Invalid barcode quantity".to_string())
+    }
+
+    /// Amount to charge as printed on the label, for embedded prices
+    pub fn line_total(&self) -> Option<Decimal> {
+        (self.value == EmbeddedValue::Price).then_some(self.amount)
+    }
+}
+
+/// Quantity an embedded price buys at the unit price, rounded to three decimals
+pub fn label_quantity(amount: Decimal, unit_price: Decimal) -> Decimal {
+    (amount / unit_price).round_dp(3)
+}
+
+/// Parse configured formats such as "20PPPPPWWWWWC, 22PPPPP$$$$$C"
+pub fn parse_scale_formats(value: &str) -> Result<Vec<ScaleBarcodeFormat>, String> {
+    let mut formats: Vec<ScaleBarcodeFormat> = Vec::new();
+
+    for part in value.split([',', ' ']) {
+        if part.trim().is_empty() {
+            continue;
+        }
+
+        let format = ScaleBarcodeFormat::parse(part)?;
+        if formats.iter().any(|f| f.prefix == format.prefix) {
+            return Err(format!("Duplicate scale barcode prefix: {}", format.prefix));
+        }
+        formats.push(format);
+    }
+
+    Ok(formats)
+}
+
+/// Format with the longest prefix matching a scanned code
+pub fn find_scale_format<'a>(
+    formats: &'a [ScaleBarcodeFormat],
+    code: &str,
+) -> Option<&'a ScaleBarcodeFormat> {
+    formats
+        .iter()
+        .filter(|f| f.matches(code))
+        .max_by_key(|f| f.prefix.len())
+}
+
+/// Format scale barcode formats for display ("20PPPPPWWWWWC, 22PPPPP$$$$$C")
+pub fn format_scale_formats(formats: &[ScaleBarcodeFormat]) -> String {
+    formats
+        .iter()
+        .map(|f| f.pattern())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_parse_formats() {
+        let formats = parse_scale_formats("20PPPPPWWWWWC, 2pppppp$$$$$c").unwrap();
+
+        assert_eq!(
+            formats[0],
+            ScaleBarcodeFormat {
+                prefix: "20".to_string(),
+                plu_digits: 5,
+                value: EmbeddedValue::Weight,
+            }
+        );
+        assert_eq!(formats[1].prefix, "2");
+        assert_eq!(formats[1].plu_digits, 6);
+        assert_eq!(formats[1].value, EmbeddedValue::Price);
+        assert_eq!(
+            format_scale_formats(&formats),
+            "20PPPPPWWWWWC, 2PPPPPP$$$$$C"
+        );
+
+        assert!(parse_scale_formats("20PPPPPWWWWW").is_err());
+        assert!(parse_scale_formats("40PPPPPWWWWWC").is_err());
+        assert!(parse_scale_formats("20PPPPPWW$$$C").is_err());
+        assert!(parse_scale_formats("20WWWWWWWWWWC").is_err());
+        assert!(parse_scale_formats("20PPPPPWWWWWC, 20PPPP$$$$$$C").is_err());
+        assert!(parse_scale_formats("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_decode_weight() {
+        let format = ScaleBarcodeFormat::parse("20PPPPPWWWWWC").unwrap();
+        let decoded = format.decode("2000123012506").unwrap().unwrap();
+
+        assert_eq!(decoded.plu, "00123");
+        assert_eq!(decoded.amount, dec!(1.250));
+        assert_eq!(decoded.quantity(dec!(89.90)).unwrap(), 1.25);
+
+        assert!(format.decode("2000123012507").is_err());
+        assert_eq!(format.decode("7501055300075").unwrap(), None);
+    }
+
+    #[test]
+    fn test_decode_price() {
+        let format = ScaleBarcodeFormat::parse("22PPPPP$$$$$C").unwrap();
+        let decoded = format.decode("2200042045009").unwrap().unwrap();
+
+        assert_eq!(decoded.plu, "00042");
+        assert_eq!(decoded.amount, dec!(45.00));
+        assert_eq!(decoded.quantity(dec!(90)).unwrap(), 0.5);
+        assert!(decoded.quantity(Decimal::ZERO).is_err());
+    }
+
+    #[test]
+    fn test_longest_prefix_wins() {
+        let formats = parse_scale_formats("2PPPPPP$$$$$C, 20PPPPPWWWWWC").unwrap();
+
+        assert_eq!(
+            find_scale_format(&formats, "2000123012506").map(|f| f.value),
+            Some(EmbeddedValue::Weight)
+        );
+        assert_eq!(
+            find_scale_format(&formats, "2200042045009").map(|f| f.value),
+            Some(EmbeddedValue::Price)
+        );
+        assert!(find_scale_format(&formats, "7501055300075").is_none());
+    }
+}
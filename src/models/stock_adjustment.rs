@@ -0,0 +1,84 @@
+//! Stock Adjustment Models
+//!
+//! Stock changes outside of sales and receiving (write-offs, internal use,
+//! count corrections), each valued at the product's cost.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Stock adjustment entity
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StockAdjustment {
+    pub id: String, // UUID as TEXT
+    pub product_id: String,
+    pub product_name: String, // Denormalized for reports
+
+    pub quantity: f64, // Signed change in sale units (negative for losses)
+
+    pub unit_cost: Option<Decimal>, // Cost price at the time, if known
+    pub total_cost: Decimal,        // unit_cost * quantity (negative for losses)
+
+    pub reason_id: i32,
+    pub notes: Option<String>,
+
+    pub adjusted_at: DateTime<Utc>,
+}
+
+impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for StockAdjustment {
+    fn from_row(row: &'r sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
+        use crate::utils::db_parsing::{
+            parse_datetime_from_row, parse_decimal_from_row, parse_optional_decimal_from_row,
+        };
+        use sqlx::Row;
+
+        Ok(StockAdjustment {
+            id: row.try_get("id")?,
+            product_id: row.try_get("product_id")?,
+            product_name: row.try_get("product_name")?,
+            quantity: row.try_get("quantity")?,
+            unit_cost: parse_optional_decimal_from_row(row, "unit_cost")?,
+            total_cost: parse_decimal_from_row(row, "total_cost")?,
+            reason_id: row.try_get("reason_id")?,
+            notes: row.try_get("notes")?,
+            adjusted_at: parse_datetime_from_row(row, "adjusted_at")?,
+        })
+    }
+}
+
+impl StockAdjustment {
+    /// Build an adjustment valued at the given unit cost
+    pub fn new(
+        product_id: &str,
+        product_name: &str,
+        quantity: f64,
+        unit_cost: Option<Decimal>,
+        reason_id: i32,
+        notes: Option<String>,
+    ) -> Self {
+        let total_cost = unit_cost
+            .map(|cost| cost * Decimal::from_f64_retain(quantity).unwrap_or_default())
+            .unwrap_or_default();
+
+        StockAdjustment {
+            id: uuid::Uuid::new_v4().to_string(),
+            product_id: product_id.to_string(),
+            product_name: product_name.to_string(),
+            quantity,
+            unit_cost,
+            total_cost,
+            reason_id,
+            notes,
+            adjusted_at: Utc::now(),
+        }
+    }
+}
+
+/// Write-off data (quantity is the positive amount removed)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WriteOffInput {
+    pub product_id: String,
+    pub quantity: f64,
+    pub reason_id: i32,
+    pub notes: Option<String>,
+}
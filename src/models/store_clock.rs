@@ -0,0 +1,170 @@
+//! Store Clock
+//!
+//! The store's timezone and business-day cutoff. Sales are stored in UTC;
+//! this decides which business day they belong to and how times are shown.
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+/// Store timezone and the hour a business day ends
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StoreClock {
+    pub timezone: Tz,
+    pub day_cutoff_hour: u32, // 0 = midnight; 3 = sales until 3 am count for the previous day
+}
+
+impl Default for StoreClock {
+    fn default() -> Self {
+        Self {
+            timezone: chrono_tz::America::Mexico_City,
+            day_cutoff_hour: 0,
+        }
+    }
+}
+
+impl StoreClock {
+    /// Latest cutoff accepted; later hours would move midday sales to the previous day
+    pub const MAX_CUTOFF_HOUR: u32 = 11;
+
+    /// Build a clock from an IANA timezone name such as "America/Mexico_City"
+    pub fn new(timezone: &str, day_cutoff_hour: u32) -> Result<Self, String> {
+        let timezone = timezone
+            .trim()
+            .parse::<Tz>()
+            .map_err(|_| format!("Unknown timezone: {}", timezone.trim()))?;
+
+        if day_cutoff_hour > Self::MAX_CUTOFF_HOUR {
+            return Err(format!(
+                "Business day cutoff must be between 0 and {}",
+                Self::MAX_CUTOFF_HOUR
+            ));
+        }
+
+        Ok(Self {
+            timezone,
+            day_cutoff_hour,
+        })
+    }
+
+    /// IANA name of the timezone
+    pub fn timezone_name(&self) -> &'static str {
+        self.timezone.name()
+    }
+
+    /// Store-local time of an instant
+    pub fn local(&self, instant: DateTime<Utc>) -> DateTime<Tz> {
+        instant.with_timezone(&self.timezone)
+    }
+
+    /// Format an instant in store-local time
+    pub fn format(&self, instant: &DateTime<Utc>, format: &str) -> String {
+        self.local(*instant).format(format).to_string()
+    }
+
+    /// Business day an instant belongs to, honoring the cutoff
+    pub fn business_date(&self, instant: DateTime<Utc>) -> NaiveDate {
+        (self.local(instant) - Duration::hours(self.day_cutoff_hour as i64)).date_naive()
+    }
+
+    /// Current business day
+    pub fn today(&self) -> NaiveDate {
+        self.business_date(Utc::now())
+    }
+
+    /// Position of a local hour within the business day, 0 being the first hour
+    pub fn hour_position(&self, hour: u32) -> u32 {
+        (hour + 24 - self.day_cutoff_hour) % 24
+    }
+
+    /// Start of a business day as an RFC 3339 UTC timestamp, matching `sold_at`
+    pub fn day_start(&self, date: NaiveDate) -> String {
+        let start = date.and_time(NaiveTime::MIN) + Duration::hours(self.day_cutoff_hour as i64);
+
+        // A DST gap skips the hour, so the day starts when the clock jumps
+        self.timezone
+            .from_local_datetime(&start)
+            .earliest()
+            .or_else(|| {
+                self.timezone
+                    .from_local_datetime(&(start + Duration::hours(1)))
+                    .earliest()
+            })
+            .map(|local| local.with_timezone(&Utc))
+            .unwrap_or_else(|| start.and_utc())
+            .to_rfc3339()
+    }
+
+    /// Start and exclusive end of a business day as UTC timestamps
+    pub fn day_bounds(&self, date: NaiveDate) -> (String, String) {
+        self.range_bounds(date, date)
+    }
+
+    /// Start of `from` and exclusive end of `to`, both business days
+    pub fn range_bounds(&self, from: NaiveDate, to: NaiveDate) -> (String, String) {
+        let after = to.succ_opt().unwrap_or(to);
+        (self.day_start(from), self.day_start(after))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap()
+    }
+
+    fn instant(value: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(value)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_new_validates_timezone_and_cutoff() {
+        assert!(StoreClock::new("America/Tijuana", 3).is_ok());
+        assert!(StoreClock::new("Mars/Olympus", 0).is_err());
+        assert!(StoreClock::new("America/Mexico_City", 12).is_err());
+    }
+
+    #[test]
+    fn test_day_bounds_follow_timezone_and_cutoff() {
+        let clock = StoreClock::default();
+        assert_eq!(
+            clock.day_bounds(date("2026-10-18")),
+            (
+                "2026-10-18T06:00:00+00:00".to_string(),
+                "2026-10-19T06:00:00+00:00".to_string()
+            )
+        );
+
+        let late = StoreClock::new("America/Mexico_City", 3).unwrap();
+        assert_eq!(
+            late.day_bounds(date("2026-10-18")).0,
+            "2026-10-18T09:00:00+00:00"
+        );
+
+        // Tijuana observes daylight saving time: UTC-7 in October
+        let tijuana = StoreClock::new("America/Tijuana", 0).unwrap();
+        assert_eq!(
+            tijuana.day_bounds(date("2026-10-18")).0,
+            "2026-10-18T07:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_business_date_honors_cutoff() {
+        // 8:30 pm and 2:30 am local in Mexico City (UTC-6)
+        let evening = instant("2026-10-19T02:30:00+00:00");
+        let late_night = instant("2026-10-19T08:30:00+00:00");
+
+        let clock = StoreClock::default();
+        assert_eq!(clock.business_date(evening), date("2026-10-18"));
+        assert_eq!(clock.business_date(late_night), date("2026-10-19"));
+
+        let late = StoreClock::new("America/Mexico_City", 3).unwrap();
+        assert_eq!(late.business_date(late_night), date("2026-10-18"));
+        assert_eq!(late.hour_position(2), 23);
+        assert_eq!(late.hour_position(3), 0);
+    }
+}
@@ -0,0 +1,52 @@
+//! Supplier Model
+//!
+//! Distributors products are bought from.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Supplier entity
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Supplier {
+    pub id: String, // UUID as TEXT
+    pub name: String,
+    pub phone: Option<String>,
+    pub lead_time_days: i64, // Days from ordering to receiving
+    pub created_at: DateTime<Utc>,
+}
+
+impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for Supplier {
+    fn from_row(row: &'r sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
+        use crate::utils::db_parsing::parse_datetime_from_row;
+        use sqlx::Row;
+
+        Ok(Supplier {
+            id: row.try_get("id")?,
+            name: row.try_get("name")?,
+            phone: row.try_get("phone")?,
+            lead_time_days: row.try_get("lead_time_days")?,
+            created_at: parse_datetime_from_row(row, "created_at")?,
+        })
+    }
+}
+
+/// Supplier creation/update data
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SupplierInput {
+    pub name: String,
+    pub phone: Option<String>,
+    pub lead_time_days: i64,
+}
+
+impl SupplierInput {
+    /// Create a new Supplier from this input
+    pub fn to_supplier(self) -> Supplier {
+        Supplier {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: self.name,
+            phone: self.phone,
+            lead_time_days: self.lead_time_days,
+            created_at: Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! Local Mock PAC
+//!
+//! Stamps documents without contacting the SAT. The seals are placeholders,
+//! so the resulting invoices have no fiscal validity.
+
+use crate::pac::{PacClient, StampResult};
+use crate::utils::cfdi::insert_complement;
+use async_trait::async_trait;
+use chrono::Utc;
+
+/// RFC of the SAT's test provider, used by the mock stamp
+const MOCK_PROVIDER_RFC: &str = "SPR190613I52";
+
+#[derive(Debug, Clone, Default)]
+pub struct MockPac;
+
+impl MockPac {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl PacClient for MockPac {
+    async fn stamp(&self, xml: &str) -> Result<StampResult, String> {
+        let uuid = uuid::Uuid::new_v4().to_string().to_uppercase();
+        let stamped_at = Utc::now();
+        let seal = format!("MOCK-{}", uuid.replace('-', ""));
+
+        // Stamp times are in central Mexico time, as the SAT issues them
+        let stamped_local = stamped_at
+            .with_timezone(&chrono_tz::America::Mexico_City)
+            .format("%Y-%m-%dT%H:%M:%S");
+
+        let sealed = xml.replacen(" Sello=\"\"", &format!(" Sello=\"{}\"", seal), 1);
+        let complement = format!(
+            "<tfd:TimbreFiscalDigital xmlns:tfd=\"http://www.sat.gob.mx/TimbreFiscalDigital\" \
+             xsi:schemaLocation=\"http://www.sat.gob.mx/TimbreFiscalDigital \
+             http://www.sat.gob.mx/sitio_internet/cfd/TimbreFiscalDigital/TimbreFiscalDigitalv11.xsd\" \
+             Version=\"1.1\" UUID=\"{}\" FechaTimbrado=\"{}\" RfcProvCertif=\"{}\" \
+             SelloCFD=\"{}\" NoCertificadoSAT=\"00000000000000000000\" SelloSAT=\"{}\"/>",
+            uuid, stamped_local, MOCK_PROVIDER_RFC, seal, seal
+        );
+
+        Ok(StampResult {
+            xml: insert_complement(&sealed, &complement)?,
+            uuid,
+            stamped_at,
+        })
+    }
+}
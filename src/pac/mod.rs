@@ -0,0 +1,27 @@
+//! PAC Module
+//!
+//! Stamping of CFDI documents by an authorized certification provider (PAC).
+//! Providers plug in through `PacClient`; `MockPac` stamps locally for testing
+//! and for stores that have not contracted a provider yet.
+
+mod mock;
+
+pub use mock::MockPac;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// Result of stamping a CFDI
+#[derive(Debug, Clone, PartialEq)]
+pub struct StampResult {
+    pub uuid: String, // Fiscal folio (folio fiscal)
+    pub stamped_at: DateTime<Utc>,
+    pub xml: String, // CFDI with the TimbreFiscalDigital complement
+}
+
+/// Certification provider that seals and stamps CFDI documents
+#[async_trait]
+pub trait PacClient: Send + Sync {
+    /// Stamp an unsealed CFDI, returning the stamped document
+    async fn stamp(&self, xml: &str) -> Result<StampResult, String>;
+}
@@ -0,0 +1,120 @@
+//! SQLite Stock Adjustment Repository Implementation
+
+use crate::models::StockAdjustment;
+use crate::repo::sqlite::consume_lots_fefo;
+use crate::repo::AdjustmentRepository;
+use async_trait::async_trait;
+use sqlx::{Sqlite, SqlitePool, Transaction};
+
+pub struct SqliteAdjustmentRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteAdjustmentRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Insert an adjustment record without touching stock
+pub(crate) async fn insert_adjustment(
+    tx: &mut Transaction<'_, Sqlite>,
+    adjustment: &StockAdjustment,
+) -> Result<(), String> {
+    sqlx::query(
+        r#"
+        INSERT INTO stock_adjustment (
+            id, product_id, product_name, quantity, unit_cost,
+            total_cost, reason_id, notes, adjusted_at
+        )
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(&adjustment.id)
+    .bind(&adjustment.product_id)
+    .bind(&adjustment.product_name)
+    .bind(adjustment.quantity)
+    .bind(adjustment.unit_cost.map(|d| d.to_string()))
+    .bind(adjustment.total_cost.to_string())
+    .bind(adjustment.reason_id)
+    .bind(&adjustment.notes)
+    .bind(adjustment.adjusted_at.to_rfc3339())
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| format!("Failed to insert stock adjustment: {}", e))?;
+
+    Ok(())
+}
+
+/// Insert an adjustment and apply its signed quantity to the product's stock
+///
+/// Losses are also deducted from sellable lots, first expiry first out.
+pub(crate) async fn apply_adjustment(
+    tx: &mut Transaction<'_, Sqlite>,
+    adjustment: &StockAdjustment,
+) -> Result<(), String> {
+    insert_adjustment(tx, adjustment).await?;
+
+    sqlx::query(
+        r#"
+        UPDATE product
+        SET current_amount = MAX(current_amount + ?, 0),
+            updated_at = ?
+        WHERE id = ?
+        "#,
+    )
+    .bind(adjustment.quantity)
+    .bind(adjustment.adjusted_at.to_rfc3339())
+    .bind(&adjustment.product_id)
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| format!("Failed to update product stock: {}", e))?;
+
+    if adjustment.quantity < 0.0 {
+        consume_lots_fefo(tx, &adjustment.product_id, -adjustment.quantity, None).await?;
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+impl AdjustmentRepository for SqliteAdjustmentRepository {
+    async fn create(&self, adjustment: StockAdjustment) -> Result<StockAdjustment, String> {
+        // Start transaction
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        apply_adjustment(&mut tx, &adjustment).await?;
+
+        // Commit transaction
+        tx.commit()
+            .await
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+        Ok(adjustment)
+    }
+
+    async fn list_by_date_range(
+        &self,
+        start: &str,
+        end: &str,
+    ) -> Result<Vec<StockAdjustment>, String> {
+        let adjustments = sqlx::query_as::<_, StockAdjustment>(
+            r#"
+            SELECT * FROM stock_adjustment
+            WHERE adjusted_at >= ? AND adjusted_at < ?
+            ORDER BY adjusted_at DESC
+            "#,
+        )
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to list stock adjustments: {}", e))?;
+
+        Ok(adjustments)
+    }
+}
@@ -1,6 +1,9 @@
 //! SQLite Catalog Repository Implementation
 
-use crate::models::{ItemCondition, StatusLoan, UnitMeasurement};
+use crate::models::{
+    AdjustmentReason, ItemCondition, PriceChangeSource, StatusLoan, UnitMeasurement,
+    UnitMeasurementInput,
+};
 use crate::repo::CatalogRepository;
 use async_trait::async_trait;
 use sqlx::SqlitePool;
@@ -27,6 +30,98 @@ impl CatalogRepository for SqliteCatalogRepository {
         Ok(units)
     }
 
+    async fn get_unit(&self, id: i32) -> Result<Option<UnitMeasurement>, String> {
+        let unit =
+            sqlx::query_as::<_, UnitMeasurement>("SELECT * FROM unit_measurement WHERE id = ?")
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| format!("Failed to get unit measurement: {}", e))?;
+
+        Ok(unit)
+    }
+
+    async fn create_unit(&self, input: UnitMeasurementInput) -> Result<UnitMeasurement, String> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO unit_measurement (description, abbreviation, allows_fraction, decimals)
+            VALUES (?, ?, ?, ?)
+            "#,
+        )
+        .bind(&input.description)
+        .bind(&input.abbreviation)
+        .bind(input.allows_fraction)
+        .bind(input.decimals)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to create unit measurement: {}", e))?;
+
+        Ok(UnitMeasurement {
+            id: result.last_insert_rowid() as i32,
+            description: input.description,
+            abbreviation: input.abbreviation,
+            allows_fraction: input.allows_fraction,
+            decimals: input.decimals,
+        })
+    }
+
+    async fn update_unit(
+        &self,
+        id: i32,
+        input: UnitMeasurementInput,
+    ) -> Result<UnitMeasurement, String> {
+        let result = sqlx::query(
+            r#"
+            UPDATE unit_measurement
+            SET description = ?, abbreviation = ?, allows_fraction = ?, decimals = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(&input.description)
+        .bind(&input.abbreviation)
+        .bind(input.allows_fraction)
+        .bind(input.decimals)
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to update unit measurement: {}", e))?;
+
+        if result.rows_affected() == 0 {
+            return Err(format!("Unit not found: {}", id));
+        }
+
+        Ok(UnitMeasurement {
+            id,
+            description: input.description,
+            abbreviation: input.abbreviation,
+            allows_fraction: input.allows_fraction,
+            decimals: input.decimals,
+        })
+    }
+
+    async fn delete_unit(&self, id: i32) -> Result<(), String> {
+        sqlx::query("DELETE FROM unit_measurement WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to delete unit measurement: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn count_unit_products(&self, id: i32) -> Result<i64, String> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM product WHERE unit_measurement_id = ? OR purchase_unit_id = ?",
+        )
+        .bind(id)
+        .bind(id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to count unit products: {}", e))?;
+
+        Ok(count)
+    }
+
     async fn get_conditions(&self) -> Result<Vec<ItemCondition>, String> {
         let conditions =
             sqlx::query_as::<_, ItemCondition>("SELECT * FROM item_condition ORDER BY id")
@@ -45,4 +140,24 @@ impl CatalogRepository for SqliteCatalogRepository {
 
         Ok(statuses)
     }
+
+    async fn get_adjustment_reasons(&self) -> Result<Vec<AdjustmentReason>, String> {
+        let reasons =
+            sqlx::query_as::<_, AdjustmentReason>("SELECT * FROM adjustment_reason ORDER BY id")
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| format!("Failed to get adjustment reasons: {}", e))?;
+
+        Ok(reasons)
+    }
+
+    async fn get_price_change_sources(&self) -> Result<Vec<PriceChangeSource>, String> {
+        let sources =
+            sqlx::query_as::<_, PriceChangeSource>("SELECT * FROM price_change_source ORDER BY id")
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| format!("Failed to get price change sources: {}", e))?;
+
+        Ok(sources)
+    }
 }
@@ -0,0 +1,102 @@
+//! SQLite Category Repository Implementation
+
+use crate::models::{Category, CategoryInput};
+use crate::repo::CategoryRepository;
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+pub struct SqliteCategoryRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteCategoryRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CategoryRepository for SqliteCategoryRepository {
+    async fn create(&self, input: CategoryInput) -> Result<Category, String> {
+        let category = input.to_category();
+
+        sqlx::query(
+            r#"
+            INSERT INTO category (id, name, parent_id, created_at)
+            VALUES (?, ?, ?, ?)
+            "#,
+        )
+        .bind(&category.id)
+        .bind(&category.name)
+        .bind(&category.parent_id)
+        .bind(category.created_at.to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to create category: {}", e))?;
+
+        Ok(category)
+    }
+
+    async fn get_by_id(&self, id: &str) -> Result<Option<Category>, String> {
+        let category = sqlx::query_as::<_, Category>("SELECT * FROM category WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to get category by id: {}", e))?;
+
+        Ok(category)
+    }
+
+    async fn list_all(&self) -> Result<Vec<Category>, String> {
+        let categories = sqlx::query_as::<_, Category>("SELECT * FROM category ORDER BY name")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to list categories: {}", e))?;
+
+        Ok(categories)
+    }
+
+    async fn update(&self, id: &str, input: CategoryInput) -> Result<Category, String> {
+        sqlx::query("UPDATE category SET name = ?, parent_id = ? WHERE id = ?")
+            .bind(&input.name)
+            .bind(&input.parent_id)
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to update category: {}", e))?;
+
+        // Fetch and return updated category
+        self.get_by_id(id)
+            .await?
+            .ok_or_else(|| format!("Category not found after update: {}", id))
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), String> {
+        // Start transaction
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        // Unassign products so the foreign key is not violated
+        sqlx::query("UPDATE product SET category_id = NULL WHERE category_id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("Failed to unassign category products: {}", e))?;
+
+        sqlx::query("DELETE FROM category WHERE id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("Failed to delete category: {}", e))?;
+
+        // Commit transaction
+        tx.commit()
+            .await
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+        Ok(())
+    }
+}
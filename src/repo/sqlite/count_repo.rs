@@ -0,0 +1,223 @@
+//! SQLite Inventory Count Repository Implementation
+
+use crate::models::{InventoryCount, InventoryCountLine, StockAdjustment};
+use crate::repo::sqlite::apply_adjustment;
+use crate::repo::InventoryCountRepository;
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::SqlitePool;
+
+/// Count columns plus line totals, shared by the count queries
+const COUNT_SELECT: &str = r#"
+    SELECT c.*,
+        (SELECT COUNT(*) FROM inventory_count_line l WHERE l.count_id = c.id) AS total_lines,
+        (SELECT COUNT(*) FROM inventory_count_line l
+            WHERE l.count_id = c.id AND l.counted_quantity IS NOT NULL) AS counted_lines
+    FROM inventory_count c
+"#;
+
+pub struct SqliteInventoryCountRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteInventoryCountRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl InventoryCountRepository for SqliteInventoryCountRepository {
+    async fn create(&self, name: &str) -> Result<InventoryCount, String> {
+        let id = uuid::Uuid::new_v4().to_string();
+
+        // Start transaction
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        sqlx::query("INSERT INTO inventory_count (id, name, started_at) VALUES (?, ?, ?)")
+            .bind(&id)
+            .bind(name)
+            .bind(Utc::now().to_rfc3339())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("Failed to create inventory count: {}", e))?;
+
+        // Snapshot the expected stock of every product (kits are counted
+        // through their components)
+        sqlx::query(
+            r#"
+            INSERT INTO inventory_count_line (
+                count_id, product_id, product_name, barcode, expected_quantity, unit_cost
+            )
+            SELECT ?, id, full_name, barcode, current_amount, cost_price
+            FROM product
+            WHERE archived_at IS NULL AND is_kit = 0
+            "#,
+        )
+        .bind(&id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to snapshot stock: {}", e))?;
+
+        // Commit transaction
+        tx.commit()
+            .await
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+        self.get_by_id(&id)
+            .await?
+            .ok_or_else(|| format!("Inventory count not found after create: {}", id))
+    }
+
+    async fn get_by_id(&self, id: &str) -> Result<Option<InventoryCount>, String> {
+        let count =
+            sqlx::query_as::<_, InventoryCount>(&format!("{} WHERE c.id = ?", COUNT_SELECT))
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| format!("Failed to get inventory count by id: {}", e))?;
+
+        Ok(count)
+    }
+
+    async fn list_all(&self) -> Result<Vec<InventoryCount>, String> {
+        let counts = sqlx::query_as::<_, InventoryCount>(&format!(
+            "{} ORDER BY c.started_at DESC",
+            COUNT_SELECT
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to list inventory counts: {}", e))?;
+
+        Ok(counts)
+    }
+
+    async fn list_lines(&self, count_id: &str) -> Result<Vec<InventoryCountLine>, String> {
+        let lines = sqlx::query_as::<_, InventoryCountLine>(
+            "SELECT * FROM inventory_count_line WHERE count_id = ? ORDER BY product_name",
+        )
+        .bind(count_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to list inventory count lines: {}", e))?;
+
+        Ok(lines)
+    }
+
+    async fn get_line(
+        &self,
+        count_id: &str,
+        product_id: &str,
+    ) -> Result<Option<InventoryCountLine>, String> {
+        let line = sqlx::query_as::<_, InventoryCountLine>(
+            "SELECT * FROM inventory_count_line WHERE count_id = ? AND product_id = ?",
+        )
+        .bind(count_id)
+        .bind(product_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to get inventory count line: {}", e))?;
+
+        Ok(line)
+    }
+
+    async fn set_counted(
+        &self,
+        count_id: &str,
+        product_id: &str,
+        counted_quantity: Option<f64>,
+    ) -> Result<InventoryCountLine, String> {
+        let counted_at = counted_quantity.map(|_| Utc::now().to_rfc3339());
+
+        sqlx::query(
+            r#"
+            UPDATE inventory_count_line
+            SET counted_quantity = ?, counted_at = ?
+            WHERE count_id = ? AND product_id = ?
+            "#,
+        )
+        .bind(counted_quantity)
+        .bind(counted_at)
+        .bind(count_id)
+        .bind(product_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to update counted quantity: {}", e))?;
+
+        // Fetch and return updated line
+        self.get_line(count_id, product_id)
+            .await?
+            .ok_or_else(|| format!("Product is not part of this count: {}", product_id))
+    }
+
+    async fn set_approved(
+        &self,
+        count_id: &str,
+        product_id: &str,
+        approved: bool,
+    ) -> Result<(), String> {
+        sqlx::query(
+            "UPDATE inventory_count_line SET approved = ? WHERE count_id = ? AND product_id = ?",
+        )
+        .bind(approved)
+        .bind(count_id)
+        .bind(product_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to update line approval: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn post(&self, count_id: &str, adjustments: Vec<StockAdjustment>) -> Result<(), String> {
+        // Start transaction
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        // Close the count first so it can only be posted once
+        let result = sqlx::query(
+            "UPDATE inventory_count SET posted_at = ? WHERE id = ? AND posted_at IS NULL",
+        )
+        .bind(Utc::now().to_rfc3339())
+        .bind(count_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to close inventory count: {}", e))?;
+
+        if result.rows_affected() == 0 {
+            return Err("Inventory count is already posted".to_string());
+        }
+
+        for adjustment in &adjustments {
+            apply_adjustment(&mut tx, adjustment).await?;
+        }
+
+        // Commit transaction
+        tx.commit()
+            .await
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), String> {
+        let result = sqlx::query("DELETE FROM inventory_count WHERE id = ? AND posted_at IS NULL")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to delete inventory count: {}", e))?;
+
+        if result.rows_affected() == 0 {
+            return Err("Only open counts can be deleted".to_string());
+        }
+
+        Ok(())
+    }
+}
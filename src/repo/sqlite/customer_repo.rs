@@ -0,0 +1,98 @@
+//! SQLite Customer Repository Implementation
+
+use crate::models::{Customer, CustomerInput};
+use crate::repo::CustomerRepository;
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+pub struct SqliteCustomerRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteCustomerRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CustomerRepository for SqliteCustomerRepository {
+    async fn create(&self, input: CustomerInput) -> Result<Customer, String> {
+        let customer = input.to_customer();
+        let profile = customer.tax_profile.as_ref();
+
+        sqlx::query(
+            r#"
+            INSERT INTO customer (
+                id, name, phone, rfc, legal_name, tax_regime, zip_code, cfdi_use,
+                price_list_id, created_at
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&customer.id)
+        .bind(&customer.name)
+        .bind(&customer.phone)
+        .bind(profile.map(|p| &p.rfc))
+        .bind(profile.map(|p| &p.legal_name))
+        .bind(profile.map(|p| &p.tax_regime))
+        .bind(profile.map(|p| &p.zip_code))
+        .bind(profile.map(|p| &p.cfdi_use))
+        .bind(&customer.price_list_id)
+        .bind(customer.created_at.to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to create customer: {}", e))?;
+
+        Ok(customer)
+    }
+
+    async fn get_by_id(&self, id: &str) -> Result<Option<Customer>, String> {
+        let customer = sqlx::query_as::<_, Customer>("SELECT * FROM customer WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to get customer by id: {}", e))?;
+
+        Ok(customer)
+    }
+
+    async fn list_all(&self) -> Result<Vec<Customer>, String> {
+        let customers = sqlx::query_as::<_, Customer>("SELECT * FROM customer ORDER BY name")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to list customers: {}", e))?;
+
+        Ok(customers)
+    }
+
+    async fn update(&self, id: &str, input: CustomerInput) -> Result<Customer, String> {
+        let profile = input.tax_profile.as_ref();
+
+        sqlx::query(
+            r#"
+            UPDATE customer
+            SET name = ?, phone = ?, rfc = ?, legal_name = ?,
+                tax_regime = ?, zip_code = ?, cfdi_use = ?, price_list_id = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(&input.name)
+        .bind(&input.phone)
+        .bind(profile.map(|p| &p.rfc))
+        .bind(profile.map(|p| &p.legal_name))
+        .bind(profile.map(|p| &p.tax_regime))
+        .bind(profile.map(|p| &p.zip_code))
+        .bind(profile.map(|p| &p.cfdi_use))
+        .bind(&input.price_list_id)
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to update customer: {}", e))?;
+
+        // Fetch and return updated customer
+        self.get_by_id(id)
+            .await?
+            .ok_or_else(|| format!("Customer not found after update: {}", id))
+    }
+}
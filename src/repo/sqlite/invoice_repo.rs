@@ -0,0 +1,163 @@
+//! SQLite Invoice Repository Implementation
+
+use crate::models::{InvoiceRecord, ProductTaxCode};
+use crate::repo::InvoiceRepository;
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+pub struct SqliteInvoiceRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteInvoiceRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl InvoiceRepository for SqliteInvoiceRepository {
+    async fn get_tax_code(&self, product_id: &str) -> Result<Option<ProductTaxCode>, String> {
+        let code = sqlx::query_as::<_, ProductTaxCode>(
+            "SELECT * FROM product_tax_code WHERE product_id = ?",
+        )
+        .bind(product_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to get product tax code: {}", e))?;
+
+        Ok(code)
+    }
+
+    async fn list_tax_codes(&self, product_ids: &[String]) -> Result<Vec<ProductTaxCode>, String> {
+        let ids_json = serde_json::to_string(product_ids)
+            .map_err(|e| format!("Failed to encode product ids: {}", e))?;
+
+        let codes = sqlx::query_as::<_, ProductTaxCode>(
+            r#"
+            SELECT * FROM product_tax_code
+            WHERE product_id IN (SELECT value FROM json_each(?))
+            "#,
+        )
+        .bind(ids_json)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to list product tax codes: {}", e))?;
+
+        Ok(codes)
+    }
+
+    async fn set_tax_code(&self, code: ProductTaxCode) -> Result<(), String> {
+        sqlx::query(
+            r#"
+            INSERT INTO product_tax_code (product_id, sat_product_code, sat_unit_code, tax)
+            VALUES (?, ?, ?, ?)
+            ON CONFLICT(product_id) DO UPDATE SET
+                sat_product_code = excluded.sat_product_code,
+                sat_unit_code = excluded.sat_unit_code,
+                tax = excluded.tax
+            "#,
+        )
+        .bind(&code.product_id)
+        .bind(&code.sat_product_code)
+        .bind(&code.sat_unit_code)
+        .bind(code.tax.code())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to save product tax code: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn next_folio(&self, series: &str) -> Result<i64, String> {
+        let last =
+            sqlx::query_scalar::<_, Option<i64>>("SELECT MAX(folio) FROM invoice WHERE series = ?")
+                .bind(series)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|e| format!("Failed to get next invoice folio: {}", e))?;
+
+        Ok(last.unwrap_or(0) + 1)
+    }
+
+    async fn create(&self, record: &InvoiceRecord, sale_ids: &[String]) -> Result<(), String> {
+        let data = serde_json::to_string(&record.invoice)
+            .map_err(|e| format!("Failed to encode invoice: {}", e))?;
+
+        // Start transaction
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO invoice (id, series, folio, uuid, receiver_rfc, total, data, xml, stamped_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&record.id)
+        .bind(&record.invoice.series)
+        .bind(record.invoice.folio)
+        .bind(&record.uuid)
+        .bind(&record.invoice.receiver.rfc)
+        .bind(record.invoice.total().to_string())
+        .bind(data)
+        .bind(&record.xml)
+        .bind(record.stamped_at.to_rfc3339())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to create invoice: {}", e))?;
+
+        for sale_id in sale_ids {
+            sqlx::query("INSERT INTO invoice_sale (sale_id, invoice_id) VALUES (?, ?)")
+                .bind(sale_id)
+                .bind(&record.id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| format!("Failed to link sale to invoice: {}", e))?;
+        }
+
+        // Commit transaction
+        tx.commit()
+            .await
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn get_by_sale(&self, sale_id: &str) -> Result<Option<InvoiceRecord>, String> {
+        let record = sqlx::query_as::<_, InvoiceRecord>(
+            r#"
+            SELECT i.* FROM invoice i
+            JOIN invoice_sale s ON s.invoice_id = i.id
+            WHERE s.sale_id = ?
+            "#,
+        )
+        .bind(sale_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to get invoice for sale: {}", e))?;
+
+        Ok(record)
+    }
+
+    async fn invoiced_sale_ids(&self, sale_ids: &[String]) -> Result<Vec<String>, String> {
+        let ids_json = serde_json::to_string(sale_ids)
+            .map_err(|e| format!("Failed to encode sale ids: {}", e))?;
+
+        let ids = sqlx::query_scalar::<_, String>(
+            r#"
+            SELECT sale_id FROM invoice_sale
+            WHERE sale_id IN (SELECT value FROM json_each(?))
+            "#,
+        )
+        .bind(ids_json)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to list invoiced sales: {}", e))?;
+
+        Ok(ids)
+    }
+}
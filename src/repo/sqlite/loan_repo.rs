@@ -172,6 +172,23 @@ impl LoanRepository for SqliteLoanRepository {
         Ok(payments)
     }
 
+    async fn payments_between(&self, start: &str, end: &str) -> Result<Vec<LoanPayment>, String> {
+        let payments = sqlx::query_as::<_, LoanPayment>(
+            r#"
+            SELECT * FROM loan_payment
+            WHERE payment_date >= ? AND payment_date < ?
+            ORDER BY payment_date
+            "#,
+        )
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to get loan payments: {}", e))?;
+
+        Ok(payments)
+    }
+
     async fn get_active(&self) -> Result<Vec<Loan>, String> {
         let loans = sqlx::query_as::<_, Loan>(
             "SELECT * FROM loan WHERE status_id IN (1, 2) ORDER BY created_at DESC",
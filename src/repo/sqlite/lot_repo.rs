@@ -0,0 +1,451 @@
+//! SQLite Product Lot Repository Implementation
+
+use crate::models::{ExpiringLot, ItemCondition, PriceHistoryEntry, ProductLot, StockAdjustment};
+use crate::repo::sqlite::{apply_price_change, insert_adjustment};
+use crate::repo::LotRepository;
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use sqlx::{Sqlite, SqlitePool, Transaction};
+
+/// Rounding slack when comparing fractional stock quantities
+const STOCK_TOLERANCE: f64 = 1e-9;
+
+pub struct SqliteLotRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteLotRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Deduct a quantity from the product's sellable lots, first expiry first out
+///
+/// Lots without an expiry date are consumed after dated ones, oldest first.
+/// Any quantity not covered by lots comes from untracked stock.
+///
+/// Sales pass the business day they are made on: lots expired by then are
+/// skipped and the sale fails when untracked stock can't cover the rest, so
+/// it must run before the product's stock is deducted.
+pub(crate) async fn consume_lots_fefo(
+    tx: &mut Transaction<'_, Sqlite>,
+    product_id: &str,
+    quantity: f64,
+    sold_on: Option<NaiveDate>,
+) -> Result<(), String> {
+    // Stock on hand not held in any sellable lot
+    let (product_name, untracked): (String, f64) = sqlx::query_as(
+        r#"
+        SELECT full_name, current_amount - COALESCE((
+            SELECT SUM(remaining_quantity) FROM product_lot
+            WHERE product_id = product.id AND condition_id = ?
+        ), 0)
+        FROM product WHERE id = ?
+        "#,
+    )
+    .bind(ItemCondition::GOOD)
+    .bind(product_id)
+    .fetch_one(&mut **tx)
+    .await
+    .map_err(|e| format!("Failed to load product stock: {}", e))?;
+
+    let lots: Vec<(String, f64)> = sqlx::query_as(
+        r#"
+        SELECT id, remaining_quantity FROM product_lot
+        WHERE product_id = ? AND condition_id = ? AND remaining_quantity > 0
+          AND (? IS NULL OR expires_at IS NULL OR expires_at >= ?)
+        ORDER BY expires_at IS NULL, expires_at, received_at
+        "#,
+    )
+    .bind(product_id)
+    .bind(ItemCondition::GOOD)
+    .bind(sold_on)
+    .bind(sold_on)
+    .fetch_all(&mut **tx)
+    .await
+    .map_err(|e| format!("Failed to load product lots: {}", e))?;
+
+    let mut pending = quantity;
+    for (lot_id, remaining) in lots {
+        if pending <= 0.0 {
+            break;
+        }
+
+        let taken = remaining.min(pending);
+        sqlx::query(
+            "UPDATE product_lot SET remaining_quantity = remaining_quantity - ? WHERE id = ?",
+        )
+        .bind(taken)
+        .bind(&lot_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| format!("Failed to update product lot: {}", e))?;
+
+        pending -= taken;
+    }
+
+    if sold_on.is_some() && pending > untracked.max(0.0) + STOCK_TOLERANCE {
+        return Err(format!(
+            "Insufficient unexpired stock for '{}': available {}, requested {}",
+            product_name,
+            quantity - pending + untracked.max(0.0),
+            quantity
+        ));
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+impl LotRepository for SqliteLotRepository {
+    async fn receive(
+        &self,
+        product_id: &str,
+        quantity: f64,
+        lot_code: Option<String>,
+        expires_at: Option<NaiveDate>,
+        price_change: Option<PriceHistoryEntry>,
+    ) -> Result<ProductLot, String> {
+        let lot = ProductLot {
+            id: uuid::Uuid::new_v4().to_string(),
+            product_id: product_id.to_string(),
+            lot_code,
+            expires_at,
+            received_quantity: quantity,
+            remaining_quantity: quantity,
+            condition_id: ItemCondition::GOOD,
+            received_at: Utc::now(),
+        };
+
+        // Start transaction
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO product_lot (
+                id, product_id, lot_code, expires_at, received_quantity,
+                remaining_quantity, condition_id, received_at
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&lot.id)
+        .bind(&lot.product_id)
+        .bind(&lot.lot_code)
+        .bind(lot.expires_at)
+        .bind(lot.received_quantity)
+        .bind(lot.remaining_quantity)
+        .bind(lot.condition_id)
+        .bind(lot.received_at.to_rfc3339())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to insert product lot: {}", e))?;
+
+        sqlx::query(
+            r#"
+            UPDATE product
+            SET current_amount = current_amount + ?,
+                updated_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(quantity)
+        .bind(lot.received_at.to_rfc3339())
+        .bind(product_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to update product stock: {}", e))?;
+
+        if let Some(ref entry) = price_change {
+            apply_price_change(&mut tx, entry).await?;
+        }
+
+        // Commit transaction
+        tx.commit()
+            .await
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+        Ok(lot)
+    }
+
+    async fn get_by_id(&self, id: &str) -> Result<Option<ProductLot>, String> {
+        let lot = sqlx::query_as::<_, ProductLot>("SELECT * FROM product_lot WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to get product lot by id: {}", e))?;
+
+        Ok(lot)
+    }
+
+    async fn list_by_product(&self, product_id: &str) -> Result<Vec<ProductLot>, String> {
+        let lots = sqlx::query_as::<_, ProductLot>(
+            r#"
+            SELECT * FROM product_lot
+            WHERE product_id = ?
+            ORDER BY expires_at IS NULL, expires_at, received_at
+            "#,
+        )
+        .bind(product_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to list product lots: {}", e))?;
+
+        Ok(lots)
+    }
+
+    async fn list_expiring(&self, until: NaiveDate) -> Result<Vec<ExpiringLot>, String> {
+        let lots = sqlx::query_as::<_, ExpiringLot>(
+            r#"
+            SELECT l.*, p.full_name AS product_name
+            FROM product_lot l
+            JOIN product p ON p.id = l.product_id
+            WHERE l.condition_id = ?
+              AND l.remaining_quantity > 0
+              AND l.expires_at IS NOT NULL
+              AND l.expires_at <= ?
+            ORDER BY l.expires_at, p.full_name
+            "#,
+        )
+        .bind(ItemCondition::GOOD)
+        .bind(until)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to list expiring lots: {}", e))?;
+
+        Ok(lots)
+    }
+
+    async fn list_received_between(
+        &self,
+        start: &str,
+        end: &str,
+    ) -> Result<Vec<ExpiringLot>, String> {
+        let lots = sqlx::query_as::<_, ExpiringLot>(
+            r#"
+            SELECT l.*, p.full_name AS product_name
+            FROM product_lot l
+            JOIN product p ON p.id = l.product_id
+            WHERE l.condition_id = ?
+              AND l.received_at >= ? AND l.received_at < ?
+            ORDER BY l.received_at
+            "#,
+        )
+        .bind(ItemCondition::GOOD)
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to list received lots: {}", e))?;
+
+        Ok(lots)
+    }
+
+    async fn move_to_condition(
+        &self,
+        lot_id: &str,
+        quantity: f64,
+        condition_id: i32,
+        adjustment: StockAdjustment,
+    ) -> Result<ProductLot, String> {
+        let source = self
+            .get_by_id(lot_id)
+            .await?
+            .ok_or_else(|| format!("Lot not found: {}", lot_id))?;
+
+        let moved = ProductLot {
+            id: uuid::Uuid::new_v4().to_string(),
+            received_quantity: quantity,
+            remaining_quantity: quantity,
+            condition_id,
+            ..source.clone()
+        };
+
+        // Start transaction
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        // Split the lot, keeping the moved part as its own record
+        sqlx::query(
+            "UPDATE product_lot SET remaining_quantity = remaining_quantity - ? WHERE id = ?",
+        )
+        .bind(quantity)
+        .bind(lot_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to update product lot: {}", e))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO product_lot (
+                id, product_id, lot_code, expires_at, received_quantity,
+                remaining_quantity, condition_id, received_at
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&moved.id)
+        .bind(&moved.product_id)
+        .bind(&moved.lot_code)
+        .bind(moved.expires_at)
+        .bind(moved.received_quantity)
+        .bind(moved.remaining_quantity)
+        .bind(moved.condition_id)
+        .bind(moved.received_at.to_rfc3339())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to insert product lot: {}", e))?;
+
+        // Remove the moved quantity from sellable stock
+        sqlx::query(
+            r#"
+            UPDATE product
+            SET current_amount = MAX(current_amount - ?, 0),
+                updated_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(quantity)
+        .bind(adjustment.adjusted_at.to_rfc3339())
+        .bind(&moved.product_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to update product stock: {}", e))?;
+
+        // Record the write-off
+        insert_adjustment(&mut tx, &adjustment).await?;
+
+        // Commit transaction
+        tx.commit()
+            .await
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+        Ok(moved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repo::ProductRepository;
+    use crate::utils::test_store::{product_input, TestStore};
+    use chrono::Duration;
+    use rust_decimal_macros::dec;
+
+    struct Fixture {
+        store: TestStore,
+        product_id: String,
+    }
+
+    /// Product with the given untracked stock
+    async fn fixture(untracked: f64) -> Fixture {
+        let store = TestStore::new().await;
+        let product = store
+            .products
+            .create(product_input("Yogurt", dec!(15), untracked))
+            .await
+            .unwrap();
+
+        Fixture {
+            store,
+            product_id: product.id,
+        }
+    }
+
+    impl Fixture {
+        async fn receive(&self, quantity: f64, expires_in_days: Option<i64>) -> String {
+            let expires_at = expires_in_days.map(|days| today() + Duration::days(days));
+            self.store
+                .lots
+                .receive(&self.product_id, quantity, None, expires_at, None)
+                .await
+                .unwrap()
+                .id
+        }
+
+        /// Deduct a quantity like a sale or a loss does
+        async fn consume(&self, quantity: f64, sold_on: Option<NaiveDate>) -> Result<(), String> {
+            let mut tx = self.store.pool.begin().await.unwrap();
+            consume_lots_fefo(&mut tx, &self.product_id, quantity, sold_on).await?;
+            sqlx::query("UPDATE product SET current_amount = current_amount - ? WHERE id = ?")
+                .bind(quantity)
+                .bind(&self.product_id)
+                .execute(&mut *tx)
+                .await
+                .unwrap();
+            tx.commit().await.unwrap();
+            Ok(())
+        }
+
+        async fn remaining(&self, lot_id: &str) -> f64 {
+            self.store
+                .lots
+                .get_by_id(lot_id)
+                .await
+                .unwrap()
+                .unwrap()
+                .remaining_quantity
+        }
+    }
+
+    fn today() -> NaiveDate {
+        Utc::now().date_naive()
+    }
+
+    #[tokio::test]
+    async fn test_fefo_takes_earliest_expiry_first_across_lots() {
+        let fx = fixture(0.0).await;
+        let undated = fx.receive(4.0, None).await;
+        let later = fx.receive(3.0, Some(10)).await;
+        let sooner = fx.receive(2.0, Some(5)).await;
+
+        fx.consume(4.0, Some(today())).await.unwrap();
+
+        assert_eq!(fx.remaining(&sooner).await, 0.0);
+        assert_eq!(fx.remaining(&later).await, 1.0);
+        assert_eq!(fx.remaining(&undated).await, 4.0);
+    }
+
+    #[tokio::test]
+    async fn test_fefo_running_out_of_lots() {
+        // 2 untracked units plus a lot of 3
+        let fx = fixture(2.0).await;
+        let lot = fx.receive(3.0, Some(5)).await;
+
+        // The lot is emptied and the rest comes from untracked stock
+        fx.consume(5.0, Some(today())).await.unwrap();
+        assert_eq!(fx.remaining(&lot).await, 0.0);
+
+        // Nothing left to cover a sale
+        let fx = fixture(0.0).await;
+        fx.receive(3.0, Some(5)).await;
+        assert!(fx.consume(4.0, Some(today())).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fefo_skips_expired_lots_in_sales() {
+        let fx = fixture(0.0).await;
+        let expired = fx.receive(5.0, Some(-1)).await;
+        let good = fx.receive(2.0, Some(3)).await;
+
+        fx.consume(2.0, Some(today())).await.unwrap();
+        assert_eq!(fx.remaining(&expired).await, 5.0);
+        assert_eq!(fx.remaining(&good).await, 0.0);
+
+        // Only expired stock left: the sale is blocked
+        let err = fx.consume(1.0, Some(today())).await.unwrap_err();
+        assert!(err.contains("unexpired"), "{}", err);
+
+        // Losses outside a sale still come off the expired lot
+        fx.consume(1.0, None).await.unwrap();
+        assert_eq!(fx.remaining(&expired).await, 4.0);
+    }
+}
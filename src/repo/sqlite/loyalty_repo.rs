@@ -0,0 +1,59 @@
+//! SQLite Loyalty Repository Implementation
+
+use crate::models::PointsEntry;
+use crate::repo::LoyaltyRepository;
+use async_trait::async_trait;
+use sqlx::{Sqlite, SqlitePool, Transaction};
+
+pub struct SqliteLoyaltyRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteLoyaltyRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Insert a points ledger entry, linked to the sale it came from if any
+pub(crate) async fn insert_points_entry(
+    tx: &mut Transaction<'_, Sqlite>,
+    entry: &PointsEntry,
+    sale_id: Option<&str>,
+) -> Result<(), String> {
+    sqlx::query(
+        r#"
+        INSERT INTO loyalty_entry (
+            id, customer_id, sale_id, points, kind, expires_at, created_at
+        )
+        VALUES (?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(&entry.id)
+    .bind(&entry.customer_id)
+    .bind(sale_id)
+    .bind(entry.points)
+    .bind(entry.kind.code())
+    .bind(entry.expires_at.map(|at| at.to_rfc3339()))
+    .bind(entry.created_at.to_rfc3339())
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| format!("Failed to record loyalty points: {}", e))?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl LoyaltyRepository for SqliteLoyaltyRepository {
+    async fn list_entries(&self, customer_id: &str) -> Result<Vec<PointsEntry>, String> {
+        let entries = sqlx::query_as::<_, PointsEntry>(
+            "SELECT * FROM loyalty_entry WHERE customer_id = ? ORDER BY created_at, rowid",
+        )
+        .bind(customer_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to list loyalty points: {}", e))?;
+
+        Ok(entries)
+    }
+}
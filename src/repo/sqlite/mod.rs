@@ -1,11 +1,37 @@
 //! SQLite Repository Implementations
 
+mod adjustment_repo;
 mod catalog_repo;
+mod category_repo;
+mod count_repo;
+mod customer_repo;
+mod invoice_repo;
 mod loan_repo;
+mod lot_repo;
+mod loyalty_repo;
+mod price_list_repo;
 mod product_repo;
+mod purchase_order_repo;
 mod sale_repo;
+mod settings_repo;
+mod supplier_repo;
 
+pub use adjustment_repo::SqliteAdjustmentRepository;
+pub(crate) use adjustment_repo::{apply_adjustment, insert_adjustment};
 pub use catalog_repo::SqliteCatalogRepository;
+pub use category_repo::SqliteCategoryRepository;
+pub use count_repo::SqliteInventoryCountRepository;
+pub use customer_repo::SqliteCustomerRepository;
+pub use invoice_repo::SqliteInvoiceRepository;
 pub use loan_repo::SqliteLoanRepository;
+pub(crate) use lot_repo::consume_lots_fefo;
+pub use lot_repo::SqliteLotRepository;
+pub(crate) use loyalty_repo::insert_points_entry;
+pub use loyalty_repo::SqliteLoyaltyRepository;
+pub use price_list_repo::SqlitePriceListRepository;
 pub use product_repo::SqliteProductRepository;
+pub(crate) use product_repo::{apply_price_change, CATEGORY_SUBTREE_CTE};
+pub use purchase_order_repo::SqlitePurchaseOrderRepository;
 pub use sale_repo::SqliteSaleRepository;
+pub use settings_repo::SqliteSettingsRepository;
+pub use supplier_repo::SqliteSupplierRepository;
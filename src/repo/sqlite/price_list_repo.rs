@@ -0,0 +1,203 @@
+//! SQLite Price List Repository Implementation
+
+use crate::models::{PriceList, PriceListInput, PriceListItem, PriceTier};
+use crate::repo::PriceListRepository;
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use sqlx::SqlitePool;
+
+pub struct SqlitePriceListRepository {
+    pool: SqlitePool,
+}
+
+impl SqlitePriceListRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PriceListRepository for SqlitePriceListRepository {
+    async fn list_all(&self) -> Result<Vec<PriceList>, String> {
+        let lists = sqlx::query_as::<_, PriceList>("SELECT * FROM price_list ORDER BY name")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to list price lists: {}", e))?;
+
+        Ok(lists)
+    }
+
+    async fn get_by_id(&self, id: &str) -> Result<Option<PriceList>, String> {
+        let list = sqlx::query_as::<_, PriceList>("SELECT * FROM price_list WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to get price list by id: {}", e))?;
+
+        Ok(list)
+    }
+
+    async fn create(&self, input: PriceListInput) -> Result<PriceList, String> {
+        let list = input.to_price_list();
+
+        sqlx::query(
+            r#"
+            INSERT INTO price_list (id, name, discount_percent, created_at)
+            VALUES (?, ?, ?, ?)
+            "#,
+        )
+        .bind(&list.id)
+        .bind(&list.name)
+        .bind(list.discount_percent.to_string())
+        .bind(list.created_at.to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to create price list: {}", e))?;
+
+        Ok(list)
+    }
+
+    async fn update(&self, id: &str, input: PriceListInput) -> Result<PriceList, String> {
+        sqlx::query("UPDATE price_list SET name = ?, discount_percent = ? WHERE id = ?")
+            .bind(&input.name)
+            .bind(input.discount_percent.to_string())
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to update price list: {}", e))?;
+
+        // Fetch and return updated price list
+        self.get_by_id(id)
+            .await?
+            .ok_or_else(|| format!("Price list not found after update: {}", id))
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), String> {
+        sqlx::query("DELETE FROM price_list WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to delete price list: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn list_items(&self, price_list_id: &str) -> Result<Vec<PriceListItem>, String> {
+        let items = sqlx::query_as::<_, PriceListItem>(
+            r#"
+            SELECT i.price_list_id, i.product_id, p.full_name AS product_name, i.price
+            FROM price_list_item i
+            JOIN product p ON p.id = i.product_id
+            WHERE i.price_list_id = ?
+            ORDER BY p.full_name
+            "#,
+        )
+        .bind(price_list_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to list price list items: {}", e))?;
+
+        Ok(items)
+    }
+
+    async fn set_item_price(
+        &self,
+        price_list_id: &str,
+        product_id: &str,
+        price: Decimal,
+    ) -> Result<(), String> {
+        sqlx::query(
+            r#"
+            INSERT INTO price_list_item (price_list_id, product_id, price)
+            VALUES (?, ?, ?)
+            ON CONFLICT(price_list_id, product_id) DO UPDATE SET price = excluded.price
+            "#,
+        )
+        .bind(price_list_id)
+        .bind(product_id)
+        .bind(price.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to save list price: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn remove_item(&self, price_list_id: &str, product_id: &str) -> Result<(), String> {
+        sqlx::query("DELETE FROM price_list_item WHERE price_list_id = ? AND product_id = ?")
+            .bind(price_list_id)
+            .bind(product_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to remove list price: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn list_tiers(&self, product_ids: &[String]) -> Result<Vec<PriceTier>, String> {
+        let ids_json = serde_json::to_string(product_ids)
+            .map_err(|e| format!("Failed to encode product ids: {}", e))?;
+
+        let tiers = sqlx::query_as::<_, PriceTier>(
+            r#"
+            SELECT * FROM product_price_tier
+            WHERE product_id IN (SELECT value FROM json_each(?))
+            ORDER BY product_id, min_quantity
+            "#,
+        )
+        .bind(ids_json)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to list price tiers: {}", e))?;
+
+        Ok(tiers)
+    }
+
+    async fn list_all_tiers(&self) -> Result<Vec<PriceTier>, String> {
+        let tiers = sqlx::query_as::<_, PriceTier>(
+            "SELECT * FROM product_price_tier ORDER BY product_id, min_quantity",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to list price tiers: {}", e))?;
+
+        Ok(tiers)
+    }
+
+    async fn set_tiers(&self, product_id: &str, tiers: &[PriceTier]) -> Result<(), String> {
+        // Start transaction
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        sqlx::query("DELETE FROM product_price_tier WHERE product_id = ?")
+            .bind(product_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("Failed to clear price tiers: {}", e))?;
+
+        for tier in tiers {
+            sqlx::query(
+                r#"
+                INSERT INTO product_price_tier (product_id, min_quantity, unit_price)
+                VALUES (?, ?, ?)
+                "#,
+            )
+            .bind(product_id)
+            .bind(tier.min_quantity)
+            .bind(tier.unit_price.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("Failed to save price tier: {}", e))?;
+        }
+
+        // Commit transaction
+        tx.commit()
+            .await
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+        Ok(())
+    }
+}
@@ -1,9 +1,79 @@
 //! SQLite Product Repository Implementation
 
-use crate::models::{Product, ProductInput};
+use crate::models::kit::kit_availability;
+use crate::models::{
+    AdjustmentReason, BarcodeMatch, KitComponent, KitComponentInput, PriceChangeSource,
+    PriceHistoryEntry, Product, ProductBarcode, ProductBarcodeInput, ProductInput, StockAdjustment,
+};
+use crate::repo::sqlite::apply_adjustment;
 use crate::repo::{PaginatedResult, ProductRepository};
 use async_trait::async_trait;
-use sqlx::SqlitePool;
+use sqlx::{Sqlite, SqlitePool, Transaction};
+
+/// Recursive CTE selecting a category and all of its descendants into `subtree`
+///
+/// Expects the root category ID as its only bind parameter.
+pub(crate) const CATEGORY_SUBTREE_CTE: &str = r#"
+    WITH RECURSIVE subtree(id) AS (
+        SELECT id FROM category WHERE id = ?
+        UNION ALL
+        SELECT c.id FROM category c JOIN subtree s ON c.parent_id = s.id
+    )
+"#;
+
+/// Kit components joined with the component's name and stock
+const KIT_COMPONENT_SELECT: &str = r#"
+    SELECT pc.kit_id, pc.component_id, pc.quantity,
+        c.full_name AS component_name, c.current_amount AS component_stock,
+        c.archived_at IS NOT NULL AS component_archived
+    FROM product_component pc
+    JOIN product c ON c.id = pc.component_id
+"#;
+
+/// Insert a price history entry without touching the product
+async fn insert_price_change(
+    tx: &mut Transaction<'_, Sqlite>,
+    entry: &PriceHistoryEntry,
+) -> Result<(), String> {
+    sqlx::query(
+        r#"
+        INSERT INTO product_price_history (
+            id, product_id, old_price, new_price, old_cost, new_cost, source_id, changed_at
+        )
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(&entry.id)
+    .bind(&entry.product_id)
+    .bind(entry.old_price.to_string())
+    .bind(entry.new_price.to_string())
+    .bind(entry.old_cost.map(|d| d.to_string()))
+    .bind(entry.new_cost.map(|d| d.to_string()))
+    .bind(entry.source_id)
+    .bind(entry.changed_at.to_rfc3339())
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| format!("Failed to record price change: {}", e))?;
+
+    Ok(())
+}
+
+/// Set a product's price and cost and record the change in the price history
+pub(crate) async fn apply_price_change(
+    tx: &mut Transaction<'_, Sqlite>,
+    entry: &PriceHistoryEntry,
+) -> Result<(), String> {
+    sqlx::query("UPDATE product SET user_price = ?, cost_price = ?, updated_at = ? WHERE id = ?")
+        .bind(entry.new_price.to_string())
+        .bind(entry.new_cost.map(|d| d.to_string()))
+        .bind(entry.changed_at.to_rfc3339())
+        .bind(&entry.product_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| format!("Failed to update product price: {}", e))?;
+
+    insert_price_change(tx, entry).await
+}
 
 pub struct SqliteProductRepository {
     pool: SqlitePool,
@@ -13,6 +83,32 @@ impl SqliteProductRepository {
     pub fn new(pool: SqlitePool) -> Self {
         Self { pool }
     }
+
+    /// Replace the stored stock of kits with what their components can assemble
+    async fn fill_kit_stock(&self, products: &mut [Product]) -> Result<(), String> {
+        if !products.iter().any(|product| product.is_kit) {
+            return Ok(());
+        }
+
+        let components = sqlx::query_as::<_, KitComponent>(&format!(
+            "{} ORDER BY pc.kit_id",
+            KIT_COMPONENT_SELECT
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to get kit components: {}", e))?;
+
+        for product in products.iter_mut().filter(|product| product.is_kit) {
+            let own: Vec<KitComponent> = components
+                .iter()
+                .filter(|component| component.kit_id == product.id)
+                .cloned()
+                .collect();
+            product.current_amount = kit_availability(&own);
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -25,9 +121,10 @@ impl ProductRepository for SqliteProductRepository {
             INSERT INTO product (
                 id, barcode, full_name, user_price, cost_price,
                 min_amount, current_amount, unit_measurement_id,
-                created_at, updated_at
+                purchase_unit_id, units_per_purchase,
+                category_id, supplier_id, created_at, updated_at
             )
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(&product.id)
@@ -38,6 +135,10 @@ impl ProductRepository for SqliteProductRepository {
         .bind(product.min_amount)
         .bind(product.current_amount)
         .bind(product.unit_measurement_id)
+        .bind(product.purchase_unit_id)
+        .bind(product.units_per_purchase)
+        .bind(&product.category_id)
+        .bind(&product.supplier_id)
         .bind(product.created_at.to_rfc3339())
         .bind(product.updated_at.to_rfc3339())
         .execute(&self.pool)
@@ -48,30 +149,164 @@ impl ProductRepository for SqliteProductRepository {
     }
 
     async fn get_by_id(&self, id: &str) -> Result<Option<Product>, String> {
-        let product = sqlx::query_as::<_, Product>("SELECT * FROM product WHERE id = ?")
+        let mut product = sqlx::query_as::<_, Product>("SELECT * FROM product WHERE id = ?")
             .bind(id)
             .fetch_optional(&self.pool)
             .await
             .map_err(|e| format!("Failed to get product by id: {}", e))?;
+        if let Some(product) = product.as_mut() {
+            self.fill_kit_stock(std::slice::from_mut(product)).await?;
+        }
 
         Ok(product)
     }
 
     async fn get_by_barcode(&self, barcode: &str) -> Result<Option<Product>, String> {
+        Ok(self.resolve_barcode(barcode).await?.map(|m| m.product))
+    }
+
+    async fn resolve_barcode(&self, barcode: &str) -> Result<Option<BarcodeMatch>, String> {
+        // The product's own barcode always counts as a single unit
         let product = sqlx::query_as::<_, Product>("SELECT * FROM product WHERE barcode = ?")
             .bind(barcode)
             .fetch_optional(&self.pool)
             .await
             .map_err(|e| format!("Failed to get product by barcode: {}", e))?;
 
-        Ok(product)
+        if let Some(mut product) = product {
+            self.fill_kit_stock(std::slice::from_mut(&mut product))
+                .await?;
+            return Ok(Some(BarcodeMatch {
+                product,
+                pack_quantity: 1.0,
+                line_total: None,
+            }));
+        }
+
+        let additional =
+            sqlx::query_as::<_, ProductBarcode>("SELECT * FROM product_barcode WHERE barcode = ?")
+                .bind(barcode)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| format!("Failed to get product by barcode: {}", e))?;
+
+        match additional {
+            Some(additional) => Ok(self
+                .get_by_id(&additional.product_id)
+                .await?
+                .map(|product| BarcodeMatch {
+                    product,
+                    pack_quantity: additional.pack_quantity,
+                    line_total: None,
+                })),
+            None => Ok(None),
+        }
     }
 
-    async fn list_all(&self) -> Result<Vec<Product>, String> {
-        let products = sqlx::query_as::<_, Product>("SELECT * FROM product ORDER BY full_name")
-            .fetch_all(&self.pool)
+    async fn list_barcodes(&self, product_id: &str) -> Result<Vec<ProductBarcode>, String> {
+        let barcodes = sqlx::query_as::<_, ProductBarcode>(
+            "SELECT * FROM product_barcode WHERE product_id = ? ORDER BY pack_quantity, barcode",
+        )
+        .bind(product_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to list product barcodes: {}", e))?;
+
+        Ok(barcodes)
+    }
+
+    async fn add_barcode(
+        &self,
+        product_id: &str,
+        input: ProductBarcodeInput,
+    ) -> Result<ProductBarcode, String> {
+        let barcode = input.to_product_barcode(product_id);
+
+        sqlx::query(
+            r#"
+            INSERT INTO product_barcode (barcode, product_id, pack_quantity, created_at)
+            VALUES (?, ?, ?, ?)
+            "#,
+        )
+        .bind(&barcode.barcode)
+        .bind(&barcode.product_id)
+        .bind(barcode.pack_quantity)
+        .bind(barcode.created_at.to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to add product barcode: {}", e))?;
+
+        Ok(barcode)
+    }
+
+    async fn last_barcode_with_prefix(&self, prefix: &str) -> Result<Option<String>, String> {
+        let barcode: Option<String> = sqlx::query_scalar(
+            r#"
+            SELECT MAX(barcode) FROM (
+                SELECT barcode FROM product
+                UNION ALL
+                SELECT barcode FROM product_barcode
+            )
+            WHERE length(barcode) = 13 AND barcode GLOB ?
+            "#,
+        )
+        // Only all-digit codes, so stray text codes cannot outrank the sequence
+        .bind(format!("{}{}", prefix, "[0-9]".repeat(13 - prefix.len())))
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to get last barcode: {}", e))?;
+
+        Ok(barcode)
+    }
+
+    async fn assign_barcodes(&self, assignments: Vec<(String, String)>) -> Result<(), String> {
+        let updated_at = chrono::Utc::now();
+
+        // Start transaction
+        let mut tx = self
+            .pool
+            .begin()
             .await
-            .map_err(|e| format!("Failed to list products: {}", e))?;
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        for (product_id, barcode) in &assignments {
+            sqlx::query(
+                "UPDATE product SET barcode = ?, updated_at = ? WHERE id = ? AND barcode IS NULL",
+            )
+            .bind(barcode)
+            .bind(updated_at.to_rfc3339())
+            .bind(product_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("Failed to assign barcode: {}", e))?;
+        }
+
+        // Commit transaction
+        tx.commit()
+            .await
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn remove_barcode(&self, barcode: &str) -> Result<(), String> {
+        sqlx::query("DELETE FROM product_barcode WHERE barcode = ?")
+            .bind(barcode)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to remove product barcode: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn list_all(&self) -> Result<Vec<Product>, String> {
+        let mut products = sqlx::query_as::<_, Product>(
+            "SELECT * FROM product WHERE archived_at IS NULL ORDER BY full_name",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to list products: {}", e))?;
+        self.fill_kit_stock(&mut products).await?;
 
         Ok(products)
     }
@@ -84,20 +319,22 @@ impl ProductRepository for SqliteProductRepository {
         use crate::utils::db_parsing::calculate_offset;
 
         // Get total count
-        let total_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM product")
-            .fetch_one(&self.pool)
-            .await
-            .map_err(|e| format!("Failed to count products: {}", e))?;
+        let total_count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM product WHERE archived_at IS NULL")
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|e| format!("Failed to count products: {}", e))?;
 
         // Get paginated products
-        let products = sqlx::query_as::<_, Product>(
-            "SELECT * FROM product ORDER BY full_name LIMIT ? OFFSET ?",
+        let mut products = sqlx::query_as::<_, Product>(
+            "SELECT * FROM product WHERE archived_at IS NULL ORDER BY full_name LIMIT ? OFFSET ?",
         )
         .bind(page_size)
         .bind(calculate_offset(page, page_size))
         .fetch_all(&self.pool)
         .await
         .map_err(|e| format!("Failed to list products: {}", e))?;
+        self.fill_kit_stock(&mut products).await?;
 
         Ok(PaginatedResult {
             items: products,
@@ -110,12 +347,27 @@ impl ProductRepository for SqliteProductRepository {
     async fn update(&self, id: &str, input: ProductInput) -> Result<Product, String> {
         let updated_at = chrono::Utc::now();
 
+        // Start transaction
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        let current = sqlx::query_as::<_, Product>("SELECT * FROM product WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| format!("Failed to get product by id: {}", e))?
+            .ok_or_else(|| format!("Product not found: {}", id))?;
+
         sqlx::query(
             r#"
             UPDATE product
             SET barcode = ?, full_name = ?, user_price = ?, cost_price = ?,
-                min_amount = ?, current_amount = ?, unit_measurement_id = ?,
-                updated_at = ?
+                min_amount = ?, unit_measurement_id = ?,
+                purchase_unit_id = ?, units_per_purchase = ?,
+                category_id = ?, supplier_id = ?, updated_at = ?
             WHERE id = ?
             "#,
         )
@@ -124,14 +376,47 @@ impl ProductRepository for SqliteProductRepository {
         .bind(input.user_price.to_string())
         .bind(input.cost_price.map(|d| d.to_string()))
         .bind(input.min_amount)
-        .bind(input.current_amount)
         .bind(input.unit_measurement_id)
+        .bind(input.purchase_unit_id)
+        .bind(input.units_per_purchase)
+        .bind(&input.category_id)
+        .bind(&input.supplier_id)
         .bind(updated_at.to_rfc3339())
         .bind(id)
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await
         .map_err(|e| format!("Failed to update product: {}", e))?;
 
+        // Record manual price and cost edits
+        let entry = PriceHistoryEntry::new(
+            &current,
+            input.user_price,
+            input.cost_price,
+            PriceChangeSource::MANUAL_EDIT,
+        );
+        if entry.is_change() {
+            insert_price_change(&mut tx, &entry).await?;
+        }
+
+        // Stock edits are count corrections valued at the saved cost; kit stock is derived
+        let difference = input.current_amount - current.current_amount;
+        if !current.is_kit && difference != 0.0 {
+            let adjustment = StockAdjustment::new(
+                id,
+                &input.full_name,
+                difference,
+                input.cost_price,
+                AdjustmentReason::COUNT_CORRECTION,
+                None,
+            );
+            apply_adjustment(&mut tx, &adjustment).await?;
+        }
+
+        // Commit transaction
+        tx.commit()
+            .await
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
         // Fetch and return updated product
         self.get_by_id(id)
             .await?
@@ -148,37 +433,80 @@ impl ProductRepository for SqliteProductRepository {
         Ok(())
     }
 
-    async fn update_stock(&self, id: &str, new_amount: f64) -> Result<(), String> {
-        let updated_at = chrono::Utc::now();
+    async fn archive(&self, id: &str) -> Result<(), String> {
+        let archived_at = chrono::Utc::now();
 
-        sqlx::query("UPDATE product SET current_amount = ?, updated_at = ? WHERE id = ?")
-            .bind(new_amount)
-            .bind(updated_at.to_rfc3339())
+        sqlx::query("UPDATE product SET archived_at = ?, updated_at = ? WHERE id = ?")
+            .bind(archived_at.to_rfc3339())
+            .bind(archived_at.to_rfc3339())
             .bind(id)
             .execute(&self.pool)
             .await
-            .map_err(|e| format!("Failed to update stock: {}", e))?;
+            .map_err(|e| format!("Failed to archive product: {}", e))?;
 
         Ok(())
     }
 
+    async fn restore(&self, id: &str) -> Result<(), String> {
+        sqlx::query("UPDATE product SET archived_at = NULL, updated_at = ? WHERE id = ?")
+            .bind(chrono::Utc::now().to_rfc3339())
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to restore product: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn list_archived(&self) -> Result<Vec<Product>, String> {
+        let mut products = sqlx::query_as::<_, Product>(
+            "SELECT * FROM product WHERE archived_at IS NOT NULL ORDER BY full_name",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to list archived products: {}", e))?;
+        self.fill_kit_stock(&mut products).await?;
+
+        Ok(products)
+    }
+
+    async fn has_history(&self, id: &str) -> Result<bool, String> {
+        let has_history: bool = sqlx::query_scalar(
+            r#"
+            SELECT EXISTS (SELECT 1 FROM operation WHERE product_id = ?)
+                OR EXISTS (SELECT 1 FROM stock_adjustment WHERE product_id = ?)
+            "#,
+        )
+        .bind(id)
+        .bind(id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to check product history: {}", e))?;
+
+        Ok(has_history)
+    }
+
     async fn search(&self, query: &str) -> Result<Vec<Product>, String> {
         use crate::utils::db_parsing::format_like_pattern;
 
         let search_term = format_like_pattern(query);
 
-        let products = sqlx::query_as::<_, Product>(
+        let mut products = sqlx::query_as::<_, Product>(
             r#"
             SELECT * FROM product
-            WHERE full_name LIKE ? OR barcode LIKE ?
+            WHERE (full_name LIKE ? OR barcode LIKE ?
+                   OR id IN (SELECT product_id FROM product_barcode WHERE barcode LIKE ?))
+              AND archived_at IS NULL
             ORDER BY full_name
             "#,
         )
         .bind(&search_term)
         .bind(&search_term)
+        .bind(&search_term)
         .fetch_all(&self.pool)
         .await
         .map_err(|e| format!("Failed to search products: {}", e))?;
+        self.fill_kit_stock(&mut products).await?;
 
         Ok(products)
     }
@@ -186,6 +514,7 @@ impl ProductRepository for SqliteProductRepository {
     async fn search_paginated(
         &self,
         query: &str,
+        category_id: Option<&str>,
         page: i64,
         page_size: i64,
     ) -> Result<PaginatedResult<Product>, String> {
@@ -194,34 +523,49 @@ impl ProductRepository for SqliteProductRepository {
         let search_term = format_like_pattern(query);
 
         // Get total count of matching products
-        let total_count: i64 = sqlx::query_scalar(
+        let total_count: i64 = sqlx::query_scalar(&format!(
             r#"
+            {CATEGORY_SUBTREE_CTE}
             SELECT COUNT(*) FROM product
-            WHERE full_name LIKE ? OR barcode LIKE ?
-            "#,
-        )
+            WHERE (full_name LIKE ? OR barcode LIKE ?
+                   OR id IN (SELECT product_id FROM product_barcode WHERE barcode LIKE ?))
+              AND (? IS NULL OR category_id IN (SELECT id FROM subtree))
+              AND archived_at IS NULL
+            "#
+        ))
+        .bind(category_id)
+        .bind(&search_term)
         .bind(&search_term)
         .bind(&search_term)
+        .bind(category_id)
         .fetch_one(&self.pool)
         .await
         .map_err(|e| format!("Failed to count search results: {}", e))?;
 
         // Get paginated search results
-        let products = sqlx::query_as::<_, Product>(
+        let mut products = sqlx::query_as::<_, Product>(&format!(
             r#"
+            {CATEGORY_SUBTREE_CTE}
             SELECT * FROM product
-            WHERE full_name LIKE ? OR barcode LIKE ?
+            WHERE (full_name LIKE ? OR barcode LIKE ?
+                   OR id IN (SELECT product_id FROM product_barcode WHERE barcode LIKE ?))
+              AND (? IS NULL OR category_id IN (SELECT id FROM subtree))
+              AND archived_at IS NULL
             ORDER BY full_name
             LIMIT ? OFFSET ?
-            "#,
-        )
+            "#
+        ))
+        .bind(category_id)
+        .bind(&search_term)
         .bind(&search_term)
         .bind(&search_term)
+        .bind(category_id)
         .bind(page_size)
         .bind(calculate_offset(page, page_size))
         .fetch_all(&self.pool)
         .await
         .map_err(|e| format!("Failed to search products: {}", e))?;
+        self.fill_kit_stock(&mut products).await?;
 
         Ok(PaginatedResult {
             items: products,
@@ -231,14 +575,179 @@ impl ProductRepository for SqliteProductRepository {
         })
     }
 
+    async fn list_by_category(&self, category_id: &str) -> Result<Vec<Product>, String> {
+        let mut products = sqlx::query_as::<_, Product>(&format!(
+            r#"
+            {CATEGORY_SUBTREE_CTE}
+            SELECT * FROM product
+            WHERE category_id IN (SELECT id FROM subtree) AND archived_at IS NULL
+            ORDER BY full_name
+            "#
+        ))
+        .bind(category_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to list products by category: {}", e))?;
+        self.fill_kit_stock(&mut products).await?;
+
+        Ok(products)
+    }
+
     async fn get_low_stock(&self) -> Result<Vec<Product>, String> {
-        let products = sqlx::query_as::<_, Product>(
-            "SELECT * FROM product WHERE current_amount <= min_amount ORDER BY full_name",
+        let mut products = sqlx::query_as::<_, Product>(
+            r#"
+            SELECT * FROM product
+            WHERE (current_amount <= min_amount OR is_kit = 1) AND archived_at IS NULL
+            ORDER BY full_name
+            "#,
         )
         .fetch_all(&self.pool)
         .await
         .map_err(|e| format!("Failed to get low stock products: {}", e))?;
+        self.fill_kit_stock(&mut products).await?;
+        products.retain(|product| product.is_low_stock());
 
         Ok(products)
     }
+
+    async fn update_prices(&self, entries: Vec<PriceHistoryEntry>) -> Result<(), String> {
+        // Start transaction
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        for entry in &entries {
+            apply_price_change(&mut tx, entry).await?;
+        }
+
+        // Commit transaction
+        tx.commit()
+            .await
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn list_price_history(&self, product_id: &str) -> Result<Vec<PriceHistoryEntry>, String> {
+        let entries = sqlx::query_as::<_, PriceHistoryEntry>(
+            r#"
+            SELECT * FROM product_price_history
+            WHERE product_id = ?
+            ORDER BY changed_at DESC
+            "#,
+        )
+        .bind(product_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to list price history: {}", e))?;
+
+        Ok(entries)
+    }
+
+    async fn list_components(&self, kit_id: &str) -> Result<Vec<KitComponent>, String> {
+        let components = sqlx::query_as::<_, KitComponent>(&format!(
+            "{} WHERE pc.kit_id = ? ORDER BY c.full_name",
+            KIT_COMPONENT_SELECT
+        ))
+        .bind(kit_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to list kit components: {}", e))?;
+
+        Ok(components)
+    }
+
+    async fn set_component(&self, kit_id: &str, input: KitComponentInput) -> Result<(), String> {
+        // Start transaction
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO product_component (kit_id, component_id, quantity)
+            VALUES (?, ?, ?)
+            ON CONFLICT (kit_id, component_id) DO UPDATE SET quantity = excluded.quantity
+            "#,
+        )
+        .bind(kit_id)
+        .bind(&input.component_id)
+        .bind(input.quantity)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to set kit component: {}", e))?;
+
+        // A kit keeps no stock of its own
+        sqlx::query(
+            "UPDATE product SET is_kit = 1, current_amount = 0, updated_at = ? WHERE id = ?",
+        )
+        .bind(chrono::Utc::now().to_rfc3339())
+        .bind(kit_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to mark product as kit: {}", e))?;
+
+        // Commit transaction
+        tx.commit()
+            .await
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn remove_component(&self, kit_id: &str, component_id: &str) -> Result<(), String> {
+        // Start transaction
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        sqlx::query("DELETE FROM product_component WHERE kit_id = ? AND component_id = ?")
+            .bind(kit_id)
+            .bind(component_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("Failed to remove kit component: {}", e))?;
+
+        // Without components the product goes back to its own stock, which
+        // starts empty
+        sqlx::query(
+            r#"
+            UPDATE product
+            SET is_kit = EXISTS (SELECT 1 FROM product_component WHERE kit_id = ?1),
+                current_amount = 0,
+                updated_at = ?2
+            WHERE id = ?1 AND is_kit = 1
+            "#,
+        )
+        .bind(kit_id)
+        .bind(chrono::Utc::now().to_rfc3339())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Failed to update kit: {}", e))?;
+
+        // Commit transaction
+        tx.commit()
+            .await
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+        Ok(())
+    }
+
+    async fn is_component(&self, id: &str) -> Result<bool, String> {
+        let is_component: bool = sqlx::query_scalar(
+            "SELECT EXISTS (SELECT 1 FROM product_component WHERE component_id = ?)",
+        )
+        .bind(id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to check kit components: {}", e))?;
+
+        Ok(is_component)
+    }
 }
@@ -0,0 +1,126 @@
+//! SQLite Purchase Order Repository Implementation
+
+use crate::models::{PurchaseOrder, PurchaseOrderLine};
+use crate::repo::PurchaseOrderRepository;
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::SqlitePool;
+
+/// Order columns plus supplier name and line totals, shared by the order queries
+const ORDER_SELECT: &str = r#"
+    SELECT o.*, s.name AS supplier_name,
+        (SELECT COUNT(*) FROM purchase_order_line l WHERE l.order_id = o.id) AS line_count,
+        (SELECT printf('%.2f', COALESCE(SUM(CAST(l.unit_cost AS REAL) * l.quantity), 0))
+            FROM purchase_order_line l WHERE l.order_id = o.id) AS total_cost
+    FROM purchase_order o
+    LEFT JOIN supplier s ON s.id = o.supplier_id
+"#;
+
+pub struct SqlitePurchaseOrderRepository {
+    pool: SqlitePool,
+}
+
+impl SqlitePurchaseOrderRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_by_id(&self, id: &str) -> Result<Option<PurchaseOrder>, String> {
+        let order = sqlx::query_as::<_, PurchaseOrder>(&format!("{} WHERE o.id = ?", ORDER_SELECT))
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to get purchase order by id: {}", e))?;
+
+        Ok(order)
+    }
+}
+
+#[async_trait]
+impl PurchaseOrderRepository for SqlitePurchaseOrderRepository {
+    async fn create(
+        &self,
+        supplier_id: Option<String>,
+        lines: Vec<PurchaseOrderLine>,
+    ) -> Result<PurchaseOrder, String> {
+        let id = uuid::Uuid::new_v4().to_string();
+
+        // Start transaction
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        sqlx::query("INSERT INTO purchase_order (id, supplier_id, created_at) VALUES (?, ?, ?)")
+            .bind(&id)
+            .bind(&supplier_id)
+            .bind(Utc::now().to_rfc3339())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("Failed to create purchase order: {}", e))?;
+
+        for line in &lines {
+            sqlx::query(
+                r#"
+                INSERT INTO purchase_order_line (
+                    order_id, product_id, product_name, quantity, purchase_quantity, unit_cost
+                )
+                VALUES (?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(&id)
+            .bind(&line.product_id)
+            .bind(&line.product_name)
+            .bind(line.quantity)
+            .bind(line.purchase_quantity)
+            .bind(line.unit_cost.map(|d| d.to_string()))
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("Failed to add purchase order line: {}", e))?;
+        }
+
+        // Commit transaction
+        tx.commit()
+            .await
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+        self.get_by_id(&id)
+            .await?
+            .ok_or_else(|| format!("Purchase order not found after create: {}", id))
+    }
+
+    async fn list_all(&self) -> Result<Vec<PurchaseOrder>, String> {
+        let orders = sqlx::query_as::<_, PurchaseOrder>(&format!(
+            "{} ORDER BY o.created_at DESC",
+            ORDER_SELECT
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to list purchase orders: {}", e))?;
+
+        Ok(orders)
+    }
+
+    async fn list_lines(&self, order_id: &str) -> Result<Vec<PurchaseOrderLine>, String> {
+        let lines = sqlx::query_as::<_, PurchaseOrderLine>(
+            "SELECT * FROM purchase_order_line WHERE order_id = ? ORDER BY product_name",
+        )
+        .bind(order_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to list purchase order lines: {}", e))?;
+
+        Ok(lines)
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), String> {
+        sqlx::query("DELETE FROM purchase_order WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to delete purchase order: {}", e))?;
+
+        Ok(())
+    }
+}
@@ -1,10 +1,30 @@
 //! SQLite Sale Repository Implementation
 
-use crate::models::{Operation, Sale, SaleInput};
-use crate::repo::SaleRepository;
+use crate::models::{
+    InvoiceReceiver, Operation, PointsEntry, PointsKind, ProductSales, Sale, SaleFilter, SaleInput,
+    StoreClock,
+};
+use crate::repo::sqlite::{consume_lots_fefo, insert_points_entry, CATEGORY_SUBTREE_CTE};
+use crate::repo::{PaginatedResult, SaleRepository};
 use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
 use sqlx::SqlitePool;
 
+/// Sales history filters, bound as ?1..?6
+const SALE_FILTER_WHERE: &str = r#"
+    WHERE (?1 IS NULL OR s.sold_at >= ?1)
+      AND (?2 IS NULL OR s.sold_at < ?2)
+      AND (?3 IS NULL OR CAST(s.total_amount AS REAL) >= CAST(?3 AS REAL))
+      AND (?4 IS NULL OR CAST(s.total_amount AS REAL) <= CAST(?4 AS REAL))
+      AND (?5 IS NULL OR s.is_loan = ?5)
+      AND (?6 IS NULL OR EXISTS (
+            SELECT 1 FROM operation o
+            LEFT JOIN product p ON p.id = o.product_id
+            WHERE o.sale_id = s.id
+              AND (o.product_name LIKE ?6 OR p.barcode LIKE ?6)
+          ))
+"#;
+
 pub struct SqliteSaleRepository {
     pool: SqlitePool,
 }
@@ -17,7 +37,12 @@ impl SqliteSaleRepository {
 
 #[async_trait]
 impl SaleRepository for SqliteSaleRepository {
-    async fn create(&self, input: SaleInput) -> Result<Sale, String> {
+    async fn create(
+        &self,
+        input: SaleInput,
+        points: &[PointsEntry],
+        sold_on: NaiveDate,
+    ) -> Result<Sale, String> {
         let sale = input.to_sale();
         let operations = input.to_operations(&sale.id);
 
@@ -33,9 +58,9 @@ impl SaleRepository for SqliteSaleRepository {
             r#"
             INSERT INTO sale (
                 id, total_amount, paid_amount,
-                change_amount, is_loan, sold_at
+                change_amount, is_loan, sold_at, customer_id, points_amount
             )
-            VALUES (?, ?, ?, ?, ?, ?)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(&sale.id)
@@ -44,10 +69,39 @@ impl SaleRepository for SqliteSaleRepository {
         .bind(sale.change_amount.to_string())
         .bind(sale.is_loan as i32)
         .bind(sale.sold_at.to_rfc3339())
+        .bind(&sale.customer_id)
+        .bind(sale.points_amount.to_string())
         .execute(&mut *tx)
         .await
         .map_err(|e| format!("Failed to insert sale: {}", e))?;
 
+        // Snapshot the tax profile, so later edits to the customer don't change it
+        if let Some(profile) = &input.tax_profile {
+            sqlx::query(
+                r#"
+                INSERT INTO sale_tax_profile (
+                    sale_id, rfc, legal_name, tax_regime, zip_code, cfdi_use
+                )
+                VALUES (?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(&sale.id)
+            .bind(&profile.rfc)
+            .bind(&profile.legal_name)
+            .bind(&profile.tax_regime)
+            .bind(&profile.zip_code)
+            .bind(&profile.cfdi_use)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("Failed to save sale tax profile: {}", e))?;
+        }
+
+        // Expirations aren't part of the sale, they are only settled with it
+        for entry in points {
+            let sale_id = (entry.kind != PointsKind::Expire).then_some(sale.id.as_str());
+            insert_points_entry(&mut tx, entry, sale_id).await?;
+        }
+
         // Insert operations
         for operation in &operations {
             sqlx::query(
@@ -71,21 +125,63 @@ impl SaleRepository for SqliteSaleRepository {
             .await
             .map_err(|e| format!("Failed to insert operation: {}", e))?;
 
-            // Update product stock
-            sqlx::query(
-                r#"
-                UPDATE product
-                SET current_amount = current_amount - ?,
-                    updated_at = ?
-                WHERE id = ?
-                "#,
+            // Kits deduct their components instead of their own stock
+            let components: Vec<(String, f64)> = sqlx::query_as(
+                "SELECT component_id, quantity FROM product_component WHERE kit_id = ?",
             )
-            .bind(operation.quantity)
-            .bind(operation.recorded_at.to_rfc3339())
             .bind(&operation.product_id)
-            .execute(&mut *tx)
+            .fetch_all(&mut *tx)
             .await
-            .map_err(|e| format!("Failed to update product stock: {}", e))?;
+            .map_err(|e| format!("Failed to get kit components: {}", e))?;
+
+            let is_kit = !components.is_empty();
+            let deductions = if !is_kit {
+                vec![(operation.product_id.clone(), operation.quantity)]
+            } else {
+                components
+                    .into_iter()
+                    .map(|(component_id, quantity)| (component_id, quantity * operation.quantity))
+                    .collect()
+            };
+
+            // Keep what each kit deducted, since its composition may change later
+            if is_kit {
+                for (component_id, quantity) in &deductions {
+                    sqlx::query(
+                        r#"
+                        INSERT INTO operation_component (operation_id, component_id, quantity)
+                        VALUES (?, ?, ?)
+                        "#,
+                    )
+                    .bind(&operation.id)
+                    .bind(component_id)
+                    .bind(quantity)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|e| format!("Failed to insert operation component: {}", e))?;
+                }
+            }
+
+            for (product_id, quantity) in &deductions {
+                // Deduct from the soonest-expiring lots still good on the sale day
+                consume_lots_fefo(&mut tx, product_id, *quantity, Some(sold_on)).await?;
+
+                // Update product stock
+                sqlx::query(
+                    r#"
+                    UPDATE product
+                    SET current_amount = current_amount - ?,
+                        updated_at = ?
+                    WHERE id = ?
+                    "#,
+                )
+                .bind(quantity)
+                .bind(operation.recorded_at.to_rfc3339())
+                .bind(product_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| format!("Failed to update product stock: {}", e))?;
+            }
         }
 
         // Commit transaction
@@ -96,6 +192,29 @@ impl SaleRepository for SqliteSaleRepository {
         Ok(sale)
     }
 
+    async fn get_tax_profile(&self, sale_id: &str) -> Result<Option<InvoiceReceiver>, String> {
+        let row: Option<(String, String, String, String, String)> = sqlx::query_as(
+            r#"
+            SELECT rfc, legal_name, tax_regime, zip_code, cfdi_use
+            FROM sale_tax_profile WHERE sale_id = ?
+            "#,
+        )
+        .bind(sale_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to get sale tax profile: {}", e))?;
+
+        Ok(row.map(
+            |(rfc, legal_name, tax_regime, zip_code, cfdi_use)| InvoiceReceiver {
+                rfc,
+                legal_name,
+                tax_regime,
+                zip_code,
+                cfdi_use,
+            },
+        ))
+    }
+
     async fn get_by_id(&self, id: &str) -> Result<Option<Sale>, String> {
         let sale = sqlx::query_as::<_, Sale>("SELECT * FROM sale WHERE id = ?")
             .bind(id)
@@ -107,7 +226,7 @@ impl SaleRepository for SqliteSaleRepository {
     }
 
     async fn list_all(&self) -> Result<Vec<Sale>, String> {
-        let sales = sqlx::query_as::<_, Sale>("SELECT * FROM sale ORDER BY created_at DESC")
+        let sales = sqlx::query_as::<_, Sale>("SELECT * FROM sale ORDER BY sold_at DESC")
             .fetch_all(&self.pool)
             .await
             .map_err(|e| format!("Failed to list sales: {}", e))?;
@@ -117,7 +236,16 @@ impl SaleRepository for SqliteSaleRepository {
 
     async fn get_operations(&self, sale_id: &str) -> Result<Vec<Operation>, String> {
         let operations = sqlx::query_as::<_, Operation>(
-            "SELECT * FROM operation WHERE sale_id = ? ORDER BY recorded_at",
+            r#"
+            SELECT o.*,
+                   u.abbreviation AS unit_abbreviation,
+                   CASE WHEN u.allows_fraction THEN u.decimals ELSE 0 END AS quantity_decimals
+            FROM operation o
+            LEFT JOIN product p ON p.id = o.product_id
+            LEFT JOIN unit_measurement u ON u.id = p.unit_measurement_id
+            WHERE o.sale_id = ?
+            ORDER BY o.recorded_at
+            "#,
         )
         .bind(sale_id)
         .fetch_all(&self.pool)
@@ -131,8 +259,8 @@ impl SaleRepository for SqliteSaleRepository {
         let sales = sqlx::query_as::<_, Sale>(
             r#"
             SELECT * FROM sale
-            WHERE created_at BETWEEN ? AND ?
-            ORDER BY created_at DESC
+            WHERE sold_at >= ? AND sold_at < ?
+            ORDER BY sold_at DESC
             "#,
         )
         .bind(start)
@@ -152,7 +280,7 @@ impl SaleRepository for SqliteSaleRepository {
             SELECT s.* FROM sale s
             JOIN loan l ON s.id = l.id
             WHERE l.debtor_name LIKE ?
-            ORDER BY s.created_at DESC
+            ORDER BY s.sold_at DESC
             "#,
         )
         .bind(&search_term)
@@ -162,4 +290,252 @@ impl SaleRepository for SqliteSaleRepository {
 
         Ok(sales)
     }
+
+    async fn operations_between(
+        &self,
+        start: &str,
+        end: &str,
+        category_id: Option<&str>,
+    ) -> Result<Vec<Operation>, String> {
+        let operations = sqlx::query_as::<_, Operation>(&format!(
+            r#"
+            {CATEGORY_SUBTREE_CTE}
+            SELECT o.*,
+                   u.abbreviation AS unit_abbreviation,
+                   CASE WHEN u.allows_fraction THEN u.decimals ELSE 0 END AS quantity_decimals
+            FROM operation o
+            JOIN sale s ON s.id = o.sale_id
+            LEFT JOIN product p ON p.id = o.product_id
+            LEFT JOIN unit_measurement u ON u.id = p.unit_measurement_id
+            WHERE s.sold_at >= ? AND s.sold_at < ?
+              AND (? IS NULL OR p.category_id IN (SELECT id FROM subtree))
+            ORDER BY s.sold_at, o.recorded_at
+            "#
+        ))
+        .bind(category_id)
+        .bind(start)
+        .bind(end)
+        .bind(category_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to get operations: {}", e))?;
+
+        Ok(operations)
+    }
+
+    async fn product_sales_between(
+        &self,
+        start: &str,
+        end: &str,
+        category_id: Option<&str>,
+    ) -> Result<Vec<ProductSales>, String> {
+        let totals = sqlx::query_as::<_, ProductSales>(&format!(
+            r#"
+            {CATEGORY_SUBTREE_CTE}
+            SELECT o.product_id,
+                   COALESCE(p.full_name, MAX(o.product_name)) AS product_name,
+                   SUM(o.quantity) AS quantity,
+                   printf('%.2f', SUM(CAST(o.subtotal AS REAL))) AS revenue,
+                   CASE WHEN p.cost_price IS NULL THEN NULL
+                        ELSE printf('%.2f', SUM(o.quantity) * CAST(p.cost_price AS REAL))
+                   END AS cost
+            FROM operation o
+            JOIN sale s ON s.id = o.sale_id
+            LEFT JOIN product p ON p.id = o.product_id
+            WHERE s.sold_at >= ? AND s.sold_at < ?
+              AND (? IS NULL OR p.category_id IN (SELECT id FROM subtree))
+            GROUP BY o.product_id
+            ORDER BY SUM(CAST(o.subtotal AS REAL)) DESC
+            "#
+        ))
+        .bind(category_id)
+        .bind(start)
+        .bind(end)
+        .bind(category_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to get product sales: {}", e))?;
+
+        Ok(totals)
+    }
+
+    async fn totals_by_range(
+        &self,
+        ranges: &[(String, String)],
+        category_id: Option<&str>,
+    ) -> Result<Vec<(usize, rust_decimal::Decimal)>, String> {
+        use crate::utils::db_parsing::parse_decimal_from_row;
+        use sqlx::Row;
+
+        // All ranges go in as one JSON array so a single query aggregates them
+        let ranges_json = serde_json::to_string(ranges)
+            .map_err(|e| format!("Failed to encode date ranges: {}", e))?;
+
+        // Without a category whole sales count; with one, only the sales
+        // with its products and the revenue of those lines
+        let rows = sqlx::query(&format!(
+            r#"
+            {CATEGORY_SUBTREE_CTE},
+            bucket AS (
+                SELECT CAST(r.key AS INTEGER) AS idx,
+                       json_extract(r.value, '$[0]') AS start_at,
+                       json_extract(r.value, '$[1]') AS end_at
+                FROM json_each(?2) r
+            ),
+            line AS (
+                SELECT s.id AS sale_id, s.sold_at, s.total_amount AS amount
+                FROM sale s
+                WHERE ?1 IS NULL
+                UNION ALL
+                SELECT s.id, s.sold_at, o.subtotal
+                FROM sale s
+                JOIN operation o ON o.sale_id = s.id
+                JOIN product p ON p.id = o.product_id
+                WHERE p.category_id IN (SELECT id FROM subtree)
+            )
+            SELECT b.idx,
+                   COUNT(DISTINCT l.sale_id) AS tickets,
+                   printf('%.2f', COALESCE(SUM(CAST(l.amount AS REAL)), 0)) AS revenue
+            FROM bucket b
+            LEFT JOIN line l ON l.sold_at >= b.start_at AND l.sold_at < b.end_at
+            GROUP BY b.idx
+            ORDER BY b.idx
+            "#
+        ))
+        .bind(category_id)
+        .bind(ranges_json)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to get sales totals: {}", e))?;
+
+        rows.iter()
+            .map(|row| {
+                let tickets: i64 = row.try_get("tickets")?;
+                Ok((tickets as usize, parse_decimal_from_row(row, "revenue")?))
+            })
+            .collect::<Result<_, sqlx::Error>>()
+            .map_err(|e| format!("Failed to read sales totals: {}", e))
+    }
+
+    async fn last_sold_per_product(&self) -> Result<Vec<(String, DateTime<Utc>)>, String> {
+        use crate::utils::db_parsing::parse_datetime_from_row;
+        use sqlx::Row;
+
+        let rows = sqlx::query(
+            r#"
+            SELECT o.product_id, MAX(s.sold_at) AS last_sold_at
+            FROM operation o
+            JOIN sale s ON s.id = o.sale_id
+            GROUP BY o.product_id
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to get last sales: {}", e))?;
+
+        rows.iter()
+            .map(|row| {
+                Ok((
+                    row.try_get("product_id")?,
+                    parse_datetime_from_row(row, "last_sold_at")?,
+                ))
+            })
+            .collect::<Result<_, sqlx::Error>>()
+            .map_err(|e| format!("Failed to read last sales: {}", e))
+    }
+
+    async fn search_paginated(
+        &self,
+        filter: &SaleFilter,
+        clock: &StoreClock,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PaginatedResult<Sale>, String> {
+        use crate::utils::db_parsing::{calculate_offset, format_like_pattern};
+
+        let (start, end) = filter.sold_at_bounds(clock);
+        let min_total = filter.min_total.map(|amount| amount.to_string());
+        let max_total = filter.max_total.map(|amount| amount.to_string());
+        let is_loan = filter.payment.is_loan();
+        let product = filter.product.trim();
+        let product_term = (!product.is_empty()).then(|| format_like_pattern(product));
+
+        // Get total count of matching sales
+        let total_count: i64 =
+            sqlx::query_scalar(&format!("SELECT COUNT(*) FROM sale s {SALE_FILTER_WHERE}"))
+                .bind(&start)
+                .bind(&end)
+                .bind(&min_total)
+                .bind(&max_total)
+                .bind(is_loan)
+                .bind(&product_term)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|e| format!("Failed to count sales: {}", e))?;
+
+        // Get the requested page
+        let sales = sqlx::query_as::<_, Sale>(&format!(
+            r#"
+            SELECT s.* FROM sale s
+            {SALE_FILTER_WHERE}
+            ORDER BY s.sold_at DESC
+            LIMIT ?7 OFFSET ?8
+            "#
+        ))
+        .bind(&start)
+        .bind(&end)
+        .bind(&min_total)
+        .bind(&max_total)
+        .bind(is_loan)
+        .bind(&product_term)
+        .bind(page_size)
+        .bind(calculate_offset(page, page_size))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to search sales: {}", e))?;
+
+        Ok(PaginatedResult {
+            items: sales,
+            total_count,
+            page,
+            page_size,
+        })
+    }
+
+    async fn units_sold_since(&self, since: DateTime<Utc>) -> Result<Vec<(String, f64)>, String> {
+        // Kit sales count toward the components they deducted. Sales made
+        // before those were recorded fall back to the kit's current components.
+        let totals: Vec<(String, f64)> = sqlx::query_as(
+            r#"
+            SELECT product_id, SUM(quantity) FROM (
+                SELECT o.product_id, o.quantity
+                FROM operation o
+                JOIN sale s ON s.id = o.sale_id
+                WHERE s.sold_at >= ?1
+                UNION ALL
+                SELECT oc.component_id, oc.quantity
+                FROM operation_component oc
+                JOIN operation o ON o.id = oc.operation_id
+                JOIN sale s ON s.id = o.sale_id
+                WHERE s.sold_at >= ?1
+                UNION ALL
+                SELECT pc.component_id, o.quantity * pc.quantity
+                FROM operation o
+                JOIN sale s ON s.id = o.sale_id
+                JOIN product_component pc ON pc.kit_id = o.product_id
+                WHERE s.sold_at >= ?1
+                  AND NOT EXISTS (
+                      SELECT 1 FROM operation_component oc WHERE oc.operation_id = o.id
+                  )
+            )
+            GROUP BY product_id
+            "#,
+        )
+        .bind(since.to_rfc3339())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to get units sold: {}", e))?;
+
+        Ok(totals)
+    }
 }
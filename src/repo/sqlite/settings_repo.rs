@@ -0,0 +1,44 @@
+//! SQLite Settings Repository Implementation
+
+use crate::repo::SettingsRepository;
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+pub struct SqliteSettingsRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteSettingsRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SettingsRepository for SqliteSettingsRepository {
+    async fn get(&self, key: &str) -> Result<Option<String>, String> {
+        let value = sqlx::query_scalar::<_, String>("SELECT value FROM app_setting WHERE key = ?")
+            .bind(key)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to get setting {}: {}", key, e))?;
+
+        Ok(value)
+    }
+
+    async fn set(&self, key: &str, value: &str) -> Result<(), String> {
+        sqlx::query(
+            r#"
+            INSERT INTO app_setting (key, value) VALUES (?, ?)
+            ON CONFLICT(key) DO UPDATE SET value = excluded.value
+            "#,
+        )
+        .bind(key)
+        .bind(value)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to save setting {}: {}", key, e))?;
+
+        Ok(())
+    }
+}
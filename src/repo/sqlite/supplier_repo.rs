@@ -0,0 +1,104 @@
+//! SQLite Supplier Repository Implementation
+
+use crate::models::{Supplier, SupplierInput};
+use crate::repo::SupplierRepository;
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+pub struct SqliteSupplierRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteSupplierRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_by_id(&self, id: &str) -> Result<Option<Supplier>, String> {
+        let supplier = sqlx::query_as::<_, Supplier>("SELECT * FROM supplier WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to get supplier by id: {}", e))?;
+
+        Ok(supplier)
+    }
+}
+
+#[async_trait]
+impl SupplierRepository for SqliteSupplierRepository {
+    async fn create(&self, input: SupplierInput) -> Result<Supplier, String> {
+        let supplier = input.to_supplier();
+
+        sqlx::query(
+            r#"
+            INSERT INTO supplier (id, name, phone, lead_time_days, created_at)
+            VALUES (?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&supplier.id)
+        .bind(&supplier.name)
+        .bind(&supplier.phone)
+        .bind(supplier.lead_time_days)
+        .bind(supplier.created_at.to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to create supplier: {}", e))?;
+
+        Ok(supplier)
+    }
+
+    async fn list_all(&self) -> Result<Vec<Supplier>, String> {
+        let suppliers = sqlx::query_as::<_, Supplier>("SELECT * FROM supplier ORDER BY name")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to list suppliers: {}", e))?;
+
+        Ok(suppliers)
+    }
+
+    async fn update(&self, id: &str, input: SupplierInput) -> Result<Supplier, String> {
+        sqlx::query("UPDATE supplier SET name = ?, phone = ?, lead_time_days = ? WHERE id = ?")
+            .bind(&input.name)
+            .bind(&input.phone)
+            .bind(input.lead_time_days)
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to update supplier: {}", e))?;
+
+        // Fetch and return updated supplier
+        self.get_by_id(id)
+            .await?
+            .ok_or_else(|| format!("Supplier not found after update: {}", id))
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), String> {
+        // Start transaction
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        // Unassign products so the foreign key is not violated
+        sqlx::query("UPDATE product SET supplier_id = NULL WHERE supplier_id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("Failed to unassign supplier products: {}", e))?;
+
+        sqlx::query("DELETE FROM supplier WHERE id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("Failed to delete supplier: {}", e))?;
+
+        // Commit transaction
+        tx.commit()
+            .await
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@
 
 use crate::models::*;
 use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
 
 /// Pagination result wrapper
 #[derive(Debug, Clone)]
@@ -20,66 +21,408 @@ pub trait ProductRepository: Send + Sync {
     /// Create a new product
     async fn create(&self, input: ProductInput) -> Result<Product, String>;
 
-    /// Get product by ID
+    /// Get product by ID, including archived products
     async fn get_by_id(&self, id: &str) -> Result<Option<Product>, String>;
 
-    /// Get product by barcode
+    /// Get product by its own or any additional barcode, including archived products
     async fn get_by_barcode(&self, barcode: &str) -> Result<Option<Product>, String>;
 
-    /// List all products
+    /// Resolve a scanned barcode to its product and pack quantity
+    async fn resolve_barcode(&self, barcode: &str) -> Result<Option<BarcodeMatch>, String>;
+
+    /// List additional barcodes of a product
+    async fn list_barcodes(&self, product_id: &str) -> Result<Vec<ProductBarcode>, String>;
+
+    /// Add an additional barcode to a product
+    async fn add_barcode(
+        &self,
+        product_id: &str,
+        input: ProductBarcodeInput,
+    ) -> Result<ProductBarcode, String>;
+
+    /// Highest barcode starting with a prefix, across product and additional barcodes
+    async fn last_barcode_with_prefix(&self, prefix: &str) -> Result<Option<String>, String>;
+
+    /// Set the barcode of products that have none, as (product_id, barcode)
+    async fn assign_barcodes(&self, assignments: Vec<(String, String)>) -> Result<(), String>;
+
+    /// Remove an additional barcode
+    async fn remove_barcode(&self, barcode: &str) -> Result<(), String>;
+
+    /// List all active products
     async fn list_all(&self) -> Result<Vec<Product>, String>;
 
-    /// List products with pagination
+    /// List active products with pagination
     async fn list_paginated(
         &self,
         page: i64,
         page_size: i64,
     ) -> Result<PaginatedResult<Product>, String>;
 
-    /// Update product
+    /// Update product, recording a stock change as a count correction
     async fn update(&self, id: &str, input: ProductInput) -> Result<Product, String>;
 
-    /// Delete product
+    /// Permanently delete product
     async fn delete(&self, id: &str) -> Result<(), String>;
 
-    /// Update stock amount
-    async fn update_stock(&self, id: &str, new_amount: f64) -> Result<(), String>;
+    /// Archive product, hiding it from sales and product lists
+    async fn archive(&self, id: &str) -> Result<(), String>;
+
+    /// Restore an archived product
+    async fn restore(&self, id: &str) -> Result<(), String>;
 
-    /// Search products by name or barcode
+    /// List archived products
+    async fn list_archived(&self) -> Result<Vec<Product>, String>;
+
+    /// Check if the product appears in sales or stock adjustments
+    async fn has_history(&self, id: &str) -> Result<bool, String>;
+
+    /// Search active products by name or barcode
     async fn search(&self, query: &str) -> Result<Vec<Product>, String>;
 
-    /// Search products with pagination
+    /// Search active products with pagination, optionally restricted to a category
+    /// and its subcategories
     async fn search_paginated(
         &self,
         query: &str,
+        category_id: Option<&str>,
         page: i64,
         page_size: i64,
     ) -> Result<PaginatedResult<Product>, String>;
 
-    /// Get low stock products
+    /// List active products in a category and its subcategories
+    async fn list_by_category(&self, category_id: &str) -> Result<Vec<Product>, String>;
+
+    /// Get active low stock products
     async fn get_low_stock(&self) -> Result<Vec<Product>, String>;
+
+    /// Apply price changes and record them in the price history atomically
+    async fn update_prices(&self, entries: Vec<PriceHistoryEntry>) -> Result<(), String>;
+
+    /// List a product's price and cost changes, newest first
+    async fn list_price_history(&self, product_id: &str) -> Result<Vec<PriceHistoryEntry>, String>;
+
+    /// List the components of a kit
+    async fn list_components(&self, kit_id: &str) -> Result<Vec<KitComponent>, String>;
+
+    /// Add a component to a kit or change its quantity, making the product a kit
+    async fn set_component(&self, kit_id: &str, input: KitComponentInput) -> Result<(), String>;
+
+    /// Remove a component from a kit
+    async fn remove_component(&self, kit_id: &str, component_id: &str) -> Result<(), String>;
+
+    /// Check whether a product is a component of any kit
+    async fn is_component(&self, id: &str) -> Result<bool, String>;
+}
+
+/// Category repository trait
+#[async_trait]
+pub trait CategoryRepository: Send + Sync {
+    /// Create a new category
+    async fn create(&self, input: CategoryInput) -> Result<Category, String>;
+
+    /// Get category by ID
+    async fn get_by_id(&self, id: &str) -> Result<Option<Category>, String>;
+
+    /// List all categories
+    async fn list_all(&self) -> Result<Vec<Category>, String>;
+
+    /// Update category
+    async fn update(&self, id: &str, input: CategoryInput) -> Result<Category, String>;
+
+    /// Delete category, leaving its products uncategorized
+    async fn delete(&self, id: &str) -> Result<(), String>;
+}
+
+/// Supplier repository trait
+#[async_trait]
+pub trait SupplierRepository: Send + Sync {
+    /// Create a new supplier
+    async fn create(&self, input: SupplierInput) -> Result<Supplier, String>;
+
+    /// List all suppliers
+    async fn list_all(&self) -> Result<Vec<Supplier>, String>;
+
+    /// Update supplier
+    async fn update(&self, id: &str, input: SupplierInput) -> Result<Supplier, String>;
+
+    /// Delete supplier, leaving its products without supplier
+    async fn delete(&self, id: &str) -> Result<(), String>;
+}
+
+/// Customer repository trait
+#[async_trait]
+pub trait CustomerRepository: Send + Sync {
+    /// Create a new customer
+    async fn create(&self, input: CustomerInput) -> Result<Customer, String>;
+
+    /// Get customer by ID
+    async fn get_by_id(&self, id: &str) -> Result<Option<Customer>, String>;
+
+    /// List all customers
+    async fn list_all(&self) -> Result<Vec<Customer>, String>;
+
+    /// Update customer
+    async fn update(&self, id: &str, input: CustomerInput) -> Result<Customer, String>;
+}
+
+/// Price list and quantity tier repository trait
+#[async_trait]
+pub trait PriceListRepository: Send + Sync {
+    /// List all price lists
+    async fn list_all(&self) -> Result<Vec<PriceList>, String>;
+
+    /// Get price list by ID
+    async fn get_by_id(&self, id: &str) -> Result<Option<PriceList>, String>;
+
+    /// Create a new price list
+    async fn create(&self, input: PriceListInput) -> Result<PriceList, String>;
+
+    /// Update price list
+    async fn update(&self, id: &str, input: PriceListInput) -> Result<PriceList, String>;
+
+    /// Delete a price list, its customers go back to regular prices
+    async fn delete(&self, id: &str) -> Result<(), String>;
+
+    /// Product prices on a list, by product name
+    async fn list_items(&self, price_list_id: &str) -> Result<Vec<PriceListItem>, String>;
+
+    /// Set the price of a product on a list
+    async fn set_item_price(
+        &self,
+        price_list_id: &str,
+        product_id: &str,
+        price: rust_decimal::Decimal,
+    ) -> Result<(), String>;
+
+    /// Remove a product from a list
+    async fn remove_item(&self, price_list_id: &str, product_id: &str) -> Result<(), String>;
+
+    /// Quantity tiers of the given products, by product and quantity
+    async fn list_tiers(&self, product_ids: &[String]) -> Result<Vec<PriceTier>, String>;
+
+    /// Quantity tiers of all products
+    async fn list_all_tiers(&self) -> Result<Vec<PriceTier>, String>;
+
+    /// Replace the quantity tiers of a product
+    async fn set_tiers(&self, product_id: &str, tiers: &[PriceTier]) -> Result<(), String>;
+}
+
+/// Loyalty points ledger repository trait
+#[async_trait]
+pub trait LoyaltyRepository: Send + Sync {
+    /// Ledger of a customer, oldest first
+    async fn list_entries(&self, customer_id: &str) -> Result<Vec<PointsEntry>, String>;
+}
+
+/// Application settings repository trait
+#[async_trait]
+pub trait SettingsRepository: Send + Sync {
+    /// Get a setting value, None when it was never set
+    async fn get(&self, key: &str) -> Result<Option<String>, String>;
+
+    /// Set a setting value
+    async fn set(&self, key: &str, value: &str) -> Result<(), String>;
+}
+
+/// Purchase order repository trait
+#[async_trait]
+pub trait PurchaseOrderRepository: Send + Sync {
+    /// Create a draft purchase order with its lines
+    async fn create(
+        &self,
+        supplier_id: Option<String>,
+        lines: Vec<PurchaseOrderLine>,
+    ) -> Result<PurchaseOrder, String>;
+
+    /// List all purchase orders, newest first
+    async fn list_all(&self) -> Result<Vec<PurchaseOrder>, String>;
+
+    /// List the lines of a purchase order
+    async fn list_lines(&self, order_id: &str) -> Result<Vec<PurchaseOrderLine>, String>;
+
+    /// Delete a purchase order
+    async fn delete(&self, id: &str) -> Result<(), String>;
+}
+
+/// Product lot repository trait
+#[async_trait]
+pub trait LotRepository: Send + Sync {
+    /// Record a received lot and add its quantity to the product's stock,
+    /// applying the new cost from the receipt when there is one
+    async fn receive(
+        &self,
+        product_id: &str,
+        quantity: f64,
+        lot_code: Option<String>,
+        expires_at: Option<NaiveDate>,
+        price_change: Option<PriceHistoryEntry>,
+    ) -> Result<ProductLot, String>;
+
+    /// Get lot by ID
+    async fn get_by_id(&self, id: &str) -> Result<Option<ProductLot>, String>;
+
+    /// List all lots of a product, soonest expiry first
+    async fn list_by_product(&self, product_id: &str) -> Result<Vec<ProductLot>, String>;
+
+    /// List sellable lots expiring on or before the given date
+    async fn list_expiring(&self, until: NaiveDate) -> Result<Vec<ExpiringLot>, String>;
+
+    /// List lots received in [start, end), oldest first
+    ///
+    /// Lots split off by a condition change keep the original receipt time,
+    /// so only sellable lots are returned.
+    async fn list_received_between(
+        &self,
+        start: &str,
+        end: &str,
+    ) -> Result<Vec<ExpiringLot>, String>;
+
+    /// Move part of a lot to another condition, removing it from sellable stock
+    ///
+    /// Returns the new lot holding the moved quantity. The write-off is
+    /// recorded as `adjustment` in the same transaction.
+    async fn move_to_condition(
+        &self,
+        lot_id: &str,
+        quantity: f64,
+        condition_id: i32,
+        adjustment: StockAdjustment,
+    ) -> Result<ProductLot, String>;
+}
+
+/// Stock adjustment repository trait
+#[async_trait]
+pub trait AdjustmentRepository: Send + Sync {
+    /// Record an adjustment and apply its quantity to the product's stock
+    async fn create(&self, adjustment: StockAdjustment) -> Result<StockAdjustment, String>;
+
+    /// List adjustments from `start` up to the exclusive `end` (RFC3339 bounds), newest first
+    async fn list_by_date_range(
+        &self,
+        start: &str,
+        end: &str,
+    ) -> Result<Vec<StockAdjustment>, String>;
+}
+
+/// Inventory count repository trait
+#[async_trait]
+pub trait InventoryCountRepository: Send + Sync {
+    /// Start a count, freezing the current stock of every product
+    async fn create(&self, name: &str) -> Result<InventoryCount, String>;
+
+    /// Get count by ID
+    async fn get_by_id(&self, id: &str) -> Result<Option<InventoryCount>, String>;
+
+    /// List all counts, newest first
+    async fn list_all(&self) -> Result<Vec<InventoryCount>, String>;
+
+    /// List the lines of a count, ordered by product name
+    async fn list_lines(&self, count_id: &str) -> Result<Vec<InventoryCountLine>, String>;
+
+    /// Get the line of a product in a count
+    async fn get_line(
+        &self,
+        count_id: &str,
+        product_id: &str,
+    ) -> Result<Option<InventoryCountLine>, String>;
+
+    /// Set or clear the counted quantity of a line
+    async fn set_counted(
+        &self,
+        count_id: &str,
+        product_id: &str,
+        counted_quantity: Option<f64>,
+    ) -> Result<InventoryCountLine, String>;
+
+    /// Approve or reject posting a line's variance
+    async fn set_approved(
+        &self,
+        count_id: &str,
+        product_id: &str,
+        approved: bool,
+    ) -> Result<(), String>;
+
+    /// Apply the count's adjustments and close it, all in one transaction
+    async fn post(&self, count_id: &str, adjustments: Vec<StockAdjustment>) -> Result<(), String>;
+
+    /// Delete an open count and its lines
+    async fn delete(&self, id: &str) -> Result<(), String>;
 }
 
 /// Sale repository trait
 #[async_trait]
 pub trait SaleRepository: Send + Sync {
-    /// Create a new sale with operations
-    async fn create(&self, input: SaleInput) -> Result<Sale, String>;
+    /// Create a new sale with operations and the loyalty points it moves,
+    /// made on the given business day
+    async fn create(
+        &self,
+        input: SaleInput,
+        points: &[PointsEntry],
+        sold_on: NaiveDate,
+    ) -> Result<Sale, String>;
 
     /// Get sale by ID
     async fn get_by_id(&self, id: &str) -> Result<Option<Sale>, String>;
 
+    /// Tax profile captured at checkout, if the customer asked for an invoice
+    async fn get_tax_profile(&self, sale_id: &str) -> Result<Option<InvoiceReceiver>, String>;
+
     /// List all sales
     async fn list_all(&self) -> Result<Vec<Sale>, String>;
 
     /// Get operations for a sale
     async fn get_operations(&self, sale_id: &str) -> Result<Vec<Operation>, String>;
 
-    /// List sales within date range
+    /// List sales from `start` up to the exclusive `end`
     async fn list_by_date_range(&self, start: &str, end: &str) -> Result<Vec<Sale>, String>;
 
     /// Get sales for a specific customer (by debtor name)
     async fn get_by_customer(&self, customer_name: &str) -> Result<Vec<Sale>, String>;
+
+    /// Get operations of sales made in [start, end), oldest first, optionally
+    /// only those of a category's products (including subcategories)
+    async fn operations_between(
+        &self,
+        start: &str,
+        end: &str,
+        category_id: Option<&str>,
+    ) -> Result<Vec<Operation>, String>;
+
+    /// Units and revenue per product sold in [start, end), best sellers first,
+    /// optionally only for a category (including subcategories)
+    async fn product_sales_between(
+        &self,
+        start: &str,
+        end: &str,
+        category_id: Option<&str>,
+    ) -> Result<Vec<ProductSales>, String>;
+
+    /// Tickets and revenue within each [start, end) range, in the order given.
+    /// With a category, only sales of its products (including subcategories)
+    /// count, for the revenue of those lines.
+    async fn totals_by_range(
+        &self,
+        ranges: &[(String, String)],
+        category_id: Option<&str>,
+    ) -> Result<Vec<(usize, rust_decimal::Decimal)>, String>;
+
+    /// Most recent sale time of every product ever sold, as (product_id, sold_at)
+    async fn last_sold_per_product(&self) -> Result<Vec<(String, DateTime<Utc>)>, String>;
+
+    /// Search sales with history filters and pagination, newest first
+    async fn search_paginated(
+        &self,
+        filter: &SaleFilter,
+        clock: &StoreClock,
+        page: i64,
+        page_size: i64,
+    ) -> Result<PaginatedResult<Sale>, String>;
+
+    /// Total quantity sold per product since a moment, as (product_id, quantity)
+    ///
+    /// Kit sales also count toward the components they deducted when sold.
+    async fn units_sold_since(&self, since: DateTime<Utc>) -> Result<Vec<(String, f64)>, String>;
 }
 
 /// Loan repository trait
@@ -105,6 +448,9 @@ pub trait LoanRepository: Send + Sync {
     /// Record a payment
     async fn record_payment(&self, input: LoanPaymentInput) -> Result<LoanPayment, String>;
 
+    /// Get payments received in [start, end), oldest first
+    async fn payments_between(&self, start: &str, end: &str) -> Result<Vec<LoanPayment>, String>;
+
     /// Get payments for a loan
     async fn get_payments(&self, loan_id: &str) -> Result<Vec<LoanPayment>, String>;
 
@@ -132,9 +478,59 @@ pub trait CatalogRepository: Send + Sync {
     /// Get all unit measurements
     async fn get_units(&self) -> Result<Vec<UnitMeasurement>, String>;
 
+    /// Get a unit measurement by ID
+    async fn get_unit(&self, id: i32) -> Result<Option<UnitMeasurement>, String>;
+
+    /// Create a unit measurement
+    async fn create_unit(&self, input: UnitMeasurementInput) -> Result<UnitMeasurement, String>;
+
+    /// Update a unit measurement
+    async fn update_unit(
+        &self,
+        id: i32,
+        input: UnitMeasurementInput,
+    ) -> Result<UnitMeasurement, String>;
+
+    /// Delete a unit measurement
+    async fn delete_unit(&self, id: i32) -> Result<(), String>;
+
+    /// Count products (archived included) using a unit as sale or purchase unit
+    async fn count_unit_products(&self, id: i32) -> Result<i64, String>;
+
     /// Get all item conditions
     async fn get_conditions(&self) -> Result<Vec<ItemCondition>, String>;
 
     /// Get all loan statuses
     async fn get_loan_statuses(&self) -> Result<Vec<StatusLoan>, String>;
+
+    /// Get all stock adjustment reasons
+    async fn get_adjustment_reasons(&self) -> Result<Vec<AdjustmentReason>, String>;
+
+    /// Get all price change sources
+    async fn get_price_change_sources(&self) -> Result<Vec<PriceChangeSource>, String>;
+}
+
+/// Invoice repository trait
+#[async_trait]
+pub trait InvoiceRepository: Send + Sync {
+    /// Get the SAT codes of a product, if set
+    async fn get_tax_code(&self, product_id: &str) -> Result<Option<ProductTaxCode>, String>;
+
+    /// Get the SAT codes set for any of the given products
+    async fn list_tax_codes(&self, product_ids: &[String]) -> Result<Vec<ProductTaxCode>, String>;
+
+    /// Save the SAT codes of a product
+    async fn set_tax_code(&self, code: ProductTaxCode) -> Result<(), String>;
+
+    /// Next folio of a series
+    async fn next_folio(&self, series: &str) -> Result<i64, String>;
+
+    /// Save a stamped invoice and mark its sales as invoiced
+    async fn create(&self, record: &InvoiceRecord, sale_ids: &[String]) -> Result<(), String>;
+
+    /// Get the invoice that includes a sale
+    async fn get_by_sale(&self, sale_id: &str) -> Result<Option<InvoiceRecord>, String>;
+
+    /// Which of the given sales are already invoiced
+    async fn invoiced_sale_ids(&self, sale_ids: &[String]) -> Result<Vec<String>, String>;
 }
@@ -0,0 +1,349 @@
+//! CFDI XML Utilities
+//!
+//! Writing invoices as CFDI 4.0 XML (Anexo 20). The document is left unsealed
+//! (`Sello` empty); the PAC seals and stamps it.
+
+use crate::models::invoice::TaxLine;
+use crate::models::{Invoice, InvoiceIssuer, StoreClock, TaxTreatment};
+use rust_decimal::Decimal;
+use std::fmt::Write;
+
+const CFDI_NAMESPACE: &str = "http://www.sat.gob.mx/cfd/4";
+const CFDI_SCHEMA: &str = "http://www.sat.gob.mx/sitio_internet/cfd/4/cfdv40.xsd";
+
+/// IVA in the SAT tax catalog (c_Impuesto)
+const IVA: &str = "002";
+
+/// Write an invoice as CFDI 4.0 XML
+pub fn write_cfdi(invoice: &Invoice, issuer: &InvoiceIssuer, clock: &StoreClock) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let tax_totals = invoice.tax_totals();
+
+    let _ = write!(
+        xml,
+        "<cfdi:Comprobante xmlns:cfdi=\"{}\" xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" xsi:schemaLocation=\"{} {}\"",
+        CFDI_NAMESPACE, CFDI_NAMESPACE, CFDI_SCHEMA
+    );
+    attributes(
+        &mut xml,
+        &[
+            ("Version", "4.0".to_string()),
+            ("Serie", invoice.series.clone()),
+            ("Folio", invoice.folio.to_string()),
+            (
+                "Fecha",
+                clock.format(&invoice.issued_at, "%Y-%m-%dT%H:%M:%S"),
+            ),
+            ("Sello", String::new()),
+            ("FormaPago", invoice.payment_form.clone()),
+            ("NoCertificado", issuer.certificate_number.clone()),
+            ("Certificado", String::new()),
+            ("SubTotal", money(invoice.subtotal())),
+            ("Moneda", "MXN".to_string()),
+            ("Total", money(invoice.total())),
+            ("TipoDeComprobante", "I".to_string()),
+            ("Exportacion", "01".to_string()),
+            ("MetodoPago", invoice.payment_method.code().to_string()),
+            ("LugarExpedicion", issuer.zip_code.clone()),
+        ],
+    );
+    xml.push_str(">\n");
+
+    if let Some(day) = invoice.global_day {
+        element(
+            &mut xml,
+            1,
+            "cfdi:InformacionGlobal",
+            &[
+                ("Periodicidad", "01".to_string()), // Diario
+                ("Meses", day.format("%m").to_string()),
+                ("Año", day.format("%Y").to_string()),
+            ],
+        );
+    }
+
+    element(
+        &mut xml,
+        1,
+        "cfdi:Emisor",
+        &[
+            ("Rfc", issuer.rfc.clone()),
+            ("Nombre", issuer.legal_name.clone()),
+            ("RegimenFiscal", issuer.tax_regime.clone()),
+        ],
+    );
+
+    let receiver = &invoice.receiver;
+    element(
+        &mut xml,
+        1,
+        "cfdi:Receptor",
+        &[
+            ("Rfc", receiver.rfc.clone()),
+            ("Nombre", receiver.legal_name.clone()),
+            ("DomicilioFiscalReceptor", receiver.zip_code.clone()),
+            ("RegimenFiscalReceptor", receiver.tax_regime.clone()),
+            ("UsoCFDI", receiver.cfdi_use.clone()),
+        ],
+    );
+
+    xml.push_str("  <cfdi:Conceptos>\n");
+    for concept in &invoice.concepts {
+        xml.push_str("    <cfdi:Concepto");
+        attributes(
+            &mut xml,
+            &[
+                ("ClaveProdServ", concept.product_code.clone()),
+                ("NoIdentificacion", concept.identification.clone()),
+                ("Cantidad", concept.quantity.normalize().to_string()),
+                ("ClaveUnidad", concept.unit_code.clone()),
+                ("Descripcion", concept.description.clone()),
+                ("ValorUnitario", unit_value(concept.unit_value)),
+                ("Importe", money(concept.amount)),
+                ("ObjetoImp", "02".to_string()), // Sí objeto de impuesto
+            ],
+        );
+        xml.push_str(">\n      <cfdi:Impuestos>\n        <cfdi:Traslados>\n");
+        for tax in &concept.taxes {
+            element(&mut xml, 5, "cfdi:Traslado", &transfer(tax));
+        }
+        xml.push_str("        </cfdi:Traslados>\n      </cfdi:Impuestos>\n    </cfdi:Concepto>\n");
+    }
+    xml.push_str("  </cfdi:Conceptos>\n");
+
+    // Exempt lines have no tax amount, so the total is omitted when all are exempt
+    xml.push_str("  <cfdi:Impuestos");
+    if tax_totals
+        .iter()
+        .any(|t| t.treatment != TaxTreatment::Exempt)
+    {
+        attributes(
+            &mut xml,
+            &[("TotalImpuestosTrasladados", money(invoice.total_taxes()))],
+        );
+    }
+    xml.push_str(">\n    <cfdi:Traslados>\n");
+    for tax in &tax_totals {
+        element(&mut xml, 3, "cfdi:Traslado", &transfer(tax));
+    }
+    xml.push_str("    </cfdi:Traslados>\n  </cfdi:Impuestos>\n");
+
+    xml.push_str("</cfdi:Comprobante>\n");
+    xml
+}
+
+/// Add a complement (such as the stamp) to a CFDI document
+pub fn insert_complement(xml: &str, complement: &str) -> Result<String, String> {
+    let closing = "</cfdi:Comprobante>";
+    let position = xml
+        .rfind(closing)
+        .ok_or_else(|| "Failed to add complement: not a CFDI document".to_string())?;
+
+    let mut stamped = String::with_capacity(xml.len() + complement.len() + 40);
+    stamped.push_str(&xml[..position]);
+    stamped.push_str("  <cfdi:Complemento>\n    ");
+    stamped.push_str(complement);
+    stamped.push_str("\n  </cfdi:Complemento>\n");
+    stamped.push_str(&xml[position..]);
+    Ok(stamped)
+}
+
+/// Value of the first attribute with the given name, such as the stamp's "SelloSAT"
+pub fn xml_attribute(xml: &str, name: &str) -> Option<String> {
+    let marker = format!(" {}=\"", name);
+    let start = xml.find(&marker)? + marker.len();
+    let end = start + xml[start..].find('"')?;
+    Some(unescape_xml(&xml[start..end]))
+}
+
+/// Escape text for an XML attribute value
+pub fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn unescape_xml(value: &str) -> String {
+    value
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+/// Attributes of a tax transfer (Traslado)
+fn transfer(tax: &TaxLine) -> Vec<(&'static str, String)> {
+    let mut attrs = vec![("Base", money(tax.base)), ("Impuesto", IVA.to_string())];
+    match tax.treatment.rate() {
+        Some(rate) => {
+            attrs.push(("TipoFactor", "Tasa".to_string()));
+            attrs.push(("TasaOCuota", format!("{:.6}", rate)));
+            attrs.push(("Importe", money(tax.amount)));
+        }
+        None => attrs.push(("TipoFactor", "Exento".to_string())),
+    }
+    attrs
+}
+
+fn element(xml: &mut String, depth: usize, name: &str, attrs: &[(&str, String)]) {
+    let _ = write!(xml, "{}<{}", "  ".repeat(depth), name);
+    attributes(xml, attrs);
+    xml.push_str("/>\n");
+}
+
+fn attributes(xml: &mut String, attrs: &[(&str, String)]) {
+    for (name, value) in attrs {
+        let _ = write!(xml, " {}=\"{}\"", name, escape_xml(value));
+    }
+}
+
+fn money(amount: Decimal) -> String {
+    format!("{:.2}", amount)
+}
+
+/// Unit values keep up to six decimals, trailing zeros trimmed past cents
+fn unit_value(amount: Decimal) -> String {
+    let value = amount.round_dp(6).normalize();
+    if value.scale() < 2 {
+        money(value)
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::invoice::{InvoiceConcept, PaymentMethod};
+    use crate::models::InvoiceReceiver;
+    use chrono::{DateTime, NaiveDate, Utc};
+    use rust_decimal_macros::dec;
+
+    fn issuer() -> InvoiceIssuer {
+        InvoiceIssuer {
+            rfc: "EKU9003173C9".to_string(),
+            legal_name: "ESCUELA KEMPER URGATE".to_string(),
+            tax_regime: "601".to_string(),
+            zip_code: "42501".to_string(),
+            series: "A".to_string(),
+            certificate_number: "30001000000500003416".to_string(),
+        }
+    }
+
+    fn invoice(treatments: &[TaxTreatment]) -> Invoice {
+        let concepts = treatments
+            .iter()
+            .map(|treatment| {
+                let (base, tax) = treatment.split(dec!(116.00));
+                InvoiceConcept {
+                    product_code: "50202306".to_string(),
+                    identification: "p1".to_string(),
+                    quantity: dec!(2),
+                    unit_code: "H87".to_string(),
+                    description: "Refresco <2L> & \"light\"".to_string(),
+                    unit_value: (base / dec!(2)).round_dp(6),
+                    amount: base,
+                    taxes: vec![TaxLine {
+                        treatment: *treatment,
+                        base,
+                        amount: tax,
+                    }],
+                }
+            })
+            .collect();
+
+        Invoice {
+            series: "A".to_string(),
+            folio: 7,
+            issued_at: DateTime::parse_from_rfc3339("2026-10-18T20:15:00+00:00")
+                .unwrap()
+                .with_timezone(&Utc),
+            payment_form: "01".to_string(),
+            payment_method: PaymentMethod::SinglePayment,
+            receiver: InvoiceReceiver::general_public("42501"),
+            global_day: NaiveDate::from_ymd_opt(2026, 10, 18),
+            concepts,
+        }
+    }
+
+    #[test]
+    fn test_write_cfdi_header_and_totals() {
+        let xml = write_cfdi(
+            &invoice(&[TaxTreatment::Iva16]),
+            &issuer(),
+            &StoreClock::default(),
+        );
+
+        assert!(
+            xml.contains("Version=\"4.0\" Serie=\"A\" Folio=\"7\" Fecha=\"2026-10-18T14:15:00\"")
+        );
+        assert!(xml.contains("SubTotal=\"100.00\" Moneda=\"MXN\" Total=\"116.00\""));
+        assert!(
+            xml.contains("<cfdi:InformacionGlobal Periodicidad=\"01\" Meses=\"10\" Año=\"2026\"/>")
+        );
+        assert!(xml.contains("Descripcion=\"Refresco &lt;2L&gt; &amp; &quot;light&quot;\""));
+        assert!(xml.contains("Cantidad=\"2\" ClaveUnidad=\"H87\""));
+        assert!(xml.contains("ValorUnitario=\"50.00\""));
+        assert!(xml.contains(
+            "<cfdi:Traslado Base=\"100.00\" Impuesto=\"002\" TipoFactor=\"Tasa\" TasaOCuota=\"0.160000\" Importe=\"16.00\"/>"
+        ));
+        assert!(xml.contains("<cfdi:Impuestos TotalImpuestosTrasladados=\"16.00\">"));
+        assert!(xml.ends_with("</cfdi:Comprobante>\n"));
+    }
+
+    #[test]
+    fn test_exempt_lines_have_no_rate_or_total() {
+        let xml = write_cfdi(
+            &invoice(&[TaxTreatment::Exempt]),
+            &issuer(),
+            &StoreClock::default(),
+        );
+
+        assert!(
+            xml.contains("<cfdi:Traslado Base=\"116.00\" Impuesto=\"002\" TipoFactor=\"Exento\"/>")
+        );
+        assert!(xml.contains("  <cfdi:Impuestos>\n"));
+        assert!(!xml.contains("TotalImpuestosTrasladados"));
+    }
+
+    #[test]
+    fn test_insert_complement() {
+        let xml = write_cfdi(
+            &invoice(&[TaxTreatment::Iva0]),
+            &issuer(),
+            &StoreClock::default(),
+        );
+        let stamped = insert_complement(&xml, "<tfd:TimbreFiscalDigital/>").unwrap();
+
+        assert!(stamped.contains(
+            "  <cfdi:Complemento>\n    <tfd:TimbreFiscalDigital/>\n  </cfdi:Complemento>\n</cfdi:Comprobante>"
+        ));
+        assert!(insert_complement("<xml/>", "<x/>").is_err());
+    }
+
+    #[test]
+    fn test_xml_attribute() {
+        let xml = write_cfdi(
+            &invoice(&[TaxTreatment::Iva16]),
+            &issuer(),
+            &StoreClock::default(),
+        );
+
+        assert_eq!(xml_attribute(&xml, "Folio").as_deref(), Some("7"));
+        assert_eq!(
+            xml_attribute(&xml, "Descripcion").as_deref(),
+            Some("Refresco <2L> & \"light\"")
+        );
+        assert_eq!(xml_attribute(&xml, "SelloSAT"), None);
+    }
+}
@@ -40,12 +40,59 @@ pub async fn initialize_database(database_url: &str) -> Result<DbPool, sqlx::Err
     Ok(pool)
 }
 
+/// Columns added to existing tables after their first release
+///
+/// `CREATE TABLE IF NOT EXISTS` leaves tables from older databases untouched,
+/// so each entry is added with `ALTER TABLE` when the column is missing.
+/// Entries are `(table, column, column definition)`.
+const COLUMN_MIGRATIONS: &[(&str, &str, &str)] = &[
+    ("product", "category_id", "TEXT REFERENCES category(id)"),
+    (
+        "product",
+        "purchase_unit_id",
+        "INTEGER REFERENCES unit_measurement(id)",
+    ),
+    ("product", "units_per_purchase", "REAL NOT NULL DEFAULT 1"),
+    ("product", "supplier_id", "TEXT REFERENCES supplier(id)"),
+    ("product", "archived_at", "TEXT"),
+    ("product", "is_kit", "INTEGER NOT NULL DEFAULT 0"),
+    ("supplier", "lead_time_days", "INTEGER NOT NULL DEFAULT 0"),
+    (
+        "customer",
+        "price_list_id",
+        "TEXT REFERENCES price_list(id) ON DELETE SET NULL",
+    ),
+    ("sale", "customer_id", "TEXT REFERENCES customer(id)"),
+    ("sale", "points_amount", "TEXT NOT NULL DEFAULT '0'"),
+    (
+        "unit_measurement",
+        "allows_fraction",
+        "INTEGER NOT NULL DEFAULT 0",
+    ),
+    ("unit_measurement", "decimals", "INTEGER NOT NULL DEFAULT 0"),
+];
+
+/// Statements run once right after a column from `COLUMN_MIGRATIONS` is added
+///
+/// Entries are `(table, column, statement)`, used to give existing rows a
+/// value other than the column default. Kilograms and liters were always sold
+/// by weight or volume.
+const COLUMN_BACKFILLS: &[(&str, &str, &str)] = &[(
+    "unit_measurement",
+    "decimals",
+    "UPDATE unit_measurement SET allows_fraction = 1, decimals = 3 WHERE id IN (1, 2)",
+)];
+
 /// Run database migrations
 ///
 /// Executes the SQL schema file to create tables and insert initial data.
 async fn run_migrations(pool: &DbPool) -> Result<(), sqlx::Error> {
     tracing::info!("Running database migrations...");
 
+    // Add new columns to tables created by older versions before the schema
+    // file runs, since it may create indexes on them
+    add_missing_columns(pool).await?;
+
     // Read the migration SQL file
     let migration_sql = include_str!("../../migrations/sqlite_schema.sql");
 
@@ -63,6 +110,47 @@ async fn run_migrations(pool: &DbPool) -> Result<(), sqlx::Error> {
     Ok(())
 }
 
+/// Add any column from `COLUMN_MIGRATIONS` missing in an existing table
+async fn add_missing_columns(pool: &DbPool) -> Result<(), sqlx::Error> {
+    for (table, column, definition) in COLUMN_MIGRATIONS {
+        let table_columns: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM pragma_table_info(?)")
+            .bind(table)
+            .fetch_one(pool)
+            .await?;
+
+        // Fresh databases get the column from the schema file
+        if table_columns == 0 {
+            continue;
+        }
+
+        let has_column: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM pragma_table_info(?) WHERE name = ?")
+                .bind(table)
+                .bind(column)
+                .fetch_one(pool)
+                .await?;
+
+        if has_column == 0 {
+            tracing::info!("Adding column {}.{}", table, column);
+            sqlx::query(&format!(
+                "ALTER TABLE {} ADD COLUMN {} {}",
+                table, column, definition
+            ))
+            .execute(pool)
+            .await?;
+
+            for (_, _, statement) in COLUMN_BACKFILLS
+                .iter()
+                .filter(|(t, c, _)| t == table && c == column)
+            {
+                sqlx::query(statement).execute(pool).await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// Get the default database URL
 ///
 /// Uses the DATA_DIR environment variable or defaults to ./data
@@ -78,6 +166,15 @@ pub fn get_database_url() -> String {
     format!("sqlite:{}", db_path.display())
 }
 
+/// Migrated database in a fresh temp file, for repository and API tests
+#[cfg(test)]
+pub async fn test_database() -> DbPool {
+    let db_path = std::env::temp_dir().join(format!("pos_test_{}.db", uuid::Uuid::new_v4()));
+    initialize_database(&format!("sqlite:{}", db_path.display()))
+        .await
+        .expect("Test database should initialize")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -100,6 +197,70 @@ mod tests {
         assert!(result.is_ok(), "Should be able to query migrated tables");
     }
 
+    #[tokio::test]
+    async fn test_missing_columns_are_added() {
+        // Simulate a database created before any column migration existed
+        let db_path = std::env::temp_dir().join(format!("pos_test_{}.db", uuid::Uuid::new_v4()));
+        let db_url = format!("sqlite:{}", db_path.display());
+
+        let legacy_pool = SqlitePool::connect_with(
+            SqliteConnectOptions::from_str(&db_url)
+                .unwrap()
+                .create_if_missing(true),
+        )
+        .await
+        .unwrap();
+        sqlx::query(
+            "CREATE TABLE product (id TEXT PRIMARY KEY, barcode TEXT UNIQUE, full_name TEXT NOT NULL, \
+             user_price TEXT NOT NULL, cost_price TEXT, min_amount REAL DEFAULT 0, \
+             current_amount REAL DEFAULT 0, unit_measurement_id INTEGER NOT NULL, \
+             created_at TEXT, updated_at TEXT)",
+        )
+        .execute(&legacy_pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "CREATE TABLE unit_measurement (id INTEGER PRIMARY KEY, description TEXT NOT NULL, \
+             abbreviation TEXT NOT NULL)",
+        )
+        .execute(&legacy_pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO unit_measurement (id, description, abbreviation) \
+             VALUES (1, 'Kilogram', 'kg'), (4, 'Piece', 'pcs')",
+        )
+        .execute(&legacy_pool)
+        .await
+        .unwrap();
+        legacy_pool.close().await;
+
+        let pool = initialize_database(&db_url)
+            .await
+            .expect("Migrations should upgrade a legacy database");
+
+        for (table, column, _) in COLUMN_MIGRATIONS {
+            let has_column: i64 =
+                sqlx::query_scalar("SELECT COUNT(*) FROM pragma_table_info(?) WHERE name = ?")
+                    .bind(table)
+                    .bind(column)
+                    .fetch_one(&pool)
+                    .await
+                    .unwrap();
+            assert_eq!(has_column, 1, "{}.{} should be added", table, column);
+        }
+
+        let fractional: Vec<i64> =
+            sqlx::query_scalar("SELECT allows_fraction FROM unit_measurement ORDER BY id")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(fractional, vec![1, 0], "Kilograms should stay fractional");
+
+        pool.close().await;
+        let _ = std::fs::remove_file(db_path);
+    }
+
     #[test]
     fn test_get_database_url() {
         let url = get_database_url();
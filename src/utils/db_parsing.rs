@@ -16,8 +16,8 @@ use std::str::FromStr;
 /// # Returns
 /// * `Ok(Decimal)` on success
 /// * `Err(sqlx::Error)` with appropriate column decode error
-pub fn parse_decimal_from_row<'r>(
-    row: &'r sqlx::sqlite::SqliteRow,
+pub fn parse_decimal_from_row(
+    row: &sqlx::sqlite::SqliteRow,
     column: &str,
 ) -> Result<Decimal, sqlx::Error> {
     let s: String = row.try_get(column)?;
@@ -37,8 +37,8 @@ pub fn parse_decimal_from_row<'r>(
 /// * `Ok(Some(Decimal))` if value exists and parses successfully
 /// * `Ok(None)` if value is NULL
 /// * `Err(sqlx::Error)` on parse failure
-pub fn parse_optional_decimal_from_row<'r>(
-    row: &'r sqlx::sqlite::SqliteRow,
+pub fn parse_optional_decimal_from_row(
+    row: &sqlx::sqlite::SqliteRow,
     column: &str,
 ) -> Result<Option<Decimal>, sqlx::Error> {
     let s: Option<String> = row.try_get(column)?;
@@ -59,8 +59,8 @@ pub fn parse_optional_decimal_from_row<'r>(
 /// # Returns
 /// * `Ok(DateTime<Utc>)` on success
 /// * `Err(sqlx::Error)` with appropriate column decode error
-pub fn parse_datetime_from_row<'r>(
-    row: &'r sqlx::sqlite::SqliteRow,
+pub fn parse_datetime_from_row(
+    row: &sqlx::sqlite::SqliteRow,
     column: &str,
 ) -> Result<DateTime<Utc>, sqlx::Error> {
     let s: String = row.try_get(column)?;
@@ -72,6 +72,29 @@ pub fn parse_datetime_from_row<'r>(
         })
 }
 
+/// Parse an optional DateTime<Utc> from a database TEXT column (RFC3339 format)
+///
+/// # Arguments
+/// * `row` - The database row
+/// * `column` - The column name
+///
+/// # Returns
+/// * `Ok(Some(DateTime<Utc>))` if value exists and parses successfully
+/// * `Ok(None)` if value is NULL
+/// * `Err(sqlx::Error)` on parse failure
+pub fn parse_optional_datetime_from_row(
+    row: &sqlx::sqlite::SqliteRow,
+    column: &str,
+) -> Result<Option<DateTime<Utc>>, sqlx::Error> {
+    let s: Option<String> = row.try_get(column)?;
+    s.map(|s| DateTime::parse_from_rfc3339(&s).map(|dt| dt.with_timezone(&Utc)))
+        .transpose()
+        .map_err(|e| sqlx::Error::ColumnDecode {
+            index: column.to_string(),
+            source: Box::new(e),
+        })
+}
+
 /// Calculate pagination offset
 ///
 /// # Arguments
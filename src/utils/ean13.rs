@@ -0,0 +1,154 @@
+//! EAN-13 Utilities
+//!
+//! Check digits, internal code generation and bar patterns for EAN-13
+//! barcodes. Internal codes use the GS1 restricted-circulation prefix 200,
+//! which is reserved for in-store use and never collides with manufacturer
+//! codes.
+
+/// Prefix of internally generated codes
+pub const INTERNAL_PREFIX: &str = "200";
+
+/// Left-hand odd parity (L) patterns for digits 0-9
+const L_CODES: [&str; 10] = [
+    "0001101", "0011001", "0010011", "0111101", "0100011", "0110001", "0101111", "0111011",
+    "0110111", "0001011",
+];
+
+/// Left-hand even parity (G) patterns for digits 0-9
+const G_CODES: [&str; 10] = [
+    "0100111", "0110011", "0011011", "0100001", "0011101", "0111001", "0000101", "0010001",
+    "0001001", "0010111",
+];
+
+/// Right-hand (R) patterns for digits 0-9
+const R_CODES: [&str; 10] = [
+    "1110010", "1100110", "1101100", "1000010", "1011100", "1001110", "1010000", "1000100",
+    "1001000", "1110100",
+];
+
+/// Parity of the six left-hand digits, selected by the first digit
+const PARITY: [&str; 10] = [
+    "LLLLLL", "LLGLGG", "LLGGLG", "LLGGGL", "LGLLGG", "LGGLLG", "LGGGLL", "LGLGLG", "LGLGGL",
+    "LGGLGL",
+];
+
+/// Check digit for the first 12 digits of an EAN-13 code
+///
+/// # Examples
+/// ```
+/// use pos_app::utils::ean13::check_digit;
+///
+/// assert_eq!(check_digit("750105530007"), Some(5));
+/// assert_eq!(check_digit("12345"), None);
+/// ```
+pub fn check_digit(digits: &str) -> Option<u32> {
+    if digits.len() != 12 {
+        return None;
+    }
+
+    let mut sum = 0;
+    for (i, c) in digits.chars().enumerate() {
+        let digit = c.to_digit(10)?;
+        sum += if i % 2 == 0 { digit } else { digit * 3 };
+    }
+
+    Some((10 - sum % 10) % 10)
+}
+
+/// Check whether a code is a valid EAN-13 (13 digits, correct check digit)
+pub fn is_valid(code: &str) -> bool {
+    code.len() == 13
+        && code.chars().all(|c| c.is_ascii_digit())
+        && check_digit(&code[..12]) == code[12..].parse().ok()
+}
+
+/// Internal code for a sequence number, e.g. 42 becomes 2000000000428
+pub fn internal_code(sequence: u64) -> Option<String> {
+    let digits = format!("{}{:09}", INTERNAL_PREFIX, sequence);
+    if digits.len() != 12 {
+        return None;
+    }
+
+    let check = check_digit(&digits)?;
+    Some(format!("{}{}", digits, check))
+}
+
+/// Sequence number of an internal code, None for other codes
+pub fn internal_sequence(code: &str) -> Option<u64> {
+    if !is_valid(code) || !code.starts_with(INTERNAL_PREFIX) {
+        return None;
+    }
+
+    code[INTERNAL_PREFIX.len()..12].parse().ok()
+}
+
+/// The 95 modules of a valid code, true for bars
+///
+/// Guards and digit patterns are laid out left to right: start guard, six
+/// left-hand digits, center guard, six right-hand digits, end guard.
+pub fn modules(code: &str) -> Option<Vec<bool>> {
+    if !is_valid(code) {
+        return None;
+    }
+
+    let digits: Vec<usize> = code
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .map(|d| d as usize)
+        .collect();
+    let parity = PARITY[digits[0]];
+
+    let mut pattern = String::from("101");
+    for (digit, parity) in digits[1..7].iter().zip(parity.chars()) {
+        pattern.push_str(if parity == 'L' {
+            L_CODES[*digit]
+        } else {
+            G_CODES[*digit]
+        });
+    }
+    pattern.push_str("01010");
+    for digit in &digits[7..] {
+        pattern.push_str(R_CODES[*digit]);
+    }
+    pattern.push_str("101");
+
+    Some(pattern.chars().map(|c| c == '1').collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_digit() {
+        assert_eq!(check_digit("400638133393"), Some(1));
+        assert_eq!(check_digit("750105530007"), Some(5));
+        assert_eq!(check_digit("40063813339A"), None);
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(is_valid("4006381333931"));
+        assert!(!is_valid("4006381333932"));
+        assert!(!is_valid("400638133393"));
+    }
+
+    #[test]
+    fn test_internal_codes() {
+        let code = internal_code(42).unwrap();
+        assert_eq!(code, "2000000000428");
+        assert!(is_valid(&code));
+        assert_eq!(internal_sequence(&code), Some(42));
+        assert_eq!(internal_sequence("4006381333931"), None);
+        assert_eq!(internal_code(1_000_000_000), None);
+    }
+
+    #[test]
+    fn test_modules() {
+        let modules = modules("4006381333931").unwrap();
+        assert_eq!(modules.len(), 95);
+        assert_eq!(&modules[..3], &[true, false, true]);
+        assert_eq!(&modules[45..50], &[false, true, false, true, false]);
+        assert!(super::modules("123").is_none());
+    }
+}
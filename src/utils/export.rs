@@ -0,0 +1,378 @@
+//! Export Utilities
+//!
+//! Writing tables of sales, loans and inventory data as CSV or XLSX. Every
+//! dataset has a fixed column schema so spreadsheets built on top of an export
+//! keep working, and money is written from its Decimal value, never rounded
+//! through a float.
+
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use rust_xlsxwriter::{Format, Workbook};
+
+/// File format of an export
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Xlsx,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 2] = [ExportFormat::Csv, ExportFormat::Xlsx];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "CSV",
+            ExportFormat::Xlsx => "Excel (XLSX)",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Xlsx => "xlsx",
+        }
+    }
+}
+
+/// Data that can be exported
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportDataset {
+    Sales,
+    Operations,
+    Loans,
+    LoanPayments,
+    StockMovements,
+    InventoryValuation,
+}
+
+impl ExportDataset {
+    pub fn label(self) -> &'static str {
+        match self {
+            ExportDataset::Sales => "Sales",
+            ExportDataset::Operations => "Sale Lines",
+            ExportDataset::Loans => "Loans",
+            ExportDataset::LoanPayments => "Loan Payments",
+            ExportDataset::StockMovements => "Stock Movements",
+            ExportDataset::InventoryValuation => "Inventory Valuation",
+        }
+    }
+
+    /// File name stem and worksheet name
+    pub fn key(self) -> &'static str {
+        match self {
+            ExportDataset::Sales => "sales",
+            ExportDataset::Operations => "sale_lines",
+            ExportDataset::Loans => "loans",
+            ExportDataset::LoanPayments => "loan_payments",
+            ExportDataset::StockMovements => "stock_movements",
+            ExportDataset::InventoryValuation => "inventory_valuation",
+        }
+    }
+
+    /// Column headers, in order; changing them breaks users' spreadsheets
+    pub fn columns(self) -> &'static [&'static str] {
+        match self {
+            ExportDataset::Sales => &[
+                "sale_id",
+                "sold_at",
+                "business_date",
+                "total_amount",
+                "paid_amount",
+                "change_amount",
+                "is_loan",
+                "points_amount",
+            ],
+            ExportDataset::Operations => &[
+                "sale_id",
+                "recorded_at",
+                "business_date",
+                "product_id",
+                "product_name",
+                "quantity",
+                "unit",
+                "unit_price",
+                "subtotal",
+            ],
+            ExportDataset::Loans => &[
+                "loan_id",
+                "created_at",
+                "business_date",
+                "debtor_name",
+                "debtor_phone",
+                "status",
+                "total_debt",
+                "paid_amount",
+                "remaining_amount",
+            ],
+            ExportDataset::LoanPayments => &[
+                "payment_id",
+                "loan_id",
+                "debtor_name",
+                "paid_at",
+                "business_date",
+                "amount",
+                "notes",
+            ],
+            ExportDataset::StockMovements => &[
+                "moved_at",
+                "business_date",
+                "product_id",
+                "product_name",
+                "movement",
+                "quantity",
+                "unit_cost",
+                "total_cost",
+                "reference",
+            ],
+            ExportDataset::InventoryValuation => &[
+                "product_id",
+                "barcode",
+                "product_name",
+                "unit",
+                "stock",
+                "cost_price",
+                "cost_value",
+                "sale_price",
+                "sale_value",
+            ],
+        }
+    }
+
+    /// Whether the dataset is filtered by date; the valuation is a snapshot of today
+    pub fn uses_date_range(self) -> bool {
+        self != ExportDataset::InventoryValuation
+    }
+}
+
+/// Single cell of an export
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExportValue {
+    Text(String),
+    Decimal(Decimal),
+    Number(f64),
+    Empty,
+}
+
+impl From<String> for ExportValue {
+    fn from(value: String) -> Self {
+        ExportValue::Text(value)
+    }
+}
+
+impl From<&str> for ExportValue {
+    fn from(value: &str) -> Self {
+        ExportValue::Text(value.to_string())
+    }
+}
+
+impl From<Decimal> for ExportValue {
+    fn from(value: Decimal) -> Self {
+        ExportValue::Decimal(value)
+    }
+}
+
+impl From<f64> for ExportValue {
+    fn from(value: f64) -> Self {
+        ExportValue::Number(value)
+    }
+}
+
+impl<T: Into<ExportValue>> From<Option<T>> for ExportValue {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or(ExportValue::Empty)
+    }
+}
+
+impl ExportValue {
+    /// Text written to CSV
+    fn to_text(&self) -> String {
+        match self {
+            ExportValue::Text(text) => text.clone(),
+            ExportValue::Decimal(value) => value.to_string(),
+            ExportValue::Number(value) => value.to_string(),
+            ExportValue::Empty => String::new(),
+        }
+    }
+}
+
+/// Rows of one dataset, ready to be written
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportTable {
+    pub dataset: ExportDataset,
+    pub rows: Vec<Vec<ExportValue>>,
+}
+
+impl ExportTable {
+    pub fn new(dataset: ExportDataset) -> Self {
+        Self {
+            dataset,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Append a row; it must have one value per column
+    pub fn push(&mut self, row: Vec<ExportValue>) {
+        debug_assert_eq!(row.len(), self.dataset.columns().len());
+        self.rows.push(row);
+    }
+
+    /// Write the table in the given format
+    pub fn write(&self, format: ExportFormat) -> Result<Vec<u8>, String> {
+        match format {
+            ExportFormat::Csv => self.write_csv(),
+            ExportFormat::Xlsx => self.write_xlsx(),
+        }
+    }
+
+    fn write_csv(&self) -> Result<Vec<u8>, String> {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+
+        writer
+            .write_record(self.dataset.columns())
+            .map_err(|e| format!("Failed to write CSV: {}", e))?;
+        for row in &self.rows {
+            writer
+                .write_record(row.iter().map(ExportValue::to_text))
+                .map_err(|e| format!("Failed to write CSV: {}", e))?;
+        }
+
+        writer
+            .into_inner()
+            .map_err(|e| format!("Failed to write CSV: {}", e))
+    }
+
+    fn write_xlsx(&self) -> Result<Vec<u8>, String> {
+        let mut workbook = Workbook::new();
+        let header = Format::new().set_bold();
+        let sheet = workbook.add_worksheet();
+        sheet
+            .set_name(self.dataset.key())
+            .map_err(|e| format!("Failed to write XLSX: {}", e))?;
+
+        for (col, title) in self.dataset.columns().iter().enumerate() {
+            sheet
+                .write_string_with_format(0, col as u16, *title, &header)
+                .map_err(|e| format!("Failed to write XLSX: {}", e))?;
+            sheet
+                .set_column_width(col as u16, (title.len() + 4).max(12) as f64)
+                .map_err(|e| format!("Failed to write XLSX: {}", e))?;
+        }
+        sheet
+            .set_freeze_panes(1, 0)
+            .map_err(|e| format!("Failed to write XLSX: {}", e))?;
+
+        for (index, row) in self.rows.iter().enumerate() {
+            let row_num = index as u32 + 1;
+            for (col, value) in row.iter().enumerate() {
+                let col = col as u16;
+                let written = match value {
+                    ExportValue::Text(text) => sheet.write_string(row_num, col, text),
+                    ExportValue::Number(number) => sheet.write_number(row_num, col, *number),
+                    ExportValue::Decimal(amount) => match exact_f64(*amount) {
+                        Some(number) => sheet.write_number(row_num, col, number),
+                        None => sheet.write_string(row_num, col, amount.to_string()),
+                    },
+                    ExportValue::Empty => continue,
+                };
+                written.map_err(|e| format!("Failed to write XLSX: {}", e))?;
+            }
+        }
+
+        workbook
+            .save_to_buffer()
+            .map_err(|e| format!("Failed to write XLSX: {}", e))
+    }
+}
+
+/// The number for an amount, if the float writes back as exactly the same digits
+///
+/// XLSX stores numbers as decimal text, so amounts with up to ~15 significant
+/// digits survive unchanged. Anything longer is written as text instead.
+fn exact_f64(amount: Decimal) -> Option<f64> {
+    let number = amount.to_f64()?;
+    (number.to_string() == amount.normalize().to_string()).then_some(number)
+}
+
+/// Suggested file name, e.g. "sales_2026-10-01_2026-10-31.xlsx"
+pub fn export_file_name(
+    dataset: ExportDataset,
+    from: &str,
+    to: &str,
+    format: ExportFormat,
+) -> String {
+    if dataset.uses_date_range() {
+        format!("{}_{}_{}.{}", dataset.key(), from, to, format.extension())
+    } else {
+        format!("{}_{}.{}", dataset.key(), to, format.extension())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn sample_table() -> ExportTable {
+        let mut table = ExportTable::new(ExportDataset::LoanPayments);
+        table.push(vec![
+            "pay-1".into(),
+            "loan-1".into(),
+            "Ana, \"La Güera\"".into(),
+            "2026-10-18 14:05:00".into(),
+            "2026-10-18".into(),
+            dec!(1234.10).into(),
+            None::<String>.into(),
+        ]);
+        table
+    }
+
+    #[test]
+    fn test_csv_keeps_schema_and_exact_decimals() {
+        let csv = String::from_utf8(sample_table().write(ExportFormat::Csv).unwrap()).unwrap();
+        let mut lines = csv.lines();
+
+        assert_eq!(
+            lines.next(),
+            Some("payment_id,loan_id,debtor_name,paid_at,business_date,amount,notes")
+        );
+        assert_eq!(
+            lines.next(),
+            Some("pay-1,loan-1,\"Ana, \"\"La Güera\"\"\",2026-10-18 14:05:00,2026-10-18,1234.10,")
+        );
+    }
+
+    #[test]
+    fn test_exact_f64_only_when_digits_survive() {
+        assert_eq!(exact_f64(dec!(1234.10)), Some(1234.1));
+        assert_eq!(exact_f64(dec!(0.1)), Some(0.1));
+        assert_eq!(exact_f64(dec!(12345678901234567.89)), None);
+    }
+
+    #[test]
+    fn test_xlsx_is_a_zip_workbook() {
+        let bytes = sample_table().write(ExportFormat::Xlsx).unwrap();
+        assert!(bytes.starts_with(b"PK"));
+    }
+
+    #[test]
+    fn test_export_file_name() {
+        assert_eq!(
+            export_file_name(
+                ExportDataset::Sales,
+                "2026-10-01",
+                "2026-10-31",
+                ExportFormat::Xlsx
+            ),
+            "sales_2026-10-01_2026-10-31.xlsx"
+        );
+        assert_eq!(
+            export_file_name(
+                ExportDataset::InventoryValuation,
+                "2026-10-01",
+                "2026-10-31",
+                ExportFormat::Csv
+            ),
+            "inventory_valuation_2026-10-31.csv"
+        );
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! Functions for formatting data for display in the UI.
 
+use crate::models::StoreClock;
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 
@@ -52,49 +53,52 @@ fn add_thousands_separator(s: &str) -> String {
     result
 }
 
-/// Format a DateTime as a readable string
+/// Format a DateTime as a readable string in store-local time
 ///
 /// # Examples
 /// ```
 /// use chrono::Utc;
+/// use pos_app::models::StoreClock;
 /// use pos_app::utils::formatting::format_datetime;
 ///
 /// let now = Utc::now();
-/// let formatted = format_datetime(&now);
+/// let formatted = format_datetime(&now, &StoreClock::default());
 /// assert!(formatted.contains(" at "));
 /// ```
-pub fn format_datetime(dt: &DateTime<Utc>) -> String {
-    dt.format("%Y-%m-%d at %H:%M:%S").to_string()
+pub fn format_datetime(dt: &DateTime<Utc>, clock: &StoreClock) -> String {
+    clock.format(dt, "%Y-%m-%d at %H:%M:%S")
 }
 
-/// Format a DateTime as a short date
+/// Format a DateTime as a short store-local date
 ///
 /// # Examples
 /// ```
 /// use chrono::Utc;
+/// use pos_app::models::StoreClock;
 /// use pos_app::utils::formatting::format_date;
 ///
 /// let now = Utc::now();
-/// let formatted = format_date(&now);
+/// let formatted = format_date(&now, &StoreClock::default());
 /// assert_eq!(formatted.len(), 10); // YYYY-MM-DD
 /// ```
-pub fn format_date(dt: &DateTime<Utc>) -> String {
-    dt.format("%Y-%m-%d").to_string()
+pub fn format_date(dt: &DateTime<Utc>, clock: &StoreClock) -> String {
+    clock.format(dt, "%Y-%m-%d")
 }
 
-/// Format a DateTime as time only
+/// Format a DateTime as store-local time only
 ///
 /// # Examples
 /// ```
 /// use chrono::Utc;
+/// use pos_app::models::StoreClock;
 /// use pos_app::utils::formatting::format_time;
 ///
 /// let now = Utc::now();
-/// let formatted = format_time(&now);
+/// let formatted = format_time(&now, &StoreClock::default());
 /// assert_eq!(formatted.len(), 8); // HH:MM:SS
 /// ```
-pub fn format_time(dt: &DateTime<Utc>) -> String {
-    dt.format("%H:%M:%S").to_string()
+pub fn format_time(dt: &DateTime<Utc>, clock: &StoreClock) -> String {
+    clock.format(dt, "%H:%M:%S")
 }
 
 /// Format a percentage with 1 decimal place
@@ -124,6 +128,19 @@ pub fn format_stock(amount: f64, unit: &str) -> String {
     format!("{:.2} {}", amount, unit)
 }
 
+/// Format a quantity with a fixed number of decimals
+///
+/// # Examples
+/// ```
+/// use pos_app::utils::formatting::format_quantity;
+///
+/// assert_eq!(format_quantity(1.25, 3), "1.250");
+/// assert_eq!(format_quantity(2.0, 0), "2");
+/// ```
+pub fn format_quantity(quantity: f64, decimals: u32) -> String {
+    format!("{:.*}", decimals as usize, quantity)
+}
+
 /// Truncate string to max length with ellipsis
 ///
 /// # Examples
@@ -202,6 +219,13 @@ mod tests {
         assert_eq!(format_stock(5.0, "units"), "5.00 units");
     }
 
+    #[test]
+    fn test_format_quantity() {
+        assert_eq!(format_quantity(1.25, 3), "1.250");
+        assert_eq!(format_quantity(2.0, 0), "2");
+        assert_eq!(format_quantity(0.5, 1), "0.5");
+    }
+
     #[test]
     fn test_truncate() {
         assert_eq!(truncate("Hello World", 5), "Hello...");
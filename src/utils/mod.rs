@@ -2,8 +2,14 @@
 //!
 //! Shared utilities used across the application.
 
+pub mod cfdi;
 pub mod db;
 pub mod db_parsing;
+pub mod ean13;
+pub mod export;
 pub mod formatting;
 pub mod pdf;
+pub mod product_csv;
+#[cfg(test)]
+pub mod test_store;
 pub mod validation;
@@ -0,0 +1,518 @@
+//! Product CSV Utilities
+//!
+//! Reading and writing the product catalog as CSV. Imported files can use any
+//! column order; each column is mapped to a product field (or ignored).
+
+use crate::models::{CategoryPath, Product, ProductInput, UnitMeasurement};
+use crate::utils::validation::{
+    parse_decimal, parse_float, validate_barcode, validate_price, validate_product_name,
+    validate_stock_amount,
+};
+use rust_decimal::Decimal;
+
+/// Product field a CSV column can be mapped to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProductColumn {
+    Barcode,
+    Name,
+    Price,
+    Cost,
+    MinStock,
+    Stock,
+    Unit,
+    Category,
+}
+
+impl ProductColumn {
+    /// All fields, in export column order
+    pub const ALL: [ProductColumn; 8] = [
+        ProductColumn::Barcode,
+        ProductColumn::Name,
+        ProductColumn::Price,
+        ProductColumn::Cost,
+        ProductColumn::MinStock,
+        ProductColumn::Stock,
+        ProductColumn::Unit,
+        ProductColumn::Category,
+    ];
+
+    /// Display label for mapping selects
+    pub fn label(self) -> &'static str {
+        match self {
+            ProductColumn::Barcode => "Barcode",
+            ProductColumn::Name => "Name",
+            ProductColumn::Price => "Price",
+            ProductColumn::Cost => "Cost",
+            ProductColumn::MinStock => "Min Stock",
+            ProductColumn::Stock => "Stock",
+            ProductColumn::Unit => "Unit",
+            ProductColumn::Category => "Category",
+        }
+    }
+
+    /// Column header used on export
+    pub fn key(self) -> &'static str {
+        match self {
+            ProductColumn::Barcode => "barcode",
+            ProductColumn::Name => "name",
+            ProductColumn::Price => "price",
+            ProductColumn::Cost => "cost",
+            ProductColumn::MinStock => "min_stock",
+            ProductColumn::Stock => "stock",
+            ProductColumn::Unit => "unit",
+            ProductColumn::Category => "category",
+        }
+    }
+
+    /// Parse a field from its export key
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|column| column.key() == key)
+    }
+
+    /// Header names recognized when guessing the mapping (English and Spanish)
+    fn aliases(self) -> &'static [&'static str] {
+        match self {
+            ProductColumn::Barcode => &["barcode", "code", "sku", "codigo", "código"],
+            ProductColumn::Name => &["name", "product", "full_name", "nombre", "producto"],
+            ProductColumn::Price => &["price", "user_price", "sale_price", "precio"],
+            ProductColumn::Cost => &["cost", "cost_price", "costo"],
+            ProductColumn::MinStock => &["min_stock", "min_amount", "minimum", "minimo", "mínimo"],
+            ProductColumn::Stock => &["stock", "current_amount", "quantity", "existencia"],
+            ProductColumn::Unit => &["unit", "unidad"],
+            ProductColumn::Category => &["category", "department", "categoria", "categoría"],
+        }
+    }
+
+    /// Guess the field of a column from its header
+    pub fn guess(header: &str) -> Option<Self> {
+        let header = header.trim().to_lowercase().replace(' ', "_");
+        Self::ALL
+            .into_iter()
+            .find(|column| column.aliases().contains(&header.as_str()))
+    }
+}
+
+/// Parsed CSV file: header row plus data rows
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvTable {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+/// Parse CSV content, detecting comma or semicolon delimiters
+///
+/// Blank rows are skipped; rows shorter than the header are padded.
+pub fn parse_csv(content: &str) -> Result<CsvTable, String> {
+    let content = content.trim_start_matches('\u{feff}');
+    let first_line = content.lines().next().unwrap_or_default();
+    let delimiter = if first_line.matches(';').count() > first_line.matches(',').count() {
+        b';'
+    } else {
+        b','
+    };
+
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .flexible(true)
+        .from_reader(content.as_bytes());
+
+    let headers: Vec<String> = reader
+        .headers()
+        .map_err(|e| format!("Failed to read CSV header: {}", e))?
+        .iter()
+        .map(|h| h.trim().to_string())
+        .collect();
+
+    if headers.iter().all(|h| h.is_empty()) {
+        return Err("The CSV file has no header row".to_string());
+    }
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(|e| format!("Failed to read CSV row: {}", e))?;
+        let mut row: Vec<String> = record.iter().map(|v| v.trim().to_string()).collect();
+
+        if row.iter().all(|v| v.is_empty()) {
+            continue;
+        }
+
+        row.resize(headers.len(), String::new());
+        rows.push(row);
+    }
+
+    Ok(CsvTable { headers, rows })
+}
+
+/// Guess a mapping for every header, using each field at most once
+pub fn guess_mapping(headers: &[String]) -> Vec<Option<ProductColumn>> {
+    let mut mapping: Vec<Option<ProductColumn>> = Vec::with_capacity(headers.len());
+
+    for header in headers {
+        let column = ProductColumn::guess(header).filter(|c| !mapping.contains(&Some(*c)));
+        mapping.push(column);
+    }
+
+    mapping
+}
+
+/// Value of a mapped field in a row, if mapped and not blank
+pub fn mapped_value<'a>(
+    row: &'a [String],
+    mapping: &[Option<ProductColumn>],
+    column: ProductColumn,
+) -> Option<&'a str> {
+    mapping
+        .iter()
+        .position(|c| *c == Some(column))
+        .and_then(|index| row.get(index))
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+}
+
+/// Build a product input from a CSV row
+///
+/// When `existing` is given, fields that are not mapped or left blank keep
+/// the product's current values, and stock is never taken from the file so a
+/// stale export cannot undo sales. New products require a name and a price
+/// and default to pieces when no unit is given.
+pub fn build_product_input(
+    row: &[String],
+    mapping: &[Option<ProductColumn>],
+    existing: Option<&Product>,
+    units: &[UnitMeasurement],
+    categories: &[CategoryPath],
+) -> Result<ProductInput, String> {
+    let mut input = match existing {
+        Some(product) => ProductInput {
+            barcode: product.barcode.clone(),
+            full_name: product.full_name.clone(),
+            user_price: product.user_price,
+            cost_price: product.cost_price,
+            min_amount: product.min_amount,
+            current_amount: product.current_amount,
+            unit_measurement_id: product.unit_measurement_id,
+            purchase_unit_id: product.purchase_unit_id,
+            units_per_purchase: product.units_per_purchase,
+            category_id: product.category_id.clone(),
+            supplier_id: product.supplier_id.clone(),
+        },
+        None => ProductInput {
+            barcode: None,
+            full_name: String::new(),
+            user_price: Decimal::ZERO,
+            cost_price: None,
+            min_amount: 0.0,
+            current_amount: 0.0,
+            unit_measurement_id: UnitMeasurement::PIECE,
+            purchase_unit_id: None,
+            units_per_purchase: 1.0,
+            category_id: None,
+            supplier_id: None,
+        },
+    };
+
+    let value = |column| mapped_value(row, mapping, column);
+
+    if let Some(barcode) = value(ProductColumn::Barcode) {
+        validate_barcode(barcode)?;
+        input.barcode = Some(barcode.to_string());
+    }
+
+    match value(ProductColumn::Name) {
+        Some(name) => {
+            validate_product_name(name)?;
+            input.full_name = name.to_string();
+        }
+        None if existing.is_none() => return Err("Name is required".to_string()),
+        None => {}
+    }
+
+    match value(ProductColumn::Price) {
+        Some(price) => {
+            let price = parse_money(price)?;
+            validate_price(price)?;
+            input.user_price = price;
+        }
+        None if existing.is_none() => return Err("Price is required".to_string()),
+        None => {}
+    }
+
+    if let Some(cost) = value(ProductColumn::Cost) {
+        let cost = parse_money(cost)?;
+        if cost < Decimal::ZERO {
+            return Err("Cost cannot be negative".to_string());
+        }
+        input.cost_price = Some(cost);
+    }
+
+    if let Some(min_stock) = value(ProductColumn::MinStock) {
+        let min_stock = parse_float(min_stock)?;
+        validate_stock_amount(min_stock)?;
+        input.min_amount = min_stock;
+    }
+
+    // Existing stock only changes through sales, receipts and adjustments
+    if let Some(stock) = value(ProductColumn::Stock).filter(|_| existing.is_none()) {
+        let stock = parse_float(stock)?;
+        validate_stock_amount(stock)?;
+        input.current_amount = stock;
+    }
+
+    if let Some(unit) = value(ProductColumn::Unit) {
+        input.unit_measurement_id = units
+            .iter()
+            .find(|u| {
+                u.abbreviation.eq_ignore_ascii_case(unit)
+                    || u.description.eq_ignore_ascii_case(unit)
+            })
+            .map(|u| u.id)
+            .ok_or_else(|| format!("Unknown unit: {}", unit))?;
+    }
+
+    if let Some(category) = value(ProductColumn::Category) {
+        input.category_id = Some(find_category(categories, category)?);
+    }
+
+    Ok(input)
+}
+
+/// Write the product catalog as CSV, using the export column keys as header
+pub fn write_products_csv(
+    products: &[Product],
+    units: &[UnitMeasurement],
+    categories: &[CategoryPath],
+) -> Result<String, String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+
+    writer
+        .write_record(ProductColumn::ALL.map(|c| c.key()))
+        .map_err(|e| format!("Failed to write CSV header: {}", e))?;
+
+    for product in products {
+        let unit = units
+            .iter()
+            .find(|u| u.id == product.unit_measurement_id)
+            .map(|u| u.abbreviation.clone())
+            .unwrap_or_default();
+        let category = product
+            .category_id
+            .as_ref()
+            .and_then(|id| categories.iter().find(|c| &c.category.id == id))
+            .map(|c| c.path.clone())
+            .unwrap_or_default();
+
+        writer
+            .write_record([
+                product.barcode.clone().unwrap_or_default(),
+                product.full_name.clone(),
+                product.user_price.to_string(),
+                product
+                    .cost_price
+                    .map(|c| c.to_string())
+                    .unwrap_or_default(),
+                product.min_amount.to_string(),
+                product.current_amount.to_string(),
+                unit,
+                category,
+            ])
+            .map_err(|e| format!("Failed to write CSV row: {}", e))?;
+    }
+
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| format!("Failed to write CSV: {}", e))?;
+    String::from_utf8(bytes).map_err(|e| format!("Failed to write CSV: {}", e))
+}
+
+/// Parse a money amount, allowing a leading currency sign
+fn parse_money(value: &str) -> Result<Decimal, String> {
+    parse_decimal(value.trim().trim_start_matches('$'))
+}
+
+/// Find a category by full path ("Abarrotes > Lácteos") or unique name
+fn find_category(categories: &[CategoryPath], value: &str) -> Result<String, String> {
+    if let Some(path) = categories
+        .iter()
+        .find(|c| c.path.eq_ignore_ascii_case(value))
+    {
+        return Ok(path.category.id.clone());
+    }
+
+    let by_name: Vec<&CategoryPath> = categories
+        .iter()
+        .filter(|c| c.category.name.eq_ignore_ascii_case(value))
+        .collect();
+
+    match by_name.as_slice() {
+        [path] => Ok(path.category.id.clone()),
+        [] => Err(format!("Unknown category: {}", value)),
+        _ => Err(format!(
+            "Category '{}' is ambiguous, use its full path",
+            value
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Category;
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
+
+    fn units() -> Vec<UnitMeasurement> {
+        vec![
+            UnitMeasurement {
+                id: UnitMeasurement::KILOGRAM,
+                description: "Kilogram".to_string(),
+                abbreviation: "kg".to_string(),
+                allows_fraction: true,
+                decimals: 3,
+            },
+            UnitMeasurement {
+                id: UnitMeasurement::PIECE,
+                description: "Piece".to_string(),
+                abbreviation: "pcs".to_string(),
+                allows_fraction: false,
+                decimals: 0,
+            },
+        ]
+    }
+
+    fn categories() -> Vec<CategoryPath> {
+        let category = |id: &str, name: &str, parent_id: Option<&str>| Category {
+            id: id.to_string(),
+            name: name.to_string(),
+            parent_id: parent_id.map(|p| p.to_string()),
+            created_at: Utc::now(),
+        };
+
+        Category::build_paths(&[
+            category("1", "Abarrotes", None),
+            category("2", "Lácteos", Some("1")),
+        ])
+    }
+
+    fn row(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_csv_detects_semicolons() {
+        let table = parse_csv("codigo;nombre;precio\n750100;Leche;28.50\n\n").unwrap();
+
+        assert_eq!(table.headers, vec!["codigo", "nombre", "precio"]);
+        assert_eq!(table.rows, vec![row(&["750100", "Leche", "28.50"])]);
+    }
+
+    #[test]
+    fn test_parse_csv_pads_short_rows() {
+        let table = parse_csv("barcode,name,price\n750100,Leche\n").unwrap();
+        assert_eq!(table.rows, vec![row(&["750100", "Leche", ""])]);
+    }
+
+    #[test]
+    fn test_guess_mapping() {
+        let headers = row(&["Código", "Nombre", "Precio", "Notes", "price"]);
+        assert_eq!(
+            guess_mapping(&headers),
+            vec![
+                Some(ProductColumn::Barcode),
+                Some(ProductColumn::Name),
+                Some(ProductColumn::Price),
+                None,
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_new_product_input() {
+        let mapping = guess_mapping(&row(&["barcode", "name", "price", "unit", "category"]));
+        let input = build_product_input(
+            &row(&["750100", "Leche entera", "$28.50", "KG", "Lácteos"]),
+            &mapping,
+            None,
+            &units(),
+            &categories(),
+        )
+        .unwrap();
+
+        assert_eq!(input.barcode.as_deref(), Some("750100"));
+        assert_eq!(input.user_price, dec!(28.50));
+        assert_eq!(input.unit_measurement_id, UnitMeasurement::KILOGRAM);
+        assert_eq!(input.category_id.as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn test_build_new_product_input_requires_price() {
+        let mapping = guess_mapping(&row(&["name", "price"]));
+        let result = build_product_input(
+            &row(&["Leche entera", ""]),
+            &mapping,
+            None,
+            &units(),
+            &categories(),
+        );
+
+        assert_eq!(result.unwrap_err(), "Price is required");
+    }
+
+    #[test]
+    fn test_build_update_keeps_unmapped_fields() {
+        let existing = ProductInput {
+            barcode: Some("750100".to_string()),
+            full_name: "Leche entera".to_string(),
+            user_price: dec!(28.50),
+            cost_price: Some(dec!(22)),
+            min_amount: 5.0,
+            current_amount: 12.0,
+            unit_measurement_id: UnitMeasurement::PIECE,
+            purchase_unit_id: None,
+            units_per_purchase: 1.0,
+            category_id: None,
+            supplier_id: None,
+        }
+        .to_product();
+
+        let mapping = guess_mapping(&row(&["barcode", "price", "stock"]));
+        let input = build_product_input(
+            &row(&["750100", "30", ""]),
+            &mapping,
+            Some(&existing),
+            &units(),
+            &categories(),
+        )
+        .unwrap();
+
+        assert_eq!(input.full_name, "Leche entera");
+        assert_eq!(input.user_price, dec!(30));
+        assert_eq!(input.cost_price, Some(dec!(22)));
+        assert_eq!(input.current_amount, 12.0);
+
+        // A stock column never overwrites existing stock
+        let input = build_product_input(
+            &row(&["750100", "30", "3"]),
+            &mapping,
+            Some(&existing),
+            &units(),
+            &categories(),
+        )
+        .unwrap();
+        assert_eq!(input.current_amount, 12.0);
+    }
+
+    #[test]
+    fn test_build_product_input_rejects_unknown_unit() {
+        let mapping = guess_mapping(&row(&["name", "price", "unit"]));
+        let result = build_product_input(
+            &row(&["Leche entera", "28.50", "gallon"]),
+            &mapping,
+            None,
+            &units(),
+            &categories(),
+        );
+
+        assert_eq!(result.unwrap_err(), "Unknown unit: gallon");
+    }
+}
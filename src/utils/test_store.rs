@@ -0,0 +1,124 @@
+//! Test Store
+//!
+//! Repositories and APIs over one fresh test database, shared by repository
+//! and API tests.
+
+use crate::api::{InventoryApi, LoansApi, SalesApi};
+use crate::models::{
+    LoyaltySettings, Product, ProductInput, Sale, SaleInput, SaleItemInput, StoreClock,
+};
+use crate::repo::sqlite::*;
+use crate::utils::db::{test_database, DbPool};
+use rust_decimal::Decimal;
+use std::sync::Arc;
+
+pub struct TestStore {
+    pub pool: DbPool,
+    pub products: Arc<SqliteProductRepository>,
+    pub categories: Arc<SqliteCategoryRepository>,
+    pub customers: Arc<SqliteCustomerRepository>,
+    pub price_lists: Arc<SqlitePriceListRepository>,
+    pub loyalty: Arc<SqliteLoyaltyRepository>,
+    pub lots: Arc<SqliteLotRepository>,
+    pub sales: Arc<SalesApi>,
+    pub inventory: Arc<InventoryApi>,
+    pub loans: Arc<LoansApi>,
+}
+
+impl TestStore {
+    pub async fn new() -> Self {
+        let pool = test_database().await;
+        let products = Arc::new(SqliteProductRepository::new(pool.clone()));
+        let catalog = Arc::new(SqliteCatalogRepository::new(pool.clone()));
+        let categories = Arc::new(SqliteCategoryRepository::new(pool.clone()));
+        let customers = Arc::new(SqliteCustomerRepository::new(pool.clone()));
+        let price_lists = Arc::new(SqlitePriceListRepository::new(pool.clone()));
+        let loyalty = Arc::new(SqliteLoyaltyRepository::new(pool.clone()));
+        let lots = Arc::new(SqliteLotRepository::new(pool.clone()));
+        let sale_repo = Arc::new(SqliteSaleRepository::new(pool.clone()));
+
+        Self {
+            sales: Arc::new(SalesApi::new(
+                sale_repo.clone(),
+                products.clone(),
+                catalog.clone(),
+                customers.clone(),
+                categories.clone(),
+                loyalty.clone(),
+                price_lists.clone(),
+            )),
+            inventory: Arc::new(InventoryApi::new(
+                products.clone(),
+                catalog,
+                categories.clone(),
+                lots.clone(),
+                Arc::new(SqliteAdjustmentRepository::new(pool.clone())),
+                Arc::new(SqliteInventoryCountRepository::new(pool.clone())),
+                Arc::new(SqliteSupplierRepository::new(pool.clone())),
+            )),
+            loans: Arc::new(LoansApi::new(
+                Arc::new(SqliteLoanRepository::new(pool.clone())),
+                sale_repo,
+            )),
+            pool,
+            products,
+            categories,
+            customers,
+            price_lists,
+            loyalty,
+            lots,
+        }
+    }
+
+    /// Process a sale today, without a loyalty program
+    pub async fn sell(&self, input: SaleInput) -> Result<Sale, String> {
+        self.sales
+            .process_sale(
+                input,
+                &LoyaltySettings::default(),
+                StoreClock::default().today(),
+            )
+            .await
+    }
+}
+
+/// Product sold by the piece, without cost, category or barcode
+pub fn product_input(name: &str, price: Decimal, stock: f64) -> ProductInput {
+    ProductInput {
+        barcode: None,
+        full_name: name.to_string(),
+        user_price: price,
+        cost_price: None,
+        min_amount: 0.0,
+        current_amount: stock,
+        unit_measurement_id: 4,
+        purchase_unit_id: None,
+        units_per_purchase: 1.0,
+        category_id: None,
+        supplier_id: None,
+    }
+}
+
+pub fn sale_item(product: &Product, quantity: f64, unit_price: Decimal) -> SaleItemInput {
+    SaleItemInput {
+        product_id: product.id.clone(),
+        product_name: product.full_name.clone(),
+        quantity,
+        unit_price,
+        line_total: None,
+    }
+}
+
+/// Sale paid in full in cash
+pub fn cash_sale(items: Vec<SaleItemInput>, customer_id: Option<String>) -> SaleInput {
+    let mut input = SaleInput {
+        items,
+        paid_amount: Decimal::ZERO,
+        customer_id,
+        tax_profile: None,
+        redeemed_points: 0,
+        points_amount: Decimal::ZERO,
+    };
+    input.paid_amount = input.total_amount();
+    input
+}
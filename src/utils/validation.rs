@@ -148,6 +148,102 @@ pub fn validate_payment_amount(amount: Decimal) -> Result<(), String> {
     Ok(())
 }
 
+/// Generic RFCs for the general public and foreign customers, which have no valid check digit
+const GENERIC_RFCS: [&str; 2] = ["XAXX010101000", "XEXX010101000"];
+
+/// Characters in the order the SAT assigns their check digit values
+const RFC_CHECK_CHARS: &str = "0123456789ABCDEFGHIJKLMN&OPQRSTUVWXYZ Ñ";
+
+/// Validate an RFC: 3 (company) or 4 (person) letters, a YYMMDD date and a 3-character homoclave
+/// whose last character is the check digit
+pub fn validate_rfc(rfc: &str) -> Result<(), String> {
+    let rfc = rfc.trim().to_uppercase();
+    let chars: Vec<char> = rfc.chars().collect();
+
+    let letters = match chars.len() {
+        12 => 3,
+        13 => 4,
+        _ => return Err("RFC must have 12 or 13 characters".to_string()),
+    };
+
+    if !chars[..letters]
+        .iter()
+        .all(|c| c.is_ascii_uppercase() || *c == 'Ñ' || *c == '&')
+    {
+        return Err(format!("RFC must start with {} letters", letters));
+    }
+
+    let date: String = chars[letters..letters + 6].iter().collect();
+    if chrono::NaiveDate::parse_from_str(&date, "%y%m%d").is_err() {
+        return Err("RFC contains an invalid date".to_string());
+    }
+
+    if !chars[letters + 6..]
+        .iter()
+        .all(|c| c.is_ascii_alphanumeric())
+    {
+        return Err("RFC homoclave must be letters or digits".to_string());
+    }
+
+    if !GENERIC_RFCS.contains(&rfc.as_str()) && rfc_check_digit(&chars) != chars[chars.len() - 1] {
+        return Err("RFC check digit is not valid".to_string());
+    }
+
+    Ok(())
+}
+
+/// Check digit of an RFC (modulo 11 over the first 12 characters, companies padded with a space)
+fn rfc_check_digit(chars: &[char]) -> char {
+    let padded: Vec<char> = if chars.len() == 12 {
+        std::iter::once(' ').chain(chars.iter().copied()).collect()
+    } else {
+        chars.to_vec()
+    };
+
+    let sum: usize = padded[..12]
+        .iter()
+        .enumerate()
+        .map(|(i, c)| RFC_CHECK_CHARS.chars().position(|v| v == *c).unwrap_or(0) * (13 - i))
+        .sum();
+
+    match 11 - sum % 11 {
+        11 => '0',
+        10 => 'A',
+        digit => char::from_digit(digit as u32, 10).unwrap_or('0'),
+    }
+}
+
+/// Validate a 5-digit Mexican ZIP code
+pub fn validate_zip_code(zip_code: &str) -> Result<(), String> {
+    let zip_code = zip_code.trim();
+    if zip_code.len() != 5 || !zip_code.chars().all(|c| c.is_ascii_digit()) {
+        return Err("ZIP code must have 5 digits".to_string());
+    }
+    Ok(())
+}
+
+/// Validate a SAT product or service code (c_ClaveProdServ)
+pub fn validate_sat_product_code(code: &str) -> Result<(), String> {
+    let code = code.trim();
+    if code.len() != 8 || !code.chars().all(|c| c.is_ascii_digit()) {
+        return Err("SAT product code must have 8 digits".to_string());
+    }
+    Ok(())
+}
+
+/// Validate a SAT unit code (c_ClaveUnidad), e.g. "H87" or "KGM"
+pub fn validate_sat_unit_code(code: &str) -> Result<(), String> {
+    let code = code.trim();
+    if !(2..=3).contains(&code.len())
+        || !code
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+    {
+        return Err("SAT unit code must be 2 or 3 uppercase letters or digits".to_string());
+    }
+    Ok(())
+}
+
 /// Parse decimal from string with validation
 pub fn parse_decimal(input: &str) -> Result<Decimal, String> {
     input
@@ -200,4 +296,27 @@ mod tests {
         assert!(validate_phone("(555) 123-4567").is_ok());
         assert!(validate_phone("123").is_err()); // Too few digits
     }
+
+    #[test]
+    fn test_validate_rfc() {
+        assert!(validate_rfc("EKU9003173C9").is_ok()); // Company
+        assert!(validate_rfc("xaxx010101000").is_ok()); // Person, any case
+        assert!(validate_rfc("EKU900317").is_err()); // Too short
+        assert!(validate_rfc("EKU9013173C9").is_err()); // Month 13
+        assert!(validate_rfc("E1U9003173C9").is_err()); // Digit in the letters
+        assert!(validate_rfc("EKU9003173C8").is_err()); // Wrong check digit
+        assert!(validate_rfc("GODE561231GR8").is_ok());
+        assert!(validate_rfc("ZUÑ920208KL4").is_ok()); // Ñ in the name letters
+        assert!(validate_rfc("XEXX010101000").is_ok()); // Foreign customers
+    }
+
+    #[test]
+    fn test_validate_sat_codes() {
+        assert!(validate_zip_code("06000").is_ok());
+        assert!(validate_zip_code("6000").is_err());
+        assert!(validate_sat_product_code("50181900").is_ok());
+        assert!(validate_sat_product_code("5018190A").is_err());
+        assert!(validate_sat_unit_code("H87").is_ok());
+        assert!(validate_sat_unit_code("kgm").is_err());
+    }
 }
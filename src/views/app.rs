@@ -4,21 +4,38 @@
 
 use dioxus::prelude::*;
 
-use super::{inventory, loans, sales};
+use super::{customers, history, inventory, loans, reports, sales, settings};
 use crate::handlers::AppState;
+use crate::models::StoreClock;
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum ActiveTab {
     Inventory,
     Sales,
+    History,
     Loans,
+    Customers,
+    Reports,
+    Settings,
 }
 
 #[component]
 pub fn App(app_state: AppState) -> Element {
     // Provide app state to all child components via context
+    let settings_handler = app_state.settings_handler.clone();
     use_context_provider(|| app_state);
 
+    // Store clock for local times and today's date; Settings replaces it on save
+    let mut store_clock = use_context_provider(|| Signal::new(StoreClock::default()));
+    use_hook(move || {
+        spawn(async move {
+            match settings_handler.get_store_clock().await {
+                Ok(clock) => store_clock.set(clock),
+                Err(err) => tracing::error!("Failed to load store clock: {}", err),
+            }
+        });
+    });
+
     // Global state: Current active tab
     let mut active_tab = use_signal(|| ActiveTab::Sales);
 
@@ -46,6 +63,12 @@ pub fn App(app_state: AppState) -> Element {
                             onclick: move |_| active_tab.set(ActiveTab::Sales),
                         }
 
+                        TabButton {
+                            label: "🧾 History",
+                            is_active: *active_tab.read() == ActiveTab::History,
+                            onclick: move |_| active_tab.set(ActiveTab::History),
+                        }
+
                         TabButton {
                             label: "📦 Inventory",
                             is_active: *active_tab.read() == ActiveTab::Inventory,
@@ -57,6 +80,24 @@ pub fn App(app_state: AppState) -> Element {
                             is_active: *active_tab.read() == ActiveTab::Loans,
                             onclick: move |_| active_tab.set(ActiveTab::Loans),
                         }
+
+                        TabButton {
+                            label: "👥 Customers",
+                            is_active: *active_tab.read() == ActiveTab::Customers,
+                            onclick: move |_| active_tab.set(ActiveTab::Customers),
+                        }
+
+                        TabButton {
+                            label: "📊 Reports",
+                            is_active: *active_tab.read() == ActiveTab::Reports,
+                            onclick: move |_| active_tab.set(ActiveTab::Reports),
+                        }
+
+                        TabButton {
+                            label: "⚙️ Settings",
+                            is_active: *active_tab.read() == ActiveTab::Settings,
+                            onclick: move |_| active_tab.set(ActiveTab::Settings),
+                        }
                     }
                 }
             }
@@ -77,9 +118,21 @@ pub fn App(app_state: AppState) -> Element {
                         ActiveTab::Sales => rsx! {
                             sales::SalesView {}
                         },
+                        ActiveTab::History => rsx! {
+                            history::SalesHistoryView {}
+                        },
                         ActiveTab::Loans => rsx! {
                             loans::LoansView {}
                         },
+                        ActiveTab::Customers => rsx! {
+                            customers::CustomersView {}
+                        },
+                        ActiveTab::Reports => rsx! {
+                            reports::ReportsView {}
+                        },
+                        ActiveTab::Settings => rsx! {
+                            settings::SettingsView {}
+                        },
                     }
                 }
             }
@@ -87,6 +140,11 @@ pub fn App(app_state: AppState) -> Element {
     }
 }
 
+/// Current store clock, re-rendering the caller when the settings change
+pub fn use_store_clock() -> StoreClock {
+    use_context::<Signal<StoreClock>>()()
+}
+
 #[component]
 fn TabButton(label: String, is_active: bool, onclick: EventHandler<MouseEvent>) -> Element {
     let base_style = "padding: 0.75rem 1.5rem; border: none; border-radius: 0.5rem; font-size: 1rem; font-weight: 500; cursor: pointer; transition: all 0.2s;";
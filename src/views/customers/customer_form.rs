@@ -0,0 +1,199 @@
+//! Customer Form Component
+//!
+//! Modal for creating or editing a customer, their tax profile and price list.
+
+use crate::handlers::AppState;
+use crate::models::{Customer, CustomerInput, InvoiceReceiver, PriceList};
+use crate::views::history::TaxProfileFields;
+use dioxus::prelude::*;
+
+#[component]
+pub fn CustomerForm(
+    customer: Option<Customer>,
+    price_lists: Vec<PriceList>,
+    on_close: EventHandler<()>,
+    on_saved: EventHandler<()>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+
+    let editing_id = customer.as_ref().map(|c| c.id.clone());
+    let profile = customer.as_ref().and_then(|c| c.tax_profile.clone());
+
+    let mut name = use_signal(|| {
+        customer
+            .as_ref()
+            .map(|c| c.name.clone())
+            .unwrap_or_default()
+    });
+    let mut phone = use_signal(|| {
+        customer
+            .as_ref()
+            .and_then(|c| c.phone.clone())
+            .unwrap_or_default()
+    });
+    let mut price_list_id = use_signal(|| {
+        customer
+            .as_ref()
+            .and_then(|c| c.price_list_id.clone())
+            .unwrap_or_default()
+    });
+    let mut has_tax_profile = use_signal(|| profile.is_some());
+    let rfc = use_signal(|| profile.as_ref().map(|p| p.rfc.clone()).unwrap_or_default());
+    let legal_name = use_signal(|| {
+        profile
+            .as_ref()
+            .map(|p| p.legal_name.clone())
+            .unwrap_or_default()
+    });
+    let tax_regime = use_signal(|| {
+        profile
+            .as_ref()
+            .map_or("616".to_string(), |p| p.tax_regime.clone())
+    });
+    let zip_code = use_signal(|| {
+        profile
+            .as_ref()
+            .map(|p| p.zip_code.clone())
+            .unwrap_or_default()
+    });
+    let cfdi_use = use_signal(|| {
+        profile
+            .as_ref()
+            .map_or("G03".to_string(), |p| p.cfdi_use.clone())
+    });
+    let mut error_msg = use_signal(String::new);
+    let mut saving = use_signal(|| false);
+
+    let handle_save = move |_| {
+        let handler = app_state.customers_handler.clone();
+        let input = CustomerInput {
+            name: name(),
+            phone: Some(phone()),
+            tax_profile: has_tax_profile().then(|| InvoiceReceiver {
+                rfc: rfc(),
+                legal_name: legal_name(),
+                tax_regime: tax_regime(),
+                zip_code: zip_code(),
+                cfdi_use: cfdi_use(),
+            }),
+            price_list_id: Some(price_list_id()),
+        };
+        let edit_id = editing_id.clone();
+        error_msg.set(String::new());
+        saving.set(true);
+
+        spawn(async move {
+            let result = match edit_id {
+                Some(id) => handler.update_customer(id, input).await,
+                None => handler.create_customer(input).await,
+            };
+
+            match result {
+                Ok(_) => on_saved.call(()),
+                Err(err) => error_msg.set(err),
+            }
+            saving.set(false);
+        });
+    };
+
+    let title = if customer.is_some() {
+        "Edit Customer"
+    } else {
+        "New Customer"
+    };
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.5); display: flex; justify-content: center; align-items: center; z-index: 1000;",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background: white; padding: 2rem; border-radius: 0.5rem; width: 560px; max-width: 95%; max-height: 90vh; overflow-y: auto;",
+                onclick: move |evt| evt.stop_propagation(),
+
+                h3 { style: "margin: 0 0 1.5rem 0; font-size: 1.25rem;", "{title}" }
+
+                div {
+                    style: "display: grid; grid-template-columns: 2fr 1fr; gap: 1rem; margin-bottom: 1rem;",
+                    div {
+                        label { style: LABEL_STYLE, "Name" }
+                        input {
+                            r#type: "text",
+                            style: INPUT_STYLE,
+                            value: "{name}",
+                            oninput: move |e| name.set(e.value())
+                        }
+                    }
+                    div {
+                        label { style: LABEL_STYLE, "Phone" }
+                        input {
+                            r#type: "tel",
+                            placeholder: "Optional",
+                            style: INPUT_STYLE,
+                            value: "{phone}",
+                            oninput: move |e| phone.set(e.value())
+                        }
+                    }
+                }
+
+                div {
+                    style: "margin-bottom: 1rem;",
+                    label { style: LABEL_STYLE, "Price list" }
+                    select {
+                        style: INPUT_STYLE,
+                        onchange: move |e| price_list_id.set(e.value()),
+                        option { value: "", selected: price_list_id().is_empty(), "Regular prices" }
+                        for list in price_lists.iter() {
+                            option {
+                                key: "{list.id}",
+                                value: "{list.id}",
+                                selected: list.id == price_list_id(),
+                                "{list.name}"
+                            }
+                        }
+                    }
+                }
+
+                label {
+                    style: "display: flex; align-items: center; gap: 0.5rem; margin-bottom: 1rem; font-size: 0.875rem; color: #4a5568; font-weight: 600;",
+                    input {
+                        r#type: "checkbox",
+                        checked: has_tax_profile(),
+                        onchange: move |e| has_tax_profile.set(e.checked())
+                    }
+                    "Tax profile for invoicing"
+                }
+
+                if has_tax_profile() {
+                    TaxProfileFields { rfc, legal_name, tax_regime, zip_code, cfdi_use }
+                }
+
+                if !error_msg().is_empty() {
+                    div {
+                        style: "background: #fff5f5; color: #c53030; padding: 0.75rem; border-radius: 0.25rem; margin: 1rem 0;",
+                        "{error_msg}"
+                    }
+                }
+
+                div {
+                    style: "display: flex; justify-content: flex-end; gap: 0.5rem; margin-top: 1rem;",
+                    button {
+                        style: "padding: 0.5rem 1rem; border: 1px solid #e2e8f0; background: white; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem;",
+                        onclick: move |_| on_close.call(()),
+                        "Cancel"
+                    }
+                    button {
+                        style: "padding: 0.5rem 1rem; background: #667eea; color: white; border: none; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem; font-weight: 500;",
+                        disabled: saving(),
+                        onclick: handle_save,
+                        if saving() { "Saving..." } else { "Save" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+const LABEL_STYLE: &str =
+    "display: block; font-weight: 600; color: #4a5568; font-size: 0.875rem; margin-bottom: 0.5rem;";
+const INPUT_STYLE: &str = "width: 100%; padding: 0.5rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 1rem; box-sizing: border-box; background: white;";
@@ -0,0 +1,197 @@
+//! Customers Module
+//!
+//! UI components for managing regular customers, their tax profiles, price
+//! lists and loyalty points.
+
+mod customer_form;
+mod points_ledger_modal;
+mod price_lists_modal;
+
+use customer_form::CustomerForm;
+use points_ledger_modal::PointsLedgerModal;
+use price_lists_modal::PriceListsModal;
+
+use crate::handlers::AppState;
+use crate::models::Customer;
+use dioxus::prelude::*;
+
+#[component]
+pub fn CustomersView() -> Element {
+    let app_state = use_context::<AppState>();
+
+    let mut search_query = use_signal(String::new);
+    // None: form closed, Some(None): new customer, Some(Some(c)): editing
+    let mut form_customer = use_signal(|| Option::<Option<Customer>>::None);
+    let mut ledger_customer = use_signal(|| Option::<Customer>::None);
+    let mut show_price_lists = use_signal(|| false);
+
+    let mut customers_resource = use_resource({
+        let customers_handler = app_state.customers_handler.clone();
+        move || {
+            let handler = customers_handler.clone();
+            async move { handler.load_customers().await }
+        }
+    });
+
+    let mut price_lists_resource = use_resource({
+        let price_lists_handler = app_state.price_lists_handler.clone();
+        move || {
+            let handler = price_lists_handler.clone();
+            async move { handler.load_price_lists().await }
+        }
+    });
+    let price_lists = match &*price_lists_resource.read_unchecked() {
+        Some(Ok(lists)) => lists.clone(),
+        _ => Vec::new(),
+    };
+
+    let query = search_query().trim().to_lowercase();
+
+    rsx! {
+        div {
+            style: "background: white; border-radius: 0.5rem; padding: 1.5rem; box-shadow: 0 1px 3px rgba(0,0,0,0.1);",
+
+            div {
+                style: "display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;",
+                h2 {
+                    style: "font-size: 1.5rem; font-weight: 600; color: #2d3748; margin: 0;",
+                    "👥 Customers"
+                }
+                div {
+                    style: "display: flex; gap: 0.5rem;",
+                    button {
+                        style: "padding: 0.625rem 1.25rem; background: white; color: #667eea; border: 1px solid #667eea; border-radius: 0.5rem; cursor: pointer; font-size: 1rem; font-weight: 500;",
+                        onclick: move |_| show_price_lists.set(true),
+                        "🏷️ Price Lists"
+                    }
+                    button {
+                        style: "padding: 0.625rem 1.25rem; background: #667eea; color: white; border: none; border-radius: 0.5rem; cursor: pointer; font-size: 1rem; font-weight: 500;",
+                        onclick: move |_| form_customer.set(Some(None)),
+                        "➕ New Customer"
+                    }
+                }
+            }
+
+            input {
+                r#type: "text",
+                placeholder: "🔍 Search by name or RFC...",
+                value: "{search_query}",
+                oninput: move |evt| search_query.set(evt.value()),
+                style: "width: 100%; padding: 0.75rem; border: 2px solid #e2e8f0; border-radius: 0.5rem; font-size: 1rem; box-sizing: border-box; margin-bottom: 1rem;",
+            }
+
+            match &*customers_resource.read_unchecked() {
+                Some(Ok(customers)) => {
+                    let customers: Vec<Customer> = customers
+                        .iter()
+                        .filter(|c| {
+                            query.is_empty()
+                                || c.name.to_lowercase().contains(&query)
+                                || c.tax_profile.as_ref().is_some_and(|p| p.rfc.to_lowercase().contains(&query))
+                        })
+                        .cloned()
+                        .collect();
+                    rsx! {
+                        if customers.is_empty() {
+                            div {
+                                style: "padding: 2rem; text-align: center; color: #718096;",
+                                "No customers found."
+                            }
+                        } else {
+                            table {
+                                style: "width: 100%; border-collapse: collapse;",
+                                thead {
+                                    tr {
+                                        style: "background: #f7fafc; text-align: left; color: #4a5568; font-size: 0.875rem;",
+                                        th { style: "padding: 0.75rem;", "Name" }
+                                        th { style: "padding: 0.75rem;", "Phone" }
+                                        th { style: "padding: 0.75rem;", "RFC" }
+                                        th { style: "padding: 0.75rem;", "Legal name" }
+                                        th { style: "padding: 0.75rem;", "Price list" }
+                                        th { style: "padding: 0.75rem;" }
+                                    }
+                                }
+                                tbody {
+                                    for customer in customers {
+                                        {
+                                            let edit_customer = customer.clone();
+                                            let points_customer = customer.clone();
+                                            let (rfc, legal_name) = match &customer.tax_profile {
+                                                Some(p) => (p.rfc.clone(), p.legal_name.clone()),
+                                                None => ("—".to_string(), String::new()),
+                                            };
+                                            let price_list = customer
+                                                .price_list_id
+                                                .as_ref()
+                                                .and_then(|id| price_lists.iter().find(|l| l.id == *id))
+                                                .map_or("Regular".to_string(), |l| l.name.clone());
+                                            rsx! {
+                                                tr {
+                                                    key: "{customer.id}",
+                                                    style: "border-bottom: 1px solid #e2e8f0;",
+                                                    td { style: "padding: 0.75rem; font-weight: 500;", "{customer.name}" }
+                                                    td { style: "padding: 0.75rem; color: #718096;", "{customer.phone.clone().unwrap_or_default()}" }
+                                                    td { style: "padding: 0.75rem; font-family: monospace;", "{rfc}" }
+                                                    td { style: "padding: 0.75rem; color: #4a5568; font-size: 0.875rem;", "{legal_name}" }
+                                                    td { style: "padding: 0.75rem; color: #4a5568; font-size: 0.875rem;", "{price_list}" }
+                                                    td {
+                                                        style: "padding: 0.75rem; text-align: right; white-space: nowrap;",
+                                                        button {
+                                                            style: "background: none; border: none; color: #b7791f; font-weight: 500; cursor: pointer; font-size: 0.875rem; margin-right: 0.5rem;",
+                                                            onclick: move |_| ledger_customer.set(Some(points_customer.clone())),
+                                                            "⭐ Points"
+                                                        }
+                                                        button {
+                                                            style: "background: none; border: none; color: #667eea; font-weight: 500; cursor: pointer; font-size: 0.875rem;",
+                                                            onclick: move |_| form_customer.set(Some(Some(edit_customer.clone()))),
+                                                            "Edit"
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                Some(Err(err)) => rsx! {
+                    div {
+                        style: "background: #fff5f5; color: #c53030; padding: 0.75rem; border-radius: 0.25rem;",
+                        "Failed to load customers: {err}"
+                    }
+                },
+                None => rsx! {
+                    div { style: "padding: 2rem; text-align: center; color: #718096;", "⏳ Loading..." }
+                },
+            }
+        }
+
+        if let Some(customer) = form_customer() {
+            CustomerForm {
+                customer,
+                price_lists: price_lists.clone(),
+                on_close: move |_| form_customer.set(None),
+                on_saved: move |_| {
+                    form_customer.set(None);
+                    customers_resource.restart();
+                },
+            }
+        }
+
+        if show_price_lists() {
+            PriceListsModal {
+                on_close: move |_| show_price_lists.set(false),
+                on_change: move |_| {
+                    price_lists_resource.restart();
+                    customers_resource.restart();
+                },
+            }
+        }
+
+        if let Some(customer) = ledger_customer() {
+            PointsLedgerModal { customer, on_close: move |_| ledger_customer.set(None) }
+        }
+    }
+}
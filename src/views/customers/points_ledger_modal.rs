@@ -0,0 +1,118 @@
+//! Points Ledger Modal Component
+//!
+//! Shows a customer's loyalty points history and running balance.
+
+use crate::handlers::AppState;
+use crate::models::{Customer, PointsKind};
+use crate::views::app::use_store_clock;
+use dioxus::prelude::*;
+
+#[component]
+pub fn PointsLedgerModal(customer: Customer, on_close: EventHandler<()>) -> Element {
+    let app_state = use_context::<AppState>();
+    let clock = use_store_clock();
+
+    let ledger_resource = use_resource({
+        let loyalty_handler = app_state.loyalty_handler.clone();
+        let customer_id = customer.id.clone();
+        move || {
+            let handler = loyalty_handler.clone();
+            let customer_id = customer_id.clone();
+            async move { handler.get_points_ledger(customer_id).await }
+        }
+    });
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; right: 0; bottom: 0; background: rgba(0,0,0,0.5); display: flex; align-items: center; justify-content: center; z-index: 1000;",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background: white; padding: 2rem; border-radius: 0.5rem; max-width: 640px; width: 90%; max-height: 80vh; overflow-y: auto;",
+                onclick: move |evt| evt.stop_propagation(),
+
+                div {
+                    style: "display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem; padding-bottom: 1rem; border-bottom: 2px solid #e2e8f0;",
+                    h3 {
+                        style: "margin: 0; font-size: 1.25rem; font-weight: 600; color: #2d3748;",
+                        "⭐ Points of {customer.name}"
+                    }
+                    button {
+                        style: "background: transparent; border: none; font-size: 1.5rem; cursor: pointer; color: #718096;",
+                        onclick: move |_| on_close.call(()),
+                        "✕"
+                    }
+                }
+
+                match &*ledger_resource.read_unchecked() {
+                    Some(Ok(entries)) if entries.is_empty() => rsx! {
+                        div { style: "padding: 2rem; text-align: center; color: #718096;", "No points yet." }
+                    },
+                    Some(Ok(entries)) => {
+                        // Running balance, newest entry first
+                        let mut balance = 0;
+                        let mut rows: Vec<_> = entries
+                            .iter()
+                            .map(|entry| {
+                                balance += entry.points;
+                                (entry.clone(), balance)
+                            })
+                            .collect();
+                        rows.reverse();
+                        rsx! {
+                            div {
+                                style: "margin-bottom: 1rem; font-size: 1.125rem; font-weight: 600; color: #2d3748;",
+                                "Balance: {balance} points"
+                            }
+                            table {
+                                style: "width: 100%; border-collapse: collapse; font-size: 0.875rem;",
+                                thead {
+                                    tr {
+                                        style: "background: #f7fafc; text-align: left; color: #4a5568;",
+                                        th { style: "padding: 0.5rem;", "Date" }
+                                        th { style: "padding: 0.5rem;", "Movement" }
+                                        th { style: "padding: 0.5rem; text-align: right;", "Points" }
+                                        th { style: "padding: 0.5rem; text-align: right;", "Balance" }
+                                        th { style: "padding: 0.5rem;", "Expires" }
+                                    }
+                                }
+                                tbody {
+                                    for (entry, running) in rows {
+                                        tr {
+                                            key: "{entry.id}",
+                                            style: "border-bottom: 1px solid #e2e8f0;",
+                                            td { style: "padding: 0.5rem;", {clock.format(&entry.created_at, "%d-%b-%Y %H:%M")} }
+                                            td { style: "padding: 0.5rem;", "{entry.kind.label()}" }
+                                            td {
+                                                style: if entry.kind == PointsKind::Earn {
+                                                    "padding: 0.5rem; text-align: right; font-family: monospace; color: #276749;"
+                                                } else {
+                                                    "padding: 0.5rem; text-align: right; font-family: monospace; color: #c53030;"
+                                                },
+                                                "{entry.points:+}"
+                                            }
+                                            td { style: "padding: 0.5rem; text-align: right; font-family: monospace;", "{running}" }
+                                            td {
+                                                style: "padding: 0.5rem; color: #718096;",
+                                                {entry.expires_at.map(|at| clock.format(&at, "%d-%b-%Y")).unwrap_or_default()}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    Some(Err(err)) => rsx! {
+                        div {
+                            style: "background: #fff5f5; color: #c53030; padding: 0.75rem; border-radius: 0.25rem;",
+                            "Failed to load points: {err}"
+                        }
+                    },
+                    None => rsx! {
+                        div { style: "padding: 2rem; text-align: center; color: #718096;", "⏳ Loading..." }
+                    },
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,353 @@
+//! Price Lists Modal Component
+//!
+//! Manages the price lists assignable to customers: their discount off the
+//! regular price and the products with a price of their own.
+
+use crate::handlers::AppState;
+use crate::models::{PriceList, PriceListInput};
+use crate::utils::formatting::format_currency;
+use dioxus::prelude::*;
+use rust_decimal::Decimal;
+
+/// Maximum number of search matches offered
+const MAX_MATCHES: usize = 5;
+
+#[component]
+pub fn PriceListsModal(on_close: EventHandler<()>, on_change: EventHandler<()>) -> Element {
+    let app_state = use_context::<AppState>();
+
+    // None: creating a new list
+    let mut selected = use_signal(|| None::<PriceList>);
+    let mut name = use_signal(String::new);
+    let mut discount = use_signal(|| "0".to_string());
+    let mut query = use_signal(String::new);
+    let mut product = use_signal(|| None::<(String, String)>);
+    let mut price = use_signal(String::new);
+    let mut error_msg = use_signal(String::new);
+
+    let mut lists_resource = use_resource({
+        let handler = app_state.price_lists_handler.clone();
+        move || {
+            let handler = handler.clone();
+            async move { handler.load_price_lists().await }
+        }
+    });
+
+    let mut items_resource = use_resource({
+        let handler = app_state.price_lists_handler.clone();
+        move || {
+            let handler = handler.clone();
+            let list_id = selected().map(|l| l.id);
+            async move {
+                match list_id {
+                    Some(id) => handler.get_list_prices(id).await,
+                    None => Ok(Vec::new()),
+                }
+            }
+        }
+    });
+
+    let matches_resource = use_resource({
+        let handler = app_state.inventory_handler.clone();
+        move || {
+            let handler = handler.clone();
+            let query = query();
+            async move {
+                if query.trim().len() < 2 {
+                    return Ok(Vec::new());
+                }
+                handler
+                    .search_products(query)
+                    .await
+                    .map(|products| products.into_iter().take(MAX_MATCHES).collect::<Vec<_>>())
+            }
+        }
+    });
+
+    let mut select_list = move |list: Option<PriceList>| {
+        name.set(list.as_ref().map(|l| l.name.clone()).unwrap_or_default());
+        discount.set(
+            list.as_ref()
+                .map_or("0".to_string(), |l| l.discount_percent.to_string()),
+        );
+        error_msg.set(String::new());
+        selected.set(list);
+    };
+
+    let save_handler = app_state.price_lists_handler.clone();
+    let handle_save = move |_| {
+        let handler = save_handler.clone();
+        let discount_percent = match discount().trim().parse::<Decimal>() {
+            Ok(discount) => discount,
+            Err(_) => {
+                error_msg.set("Invalid discount".to_string());
+                return;
+            }
+        };
+        let input = PriceListInput {
+            name: name(),
+            discount_percent,
+        };
+        let editing_id = selected().map(|l| l.id);
+
+        spawn(async move {
+            let result = match editing_id {
+                Some(id) => handler.update_price_list(id, input).await,
+                None => handler.create_price_list(input).await,
+            };
+            match result {
+                Ok(list) => {
+                    select_list(Some(list));
+                    lists_resource.restart();
+                    on_change.call(());
+                }
+                Err(err) => error_msg.set(err),
+            }
+        });
+    };
+
+    let delete_handler = app_state.price_lists_handler.clone();
+    let handle_delete = move |_| {
+        let handler = delete_handler.clone();
+        let Some(list) = selected() else {
+            return;
+        };
+        spawn(async move {
+            match handler.delete_price_list(list.id).await {
+                Ok(()) => {
+                    select_list(None);
+                    lists_resource.restart();
+                    on_change.call(());
+                }
+                Err(err) => error_msg.set(err),
+            }
+        });
+    };
+
+    let set_price_handler = app_state.price_lists_handler.clone();
+    let handle_set_price = move |_| {
+        let handler = set_price_handler.clone();
+        let Some(list) = selected() else {
+            return;
+        };
+        let Some((product_id, _)) = product() else {
+            error_msg.set("Search and pick a product first".to_string());
+            return;
+        };
+        let list_price = match price().trim().parse::<Decimal>() {
+            Ok(price) => price,
+            Err(_) => {
+                error_msg.set("Invalid price".to_string());
+                return;
+            }
+        };
+
+        spawn(async move {
+            match handler
+                .set_list_price(list.id, product_id, list_price)
+                .await
+            {
+                Ok(()) => {
+                    product.set(None);
+                    query.set(String::new());
+                    price.set(String::new());
+                    error_msg.set(String::new());
+                    items_resource.restart();
+                }
+                Err(err) => error_msg.set(err),
+            }
+        });
+    };
+
+    let remove_handler = app_state.price_lists_handler.clone();
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; right: 0; bottom: 0; background: rgba(0,0,0,0.5); display: flex; align-items: center; justify-content: center; z-index: 1000;",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background: white; padding: 2rem; border-radius: 0.5rem; max-width: 640px; width: 90%; max-height: 85vh; overflow-y: auto;",
+                onclick: move |evt| evt.stop_propagation(),
+
+                div {
+                    style: "display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem; padding-bottom: 1rem; border-bottom: 2px solid #e2e8f0;",
+                    h3 {
+                        style: "margin: 0; font-size: 1.25rem; font-weight: 600; color: #2d3748;",
+                        "🏷️ Price Lists"
+                    }
+                    button {
+                        style: "background: transparent; border: none; font-size: 1.5rem; cursor: pointer; color: #718096;",
+                        onclick: move |_| on_close.call(()),
+                        "✕"
+                    }
+                }
+
+                if !error_msg().is_empty() {
+                    div {
+                        style: "background: #fff5f5; color: #c53030; padding: 0.5rem; border-radius: 0.25rem; margin-bottom: 1rem; font-size: 0.875rem;",
+                        "{error_msg}"
+                    }
+                }
+
+                // List selector
+                {
+                    let lists = match &*lists_resource.read_unchecked() {
+                        Some(Ok(lists)) => lists.clone(),
+                        _ => Vec::new(),
+                    };
+                    let selected_id = selected().map(|l| l.id).unwrap_or_default();
+                    rsx! {
+                        select {
+                            style: INPUT_STYLE,
+                            onchange: move |e| {
+                                let id = e.value();
+                                select_list(lists.iter().find(|l| l.id == id).cloned());
+                            },
+                            option { value: "", selected: selected_id.is_empty(), "➕ New price list" }
+                            for list in lists.iter() {
+                                option {
+                                    key: "{list.id}",
+                                    value: "{list.id}",
+                                    selected: list.id == selected_id,
+                                    "{list.name}"
+                                }
+                            }
+                        }
+                    }
+                }
+
+                div {
+                    style: "display: grid; grid-template-columns: 2fr 1fr; gap: 1rem; margin: 1rem 0;",
+                    div {
+                        label { style: LABEL_STYLE, "Name" }
+                        input {
+                            r#type: "text",
+                            placeholder: "Wholesale",
+                            style: INPUT_STYLE,
+                            value: "{name}",
+                            oninput: move |e| name.set(e.value())
+                        }
+                    }
+                    div {
+                        label { style: LABEL_STYLE, "Discount (%)" }
+                        input {
+                            r#type: "number",
+                            min: "0",
+                            max: "99",
+                            step: "0.5",
+                            style: INPUT_STYLE,
+                            value: "{discount}",
+                            oninput: move |e| discount.set(e.value())
+                        }
+                    }
+                }
+                div {
+                    style: "color: #718096; font-size: 0.75rem; margin-bottom: 1rem;",
+                    "Customers on this list get the discount off the regular price of every product, except products with a list price below. Quantity tiers still apply when they are lower."
+                }
+
+                div {
+                    style: "display: flex; justify-content: flex-end; gap: 0.5rem; margin-bottom: 1.5rem;",
+                    if selected().is_some() {
+                        button {
+                            style: "padding: 0.5rem 1rem; background: white; color: #e53e3e; border: 1px solid #e53e3e; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem;",
+                            onclick: handle_delete,
+                            "Delete"
+                        }
+                    }
+                    button {
+                        style: "padding: 0.5rem 1rem; background: #667eea; color: white; border: none; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem; font-weight: 500;",
+                        onclick: handle_save,
+                        if selected().is_some() { "Save" } else { "Create" }
+                    }
+                }
+
+                // Product prices of the selected list
+                if selected().is_some() {
+                    label { style: LABEL_STYLE, "List prices" }
+
+                    if let Some(Ok(items)) = items_resource.read().as_ref() {
+                        for item in items.iter().cloned() {
+                            {
+                                let handler = remove_handler.clone();
+                                rsx! {
+                                    div {
+                                        key: "{item.product_id}",
+                                        style: "display: flex; justify-content: space-between; align-items: center; padding: 0.375rem 0; border-bottom: 1px solid #e2e8f0; font-size: 0.875rem;",
+                                        span { "{item.product_name}" }
+                                        span { style: "font-family: monospace;", "{format_currency(item.price)}" }
+                                        button {
+                                            style: "background: none; border: none; color: #e53e3e; font-weight: 500; cursor: pointer; padding: 0.25rem 0.5rem; font-size: 0.875rem;",
+                                            onclick: move |_| {
+                                                let handler = handler.clone();
+                                                let list_id = item.price_list_id.clone();
+                                                let product_id = item.product_id.clone();
+                                                spawn(async move {
+                                                    match handler.remove_list_price(list_id, product_id).await {
+                                                        Ok(()) => items_resource.restart(),
+                                                        Err(err) => error_msg.set(err),
+                                                    }
+                                                });
+                                            },
+                                            "Remove"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    div {
+                        style: "display: grid; grid-template-columns: 1fr 110px auto; gap: 0.5rem; margin-top: 0.5rem;",
+                        input {
+                            r#type: "text",
+                            placeholder: "Search product",
+                            style: "width: 100%; padding: 0.5rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 0.875rem; box-sizing: border-box;",
+                            value: match product() {
+                                Some((_, name)) => name,
+                                None => query(),
+                            },
+                            oninput: move |e| {
+                                product.set(None);
+                                query.set(e.value());
+                            }
+                        }
+                        input {
+                            r#type: "number",
+                            min: "0",
+                            step: "0.01",
+                            placeholder: "Price",
+                            style: "width: 100%; padding: 0.5rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 0.875rem; box-sizing: border-box;",
+                            value: "{price}",
+                            oninput: move |e| price.set(e.value())
+                        }
+                        button {
+                            style: "padding: 0.5rem 0.75rem; background: #667eea; color: white; border: none; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem; font-weight: 500;",
+                            onclick: handle_set_price,
+                            "Set"
+                        }
+                    }
+
+                    // Search matches
+                    if product().is_none() {
+                        if let Some(Ok(matches)) = matches_resource.read().as_ref() {
+                            for found in matches.iter().cloned() {
+                                div {
+                                    key: "{found.id}",
+                                    style: "padding: 0.375rem 0.5rem; border: 1px solid #e2e8f0; border-top: none; cursor: pointer; font-size: 0.875rem;",
+                                    onclick: move |_| product.set(Some((found.id.clone(), found.full_name.clone()))),
+                                    "{found.full_name} ({format_currency(found.user_price)})"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+const LABEL_STYLE: &str =
+    "display: block; font-weight: 600; color: #4a5568; font-size: 0.875rem; margin-bottom: 0.5rem;";
+const INPUT_STYLE: &str = "width: 100%; padding: 0.5rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 1rem; box-sizing: border-box; background: white;";
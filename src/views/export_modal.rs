@@ -0,0 +1,183 @@
+//! Shared Export Modal Component
+//!
+//! Exports one of a module's datasets for a date range to CSV or XLSX.
+
+use crate::handlers::AppState;
+use crate::utils::export::{export_file_name, ExportDataset, ExportFormat};
+use crate::views::app::use_store_clock;
+use chrono::Datelike;
+use dioxus::prelude::*;
+
+#[component]
+pub fn ExportModal(datasets: Vec<ExportDataset>, on_close: EventHandler<()>) -> Element {
+    let app_state = use_context::<AppState>();
+    let clock = use_store_clock();
+
+    let today = clock.today();
+    let first_dataset = datasets.first().copied().unwrap_or(ExportDataset::Sales);
+    let mut dataset = use_signal(|| first_dataset);
+    let mut format = use_signal(|| ExportFormat::Xlsx);
+    let mut from_date = use_signal(|| {
+        today
+            .with_day(1)
+            .unwrap_or(today)
+            .format("%Y-%m-%d")
+            .to_string()
+    });
+    let mut to_date = use_signal(|| today.format("%Y-%m-%d").to_string());
+    let mut error_msg = use_signal(String::new);
+    let mut message = use_signal(String::new);
+    let mut exporting = use_signal(|| false);
+
+    let handle_export = move |_| {
+        let handler = app_state.export_handler.clone();
+        let (dataset, format, from, to) = (dataset(), format(), from_date(), to_date());
+        error_msg.set(String::new());
+        message.set(String::new());
+        exporting.set(true);
+
+        spawn(async move {
+            let contents = match handler
+                .export(dataset, from.clone(), to.clone(), format)
+                .await
+            {
+                Ok(contents) => contents,
+                Err(err) => {
+                    error_msg.set(err);
+                    exporting.set(false);
+                    return;
+                }
+            };
+
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(file_path) = rfd::FileDialog::new()
+                .set_file_name(export_file_name(dataset, &from, &to, format))
+                .add_filter(format.label(), &[format.extension()])
+                .save_file()
+            {
+                match std::fs::write(&file_path, contents) {
+                    Ok(_) => message.set(format!(
+                        "{} exported to {}",
+                        dataset.label(),
+                        file_path.display()
+                    )),
+                    Err(e) => error_msg.set(format!("Failed to write file: {}", e)),
+                }
+            }
+            exporting.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.5); display: flex; justify-content: center; align-items: center; z-index: 1000;",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background: white; padding: 2rem; border-radius: 0.5rem; width: 480px; max-width: 95%;",
+                onclick: move |evt| evt.stop_propagation(),
+
+                h3 { style: "margin: 0 0 1.5rem 0; font-size: 1.25rem;", "⬇️ Export" }
+
+                // Dataset
+                label { style: LABEL_STYLE, "Data" }
+                div {
+                    style: "display: flex; flex-direction: column; gap: 0.375rem; margin-bottom: 1rem;",
+                    for option in datasets.iter().copied() {
+                        label {
+                            key: "{option.key()}",
+                            style: "display: flex; align-items: center; gap: 0.5rem; cursor: pointer;",
+                            input {
+                                r#type: "radio",
+                                name: "export-dataset",
+                                checked: dataset() == option,
+                                onchange: move |_| dataset.set(option),
+                            }
+                            "{option.label()}"
+                        }
+                    }
+                }
+
+                // Date range
+                if dataset().uses_date_range() {
+                    label { style: LABEL_STYLE, "Business days" }
+                    div {
+                        style: "display: flex; gap: 0.5rem; align-items: center; margin-bottom: 1rem; color: #4a5568;",
+                        input {
+                            r#type: "date",
+                            style: INPUT_STYLE,
+                            value: "{from_date}",
+                            oninput: move |e| from_date.set(e.value())
+                        }
+                        "to"
+                        input {
+                            r#type: "date",
+                            style: INPUT_STYLE,
+                            value: "{to_date}",
+                            oninput: move |e| to_date.set(e.value())
+                        }
+                    }
+                } else {
+                    div {
+                        style: "color: #718096; font-size: 0.875rem; margin-bottom: 1rem;",
+                        "Current stock as of today."
+                    }
+                }
+
+                // Format
+                label { style: LABEL_STYLE, "Format" }
+                div {
+                    style: "display: flex; gap: 1rem; margin-bottom: 1.5rem;",
+                    for option in ExportFormat::ALL {
+                        label {
+                            key: "{option.extension()}",
+                            style: "display: flex; align-items: center; gap: 0.5rem; cursor: pointer;",
+                            input {
+                                r#type: "radio",
+                                name: "export-format",
+                                checked: format() == option,
+                                onchange: move |_| format.set(option),
+                            }
+                            "{option.label()}"
+                        }
+                    }
+                }
+
+                if !error_msg().is_empty() {
+                    div {
+                        style: "background: #fff5f5; color: #c53030; padding: 0.75rem; border-radius: 0.25rem; margin-bottom: 1rem;",
+                        "{error_msg}"
+                    }
+                }
+                if !message().is_empty() {
+                    div {
+                        style: "background: #f0fff4; color: #276749; padding: 0.75rem; border-radius: 0.25rem; margin-bottom: 1rem; word-break: break-all;",
+                        "{message}"
+                    }
+                }
+
+                div {
+                    style: "display: flex; justify-content: flex-end; gap: 0.5rem;",
+                    button {
+                        style: SECONDARY_BUTTON_STYLE,
+                        onclick: move |_| on_close.call(()),
+                        "Close"
+                    }
+                    button {
+                        style: PRIMARY_BUTTON_STYLE,
+                        disabled: exporting(),
+                        onclick: handle_export,
+                        if exporting() { "Exporting..." } else { "Export" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+const LABEL_STYLE: &str =
+    "display: block; font-weight: 600; color: #4a5568; font-size: 0.875rem; margin-bottom: 0.5rem;";
+const INPUT_STYLE: &str =
+    "padding: 0.5rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 1rem;";
+const PRIMARY_BUTTON_STYLE: &str = "padding: 0.5rem 1rem; background: #667eea; color: white; border: none; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem; font-weight: 500;";
+const SECONDARY_BUTTON_STYLE: &str = "padding: 0.5rem 1rem; border: 1px solid #e2e8f0; background: white; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem;";
@@ -0,0 +1,106 @@
+//! Global Invoice Modal Component
+//!
+//! Issues the daily global invoice for the sales no customer asked to invoice.
+
+use super::invoice_modal::{CatalogSelect, InvoiceSummary};
+use crate::handlers::AppState;
+use crate::models::invoice::PAYMENT_FORMS;
+use crate::models::InvoiceRecord;
+use crate::views::app::use_store_clock;
+use dioxus::prelude::*;
+
+#[component]
+pub fn GlobalInvoiceModal(on_close: EventHandler<()>) -> Element {
+    let app_state = use_context::<AppState>();
+    let clock = use_store_clock();
+
+    let mut date = use_signal(|| clock.today().format("%Y-%m-%d").to_string());
+    let payment_form = use_signal(|| "01".to_string());
+    let mut record = use_signal(|| Option::<InvoiceRecord>::None);
+    let mut error_msg = use_signal(String::new);
+    let mut issuing = use_signal(|| false);
+
+    let handle_issue = move |_| {
+        let handler = app_state.invoicing_handler.clone();
+        let (day, form) = (date(), payment_form());
+        error_msg.set(String::new());
+        issuing.set(true);
+
+        spawn(async move {
+            match handler.issue_global_invoice(day, form).await {
+                Ok(issued) => record.set(Some(issued)),
+                Err(err) => error_msg.set(err),
+            }
+            issuing.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.5); display: flex; justify-content: center; align-items: center; z-index: 1000;",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background: white; padding: 2rem; border-radius: 0.5rem; width: 480px; max-width: 95%;",
+                onclick: move |evt| evt.stop_propagation(),
+
+                h3 { style: "margin: 0 0 1.5rem 0; font-size: 1.25rem;", "🏛️ Global Invoice" }
+
+                if let Some(issued) = record() {
+                    InvoiceSummary { record: issued }
+                } else {
+                    div {
+                        style: "color: #718096; font-size: 0.875rem; margin-bottom: 1rem;",
+                        "Invoices the day's paid sales that were not invoiced to a customer, one line per ticket, to the general public."
+                    }
+                    div {
+                        style: "display: grid; grid-template-columns: 1fr 1fr; gap: 1rem; margin-bottom: 1.5rem;",
+                        div {
+                            label { style: LABEL_STYLE, "Business day" }
+                            input {
+                                r#type: "date",
+                                style: INPUT_STYLE,
+                                value: "{date}",
+                                oninput: move |e| date.set(e.value())
+                            }
+                        }
+                        div {
+                            label { style: LABEL_STYLE, "Payment form" }
+                            CatalogSelect { catalog: PAYMENT_FORMS, value: payment_form }
+                        }
+                    }
+                }
+
+                if !error_msg().is_empty() {
+                    div {
+                        style: "background: #fff5f5; color: #c53030; padding: 0.75rem; border-radius: 0.25rem; margin: 1rem 0;",
+                        "{error_msg}"
+                    }
+                }
+
+                div {
+                    style: "display: flex; justify-content: flex-end; gap: 0.5rem; margin-top: 1rem;",
+                    button {
+                        style: SECONDARY_BUTTON_STYLE,
+                        onclick: move |_| on_close.call(()),
+                        "Close"
+                    }
+                    if record().is_none() {
+                        button {
+                            style: PRIMARY_BUTTON_STYLE,
+                            disabled: issuing(),
+                            onclick: handle_issue,
+                            if issuing() { "Stamping..." } else { "Issue Invoice" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+const LABEL_STYLE: &str =
+    "display: block; font-weight: 600; color: #4a5568; font-size: 0.875rem; margin-bottom: 0.5rem;";
+const INPUT_STYLE: &str = "width: 100%; padding: 0.5rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 1rem; box-sizing: border-box; background: white;";
+const PRIMARY_BUTTON_STYLE: &str = "padding: 0.5rem 1rem; background: #667eea; color: white; border: none; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem; font-weight: 500;";
+const SECONDARY_BUTTON_STYLE: &str = "padding: 0.5rem 1rem; border: 1px solid #e2e8f0; background: white; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem;";
@@ -0,0 +1,58 @@
+//! Helper Functions
+//!
+//! Common utilities for the sales history.
+
+use crate::models::{PaymentFilter, SaleFilter};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+/// Calculate total pages based on total count and page size
+pub fn calculate_total_pages(total_count: i64, page_size: i64) -> i64 {
+    ((total_count as f64) / (page_size as f64)).ceil() as i64
+}
+
+/// Build a sale filter from the filter bar inputs
+pub fn parse_sale_filter(
+    from: &str,
+    to: &str,
+    min_total: &str,
+    max_total: &str,
+    payment: &str,
+    product: &str,
+) -> Result<SaleFilter, String> {
+    let filter = SaleFilter {
+        from: parse_date(from, "start date")?,
+        to: parse_date(to, "end date")?,
+        min_total: parse_amount(min_total, "minimum amount")?,
+        max_total: parse_amount(max_total, "maximum amount")?,
+        payment: match payment {
+            "cash" => PaymentFilter::Cash,
+            "loan" => PaymentFilter::Loan,
+            _ => PaymentFilter::All,
+        },
+        product: product.trim().to_string(),
+    };
+    filter.validate()?;
+    Ok(filter)
+}
+
+fn parse_date(value: &str, field: &str) -> Result<Option<NaiveDate>, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map(Some)
+        .map_err(|_| format!("Invalid {}", field))
+}
+
+fn parse_amount(value: &str, field: &str) -> Result<Option<Decimal>, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    value
+        .parse::<Decimal>()
+        .map(Some)
+        .map_err(|_| format!("Invalid {}", field))
+}
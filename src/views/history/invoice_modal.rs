@@ -0,0 +1,337 @@
+//! Invoice Modal Component
+//!
+//! Issues the CFDI invoice of a sale to a customer, or shows the invoice it
+//! already has with its XML and PDF downloads.
+
+use crate::handlers::AppState;
+use crate::models::invoice::{CFDI_USES, PAYMENT_FORMS, TAX_REGIMES};
+use crate::models::{InvoiceReceiver, InvoiceRecord, Sale};
+use crate::utils::formatting::format_currency;
+use crate::views::app::use_store_clock;
+use dioxus::prelude::*;
+
+#[component]
+pub fn InvoiceModal(sale: Sale, on_close: EventHandler<()>) -> Element {
+    let app_state = use_context::<AppState>();
+
+    let mut record = use_signal(|| Option::<InvoiceRecord>::None);
+    let mut loading = use_signal(|| true);
+    let mut rfc = use_signal(String::new);
+    let mut legal_name = use_signal(String::new);
+    let mut tax_regime = use_signal(|| "616".to_string());
+    let mut zip_code = use_signal(String::new);
+    let mut cfdi_use = use_signal(|| "G03".to_string());
+    let payment_form = use_signal(|| "01".to_string());
+    let mut error_msg = use_signal(String::new);
+    let mut issuing = use_signal(|| false);
+
+    // Load the invoice the sale already has, if any, or the tax profile
+    // captured at checkout to prefill the receiver
+    let load_handler = app_state.invoicing_handler.clone();
+    let profile_handler = app_state.sales_handler.clone();
+    let load_sale_id = sale.id.clone();
+    use_hook(move || {
+        spawn(async move {
+            match load_handler.get_sale_invoice(load_sale_id.clone()).await {
+                Ok(existing) => record.set(existing),
+                Err(err) => error_msg.set(err),
+            }
+            match profile_handler.get_sale_tax_profile(load_sale_id).await {
+                Ok(Some(profile)) => {
+                    rfc.set(profile.rfc);
+                    legal_name.set(profile.legal_name);
+                    tax_regime.set(profile.tax_regime);
+                    zip_code.set(profile.zip_code);
+                    cfdi_use.set(profile.cfdi_use);
+                }
+                Ok(None) => {}
+                Err(err) => error_msg.set(err),
+            }
+            loading.set(false);
+        });
+    });
+
+    let issue_handler = app_state.invoicing_handler.clone();
+    let sale_id = sale.id.clone();
+    let handle_issue = move |_| {
+        let handler = issue_handler.clone();
+        let sale_id = sale_id.clone();
+        let receiver = InvoiceReceiver {
+            rfc: rfc(),
+            legal_name: legal_name(),
+            tax_regime: tax_regime(),
+            zip_code: zip_code(),
+            cfdi_use: cfdi_use(),
+        };
+        let form = payment_form();
+        error_msg.set(String::new());
+        issuing.set(true);
+
+        spawn(async move {
+            match handler.issue_sale_invoice(sale_id, receiver, form).await {
+                Ok(issued) => record.set(Some(issued)),
+                Err(err) => error_msg.set(err),
+            }
+            issuing.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.5); display: flex; justify-content: center; align-items: center; z-index: 1000;",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background: white; padding: 2rem; border-radius: 0.5rem; width: 560px; max-width: 95%; max-height: 90vh; overflow-y: auto;",
+                onclick: move |evt| evt.stop_propagation(),
+
+                h3 { style: "margin: 0 0 0.5rem 0; font-size: 1.25rem;", "🧾 Invoice" }
+                div {
+                    style: "color: #718096; font-size: 0.875rem; margin-bottom: 1.5rem;",
+                    "Sale {sale.id.chars().take(8).collect::<String>()} · {format_currency(sale.total_amount)}"
+                }
+
+                if loading() {
+                    div { style: "padding: 1rem; text-align: center; color: #718096;", "⏳ Loading..." }
+                } else if let Some(issued) = record() {
+                    InvoiceSummary { record: issued }
+                } else {
+                    TaxProfileFields { rfc, legal_name, tax_regime, zip_code, cfdi_use }
+                    div {
+                        style: "margin-bottom: 1.5rem;",
+                        label { style: LABEL_STYLE, "Payment form" }
+                        if sale.is_loan {
+                            div {
+                                style: "color: #718096; font-size: 0.875rem;",
+                                "Sale on credit: invoiced as payment in installments (PPD), form to be defined."
+                            }
+                        } else {
+                            CatalogSelect { catalog: PAYMENT_FORMS, value: payment_form }
+                        }
+                    }
+                }
+
+                if !error_msg().is_empty() {
+                    div {
+                        style: "background: #fff5f5; color: #c53030; padding: 0.75rem; border-radius: 0.25rem; margin: 1rem 0;",
+                        "{error_msg}"
+                    }
+                }
+
+                div {
+                    style: "display: flex; justify-content: flex-end; gap: 0.5rem; margin-top: 1rem;",
+                    button {
+                        style: SECONDARY_BUTTON_STYLE,
+                        onclick: move |_| on_close.call(()),
+                        "Close"
+                    }
+                    if !loading() && record().is_none() {
+                        button {
+                            style: PRIMARY_BUTTON_STYLE,
+                            disabled: issuing(),
+                            onclick: handle_issue,
+                            if issuing() { "Stamping..." } else { "Issue Invoice" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Receiver tax profile inputs: RFC, legal name, regime, ZIP code and CFDI use
+#[component]
+pub fn TaxProfileFields(
+    rfc: Signal<String>,
+    legal_name: Signal<String>,
+    tax_regime: Signal<String>,
+    zip_code: Signal<String>,
+    cfdi_use: Signal<String>,
+) -> Element {
+    let (mut rfc, mut legal_name, mut zip_code) = (rfc, legal_name, zip_code);
+
+    rsx! {
+        div {
+            style: "display: grid; grid-template-columns: 1fr 2fr; gap: 1rem; margin-bottom: 1rem;",
+            div {
+                label { style: LABEL_STYLE, "RFC" }
+                input {
+                    r#type: "text",
+                    style: INPUT_STYLE,
+                    value: "{rfc}",
+                    oninput: move |e| rfc.set(e.value().to_uppercase())
+                }
+            }
+            div {
+                label { style: LABEL_STYLE, "Legal name" }
+                input {
+                    r#type: "text",
+                    style: INPUT_STYLE,
+                    value: "{legal_name}",
+                    oninput: move |e| legal_name.set(e.value())
+                }
+            }
+        }
+        div {
+            style: "display: grid; grid-template-columns: 2fr 1fr; gap: 1rem; margin-bottom: 1rem;",
+            div {
+                label { style: LABEL_STYLE, "Tax regime" }
+                CatalogSelect { catalog: TAX_REGIMES, value: tax_regime }
+            }
+            div {
+                label { style: LABEL_STYLE, "ZIP code" }
+                input {
+                    r#type: "text",
+                    maxlength: "5",
+                    style: INPUT_STYLE,
+                    value: "{zip_code}",
+                    oninput: move |e| zip_code.set(e.value())
+                }
+            }
+        }
+        div {
+            style: "margin-bottom: 1rem;",
+            label { style: LABEL_STYLE, "CFDI use" }
+            CatalogSelect { catalog: CFDI_USES, value: cfdi_use }
+        }
+    }
+}
+
+/// Select over a SAT catalog, showing code and description
+#[component]
+pub fn CatalogSelect(
+    catalog: &'static [(&'static str, &'static str)],
+    value: Signal<String>,
+) -> Element {
+    let mut value = value;
+
+    rsx! {
+        select {
+            style: INPUT_STYLE,
+            onchange: move |e| value.set(e.value()),
+            for (code, description) in catalog.iter() {
+                option {
+                    key: "{code}",
+                    value: "{code}",
+                    selected: value() == *code,
+                    "{code} - {description}"
+                }
+            }
+        }
+    }
+}
+
+/// Stamped invoice details with XML and PDF downloads
+#[component]
+pub fn InvoiceSummary(record: InvoiceRecord) -> Element {
+    let app_state = use_context::<AppState>();
+    let clock = use_store_clock();
+    let mut message = use_signal(String::new);
+    let stamped_at = clock.format(&record.stamped_at, "%d-%b-%Y %H:%M");
+    let folio = record.folio_label();
+
+    let xml_record = record.clone();
+    let save_xml = move |_| {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(file_path) = rfd::FileDialog::new()
+            .set_file_name(format!("{}.xml", xml_record.uuid))
+            .add_filter("CFDI", &["xml"])
+            .save_file()
+        {
+            match std::fs::write(&file_path, &xml_record.xml) {
+                Ok(_) => message.set(format!("XML saved to {}", file_path.display())),
+                Err(e) => message.set(format!("Failed to write file: {}", e)),
+            }
+        }
+    };
+
+    let pdf_record = record.clone();
+    let settings_handler = app_state.settings_handler.clone();
+    let save_pdf = move |_| {
+        let record = pdf_record.clone();
+        let handler = settings_handler.clone();
+        spawn(async move {
+            let issuer = match handler.get_invoice_issuer().await {
+                Ok(issuer) => issuer,
+                Err(err) => {
+                    message.set(err);
+                    return;
+                }
+            };
+
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(file_path) = rfd::FileDialog::new()
+                .set_file_name(format!("factura_{}.pdf", record.folio_label()))
+                .add_filter("PDF", &["pdf"])
+                .save_file()
+            {
+                match super::invoice_template::generate_invoice_pdf(
+                    &record, &issuer, &clock, file_path,
+                ) {
+                    Ok(_) => message.set("PDF saved".to_string()),
+                    Err(e) => {
+                        tracing::error!("Failed to generate invoice PDF: {}", e);
+                        message.set(format!("Failed to generate PDF: {}", e));
+                    }
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            style: "background: #f7fafc; padding: 1rem; border-radius: 0.5rem; margin-bottom: 1rem;",
+            div {
+                style: "margin-bottom: 0.5rem;",
+                span { style: "font-weight: 500; color: #4a5568;", "Folio: " }
+                span { "{folio}" }
+            }
+            div {
+                style: "margin-bottom: 0.5rem;",
+                span { style: "font-weight: 500; color: #4a5568;", "Fiscal folio: " }
+                span { style: "font-family: monospace; font-size: 0.875rem;", "{record.uuid}" }
+            }
+            div {
+                style: "margin-bottom: 0.5rem;",
+                span { style: "font-weight: 500; color: #4a5568;", "Receiver: " }
+                span { "{record.invoice.receiver.legal_name} ({record.invoice.receiver.rfc})" }
+            }
+            div {
+                style: "margin-bottom: 0.5rem;",
+                span { style: "font-weight: 500; color: #4a5568;", "Stamped: " }
+                span { "{stamped_at}" }
+            }
+            div {
+                span { style: "font-weight: 500; color: #4a5568;", "Total: " }
+                span { style: "font-family: monospace; font-weight: 600;", "{format_currency(record.invoice.total())}" }
+            }
+        }
+
+        div {
+            style: "display: flex; gap: 0.5rem;",
+            button {
+                style: PRIMARY_BUTTON_STYLE,
+                onclick: save_xml,
+                "⬇️ XML"
+            }
+            button {
+                style: "padding: 0.5rem 1rem; background: #ed8936; color: white; border: none; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem; font-weight: 500;",
+                onclick: save_pdf,
+                "📄 PDF"
+            }
+        }
+
+        if !message().is_empty() {
+            div {
+                style: "margin-top: 0.75rem; color: #4a5568; font-size: 0.875rem; word-break: break-all;",
+                "{message}"
+            }
+        }
+    }
+}
+
+const LABEL_STYLE: &str =
+    "display: block; font-weight: 600; color: #4a5568; font-size: 0.875rem; margin-bottom: 0.5rem;";
+const INPUT_STYLE: &str = "width: 100%; padding: 0.5rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 1rem; box-sizing: border-box; background: white;";
+const PRIMARY_BUTTON_STYLE: &str = "padding: 0.5rem 1rem; background: #667eea; color: white; border: none; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem; font-weight: 500;";
+const SECONDARY_BUTTON_STYLE: &str = "padding: 0.5rem 1rem; border: 1px solid #e2e8f0; background: white; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem;";
@@ -0,0 +1,122 @@
+//! Invoice PDF generation with Typst templates
+//!
+//! Printed representation of a stamped CFDI, with the stamp data taken from
+//! the XML.
+
+use crate::models::invoice::{CFDI_USES, PAYMENT_FORMS, TAX_REGIMES};
+use crate::models::{InvoiceIssuer, InvoiceRecord, StoreClock};
+use crate::utils::cfdi::xml_attribute;
+use crate::utils::formatting::format_currency;
+use crate::utils::pdf::{compile_typst_to_pdf, escape_typst};
+use sailfish::TemplateOnce;
+
+#[derive(TemplateOnce)]
+#[template(path = "invoice.typ.stpl")]
+struct InvoiceTemplate {
+    folio: String,
+    uuid: String,
+    issued_at: String,
+    stamped_at: String,
+    issuer_rfc: String,
+    issuer_name: String,
+    issuer_regime: String,
+    place_of_issue: String,
+    receiver_rfc: String,
+    receiver_name: String,
+    receiver_regime: String,
+    receiver_zip: String,
+    cfdi_use: String,
+    global_day: Option<String>,
+    payment_form: String,
+    payment_method: String,
+    concepts: Vec<ConceptLine>,
+    subtotal: String,
+    taxes: Vec<(String, String)>,
+    total: String,
+    certificate_number: String,
+    sat_certificate_number: String,
+    issuer_seal: String,
+    sat_seal: String,
+}
+
+struct ConceptLine {
+    product_code: String,
+    quantity: String,
+    unit_code: String,
+    description: String,
+    unit_value: String,
+    amount: String,
+}
+
+pub fn generate_invoice_pdf(
+    record: &InvoiceRecord,
+    issuer: &InvoiceIssuer,
+    clock: &StoreClock,
+    file_path: std::path::PathBuf,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let invoice = &record.invoice;
+    let money = |amount| escape_typst(&format_currency(amount));
+    let stamp = |name| escape_typst(&xml_attribute(&record.xml, name).unwrap_or_default());
+
+    let concepts = invoice
+        .concepts
+        .iter()
+        .map(|concept| ConceptLine {
+            product_code: escape_typst(&concept.product_code),
+            quantity: escape_typst(&concept.quantity.normalize().to_string()),
+            unit_code: escape_typst(&concept.unit_code),
+            description: escape_typst(&concept.description),
+            unit_value: money(concept.unit_value),
+            amount: money(concept.amount),
+        })
+        .collect();
+
+    let template = InvoiceTemplate {
+        folio: escape_typst(&record.folio_label()),
+        uuid: escape_typst(&record.uuid),
+        issued_at: escape_typst(&clock.format(&invoice.issued_at, "%d-%b-%Y %H:%M")),
+        stamped_at: escape_typst(&clock.format(&record.stamped_at, "%d-%b-%Y %H:%M")),
+        issuer_rfc: escape_typst(&issuer.rfc),
+        issuer_name: escape_typst(&issuer.legal_name),
+        issuer_regime: describe(TAX_REGIMES, &issuer.tax_regime),
+        place_of_issue: escape_typst(&issuer.zip_code),
+        receiver_rfc: escape_typst(&invoice.receiver.rfc),
+        receiver_name: escape_typst(&invoice.receiver.legal_name),
+        receiver_regime: describe(TAX_REGIMES, &invoice.receiver.tax_regime),
+        receiver_zip: escape_typst(&invoice.receiver.zip_code),
+        cfdi_use: describe(CFDI_USES, &invoice.receiver.cfdi_use),
+        global_day: invoice
+            .global_day
+            .map(|day| escape_typst(&day.format("%d-%b-%Y").to_string())),
+        payment_form: describe(PAYMENT_FORMS, &invoice.payment_form),
+        payment_method: escape_typst(invoice.payment_method.code()),
+        concepts,
+        subtotal: money(invoice.subtotal()),
+        taxes: invoice
+            .tax_totals()
+            .iter()
+            .map(|tax| (escape_typst(tax.treatment.label()), money(tax.amount)))
+            .collect(),
+        total: money(invoice.total()),
+        certificate_number: escape_typst(&issuer.certificate_number),
+        sat_certificate_number: stamp("NoCertificadoSAT"),
+        issuer_seal: stamp("SelloCFD"),
+        sat_seal: stamp("SelloSAT"),
+    };
+
+    let typst_content = template.render_once()?;
+
+    // Compile Typst to PDF and save
+    compile_typst_to_pdf(typst_content, file_path)?;
+
+    Ok(())
+}
+
+/// Catalog code with its description, e.g. "601 - General de Ley Personas Morales"
+fn describe(catalog: &[(&str, &str)], code: &str) -> String {
+    let text = match catalog.iter().find(|(c, _)| *c == code) {
+        Some((_, description)) => format!("{} - {}", code, description),
+        None => code.to_string(),
+    };
+    escape_typst(&text)
+}
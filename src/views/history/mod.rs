@@ -0,0 +1,325 @@
+//! Sales History Module
+//!
+//! UI components for browsing past sales, reprinting their receipts and
+//! invoicing them.
+
+mod global_invoice_modal;
+mod helpers;
+mod invoice_modal;
+mod invoice_template;
+mod sale_row;
+
+use global_invoice_modal::GlobalInvoiceModal;
+use helpers::{calculate_total_pages, parse_sale_filter};
+use invoice_modal::InvoiceModal;
+pub use invoice_modal::TaxProfileFields;
+use sale_row::SaleRow;
+
+use crate::handlers::AppState;
+use crate::models::{Operation, Sale};
+use crate::utils::export::ExportDataset;
+use crate::views::export_modal::ExportModal;
+use crate::views::pagination_nav::PaginationNav;
+use crate::views::sales::SaleReceiptModal;
+use dioxus::prelude::*;
+
+const PAGE_SIZE: i64 = 15;
+
+#[component]
+pub fn SalesHistoryView() -> Element {
+    let app_state = use_context::<AppState>();
+
+    let mut from_date = use_signal(String::new);
+    let mut to_date = use_signal(String::new);
+    let mut min_total = use_signal(String::new);
+    let mut max_total = use_signal(String::new);
+    let mut payment = use_signal(|| "all".to_string());
+    let mut product_query = use_signal(String::new);
+    let mut current_page = use_signal(|| 1i64);
+    let mut selected_receipt = use_signal(|| Option::<(Sale, Vec<Operation>)>::None);
+    let mut message = use_signal(|| Option::<String>::None);
+    let mut show_export = use_signal(|| false);
+    let mut invoice_sale = use_signal(|| Option::<Sale>::None);
+    let mut show_global_invoice = use_signal(|| false);
+
+    let filter = use_memo(move || {
+        parse_sale_filter(
+            &from_date(),
+            &to_date(),
+            &min_total(),
+            &max_total(),
+            &payment(),
+            &product_query(),
+        )
+    });
+
+    // Load the current page of sales matching the filters
+    let sales_resource = use_resource({
+        let handler = app_state.sales_handler.clone();
+        move || {
+            let handler = handler.clone();
+            let filter = filter();
+            let page = current_page();
+
+            async move {
+                handler
+                    .search_sales_paginated(filter?, page, PAGE_SIZE)
+                    .await
+            }
+        }
+    });
+
+    // Reset to page 1 when the filters change
+    use_effect(move || {
+        let _ = filter();
+        current_page.set(1);
+    });
+
+    // Open the receipt of a past sale
+    let view_receipt_handler = use_callback({
+        let handler = app_state.sales_handler.clone();
+        move |sale_id: String| {
+            let handler = handler.clone();
+            spawn(async move {
+                match handler.get_sale_details(sale_id).await {
+                    Ok(details) => {
+                        selected_receipt.set(Some((details.sale, details.operations)));
+                    }
+                    Err(err) => message.set(Some(format!("Failed to load receipt: {}", err))),
+                }
+            });
+        }
+    });
+
+    let clear_filters = move |_| {
+        from_date.set(String::new());
+        to_date.set(String::new());
+        min_total.set(String::new());
+        max_total.set(String::new());
+        payment.set("all".to_string());
+        product_query.set(String::new());
+    };
+
+    rsx! {
+        div {
+            class: "history-view",
+
+            div {
+                style: "background: white; border-radius: 0.5rem; padding: 1.5rem; box-shadow: 0 1px 3px rgba(0,0,0,0.1);",
+
+                // Header
+                div {
+                    style: "display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;",
+                    h2 {
+                        style: "font-size: 1.5rem; font-weight: 600; color: #2d3748; margin: 0;",
+                        "🧾 Sales History"
+                    }
+                    div {
+                        style: "display: flex; gap: 0.5rem;",
+                        button {
+                            style: "background: white; color: #667eea; padding: 0.5rem 1rem; border: 2px solid #667eea; border-radius: 0.5rem; font-weight: 500; cursor: pointer; font-size: 0.875rem;",
+                            onclick: move |_| show_global_invoice.set(true),
+                            "🏛️ Global Invoice"
+                        }
+                        button {
+                            style: "background: white; color: #667eea; padding: 0.5rem 1rem; border: 2px solid #667eea; border-radius: 0.5rem; font-weight: 500; cursor: pointer; font-size: 0.875rem;",
+                            onclick: move |_| show_export.set(true),
+                            "⬇️ Export"
+                        }
+                        button {
+                            style: "background: white; color: #667eea; padding: 0.5rem 1rem; border: 2px solid #667eea; border-radius: 0.5rem; font-weight: 500; cursor: pointer; font-size: 0.875rem;",
+                            onclick: clear_filters,
+                            "Clear Filters"
+                        }
+                    }
+                }
+
+                if let Some(text) = message() {
+                    div {
+                        style: "padding: 0.75rem; margin-bottom: 1rem; background: #fff5f5; color: #c53030; border-radius: 0.5rem; border: 1px solid #f56565;",
+                        "{text}"
+                        button {
+                            style: "float: right; background: transparent; border: none; cursor: pointer; font-weight: bold;",
+                            onclick: move |_| message.set(None),
+                            "✕"
+                        }
+                    }
+                }
+
+                // Filters
+                div {
+                    style: "display: grid; grid-template-columns: repeat(3, 1fr); gap: 1rem; margin-bottom: 1.5rem;",
+                    div {
+                        label { style: LABEL_STYLE, "From" }
+                        input {
+                            r#type: "date",
+                            style: INPUT_STYLE,
+                            value: "{from_date}",
+                            oninput: move |e| from_date.set(e.value())
+                        }
+                    }
+                    div {
+                        label { style: LABEL_STYLE, "To" }
+                        input {
+                            r#type: "date",
+                            style: INPUT_STYLE,
+                            value: "{to_date}",
+                            oninput: move |e| to_date.set(e.value())
+                        }
+                    }
+                    div {
+                        label { style: LABEL_STYLE, "Payment" }
+                        select {
+                            style: INPUT_STYLE,
+                            onchange: move |e| payment.set(e.value()),
+                            option { value: "all", selected: payment() == "all", "All sales" }
+                            option { value: "cash", selected: payment() == "cash", "Cash" }
+                            option { value: "loan", selected: payment() == "loan", "Loan" }
+                        }
+                    }
+                    div {
+                        label { style: LABEL_STYLE, "Min total" }
+                        input {
+                            r#type: "number",
+                            min: "0",
+                            step: "0.01",
+                            placeholder: "0.00",
+                            style: INPUT_STYLE,
+                            value: "{min_total}",
+                            oninput: move |e| min_total.set(e.value())
+                        }
+                    }
+                    div {
+                        label { style: LABEL_STYLE, "Max total" }
+                        input {
+                            r#type: "number",
+                            min: "0",
+                            step: "0.01",
+                            placeholder: "0.00",
+                            style: INPUT_STYLE,
+                            value: "{max_total}",
+                            oninput: move |e| max_total.set(e.value())
+                        }
+                    }
+                    div {
+                        label { style: LABEL_STYLE, "Product" }
+                        input {
+                            r#type: "text",
+                            placeholder: "🔍 Name or barcode...",
+                            style: INPUT_STYLE,
+                            value: "{product_query}",
+                            oninput: move |e| product_query.set(e.value())
+                        }
+                    }
+                }
+
+                // Sales table
+                match &*sales_resource.read_unchecked() {
+                    Some(Ok(paginated)) => {
+                        let sales = paginated.items.clone();
+                        let total_count = paginated.total_count;
+                        let total_pages = calculate_total_pages(total_count, PAGE_SIZE);
+
+                        rsx! {
+                            div {
+                                style: "margin-bottom: 0.75rem; color: #718096; font-size: 0.875rem;",
+                                "{total_count} sales found"
+                            }
+
+                            div {
+                                style: "overflow-x: auto;",
+                                table {
+                                    style: "width: 100%; border-collapse: collapse;",
+                                    thead {
+                                        tr {
+                                            style: "background: #f7fafc; border-bottom: 2px solid #e2e8f0;",
+                                            th { style: "padding: 0.75rem; text-align: left; font-weight: 600; color: #4a5568;", "Date" }
+                                            th { style: "padding: 0.75rem; text-align: left; font-weight: 600; color: #4a5568;", "Receipt #" }
+                                            th { style: "padding: 0.75rem; text-align: center; font-weight: 600; color: #4a5568;", "Type" }
+                                            th { style: "padding: 0.75rem; text-align: right; font-weight: 600; color: #4a5568;", "Total" }
+                                            th { style: "padding: 0.75rem; text-align: right; font-weight: 600; color: #4a5568;", "Paid" }
+                                            th { style: "padding: 0.75rem; text-align: center; font-weight: 600; color: #4a5568;", "Details" }
+                                        }
+                                    }
+                                    tbody {
+                                        if sales.is_empty() {
+                                            tr {
+                                                td {
+                                                    colspan: "6",
+                                                    style: "padding: 3rem; text-align: center; color: #a0aec0;",
+                                                    "No sales found"
+                                                }
+                                            }
+                                        } else {
+                                            for sale in sales {
+                                                SaleRow {
+                                                    key: "{sale.id}",
+                                                    sale: sale.clone(),
+                                                    on_view: view_receipt_handler,
+                                                    on_invoice: move |sale| invoice_sale.set(Some(sale)),
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
+                            if total_pages > 1 {
+                                PaginationNav {
+                                    current_page,
+                                    total_pages,
+                                }
+                            }
+                        }
+                    },
+                    Some(Err(err)) => rsx! {
+                        div {
+                            style: "padding: 2rem; text-align: center; color: #e53e3e; background: #fff5f5; border-radius: 0.5rem;",
+                            "❌ {err}"
+                        }
+                    },
+                    None => rsx! {
+                        div {
+                            style: "padding: 2rem; text-align: center; color: #718096;",
+                            "⏳ Loading sales..."
+                        }
+                    }
+                }
+            }
+
+            // Receipt modal with operations and PDF export
+            if let Some((sale, operations)) = selected_receipt.read().as_ref() {
+                SaleReceiptModal {
+                    sale: sale.clone(),
+                    operations: operations.clone(),
+                    reprint: true,
+                    on_close: move |_| selected_receipt.set(None),
+                }
+            }
+
+            if let Some(sale) = invoice_sale() {
+                InvoiceModal {
+                    sale,
+                    on_close: move |_| invoice_sale.set(None),
+                }
+            }
+
+            if show_global_invoice() {
+                GlobalInvoiceModal {
+                    on_close: move |_| show_global_invoice.set(false),
+                }
+            }
+
+            if show_export() {
+                ExportModal {
+                    datasets: vec![ExportDataset::Sales, ExportDataset::Operations],
+                    on_close: move |_| show_export.set(false),
+                }
+            }
+        }
+    }
+}
+
+const LABEL_STYLE: &str =
+    "display: block; margin-bottom: 0.5rem; font-weight: 500; font-size: 0.875rem; color: #4a5568;";
+const INPUT_STYLE: &str = "width: 100%; padding: 0.625rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 1rem; box-sizing: border-box; background: white;";
@@ -0,0 +1,72 @@
+//! Sale Row Component
+//!
+//! Displays a single past sale in the sales history table.
+
+use crate::models::Sale;
+use crate::utils::formatting::format_currency;
+use crate::views::app::use_store_clock;
+use dioxus::prelude::*;
+
+#[component]
+pub fn SaleRow(
+    sale: Sale,
+    on_view: EventHandler<String>,
+    on_invoice: EventHandler<Sale>,
+) -> Element {
+    let clock = use_store_clock();
+    let formatted_date = clock.format(&sale.sold_at, "%d-%b-%Y %H:%M");
+    let short_id: String = sale.id.chars().take(8).collect();
+    let sale_id = sale.id.clone();
+    let invoice_sale = sale.clone();
+
+    rsx! {
+        tr {
+            style: "border-bottom: 1px solid #e2e8f0;",
+
+            td {
+                style: "padding: 0.75rem;",
+                "{formatted_date}"
+            }
+            td {
+                style: "padding: 0.75rem; color: #718096; font-family: monospace;",
+                title: "{sale.id}",
+                "{short_id}"
+            }
+            td {
+                style: "padding: 0.75rem; text-align: center;",
+                if sale.is_loan {
+                    span {
+                        style: "background: #fed7d7; color: #c53030; padding: 0.25rem 0.5rem; border-radius: 0.25rem; font-size: 0.875rem; font-weight: 500;",
+                        "💳 Loan"
+                    }
+                } else {
+                    span {
+                        style: "background: #c6f6d5; color: #22543d; padding: 0.25rem 0.5rem; border-radius: 0.25rem; font-size: 0.875rem; font-weight: 500;",
+                        "💰 Cash"
+                    }
+                }
+            }
+            td {
+                style: "padding: 0.75rem; text-align: right; font-weight: 500; font-family: monospace;",
+                "{format_currency(sale.total_amount)}"
+            }
+            td {
+                style: "padding: 0.75rem; text-align: right; font-family: monospace; color: #48bb78;",
+                "{format_currency(sale.paid_amount)}"
+            }
+            td {
+                style: "padding: 0.75rem; text-align: center; white-space: nowrap;",
+                button {
+                    style: "background: #48bb78; color: white; padding: 0.5rem 1rem; border: none; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem; font-weight: 500;",
+                    onclick: move |_| on_view.call(sale_id.clone()),
+                    "🧾 Receipt"
+                }
+                button {
+                    style: "background: white; color: #667eea; padding: 0.5rem 1rem; border: 1px solid #667eea; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem; font-weight: 500; margin-left: 0.5rem;",
+                    onclick: move |_| on_invoice.call(invoice_sale.clone()),
+                    "Invoice"
+                }
+            }
+        }
+    }
+}
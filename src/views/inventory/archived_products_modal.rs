@@ -0,0 +1,156 @@
+//! Archived Products Modal Component
+//!
+//! Lists archived products so they can be restored, or permanently deleted
+//! when they were never sold.
+
+use crate::handlers::AppState;
+use crate::utils::formatting::{format_currency, format_date};
+use crate::views::app::use_store_clock;
+use dioxus::prelude::*;
+
+#[component]
+pub fn ArchivedProductsModal(on_close: EventHandler<()>, on_change: EventHandler<()>) -> Element {
+    let app_state = use_context::<AppState>();
+    let clock = use_store_clock();
+
+    let mut error_msg = use_signal(String::new);
+
+    let mut archived_resource = use_resource({
+        let handler = app_state.inventory_handler.clone();
+        move || {
+            let handler = handler.clone();
+            async move { handler.load_archived_products().await }
+        }
+    });
+
+    let restore_handler = app_state.inventory_handler.clone();
+    let delete_handler = app_state.inventory_handler.clone();
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.5); display: flex; justify-content: center; align-items: center; z-index: 1000;",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background: white; padding: 2rem; border-radius: 0.5rem; width: 700px; max-width: 95%; max-height: 90vh; overflow-y: auto;",
+                onclick: move |evt| evt.stop_propagation(),
+
+                // Header
+                div {
+                    style: "display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;",
+                    h3 { style: "margin: 0; font-size: 1.25rem;", "🗄️ Archived Products" }
+                    button {
+                        style: "padding: 0.5rem 1rem; border: 1px solid #e2e8f0; background: white; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem;",
+                        onclick: move |_| on_close.call(()),
+                        "Close"
+                    }
+                }
+
+                if !error_msg().is_empty() {
+                    div {
+                        style: "background: #fff5f5; color: #c53030; padding: 0.75rem; border-radius: 0.25rem; margin-bottom: 1rem;",
+                        "{error_msg}"
+                    }
+                }
+
+                match &*archived_resource.read_unchecked() {
+                    Some(Ok(products)) if products.is_empty() => rsx! {
+                        div {
+                            style: "padding: 1rem; text-align: center; color: #718096;",
+                            "No archived products."
+                        }
+                    },
+                    Some(Ok(products)) => rsx! {
+                        table {
+                            style: "width: 100%; border-collapse: collapse;",
+                            thead {
+                                tr {
+                                    style: "background: #f7fafc; border-bottom: 2px solid #e2e8f0;",
+                                    th { style: HEADER_STYLE, "Product" }
+                                    th { style: HEADER_STYLE, "Price" }
+                                    th { style: HEADER_STYLE, "Archived" }
+                                    th { style: HEADER_STYLE, "" }
+                                }
+                            }
+                            tbody {
+                                for product in products.iter().cloned() {
+                                    {
+                                        let restore_id = product.id.clone();
+                                        let delete_id = product.id.clone();
+                                        let restore_handler = restore_handler.clone();
+                                        let delete_handler = delete_handler.clone();
+
+                                        rsx! {
+                                            tr {
+                                                key: "{product.id}",
+                                                style: "border-bottom: 1px solid #e2e8f0; font-size: 0.875rem;",
+                                                td {
+                                                    style: "padding: 0.5rem;",
+                                                    div { style: "font-weight: 500;", "{product.full_name}" }
+                                                    if let Some(ref barcode) = product.barcode {
+                                                        div { style: "color: #718096; font-family: monospace;", "{barcode}" }
+                                                    }
+                                                }
+                                                td { style: "padding: 0.5rem;", {format_currency(product.user_price)} }
+                                                td {
+                                                    style: "padding: 0.5rem; color: #718096;",
+                                                    {product.archived_at.as_ref().map(|at| format_date(at, &clock)).unwrap_or_default()}
+                                                }
+                                                td {
+                                                    style: "padding: 0.5rem; text-align: right; white-space: nowrap;",
+                                                    button {
+                                                        style: "background: none; border: none; color: #667eea; font-weight: 500; cursor: pointer; padding: 0.25rem 0.5rem; font-size: 0.875rem;",
+                                                        onclick: move |_| {
+                                                            let handler = restore_handler.clone();
+                                                            let id = restore_id.clone();
+                                                            spawn(async move {
+                                                                match handler.restore_product(id).await {
+                                                                    Ok(_) => {
+                                                                        error_msg.set(String::new());
+                                                                        archived_resource.restart();
+                                                                        on_change.call(());
+                                                                    }
+                                                                    Err(err) => error_msg.set(err),
+                                                                }
+                                                            });
+                                                        },
+                                                        "Restore"
+                                                    }
+                                                    button {
+                                                        style: "background: none; border: none; color: #e53e3e; font-weight: 500; cursor: pointer; padding: 0.25rem 0.5rem; font-size: 0.875rem;",
+                                                        onclick: move |_| {
+                                                            let handler = delete_handler.clone();
+                                                            let id = delete_id.clone();
+                                                            spawn(async move {
+                                                                match handler.delete_product(id).await {
+                                                                    Ok(()) => {
+                                                                        error_msg.set(String::new());
+                                                                        archived_resource.restart();
+                                                                    }
+                                                                    Err(err) => error_msg.set(err),
+                                                                }
+                                                            });
+                                                        },
+                                                        "Delete"
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    Some(Err(err)) => rsx! {
+                        div { style: "color: #e53e3e;", "Error loading archived products: {err}" }
+                    },
+                    None => rsx! {
+                        div { style: "color: #718096;", "Loading archived products..." }
+                    },
+                }
+            }
+        }
+    }
+}
+
+const HEADER_STYLE: &str = "padding: 0.5rem; text-align: left; font-weight: 600; color: #4a5568; font-size: 0.75rem; text-transform: uppercase; letter-spacing: 0.05em;";
@@ -0,0 +1,168 @@
+//! Category Manager Component
+//!
+//! Modal for creating, renaming, moving and deleting product categories.
+
+use crate::handlers::AppState;
+use crate::models::{CategoryInput, CategoryPath};
+use crate::views::inventory::CategorySelect;
+use dioxus::prelude::*;
+
+#[component]
+pub fn CategoryManager(
+    categories: Vec<CategoryPath>,
+    on_close: EventHandler<()>,
+    on_change: EventHandler<()>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+
+    let mut name = use_signal(String::new);
+    let mut parent_id = use_signal(|| None::<String>);
+    let mut editing_id = use_signal(|| None::<String>);
+    let mut error_msg = use_signal(String::new);
+
+    let save_handler = app_state.inventory_handler.clone();
+    let delete_handler = app_state.inventory_handler.clone();
+
+    let handle_save = move |_| {
+        let handler = save_handler.clone();
+        let input = CategoryInput {
+            name: name(),
+            parent_id: parent_id(),
+        };
+        let edit_id = editing_id();
+
+        spawn(async move {
+            let result = match edit_id {
+                Some(id) => handler.update_category(id, input).await.map(|_| ()),
+                None => handler.create_category(input).await.map(|_| ()),
+            };
+
+            match result {
+                Ok(()) => {
+                    name.set(String::new());
+                    parent_id.set(None);
+                    editing_id.set(None);
+                    error_msg.set(String::new());
+                    on_change.call(());
+                }
+                Err(err) => error_msg.set(err),
+            }
+        });
+    };
+
+    let save_label = if editing_id().is_some() {
+        "Update Category"
+    } else {
+        "Add Category"
+    };
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.5); display: flex; justify-content: center; align-items: center; z-index: 1000;",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background: white; padding: 2rem; border-radius: 0.5rem; width: 600px; max-width: 90%; max-height: 90vh; overflow-y: auto;",
+                onclick: move |evt| evt.stop_propagation(),
+
+                // Header
+                div {
+                    style: "display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;",
+                    h3 { style: "margin: 0; font-size: 1.25rem;", "Categories" }
+                    button {
+                        style: "padding: 0.5rem 1rem; border: 1px solid #e2e8f0; background: white; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem;",
+                        onclick: move |_| on_close.call(()),
+                        "Close"
+                    }
+                }
+
+                // Error message
+                if !error_msg().is_empty() {
+                    div {
+                        style: "background: #fff5f5; color: #c53030; padding: 0.75rem; border-radius: 0.25rem; margin-bottom: 1rem;",
+                        "{error_msg}"
+                    }
+                }
+
+                // Create / edit form
+                div {
+                    style: "display: grid; grid-template-columns: 1fr 1fr auto; gap: 0.5rem; margin-bottom: 1.5rem; align-items: center;",
+                    input {
+                        r#type: "text",
+                        placeholder: "Category name",
+                        style: "width: 100%; padding: 0.625rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 1rem; box-sizing: border-box;",
+                        value: "{name}",
+                        oninput: move |e| name.set(e.value())
+                    }
+                    CategorySelect {
+                        categories: categories.clone(),
+                        selected: parent_id(),
+                        empty_label: "No parent (department)",
+                        on_change: move |id| parent_id.set(id),
+                    }
+                    button {
+                        style: "padding: 0.625rem 1rem; background: #667eea; color: white; border: none; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem; font-weight: 500; white-space: nowrap;",
+                        onclick: handle_save,
+                        "{save_label}"
+                    }
+                }
+
+                // Category list
+                if categories.is_empty() {
+                    div {
+                        style: "padding: 1rem; text-align: center; color: #718096;",
+                        "No categories yet. Add a department to get started!"
+                    }
+                } else {
+                    for entry in categories.iter().cloned() {
+                        {
+                            let edit_entry = entry.clone();
+                            let delete_id = entry.category.id.clone();
+                            let handler = delete_handler.clone();
+                            let indent = entry.depth as f64 * 1.5;
+
+                            rsx! {
+                                div {
+                                    key: "{entry.category.id}",
+                                    style: "display: flex; justify-content: space-between; align-items: center; padding: 0.5rem 0; border-bottom: 1px solid #e2e8f0;",
+                                    span {
+                                        style: "padding-left: {indent}rem; font-size: 1rem;",
+                                        "{entry.category.name}"
+                                    }
+                                    div {
+                                        button {
+                                            style: "background: none; border: none; color: #667eea; font-weight: 500; cursor: pointer; padding: 0.25rem 0.5rem; font-size: 0.875rem;",
+                                            onclick: move |_| {
+                                                name.set(edit_entry.category.name.clone());
+                                                parent_id.set(edit_entry.category.parent_id.clone());
+                                                editing_id.set(Some(edit_entry.category.id.clone()));
+                                            },
+                                            "Edit"
+                                        }
+                                        button {
+                                            style: "background: none; border: none; color: #e53e3e; font-weight: 500; cursor: pointer; padding: 0.25rem 0.5rem; font-size: 0.875rem;",
+                                            onclick: move |_| {
+                                                let handler = handler.clone();
+                                                let id = delete_id.clone();
+                                                spawn(async move {
+                                                    match handler.delete_category(id).await {
+                                                        Ok(()) => {
+                                                            error_msg.set(String::new());
+                                                            on_change.call(());
+                                                        }
+                                                        Err(err) => error_msg.set(err),
+                                                    }
+                                                });
+                                            },
+                                            "Delete"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,36 @@
+//! Category Select Component
+//!
+//! Dropdown listing categories by their full path, indented by depth.
+
+use crate::models::CategoryPath;
+use dioxus::prelude::*;
+
+#[component]
+pub fn CategorySelect(
+    categories: Vec<CategoryPath>,
+    selected: Option<String>,
+    empty_label: &'static str,
+    on_change: EventHandler<Option<String>>,
+) -> Element {
+    rsx! {
+        select {
+            style: "width: 100%; padding: 0.625rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; background: white; font-size: 1rem;",
+            onchange: move |evt| {
+                let value = evt.value();
+                on_change.call(if value.is_empty() { None } else { Some(value) });
+            },
+            option {
+                value: "",
+                selected: selected.is_none(),
+                "{empty_label}"
+            }
+            for entry in categories.iter() {
+                option {
+                    value: "{entry.category.id}",
+                    selected: selected.as_deref() == Some(entry.category.id.as_str()),
+                    "{entry.path}"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! Count Line Row Component
+//!
+//! One product in an inventory count: expected and counted quantity, the
+//! variance valued at cost and whether the variance will be posted.
+
+use crate::handlers::AppState;
+use crate::models::InventoryCountLine;
+use crate::utils::formatting::format_currency;
+use dioxus::prelude::*;
+
+#[component]
+pub fn CountLineRow(
+    line: InventoryCountLine,
+    is_open: bool,
+    on_change: EventHandler<()>,
+    on_error: EventHandler<String>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+
+    let counted = line
+        .counted_quantity
+        .map(|q| q.to_string())
+        .unwrap_or_default();
+    let variance = line
+        .variance()
+        .map(|v| format!("{:+.2}", v))
+        .unwrap_or_else(|| "-".to_string());
+    let variance_cost = line.variance_cost();
+    let variance_style = match line.variance() {
+        Some(v) if v < 0.0 => "color: #c53030; font-weight: 600;",
+        Some(v) if v > 0.0 => "color: #38a169; font-weight: 600;",
+        _ => "color: #718096;",
+    };
+    let barcode = line.barcode.clone().unwrap_or_default();
+
+    let count_handler = app_state.inventory_handler.clone();
+    let count_id = line.count_id.clone();
+    let product_id = line.product_id.clone();
+    let handle_counted = move |evt: Event<FormData>| {
+        let handler = count_handler.clone();
+        let count_id = count_id.clone();
+        let product_id = product_id.clone();
+
+        let counted_quantity = match evt.value().trim() {
+            "" => None,
+            value => match value.parse::<f64>() {
+                Ok(q) => Some(q),
+                Err(_) => {
+                    on_error.call("Invalid counted quantity".to_string());
+                    return;
+                }
+            },
+        };
+
+        spawn(async move {
+            match handler
+                .set_counted_quantity(count_id, product_id, counted_quantity)
+                .await
+            {
+                Ok(_) => on_change.call(()),
+                Err(err) => on_error.call(err),
+            }
+        });
+    };
+
+    let approve_handler = app_state.inventory_handler.clone();
+    let count_id = line.count_id.clone();
+    let product_id = line.product_id.clone();
+    let handle_approved = move |evt: Event<FormData>| {
+        let handler = approve_handler.clone();
+        let count_id = count_id.clone();
+        let product_id = product_id.clone();
+        let approved = evt.checked();
+
+        spawn(async move {
+            match handler
+                .set_count_line_approved(count_id, product_id, approved)
+                .await
+            {
+                Ok(_) => on_change.call(()),
+                Err(err) => on_error.call(err),
+            }
+        });
+    };
+
+    rsx! {
+        tr {
+            style: "border-bottom: 1px solid #e2e8f0; font-size: 0.875rem;",
+
+            td {
+                style: "padding: 0.5rem;",
+                div { style: "font-weight: 500; color: #2d3748;", "{line.product_name}" }
+                if !barcode.is_empty() {
+                    div { style: "color: #718096; font-size: 0.75rem; font-family: monospace;", "{barcode}" }
+                }
+            }
+            td { style: "padding: 0.5rem; text-align: center;", "{line.expected_quantity:.2}" }
+            td {
+                style: "padding: 0.5rem; text-align: center;",
+                if is_open {
+                    input {
+                        r#type: "number",
+                        step: "0.001",
+                        min: "0",
+                        placeholder: "-",
+                        style: "width: 90px; padding: 0.25rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 0.875rem; text-align: center;",
+                        value: "{counted}",
+                        onchange: handle_counted
+                    }
+                } else {
+                    "{counted}"
+                }
+            }
+            td { style: "padding: 0.5rem; text-align: center; {variance_style}", "{variance}" }
+            td { style: "padding: 0.5rem; text-align: right; {variance_style}", "{format_currency(variance_cost)}" }
+            td {
+                style: "padding: 0.5rem; text-align: center;",
+                if line.has_variance() {
+                    input {
+                        r#type: "checkbox",
+                        checked: line.approved,
+                        disabled: !is_open,
+                        onchange: handle_approved
+                    }
+                }
+            }
+        }
+    }
+}
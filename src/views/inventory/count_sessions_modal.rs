@@ -0,0 +1,186 @@
+//! Count Sessions Modal Component
+//!
+//! Lists physical inventory counts, starts new ones and opens a count to
+//! continue entering quantities.
+
+use crate::handlers::AppState;
+use crate::utils::formatting::format_datetime;
+use crate::views::app::use_store_clock;
+use dioxus::prelude::*;
+
+#[component]
+pub fn CountSessionsModal(on_close: EventHandler<()>, on_open: EventHandler<String>) -> Element {
+    let app_state = use_context::<AppState>();
+    let clock = use_store_clock();
+
+    let mut new_name = use_signal(|| format!("Count {}", clock.today().format("%Y-%m")));
+    let mut error_msg = use_signal(String::new);
+    let mut refresh_trigger = use_signal(|| 0);
+
+    let mut counts_resource = use_resource({
+        let handler = app_state.inventory_handler.clone();
+        move || {
+            let handler = handler.clone();
+            async move { handler.get_counts().await }
+        }
+    });
+
+    use_effect(move || {
+        let _ = refresh_trigger();
+        counts_resource.restart();
+    });
+
+    let start_handler = app_state.inventory_handler.clone();
+    let handle_start = move |_| {
+        let handler = start_handler.clone();
+        spawn(async move {
+            match handler.start_count(new_name()).await {
+                Ok(count) => on_open.call(count.id),
+                Err(err) => error_msg.set(err),
+            }
+        });
+    };
+
+    let delete_handler = app_state.inventory_handler.clone();
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.5); display: flex; justify-content: center; align-items: center; z-index: 1000;",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background: white; padding: 2rem; border-radius: 0.5rem; width: 700px; max-width: 95%; max-height: 90vh; overflow-y: auto;",
+                onclick: move |evt| evt.stop_propagation(),
+
+                // Header
+                div {
+                    style: "display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;",
+                    h3 { style: "margin: 0; font-size: 1.25rem;", "📋 Inventory Counts" }
+                    button {
+                        style: "padding: 0.5rem 1rem; border: 1px solid #e2e8f0; background: white; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem;",
+                        onclick: move |_| on_close.call(()),
+                        "Close"
+                    }
+                }
+
+                if !error_msg().is_empty() {
+                    div {
+                        style: "background: #fff5f5; color: #c53030; padding: 0.75rem; border-radius: 0.25rem; margin-bottom: 1rem;",
+                        "{error_msg}"
+                    }
+                }
+
+                // Start a new count
+                div {
+                    style: "display: flex; gap: 0.5rem; margin-bottom: 1.5rem;",
+                    input {
+                        r#type: "text",
+                        style: "flex: 1; padding: 0.625rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 1rem;",
+                        value: "{new_name}",
+                        oninput: move |e| new_name.set(e.value())
+                    }
+                    button {
+                        style: "padding: 0.625rem 1rem; background: #667eea; color: white; border: none; border-radius: 0.25rem; cursor: pointer; font-size: 1rem; font-weight: 500;",
+                        onclick: handle_start,
+                        "Start Count"
+                    }
+                }
+                p {
+                    style: "margin: -1rem 0 1.5rem 0; color: #718096; font-size: 0.875rem;",
+                    "Starting a count freezes the current stock of every product as the expected quantity."
+                }
+
+                match &*counts_resource.read_unchecked() {
+                    Some(Ok(counts)) if counts.is_empty() => rsx! {
+                        div {
+                            style: "padding: 2rem; text-align: center; color: #718096;",
+                            "No inventory counts yet."
+                        }
+                    },
+                    Some(Ok(counts)) => rsx! {
+                        table {
+                            style: "width: 100%; border-collapse: collapse;",
+                            thead {
+                                tr {
+                                    style: "background: #f7fafc; border-bottom: 2px solid #e2e8f0;",
+                                    th { style: HEADER_STYLE, "Name" }
+                                    th { style: HEADER_STYLE, "Started" }
+                                    th { style: "{HEADER_STYLE} text-align: center;", "Counted" }
+                                    th { style: HEADER_STYLE, "Status" }
+                                    th { style: HEADER_STYLE }
+                                }
+                            }
+                            tbody {
+                                for count in counts.iter().cloned() {
+                                    tr {
+                                        key: "{count.id}",
+                                        style: "border-bottom: 1px solid #e2e8f0; font-size: 0.875rem;",
+                                        td { style: "padding: 0.5rem; font-weight: 500;", "{count.name}" }
+                                        td { style: "padding: 0.5rem; color: #4a5568;", "{format_datetime(&count.started_at, &clock)}" }
+                                        td { style: "padding: 0.5rem; text-align: center;", "{count.counted_lines} / {count.total_lines}" }
+                                        td {
+                                            style: "padding: 0.5rem;",
+                                            match count.posted_at {
+                                                Some(posted_at) => rsx! {
+                                                    span { style: "color: #718096;", "Posted {format_datetime(&posted_at, &clock)}" }
+                                                },
+                                                None => rsx! {
+                                                    span { style: "color: #38a169; font-weight: 500;", "Open" }
+                                                },
+                                            }
+                                        }
+                                        td {
+                                            style: "padding: 0.5rem; text-align: right; white-space: nowrap;",
+                                            button {
+                                                style: "background: none; border: none; color: #667eea; font-weight: 500; cursor: pointer; padding: 0.25rem 0.5rem; font-size: 0.875rem;",
+                                                onclick: {
+                                                    let id = count.id.clone();
+                                                    move |_| on_open.call(id.clone())
+                                                },
+                                                if count.is_open() { "Continue" } else { "View" }
+                                            }
+                                            if count.is_open() {
+                                                button {
+                                                    style: "background: none; border: none; color: #e53e3e; font-weight: 500; cursor: pointer; padding: 0.25rem 0.5rem; font-size: 0.875rem;",
+                                                    onclick: {
+                                                        let handler = delete_handler.clone();
+                                                        let id = count.id.clone();
+                                                        move |_| {
+                                                            let handler = handler.clone();
+                                                            let id = id.clone();
+                                                            spawn(async move {
+                                                                match handler.delete_count(id).await {
+                                                                    Ok(_) => refresh_trigger.set(refresh_trigger() + 1),
+                                                                    Err(err) => error_msg.set(err),
+                                                                }
+                                                            });
+                                                        }
+                                                    },
+                                                    "Delete"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    Some(Err(err)) => rsx! {
+                        div {
+                            style: "padding: 2rem; text-align: center; color: #e53e3e; background: #fff5f5; border-radius: 0.5rem;",
+                            "❌ {err}"
+                        }
+                    },
+                    None => rsx! {
+                        div {
+                            style: "padding: 2rem; text-align: center; color: #718096;",
+                            "⏳ Loading counts..."
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+const HEADER_STYLE: &str = "padding: 0.5rem; text-align: left; font-weight: 600; color: #4a5568; font-size: 0.75rem; text-transform: uppercase; letter-spacing: 0.05em;";
@@ -0,0 +1,139 @@
+//! Expiring Lots Modal Component
+//!
+//! "Expiring soon" report listing sellable lots that expire within a chosen
+//! window, with actions to move them to the Damaged or Expired condition.
+
+use crate::handlers::AppState;
+use crate::views::inventory::lot_row::{condition_name, LotRow};
+use dioxus::prelude::*;
+
+/// Selectable report windows in days
+const WINDOW_OPTIONS: [i64; 4] = [7, 15, 30, 60];
+
+#[component]
+pub fn ExpiringLotsModal(on_close: EventHandler<()>, on_change: EventHandler<()>) -> Element {
+    let app_state = use_context::<AppState>();
+    let mut days = use_signal(|| 7i64);
+    let mut refresh_trigger = use_signal(|| 0);
+
+    let mut lots_resource = use_resource({
+        let handler = app_state.inventory_handler.clone();
+        move || {
+            let handler = handler.clone();
+            let days = days();
+            async move { handler.get_expiring_lots(days).await }
+        }
+    });
+
+    let conditions_resource = use_resource({
+        let handler = app_state.inventory_handler.clone();
+        move || {
+            let handler = handler.clone();
+            async move { handler.get_conditions().await }
+        }
+    });
+
+    use_effect(move || {
+        let _ = refresh_trigger();
+        lots_resource.restart();
+    });
+
+    let conditions = match &*conditions_resource.read_unchecked() {
+        Some(Ok(conditions)) => conditions.clone(),
+        _ => Vec::new(),
+    };
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.5); display: flex; justify-content: center; align-items: center; z-index: 1000;",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background: white; padding: 2rem; border-radius: 0.5rem; width: 800px; max-width: 95%; max-height: 90vh; overflow-y: auto;",
+                onclick: move |evt| evt.stop_propagation(),
+
+                // Header
+                div {
+                    style: "display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;",
+                    h3 { style: "margin: 0; font-size: 1.25rem;", "⏳ Expiring Soon" }
+                    div {
+                        style: "display: flex; gap: 0.5rem; align-items: center;",
+                        select {
+                            style: "padding: 0.5rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; background: white; font-size: 0.875rem;",
+                            onchange: move |evt| {
+                                if let Ok(value) = evt.value().parse::<i64>() {
+                                    days.set(value);
+                                }
+                            },
+                            for option_days in WINDOW_OPTIONS {
+                                option {
+                                    value: "{option_days}",
+                                    selected: option_days == days(),
+                                    "Next {option_days} days"
+                                }
+                            }
+                        }
+                        button {
+                            style: "padding: 0.5rem 1rem; border: 1px solid #e2e8f0; background: white; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem;",
+                            onclick: move |_| on_close.call(()),
+                            "Close"
+                        }
+                    }
+                }
+
+                match &*lots_resource.read_unchecked() {
+                    Some(Ok(lots)) if lots.is_empty() => rsx! {
+                        div {
+                            style: "padding: 2rem; text-align: center; color: #718096;",
+                            "No lots expire in this period."
+                        }
+                    },
+                    Some(Ok(lots)) => rsx! {
+                        table {
+                            style: "width: 100%; border-collapse: collapse;",
+                            thead {
+                                tr {
+                                    style: "background: #f7fafc; border-bottom: 2px solid #e2e8f0;",
+                                    th { style: HEADER_STYLE, "Product" }
+                                    th { style: HEADER_STYLE, "Lot" }
+                                    th { style: HEADER_STYLE, "Expires" }
+                                    th { style: "{HEADER_STYLE} text-align: center;", "Remaining" }
+                                    th { style: "{HEADER_STYLE} text-align: center;", "Condition" }
+                                    th { style: "{HEADER_STYLE} text-align: right;", "Move Stock" }
+                                }
+                            }
+                            tbody {
+                                for entry in lots.iter().cloned() {
+                                    LotRow {
+                                        key: "{entry.lot.id}",
+                                        condition: condition_name(&conditions, entry.lot.condition_id),
+                                        lot: entry.lot,
+                                        product_name: Some(entry.product_name),
+                                        on_moved: move |_| {
+                                            refresh_trigger.set(refresh_trigger() + 1);
+                                            on_change.call(());
+                                        },
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    Some(Err(err)) => rsx! {
+                        div {
+                            style: "padding: 2rem; text-align: center; color: #e53e3e; background: #fff5f5; border-radius: 0.5rem;",
+                            "❌ Error loading lots: {err}"
+                        }
+                    },
+                    None => rsx! {
+                        div {
+                            style: "padding: 2rem; text-align: center; color: #718096;",
+                            "⏳ Loading lots..."
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+const HEADER_STYLE: &str = "padding: 0.5rem; text-align: left; font-weight: 600; color: #4a5568; font-size: 0.75rem; text-transform: uppercase; letter-spacing: 0.05em;";
@@ -0,0 +1,319 @@
+//! Inventory Count Modal Component
+//!
+//! Enter counted quantities for a count session, by barcode scan or from the
+//! table, review the variances at cost and post the approved ones.
+
+use crate::handlers::AppState;
+use crate::models::InventoryCountLine;
+use crate::utils::formatting::format_currency;
+use crate::views::inventory::count_line_row::CountLineRow;
+use dioxus::prelude::*;
+use rust_decimal::Decimal;
+
+/// Which lines of the count to show
+#[derive(Clone, Copy, PartialEq)]
+enum LineFilter {
+    All,
+    Uncounted,
+    Variances,
+}
+
+impl LineFilter {
+    fn matches(self, line: &InventoryCountLine) -> bool {
+        match self {
+            LineFilter::All => true,
+            LineFilter::Uncounted => line.counted_quantity.is_none(),
+            LineFilter::Variances => line.has_variance(),
+        }
+    }
+}
+
+#[component]
+pub fn InventoryCountModal(
+    count_id: String,
+    on_close: EventHandler<()>,
+    on_posted: EventHandler<()>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+
+    let mut scan_input = use_signal(String::new);
+    let mut search_query = use_signal(String::new);
+    let mut filter = use_signal(|| LineFilter::All);
+    let mut message = use_signal(String::new);
+    let mut error_msg = use_signal(String::new);
+    let mut confirming_post = use_signal(|| false);
+    let mut refresh_trigger = use_signal(|| 0);
+
+    let count_resource = use_resource({
+        let handler = app_state.inventory_handler.clone();
+        let count_id = count_id.clone();
+        move || {
+            let handler = handler.clone();
+            let count_id = count_id.clone();
+            let _ = refresh_trigger();
+            async move { handler.get_count(count_id).await }
+        }
+    });
+
+    let lines_resource = use_resource({
+        let handler = app_state.inventory_handler.clone();
+        let count_id = count_id.clone();
+        move || {
+            let handler = handler.clone();
+            let count_id = count_id.clone();
+            let _ = refresh_trigger();
+            async move { handler.get_count_lines(count_id).await }
+        }
+    });
+
+    let scan_handler = app_state.inventory_handler.clone();
+    let scan_count_id = count_id.clone();
+    let handle_scan = move |evt: KeyboardEvent| {
+        if evt.key() != Key::Enter {
+            return;
+        }
+
+        let barcode = scan_input().trim().to_string();
+        if barcode.is_empty() {
+            return;
+        }
+
+        let handler = scan_handler.clone();
+        let count_id = scan_count_id.clone();
+        spawn(async move {
+            match handler.record_count_scan(count_id, barcode).await {
+                Ok(line) => {
+                    error_msg.set(String::new());
+                    message.set(format!(
+                        "{}: {:.2} counted",
+                        line.product_name,
+                        line.counted_quantity.unwrap_or_default()
+                    ));
+                    scan_input.set(String::new());
+                    refresh_trigger.set(refresh_trigger() + 1);
+                }
+                Err(err) => {
+                    message.set(String::new());
+                    error_msg.set(err);
+                }
+            }
+        });
+    };
+
+    let post_handler = app_state.inventory_handler.clone();
+    let post_count_id = count_id.clone();
+    let handle_post = move |_| {
+        let handler = post_handler.clone();
+        let count_id = post_count_id.clone();
+        spawn(async move {
+            match handler.post_count(count_id).await {
+                Ok(_) => on_posted.call(()),
+                Err(err) => {
+                    confirming_post.set(false);
+                    error_msg.set(err);
+                }
+            }
+        });
+    };
+
+    let count = match &*count_resource.read_unchecked() {
+        Some(Ok(count)) => Some(count.clone()),
+        _ => None,
+    };
+    let is_open = count.as_ref().is_some_and(|c| c.is_open());
+    let title = count
+        .as_ref()
+        .map(|c| c.name.clone())
+        .unwrap_or_else(|| "Inventory Count".to_string());
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.5); display: flex; justify-content: center; align-items: center; z-index: 1000;",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background: white; padding: 2rem; border-radius: 0.5rem; width: 1000px; max-width: 95%; max-height: 90vh; overflow-y: auto;",
+                onclick: move |evt| evt.stop_propagation(),
+
+                // Header
+                div {
+                    style: "display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;",
+                    h3 {
+                        style: "margin: 0; font-size: 1.25rem;",
+                        "📋 {title}"
+                        if !is_open && count.is_some() {
+                            span { style: "margin-left: 0.5rem; color: #718096; font-size: 0.875rem; font-weight: 400;", "(posted)" }
+                        }
+                    }
+                    button {
+                        style: "padding: 0.5rem 1rem; border: 1px solid #e2e8f0; background: white; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem;",
+                        onclick: move |_| on_close.call(()),
+                        "Close"
+                    }
+                }
+
+                if !error_msg().is_empty() {
+                    div {
+                        style: "background: #fff5f5; color: #c53030; padding: 0.75rem; border-radius: 0.25rem; margin-bottom: 1rem;",
+                        "{error_msg}"
+                    }
+                }
+                if !message().is_empty() {
+                    div {
+                        style: "background: #f0fff4; color: #276749; padding: 0.75rem; border-radius: 0.25rem; margin-bottom: 1rem;",
+                        "{message}"
+                    }
+                }
+
+                // Scan, search and filter
+                div {
+                    style: "display: grid; grid-template-columns: 1fr 1fr 180px; gap: 0.5rem; margin-bottom: 1rem;",
+                    if is_open {
+                        input {
+                            r#type: "text",
+                            placeholder: "Scan barcode and press Enter...",
+                            autofocus: true,
+                            style: INPUT_STYLE,
+                            value: "{scan_input}",
+                            oninput: move |e| scan_input.set(e.value()),
+                            onkeydown: handle_scan
+                        }
+                    } else {
+                        div {}
+                    }
+                    input {
+                        r#type: "text",
+                        placeholder: "🔍 Filter by name or barcode...",
+                        style: INPUT_STYLE,
+                        value: "{search_query}",
+                        oninput: move |e| search_query.set(e.value())
+                    }
+                    select {
+                        style: INPUT_STYLE,
+                        onchange: move |evt| {
+                            filter.set(match evt.value().as_str() {
+                                "uncounted" => LineFilter::Uncounted,
+                                "variances" => LineFilter::Variances,
+                                _ => LineFilter::All,
+                            })
+                        },
+                        option { value: "all", selected: filter() == LineFilter::All, "All products" }
+                        option { value: "uncounted", selected: filter() == LineFilter::Uncounted, "Not counted yet" }
+                        option { value: "variances", selected: filter() == LineFilter::Variances, "With variance" }
+                    }
+                }
+
+                match &*lines_resource.read_unchecked() {
+                    Some(Ok(lines)) => {
+                        let query = search_query().trim().to_lowercase();
+                        let visible: Vec<InventoryCountLine> = lines
+                            .iter()
+                            .filter(|line| filter().matches(line))
+                            .filter(|line| {
+                                query.is_empty()
+                                    || line.product_name.to_lowercase().contains(&query)
+                                    || line.barcode.as_deref().is_some_and(|b| b.contains(&query))
+                            })
+                            .cloned()
+                            .collect();
+
+                        let counted_lines = lines.iter().filter(|l| l.counted_quantity.is_some()).count();
+                        let postable: Vec<&InventoryCountLine> = lines.iter().filter(|l| l.is_postable()).collect();
+                        let gains: Decimal = postable
+                            .iter()
+                            .map(|l| l.variance_cost())
+                            .filter(|c| *c > Decimal::ZERO)
+                            .sum();
+                        let losses: Decimal = postable
+                            .iter()
+                            .map(|l| l.variance_cost())
+                            .filter(|c| *c < Decimal::ZERO)
+                            .sum();
+                        let net = gains + losses;
+                        let postable_count = postable.len();
+
+                        rsx! {
+                            table {
+                                style: "width: 100%; border-collapse: collapse;",
+                                thead {
+                                    tr {
+                                        style: "background: #f7fafc; border-bottom: 2px solid #e2e8f0;",
+                                        th { style: HEADER_STYLE, "Product" }
+                                        th { style: "{HEADER_STYLE} text-align: center;", "Expected" }
+                                        th { style: "{HEADER_STYLE} text-align: center;", "Counted" }
+                                        th { style: "{HEADER_STYLE} text-align: center;", "Variance" }
+                                        th { style: "{HEADER_STYLE} text-align: right;", "Value at Cost" }
+                                        th { style: "{HEADER_STYLE} text-align: center;", "Post" }
+                                    }
+                                }
+                                tbody {
+                                    for line in visible {
+                                        CountLineRow {
+                                            key: "{line.product_id}",
+                                            line,
+                                            is_open,
+                                            on_change: move |_| refresh_trigger.set(refresh_trigger() + 1),
+                                            on_error: move |err| error_msg.set(err),
+                                        }
+                                    }
+                                }
+                            }
+
+                            // Summary and posting
+                            div {
+                                style: "display: flex; justify-content: space-between; align-items: center; margin-top: 1.5rem; padding-top: 1rem; border-top: 2px solid #e2e8f0;",
+                                div {
+                                    style: "display: flex; gap: 1.5rem; font-size: 0.875rem; color: #4a5568;",
+                                    span { "Counted: {counted_lines} / {lines.len()}" }
+                                    span { style: "color: #38a169;", "Gains: {format_currency(gains)}" }
+                                    span { style: "color: #c53030;", "Losses: {format_currency(losses)}" }
+                                    span { style: "font-weight: 600;", "Net: {format_currency(net)}" }
+                                }
+
+                                if is_open {
+                                    if confirming_post() {
+                                        div {
+                                            style: "display: flex; gap: 0.5rem; align-items: center;",
+                                            span { style: "font-size: 0.875rem; color: #4a5568;", "Post {postable_count} adjustments?" }
+                                            button {
+                                                style: "padding: 0.5rem 1rem; border: 1px solid #e2e8f0; background: white; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem;",
+                                                onclick: move |_| confirming_post.set(false),
+                                                "Cancel"
+                                            }
+                                            button {
+                                                style: "padding: 0.5rem 1rem; background: #48bb78; color: white; border: none; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem; font-weight: 500;",
+                                                onclick: handle_post.clone(),
+                                                "Confirm"
+                                            }
+                                        }
+                                    } else {
+                                        button {
+                                            style: "padding: 0.5rem 1rem; background: #667eea; color: white; border: none; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem; font-weight: 500;",
+                                            onclick: move |_| confirming_post.set(true),
+                                            "Post Adjustments"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    Some(Err(err)) => rsx! {
+                        div {
+                            style: "padding: 2rem; text-align: center; color: #e53e3e; background: #fff5f5; border-radius: 0.5rem;",
+                            "❌ {err}"
+                        }
+                    },
+                    None => rsx! {
+                        div {
+                            style: "padding: 2rem; text-align: center; color: #718096;",
+                            "⏳ Loading count..."
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+const HEADER_STYLE: &str = "padding: 0.5rem; text-align: left; font-weight: 600; color: #4a5568; font-size: 0.75rem; text-transform: uppercase; letter-spacing: 0.05em;";
+const INPUT_STYLE: &str = "width: 100%; padding: 0.625rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 1rem; box-sizing: border-box; background: white;";
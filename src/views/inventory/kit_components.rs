@@ -0,0 +1,199 @@
+//! Kit Components Component
+//!
+//! Manages the components of a kit (gift basket, combo pack). Adding the
+//! first component turns the product into a kit.
+
+use crate::handlers::AppState;
+use crate::models::{KitComponent, KitComponentInput};
+use dioxus::prelude::*;
+
+/// Maximum number of search matches offered as components
+const MAX_MATCHES: usize = 5;
+
+#[component]
+pub fn KitComponents(product_id: String, on_change: EventHandler<Vec<KitComponent>>) -> Element {
+    let app_state = use_context::<AppState>();
+
+    let mut query = use_signal(String::new);
+    let mut selected = use_signal(|| None::<(String, String)>);
+    let mut quantity = use_signal(|| "1".to_string());
+    let mut components = use_signal(Vec::<KitComponent>::new);
+    let mut error_msg = use_signal(String::new);
+
+    // Load the current components
+    let load_handler = app_state.inventory_handler.clone();
+    let load_id = product_id.clone();
+    use_hook(move || {
+        spawn(async move {
+            match load_handler.get_kit_components(load_id).await {
+                Ok(loaded) => components.set(loaded),
+                Err(err) => error_msg.set(err),
+            }
+        });
+    });
+
+    let matches_resource = use_resource({
+        let handler = app_state.inventory_handler.clone();
+        let product_id = product_id.clone();
+        move || {
+            let handler = handler.clone();
+            let product_id = product_id.clone();
+            let query = query();
+            async move {
+                if query.trim().len() < 2 {
+                    return Ok(Vec::new());
+                }
+                handler.search_products(query).await.map(|products| {
+                    products
+                        .into_iter()
+                        .filter(|p| p.id != product_id && !p.is_kit)
+                        .take(MAX_MATCHES)
+                        .collect::<Vec<_>>()
+                })
+            }
+        }
+    });
+
+    let add_handler = app_state.inventory_handler.clone();
+    let add_kit_id = product_id.clone();
+    let handle_add = move |_| {
+        let handler = add_handler.clone();
+        let kit_id = add_kit_id.clone();
+
+        let Some((component_id, _)) = selected() else {
+            error_msg.set("Search and pick a product first".to_string());
+            return;
+        };
+        let quantity = match quantity().parse::<f64>() {
+            Ok(quantity) => quantity,
+            Err(_) => {
+                error_msg.set("Invalid component quantity".to_string());
+                return;
+            }
+        };
+        let input = KitComponentInput {
+            component_id,
+            quantity,
+        };
+
+        spawn(async move {
+            match handler.set_kit_component(kit_id, input).await {
+                Ok(updated) => {
+                    selected.set(None);
+                    query.set(String::new());
+                    error_msg.set(String::new());
+                    components.set(updated.clone());
+                    on_change.call(updated);
+                }
+                Err(err) => error_msg.set(err),
+            }
+        });
+    };
+
+    let remove_handler = app_state.inventory_handler.clone();
+
+    rsx! {
+        div {
+            style: "margin-bottom: 1.5rem;",
+            label { style: "display: block; margin-bottom: 0.5rem; font-weight: 500; font-size: 0.875rem; color: #4a5568;", "Kit Components" }
+
+            if !error_msg().is_empty() {
+                div {
+                    style: "background: #fff5f5; color: #c53030; padding: 0.5rem; border-radius: 0.25rem; margin-bottom: 0.5rem; font-size: 0.875rem;",
+                    "{error_msg}"
+                }
+            }
+
+            if components().is_empty() {
+                div {
+                    style: "color: #718096; font-size: 0.75rem; margin-bottom: 0.5rem;",
+                    "Add components to sell this product as a kit; each sale deducts the components instead of the product's own stock."
+                }
+            }
+
+            for component in components() {
+                {
+                    let handler = remove_handler.clone();
+                    let kit_id = product_id.clone();
+                    let component_id = component.component_id.clone();
+
+                    rsx! {
+                        div {
+                            key: "{component.component_id}",
+                            style: "display: flex; justify-content: space-between; align-items: center; padding: 0.375rem 0; border-bottom: 1px solid #e2e8f0; font-size: 0.875rem;",
+                            span {
+                                "{component.component_name}"
+                                if component.component_archived {
+                                    span { style: "color: #c05621; margin-left: 0.375rem;", "(archived)" }
+                                }
+                            }
+                            span { style: "color: #718096;", "× {component.quantity} ({component.component_stock} in stock)" }
+                            button {
+                                style: "background: none; border: none; color: #e53e3e; font-weight: 500; cursor: pointer; padding: 0.25rem 0.5rem; font-size: 0.875rem;",
+                                onclick: move |_| {
+                                    let handler = handler.clone();
+                                    let kit_id = kit_id.clone();
+                                    let component_id = component_id.clone();
+                                    spawn(async move {
+                                        match handler.remove_kit_component(kit_id, component_id).await {
+                                            Ok(updated) => {
+                                                components.set(updated.clone());
+                                                on_change.call(updated);
+                                            }
+                                            Err(err) => error_msg.set(err),
+                                        }
+                                    });
+                                },
+                                "Remove"
+                            }
+                        }
+                    }
+                }
+            }
+
+            div {
+                style: "display: grid; grid-template-columns: 1fr 90px auto; gap: 0.5rem; margin-top: 0.5rem;",
+                input {
+                    r#type: "text",
+                    placeholder: "Search component",
+                    style: "width: 100%; padding: 0.5rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 0.875rem; box-sizing: border-box;",
+                    value: match selected() {
+                        Some((_, name)) => name,
+                        None => query(),
+                    },
+                    oninput: move |e| {
+                        selected.set(None);
+                        query.set(e.value());
+                    }
+                }
+                input {
+                    r#type: "number",
+                    step: "0.001",
+                    title: "Component units per kit",
+                    style: "width: 100%; padding: 0.5rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 0.875rem; box-sizing: border-box;",
+                    value: "{quantity}",
+                    oninput: move |e| quantity.set(e.value())
+                }
+                button {
+                    style: "padding: 0.5rem 0.75rem; background: #667eea; color: white; border: none; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem; font-weight: 500;",
+                    onclick: handle_add,
+                    "Add"
+                }
+            }
+
+            // Search matches
+            if selected().is_none() {
+                if let Some(Ok(matches)) = matches_resource.read().as_ref() {
+                    for product in matches.iter().cloned() {
+                        div {
+                            key: "{product.id}",
+                            style: "padding: 0.375rem 0.5rem; border: 1px solid #e2e8f0; border-top: none; cursor: pointer; font-size: 0.875rem;",
+                            onclick: move |_| selected.set(Some((product.id.clone(), product.full_name.clone()))),
+                            "{product.full_name}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
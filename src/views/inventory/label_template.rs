@@ -0,0 +1,160 @@
+//! Shelf Label PDF generation with Typst templates
+
+use crate::utils::ean13;
+use crate::utils::formatting::format_currency;
+use crate::utils::pdf::{compile_typst_to_pdf, escape_typst};
+use rust_decimal::Decimal;
+use sailfish::TemplateOnce;
+
+/// A4 page size in millimeters
+const PAGE_WIDTH_MM: f64 = 210.0;
+const PAGE_HEIGHT_MM: f64 = 297.0;
+
+/// Label sheet layouts, named after the common A4 label sheets
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LabelLayout {
+    Small,  // 3 x 8 labels of 63.5 x 33.9 mm
+    Medium, // 2 x 7 labels of 99.1 x 38.1 mm
+    Large,  // 2 x 4 labels of 99.1 x 67.7 mm
+}
+
+impl LabelLayout {
+    pub const ALL: [LabelLayout; 3] = [LabelLayout::Small, LabelLayout::Medium, LabelLayout::Large];
+
+    /// Name shown in the layout selector
+    pub fn label(&self) -> &'static str {
+        match self {
+            LabelLayout::Small => "Small (24 per sheet)",
+            LabelLayout::Medium => "Medium (14 per sheet)",
+            LabelLayout::Large => "Large (8 per sheet)",
+        }
+    }
+
+    /// Columns, rows and label size in millimeters
+    fn dimensions(&self) -> (usize, usize, f64, f64) {
+        match self {
+            LabelLayout::Small => (3, 8, 63.5, 33.9),
+            LabelLayout::Medium => (2, 7, 99.1, 38.1),
+            LabelLayout::Large => (2, 4, 99.1, 67.7),
+        }
+    }
+
+    /// Name and price font sizes in points
+    fn font_sizes(&self) -> (u32, u32) {
+        match self {
+            LabelLayout::Small => (8, 14),
+            LabelLayout::Medium => (10, 18),
+            LabelLayout::Large => (14, 32),
+        }
+    }
+
+    /// Barcode module width and bar height in millimeters
+    fn barcode_size(&self) -> (f64, f64) {
+        match self {
+            LabelLayout::Small => (0.26, 7.0),
+            LabelLayout::Medium => (0.3, 8.0),
+            LabelLayout::Large => (0.33, 14.0),
+        }
+    }
+}
+
+/// Content of one shelf label
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShelfLabel {
+    pub name: String,
+    pub price: Decimal,
+    pub unit: String,
+    pub barcode: Option<String>,
+}
+
+#[derive(TemplateOnce)]
+#[template(path = "shelf_labels.typ.stpl")]
+struct ShelfLabelsTemplate {
+    margin_x: String,
+    margin_y: String,
+    columns: usize,
+    label_width: String,
+    label_height: String,
+    name_size: u32,
+    price_size: u32,
+    bar_height: String,
+    labels: Vec<LabelItem>,
+}
+
+struct LabelItem {
+    name: String,
+    price: String,
+    unit: String,
+    barcode: Option<String>,
+    bars: Vec<Bar>, // Empty when the barcode is not EAN-13
+}
+
+/// Run of bars or spaces of the same color
+struct Bar {
+    is_bar: bool,
+    width: String,
+}
+
+pub fn generate_labels_pdf(
+    labels: &[ShelfLabel],
+    layout: LabelLayout,
+    file_path: std::path::PathBuf,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (columns, rows, width, height) = layout.dimensions();
+    let (name_size, price_size) = layout.font_sizes();
+    let (module_width, bar_height) = layout.barcode_size();
+
+    // Prepare template data
+    let items: Vec<LabelItem> = labels
+        .iter()
+        .map(|label| LabelItem {
+            name: escape_typst(&label.name),
+            price: escape_typst(&format_currency(label.price)),
+            unit: escape_typst(&label.unit),
+            barcode: label.barcode.as_deref().map(escape_typst),
+            bars: label
+                .barcode
+                .as_deref()
+                .and_then(ean13::modules)
+                .map(|modules| bar_runs(&modules, module_width))
+                .unwrap_or_default(),
+        })
+        .collect();
+
+    let template = ShelfLabelsTemplate {
+        margin_x: format!("{:.1}", (PAGE_WIDTH_MM - columns as f64 * width) / 2.0),
+        margin_y: format!("{:.1}", (PAGE_HEIGHT_MM - rows as f64 * height) / 2.0),
+        columns,
+        label_width: format!("{:.1}", width),
+        label_height: format!("{:.1}", height),
+        name_size,
+        price_size,
+        bar_height: format!("{:.1}", bar_height),
+        labels: items,
+    };
+
+    let typst_content = template.render_once()?;
+
+    // Compile Typst to PDF and save
+    compile_typst_to_pdf(typst_content, file_path)?;
+
+    Ok(())
+}
+
+/// Merge barcode modules into runs of equal color
+fn bar_runs(modules: &[bool], module_width: f64) -> Vec<Bar> {
+    let mut runs: Vec<(bool, usize)> = Vec::new();
+    for &is_bar in modules {
+        match runs.last_mut() {
+            Some((last, count)) if *last == is_bar => *count += 1,
+            _ => runs.push((is_bar, 1)),
+        }
+    }
+
+    runs.into_iter()
+        .map(|(is_bar, count)| Bar {
+            is_bar,
+            width: format!("{:.2}", count as f64 * module_width),
+        })
+        .collect()
+}
@@ -0,0 +1,114 @@
+//! Lot Row Component
+//!
+//! Displays a product lot with its expiry and lets the user move part of it
+//! to the Damaged or Expired condition.
+
+use crate::handlers::AppState;
+use crate::models::{ItemCondition, ProductLot};
+use crate::views::app::use_store_clock;
+use dioxus::prelude::*;
+
+#[component]
+pub fn LotRow(
+    lot: ProductLot,
+    product_name: Option<String>,
+    condition: String,
+    on_moved: EventHandler<()>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+
+    let mut quantity = use_signal(|| lot.remaining_quantity.to_string());
+    let mut error_msg = use_signal(String::new);
+
+    let today = use_store_clock().today();
+    let expiry_style = expiry_style(lot.days_until_expiry(today));
+    let lot_code = lot.lot_code.clone().unwrap_or_else(|| "-".to_string());
+    let expires = lot
+        .expires_at
+        .map(|date| date.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|| "No expiry".to_string());
+    let is_sellable = lot.is_sellable();
+    let lot_id = lot.id.clone();
+
+    let move_to = move |condition_id: i32| {
+        let handler = app_state.inventory_handler.clone();
+        let lot_id = lot_id.clone();
+
+        let amount = match quantity().parse::<f64>() {
+            Ok(amount) => amount,
+            Err(_) => {
+                error_msg.set("Invalid quantity".to_string());
+                return;
+            }
+        };
+
+        spawn(async move {
+            match handler.move_lot(lot_id, amount, condition_id).await {
+                Ok(_) => {
+                    error_msg.set(String::new());
+                    on_moved.call(());
+                }
+                Err(err) => error_msg.set(err),
+            }
+        });
+    };
+    let mut move_to_damaged = move_to.clone();
+    let mut move_to_expired = move_to;
+
+    rsx! {
+        tr {
+            style: "border-bottom: 1px solid #e2e8f0; font-size: 0.875rem;",
+
+            if let Some(name) = product_name {
+                td { style: "padding: 0.5rem; font-weight: 500;", "{name}" }
+            }
+            td { style: "padding: 0.5rem; font-family: monospace;", "{lot_code}" }
+            td { style: "padding: 0.5rem; {expiry_style}", "{expires}" }
+            td { style: "padding: 0.5rem; text-align: center;", "{lot.remaining_quantity:.2}" }
+            td { style: "padding: 0.5rem; text-align: center; color: #4a5568;", "{condition}" }
+            td {
+                style: "padding: 0.5rem; text-align: right; white-space: nowrap;",
+                if is_sellable {
+                    input {
+                        r#type: "number",
+                        step: "0.001",
+                        style: "width: 70px; padding: 0.25rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 0.875rem;",
+                        value: "{quantity}",
+                        oninput: move |e| quantity.set(e.value())
+                    }
+                    button {
+                        style: "background: none; border: none; color: #dd6b20; font-weight: 500; cursor: pointer; padding: 0.25rem 0.5rem; font-size: 0.875rem;",
+                        onclick: move |_| move_to_damaged(ItemCondition::DAMAGED),
+                        "Damaged"
+                    }
+                    button {
+                        style: "background: none; border: none; color: #e53e3e; font-weight: 500; cursor: pointer; padding: 0.25rem 0.5rem; font-size: 0.875rem;",
+                        onclick: move |_| move_to_expired(ItemCondition::EXPIRED),
+                        "Expired"
+                    }
+                }
+                if !error_msg().is_empty() {
+                    div { style: "color: #c53030; font-size: 0.75rem;", "{error_msg}" }
+                }
+            }
+        }
+    }
+}
+
+/// Highlight lots that are expired or about to expire
+fn expiry_style(days_left: Option<i64>) -> &'static str {
+    match days_left {
+        Some(days) if days < 0 => "color: #c53030; font-weight: 600;",
+        Some(days) if days <= 7 => "color: #dd6b20; font-weight: 600;",
+        _ => "color: #4a5568;",
+    }
+}
+
+/// Resolve a condition's description from the catalog
+pub fn condition_name(conditions: &[ItemCondition], condition_id: i32) -> String {
+    conditions
+        .iter()
+        .find(|c| c.id == condition_id)
+        .map(|c| c.description.clone())
+        .unwrap_or_default()
+}
@@ -2,21 +2,62 @@
 //!
 //! UI components for managing product inventory.
 
+mod archived_products_modal;
+mod category_manager;
+mod category_select;
+mod count_line_row;
+mod count_sessions_modal;
+mod expiring_lots_modal;
 mod helpers;
+mod inventory_count_modal;
+mod kit_components;
+mod label_template;
+mod lot_row;
+mod price_history;
+mod product_barcodes;
+mod product_csv_modal;
 mod product_form;
+mod product_lots;
+mod product_price_tiers;
 mod product_row;
+mod product_tax_codes;
 mod products_table;
+mod receive_stock_modal;
+mod reorder_modal;
+mod reprice_modal;
+mod shelf_labels_modal;
+mod shrinkage_report_modal;
 mod stat_card;
 mod stats_summary;
+mod supplier_manager;
+mod unit_manager;
+mod write_off_modal;
 
 use crate::handlers::AppState;
 use crate::models::Product;
+use crate::utils::export::ExportDataset;
+use crate::views::export_modal::ExportModal;
 use crate::views::pagination_nav::PaginationNav;
+use archived_products_modal::ArchivedProductsModal;
+use category_manager::CategoryManager;
+pub use category_select::CategorySelect;
+use count_sessions_modal::CountSessionsModal;
 use dioxus::prelude::*;
+use expiring_lots_modal::ExpiringLotsModal;
 use helpers::calculate_total_pages;
+use inventory_count_modal::InventoryCountModal;
+use product_csv_modal::ProductCsvModal;
 use product_form::ProductForm;
 use products_table::ProductsTable;
+use receive_stock_modal::ReceiveStockModal;
+use reorder_modal::ReorderModal;
+use reprice_modal::RepriceModal;
+use shelf_labels_modal::ShelfLabelsModal;
+use shrinkage_report_modal::ShrinkageReportModal;
 use stats_summary::StatsSummary;
+use supplier_manager::SupplierManager;
+use unit_manager::UnitManager;
+use write_off_modal::WriteOffModal;
 
 const PAGE_SIZE: i64 = 10;
 
@@ -30,6 +71,22 @@ pub fn InventoryView() -> Element {
     let mut editing_product = use_signal(|| None::<Product>);
     let mut refresh_trigger = use_signal(|| 0);
     let mut current_page = use_signal(|| 1i64);
+    let mut category_filter = use_signal(|| None::<String>);
+    let mut show_category_manager = use_signal(|| false);
+    let mut receiving_product = use_signal(|| None::<Product>);
+    let mut show_expiring_lots = use_signal(|| false);
+    let mut writing_off_product = use_signal(|| None::<Product>);
+    let mut show_shrinkage_report = use_signal(|| false);
+    let mut show_count_sessions = use_signal(|| false);
+    let mut open_count_id = use_signal(|| None::<String>);
+    let mut show_csv_modal = use_signal(|| false);
+    let mut show_export = use_signal(|| false);
+    let mut show_supplier_manager = use_signal(|| false);
+    let mut show_unit_manager = use_signal(|| false);
+    let mut show_reprice = use_signal(|| false);
+    let mut show_archived = use_signal(|| false);
+    let mut show_reorder = use_signal(|| false);
+    let mut show_labels = use_signal(|| false);
 
     // Load products with pagination (always paginated, whether searching or not)
     let mut products_resource = use_resource({
@@ -39,10 +96,11 @@ pub fn InventoryView() -> Element {
             let handler = handler.clone();
             let page = current_page();
             let query = search_query();
+            let category_id = category_filter();
 
             async move {
                 handler
-                    .search_products_paginated(query, page, PAGE_SIZE)
+                    .search_products_paginated(query, category_id, page, PAGE_SIZE)
                     .await
                     .map(|paginated| {
                         (
@@ -54,31 +112,62 @@ pub fn InventoryView() -> Element {
         }
     });
 
-    // Load inventory stats from all database records
+    // Load inventory stats from all database records (scoped to the selected category)
     let mut stats_resource = use_resource({
         let handler = app_state.inventory_handler.clone();
         move || {
             let handler = handler.clone();
-            async move { handler.get_stats().await }
+            let category_id = category_filter();
+            async move { handler.get_stats(category_id).await }
         }
     });
 
-    // Reset to page 1 when search query changes
+    // Load categories for the filter and the product table
+    let mut categories_resource = use_resource({
+        let handler = app_state.inventory_handler.clone();
+        move || {
+            let handler = handler.clone();
+            async move { handler.get_categories().await }
+        }
+    });
+
+    // Load units to display sale and purchase units
+    let mut units_resource = use_resource({
+        let handler = app_state.inventory_handler.clone();
+        move || {
+            let handler = handler.clone();
+            async move { handler.get_units().await }
+        }
+    });
+
+    // Reset to page 1 when search query or category filter changes
     use_effect(move || {
         let _ = search_query();
+        let _ = category_filter();
         current_page.set(1);
     });
 
-    // Refresh products and stats when trigger changes
+    // Refresh products, stats, categories and units when trigger changes
     use_effect(move || {
         let _ = refresh_trigger();
         products_resource.restart();
         stats_resource.restart();
+        categories_resource.restart();
+        units_resource.restart();
     });
 
+    let categories = match &*categories_resource.read_unchecked() {
+        Some(Ok(categories)) => categories.clone(),
+        _ => Vec::new(),
+    };
+    let units = match &*units_resource.read_unchecked() {
+        Some(Ok(units)) => units.clone(),
+        _ => Vec::new(),
+    };
+
     // Clone handlers for use in closures
     let save_handler = app_state.inventory_handler.clone();
-    let delete_handler = app_state.inventory_handler.clone();
+    let archive_handler = app_state.inventory_handler.clone();
 
     rsx! {
         div {
@@ -94,19 +183,95 @@ pub fn InventoryView() -> Element {
                     "📦 Product Inventory"
                 }
 
-                button {
-                    style: "background: #667eea; color: white; padding: 0.75rem 1.5rem; border: none; border-radius: 0.5rem; font-weight: 500; cursor: pointer; transition: background 0.2s; font-size: 1rem;",
-                    onclick: move |_| {
-                        editing_product.set(None);
-                        show_add_form.set(true);
-                    },
-                    "+ Add Product"
+                div {
+                    style: "display: flex; gap: 0.5rem;",
+
+                    button {
+                        style: "background: white; color: #667eea; padding: 0.75rem 1.5rem; border: 2px solid #667eea; border-radius: 0.5rem; font-weight: 500; cursor: pointer; font-size: 1rem;",
+                        onclick: move |_| show_count_sessions.set(true),
+                        "📋 Counts"
+                    }
+
+                    button {
+                        style: "background: white; color: #667eea; padding: 0.75rem 1.5rem; border: 2px solid #667eea; border-radius: 0.5rem; font-weight: 500; cursor: pointer; font-size: 1rem;",
+                        onclick: move |_| show_expiring_lots.set(true),
+                        "⏳ Expiring"
+                    }
+
+                    button {
+                        style: "background: white; color: #667eea; padding: 0.75rem 1.5rem; border: 2px solid #667eea; border-radius: 0.5rem; font-weight: 500; cursor: pointer; font-size: 1rem;",
+                        onclick: move |_| show_shrinkage_report.set(true),
+                        "📉 Shrinkage"
+                    }
+
+                    button {
+                        style: "background: white; color: #667eea; padding: 0.75rem 1.5rem; border: 2px solid #667eea; border-radius: 0.5rem; font-weight: 500; cursor: pointer; font-size: 1rem;",
+                        onclick: move |_| show_category_manager.set(true),
+                        "🗂️ Categories"
+                    }
+
+                    button {
+                        style: "background: white; color: #667eea; padding: 0.75rem 1.5rem; border: 2px solid #667eea; border-radius: 0.5rem; font-weight: 500; cursor: pointer; font-size: 1rem;",
+                        onclick: move |_| show_supplier_manager.set(true),
+                        "🚚 Suppliers"
+                    }
+
+                    button {
+                        style: "background: white; color: #667eea; padding: 0.75rem 1.5rem; border: 2px solid #667eea; border-radius: 0.5rem; font-weight: 500; cursor: pointer; font-size: 1rem;",
+                        onclick: move |_| show_unit_manager.set(true),
+                        "📏 Units"
+                    }
+
+                    button {
+                        style: "background: white; color: #667eea; padding: 0.75rem 1.5rem; border: 2px solid #667eea; border-radius: 0.5rem; font-weight: 500; cursor: pointer; font-size: 1rem;",
+                        onclick: move |_| show_reprice.set(true),
+                        "🏷️ Prices"
+                    }
+
+                    button {
+                        style: "background: white; color: #667eea; padding: 0.75rem 1.5rem; border: 2px solid #667eea; border-radius: 0.5rem; font-weight: 500; cursor: pointer; font-size: 1rem;",
+                        onclick: move |_| show_reorder.set(true),
+                        "🛒 Reorder"
+                    }
+
+                    button {
+                        style: "background: white; color: #667eea; padding: 0.75rem 1.5rem; border: 2px solid #667eea; border-radius: 0.5rem; font-weight: 500; cursor: pointer; font-size: 1rem;",
+                        onclick: move |_| show_labels.set(true),
+                        "🔖 Labels"
+                    }
+
+                    button {
+                        style: "background: white; color: #667eea; padding: 0.75rem 1.5rem; border: 2px solid #667eea; border-radius: 0.5rem; font-weight: 500; cursor: pointer; font-size: 1rem;",
+                        onclick: move |_| show_csv_modal.set(true),
+                        "📄 CSV"
+                    }
+
+                    button {
+                        style: "background: white; color: #667eea; padding: 0.75rem 1.5rem; border: 2px solid #667eea; border-radius: 0.5rem; font-weight: 500; cursor: pointer; font-size: 1rem;",
+                        onclick: move |_| show_export.set(true),
+                        "⬇️ Export"
+                    }
+
+                    button {
+                        style: "background: white; color: #667eea; padding: 0.75rem 1.5rem; border: 2px solid #667eea; border-radius: 0.5rem; font-weight: 500; cursor: pointer; font-size: 1rem;",
+                        onclick: move |_| show_archived.set(true),
+                        "🗄️ Archived"
+                    }
+
+                    button {
+                        style: "background: #667eea; color: white; padding: 0.75rem 1.5rem; border: none; border-radius: 0.5rem; font-weight: 500; cursor: pointer; transition: background 0.2s; font-size: 1rem;",
+                        onclick: move |_| {
+                            editing_product.set(None);
+                            show_add_form.set(true);
+                        },
+                        "+ Add Product"
+                    }
                 }
             }
 
-            // Search bar
+            // Search bar and category filter
             div {
-                style: "margin-bottom: 1.5rem;",
+                style: "display: grid; grid-template-columns: 1fr 250px; gap: 1rem; margin-bottom: 1.5rem;",
 
                 input {
                     r#type: "text",
@@ -115,24 +280,35 @@ pub fn InventoryView() -> Element {
                     oninput: move |evt| search_query.set(evt.value().clone()),
                     style: "width: 100%; padding: 0.75rem; border: 2px solid #e2e8f0; border-radius: 0.5rem; font-size: 1rem; box-sizing: border-box;",
                 }
+
+                CategorySelect {
+                    categories: categories.clone(),
+                    selected: category_filter(),
+                    empty_label: "All categories",
+                    on_change: move |category_id| category_filter.set(category_id),
+                }
             }
 
             // Content
             {
                 match &*products_resource.read_unchecked() {
                     Some(Ok((products, pagination_info))) => {
-                        let is_searching = !search_query().trim().is_empty();
+                        let is_searching = !search_query().trim().is_empty() || category_filter().is_some();
                         let total_count = pagination_info.map(|(count, _)| count).unwrap_or(products.len() as i64);
                         let total_pages = calculate_total_pages(total_count, PAGE_SIZE);
 
                         rsx! {
                             ProductsTable {
                                 products: products.clone(),
+                                categories: categories.clone(),
+                                units: units.clone(),
                                 is_search_mode: is_searching,
                                 on_edit: move |p| {
                                     editing_product.set(Some(p));
                                     show_add_form.set(true);
-                                }
+                                },
+                                on_receive: move |p| receiving_product.set(Some(p)),
+                                on_write_off: move |p| writing_off_product.set(Some(p)),
                             }
 
                             PaginationNav {
@@ -179,6 +355,128 @@ pub fn InventoryView() -> Element {
                 }
             }
 
+            if show_category_manager() {
+                CategoryManager {
+                    categories: categories.clone(),
+                    on_close: move |_| show_category_manager.set(false),
+                    on_change: move |_| refresh_trigger.set(refresh_trigger() + 1),
+                }
+            }
+
+            if show_archived() {
+                ArchivedProductsModal {
+                    on_close: move |_| show_archived.set(false),
+                    on_change: move |_| refresh_trigger.set(refresh_trigger() + 1),
+                }
+            }
+
+            if show_supplier_manager() {
+                SupplierManager {
+                    on_close: move |_| show_supplier_manager.set(false),
+                    on_change: move |_| refresh_trigger.set(refresh_trigger() + 1),
+                }
+            }
+
+            if show_unit_manager() {
+                UnitManager {
+                    on_close: move |_| show_unit_manager.set(false),
+                    on_change: move |_| refresh_trigger.set(refresh_trigger() + 1),
+                }
+            }
+
+            if show_reprice() {
+                RepriceModal {
+                    categories: categories.clone(),
+                    on_close: move |_| show_reprice.set(false),
+                    on_applied: move |_| refresh_trigger.set(refresh_trigger() + 1),
+                }
+            }
+
+            if show_reorder() {
+                ReorderModal {
+                    on_close: move |_| show_reorder.set(false),
+                }
+            }
+
+            if show_labels() {
+                ShelfLabelsModal {
+                    categories: categories.clone(),
+                    on_close: move |_| show_labels.set(false),
+                    on_change: move |_| refresh_trigger.set(refresh_trigger() + 1),
+                }
+            }
+
+            if show_expiring_lots() {
+                ExpiringLotsModal {
+                    on_close: move |_| show_expiring_lots.set(false),
+                    on_change: move |_| refresh_trigger.set(refresh_trigger() + 1),
+                }
+            }
+
+            if show_csv_modal() {
+                ProductCsvModal {
+                    on_close: move |_| show_csv_modal.set(false),
+                    on_imported: move |_| refresh_trigger.set(refresh_trigger() + 1),
+                }
+            }
+
+            if show_export() {
+                ExportModal {
+                    datasets: vec![ExportDataset::StockMovements, ExportDataset::InventoryValuation],
+                    on_close: move |_| show_export.set(false),
+                }
+            }
+
+            if show_count_sessions() {
+                CountSessionsModal {
+                    on_close: move |_| show_count_sessions.set(false),
+                    on_open: move |id| {
+                        show_count_sessions.set(false);
+                        open_count_id.set(Some(id));
+                    },
+                }
+            }
+
+            if let Some(count_id) = open_count_id() {
+                InventoryCountModal {
+                    count_id,
+                    on_close: move |_| open_count_id.set(None),
+                    on_posted: move |_| {
+                        open_count_id.set(None);
+                        refresh_trigger.set(refresh_trigger() + 1);
+                    },
+                }
+            }
+
+            if show_shrinkage_report() {
+                ShrinkageReportModal {
+                    on_close: move |_| show_shrinkage_report.set(false),
+                }
+            }
+
+            if let Some(product) = writing_off_product() {
+                WriteOffModal {
+                    product,
+                    on_close: move |_| writing_off_product.set(None),
+                    on_saved: move |_| {
+                        writing_off_product.set(None);
+                        refresh_trigger.set(refresh_trigger() + 1);
+                    },
+                }
+            }
+
+            if let Some(product) = receiving_product() {
+                ReceiveStockModal {
+                    product,
+                    units: units.clone(),
+                    on_close: move |_| receiving_product.set(None),
+                    on_received: move |_| {
+                        receiving_product.set(None);
+                        refresh_trigger.set(refresh_trigger() + 1);
+                    },
+                }
+            }
+
             if show_add_form() {
                 ProductForm {
                     on_close: move |_| {
@@ -186,6 +484,7 @@ pub fn InventoryView() -> Element {
                         editing_product.set(None);
                     },
                     initial_product: editing_product(),
+                    categories: categories.clone(),
                     on_save: move |input| {
                         let handler = save_handler.clone();
                         let is_edit = editing_product().is_some();
@@ -205,10 +504,10 @@ pub fn InventoryView() -> Element {
                             }
                         });
                     },
-                    on_delete: move |id| {
-                        let handler = delete_handler.clone();
+                    on_archive: move |id| {
+                        let handler = archive_handler.clone();
                         spawn(async move {
-                            if handler.delete_product(id).await.is_ok() {
+                            if handler.archive_product(id).await.is_ok() {
                                 show_add_form.set(false);
                                 editing_product.set(None);
                                 refresh_trigger.set(refresh_trigger() + 1);
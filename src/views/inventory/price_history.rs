@@ -0,0 +1,105 @@
+//! Price History Component
+//!
+//! Timeline of a product's price and cost changes with their source.
+
+use crate::handlers::AppState;
+use crate::utils::formatting::{format_currency, format_datetime};
+use crate::views::app::use_store_clock;
+use dioxus::prelude::*;
+use rust_decimal::Decimal;
+
+#[component]
+pub fn PriceHistory(product_id: String) -> Element {
+    let app_state = use_context::<AppState>();
+    let clock = use_store_clock();
+
+    let history_resource = use_resource({
+        let handler = app_state.inventory_handler.clone();
+        move || {
+            let handler = handler.clone();
+            let product_id = product_id.clone();
+            async move { handler.get_price_history(product_id).await }
+        }
+    });
+
+    let sources_resource = use_resource({
+        let handler = app_state.inventory_handler.clone();
+        move || {
+            let handler = handler.clone();
+            async move { handler.get_price_change_sources().await }
+        }
+    });
+
+    let source_name = |source_id: i32| match &*sources_resource.read_unchecked() {
+        Some(Ok(sources)) => sources
+            .iter()
+            .find(|s| s.id == source_id)
+            .map(|s| s.description.clone())
+            .unwrap_or_default(),
+        _ => String::new(),
+    };
+
+    rsx! {
+        div {
+            style: "margin-bottom: 1.5rem;",
+            label { style: "display: block; margin-bottom: 0.5rem; font-weight: 500; font-size: 0.875rem; color: #4a5568;", "Price History" }
+
+            match &*history_resource.read_unchecked() {
+                Some(Ok(entries)) if entries.is_empty() => rsx! {
+                    div { style: "color: #718096; font-size: 0.875rem;", "No price changes recorded yet." }
+                },
+                Some(Ok(entries)) => rsx! {
+                    div {
+                        style: "max-height: 200px; overflow-y: auto; border-left: 2px solid #e2e8f0; padding-left: 0.75rem;",
+                        for entry in entries.iter() {
+                            div {
+                                key: "{entry.id}",
+                                style: "padding: 0.375rem 0; font-size: 0.875rem;",
+                                div {
+                                    style: "display: flex; justify-content: space-between; color: #718096; font-size: 0.75rem;",
+                                    span { {format_datetime(&entry.changed_at, &clock)} }
+                                    span { {source_name(entry.source_id)} }
+                                }
+                                if entry.old_price != entry.new_price {
+                                    div {
+                                        "Price: {format_currency(entry.old_price)} → "
+                                        span {
+                                            style: "font-weight: 600; color: {change_color(entry.old_price, entry.new_price)};",
+                                            {format_currency(entry.new_price)}
+                                        }
+                                    }
+                                }
+                                if entry.old_cost != entry.new_cost {
+                                    div {
+                                        style: "color: #4a5568;",
+                                        "Cost: {format_cost(entry.old_cost)} → {format_cost(entry.new_cost)}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                Some(Err(err)) => rsx! {
+                    div { style: "color: #e53e3e; font-size: 0.875rem;", "Error loading price history: {err}" }
+                },
+                None => rsx! {
+                    div { style: "color: #718096; font-size: 0.875rem;", "Loading price history..." }
+                },
+            }
+        }
+    }
+}
+
+/// Format an optional cost, "-" when unknown
+fn format_cost(cost: Option<Decimal>) -> String {
+    cost.map(format_currency).unwrap_or_else(|| "-".to_string())
+}
+
+/// Green for increases, red for decreases
+fn change_color(old_price: Decimal, new_price: Decimal) -> &'static str {
+    if new_price > old_price {
+        "#38a169"
+    } else {
+        "#c53030"
+    }
+}
@@ -0,0 +1,133 @@
+//! Product Barcodes Component
+//!
+//! Manages the additional barcodes of a product (pack sizes, supplier codes).
+
+use crate::handlers::AppState;
+use crate::models::ProductBarcodeInput;
+use dioxus::prelude::*;
+
+#[component]
+pub fn ProductBarcodes(product_id: String) -> Element {
+    let app_state = use_context::<AppState>();
+
+    let mut new_barcode = use_signal(String::new);
+    let mut pack_quantity = use_signal(|| "1".to_string());
+    let mut error_msg = use_signal(String::new);
+    let mut refresh_trigger = use_signal(|| 0);
+
+    let mut barcodes_resource = use_resource({
+        let handler = app_state.inventory_handler.clone();
+        let product_id = product_id.clone();
+        move || {
+            let handler = handler.clone();
+            let product_id = product_id.clone();
+            async move { handler.get_barcodes(product_id).await }
+        }
+    });
+
+    use_effect(move || {
+        let _ = refresh_trigger();
+        barcodes_resource.restart();
+    });
+
+    let add_handler = app_state.inventory_handler.clone();
+    let remove_handler = app_state.inventory_handler.clone();
+
+    let handle_add = move |_| {
+        let handler = add_handler.clone();
+        let product_id = product_id.clone();
+
+        let pack_quantity = match pack_quantity().parse::<f64>() {
+            Ok(quantity) => quantity,
+            Err(_) => {
+                error_msg.set("Invalid pack quantity".to_string());
+                return;
+            }
+        };
+        let input = ProductBarcodeInput {
+            barcode: new_barcode(),
+            pack_quantity,
+        };
+
+        spawn(async move {
+            match handler.add_barcode(product_id, input).await {
+                Ok(_) => {
+                    new_barcode.set(String::new());
+                    error_msg.set(String::new());
+                    refresh_trigger.set(refresh_trigger() + 1);
+                }
+                Err(err) => error_msg.set(err),
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            style: "margin-bottom: 1.5rem;",
+            label { style: "display: block; margin-bottom: 0.5rem; font-weight: 500; font-size: 0.875rem; color: #4a5568;", "Additional Barcodes" }
+
+            if !error_msg().is_empty() {
+                div {
+                    style: "background: #fff5f5; color: #c53030; padding: 0.5rem; border-radius: 0.25rem; margin-bottom: 0.5rem; font-size: 0.875rem;",
+                    "{error_msg}"
+                }
+            }
+
+            if let Some(Ok(barcodes)) = barcodes_resource.read().as_ref() {
+                for entry in barcodes.iter().cloned() {
+                    {
+                        let handler = remove_handler.clone();
+                        let barcode = entry.barcode.clone();
+
+                        rsx! {
+                            div {
+                                key: "{entry.barcode}",
+                                style: "display: flex; justify-content: space-between; align-items: center; padding: 0.375rem 0; border-bottom: 1px solid #e2e8f0; font-size: 0.875rem;",
+                                span { style: "font-family: monospace;", "{entry.barcode}" }
+                                span { style: "color: #718096;", "× {entry.pack_quantity}" }
+                                button {
+                                    style: "background: none; border: none; color: #e53e3e; font-weight: 500; cursor: pointer; padding: 0.25rem 0.5rem; font-size: 0.875rem;",
+                                    onclick: move |_| {
+                                        let handler = handler.clone();
+                                        let barcode = barcode.clone();
+                                        spawn(async move {
+                                            match handler.remove_barcode(barcode).await {
+                                                Ok(()) => refresh_trigger.set(refresh_trigger() + 1),
+                                                Err(err) => error_msg.set(err),
+                                            }
+                                        });
+                                    },
+                                    "Remove"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            div {
+                style: "display: grid; grid-template-columns: 1fr 90px auto; gap: 0.5rem; margin-top: 0.5rem;",
+                input {
+                    r#type: "text",
+                    placeholder: "Barcode",
+                    style: "width: 100%; padding: 0.5rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 0.875rem; box-sizing: border-box;",
+                    value: "{new_barcode}",
+                    oninput: move |e| new_barcode.set(e.value())
+                }
+                input {
+                    r#type: "number",
+                    step: "1",
+                    title: "Units per scan",
+                    style: "width: 100%; padding: 0.5rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 0.875rem; box-sizing: border-box;",
+                    value: "{pack_quantity}",
+                    oninput: move |e| pack_quantity.set(e.value())
+                }
+                button {
+                    style: "padding: 0.5rem 0.75rem; background: #667eea; color: white; border: none; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem; font-weight: 500;",
+                    onclick: handle_add,
+                    "Add"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,331 @@
+//! Product CSV Modal Component
+//!
+//! Exports the product catalog to CSV and imports products from a CSV file:
+//! map the file's columns, preview what each row will do, then import.
+
+use crate::api::{ImportAction, ImportRow, ImportSummary};
+use crate::handlers::AppState;
+use crate::utils::product_csv::{guess_mapping, parse_csv, CsvTable, ProductColumn};
+use dioxus::prelude::*;
+
+#[component]
+pub fn ProductCsvModal(on_close: EventHandler<()>, on_imported: EventHandler<()>) -> Element {
+    let app_state = use_context::<AppState>();
+
+    let mut table = use_signal(|| None::<CsvTable>);
+    let mut mapping = use_signal(Vec::<Option<ProductColumn>>::new);
+    let mut preview = use_signal(|| None::<Vec<ImportRow>>);
+    let mut summary = use_signal(|| None::<ImportSummary>);
+    let mut error_msg = use_signal(String::new);
+    let mut message = use_signal(String::new);
+
+    let export_handler = app_state.inventory_handler.clone();
+    let handle_export = move |_| {
+        let handler = export_handler.clone();
+        spawn(async move {
+            let csv = match handler.export_products_csv().await {
+                Ok(csv) => csv,
+                Err(err) => {
+                    error_msg.set(err);
+                    return;
+                }
+            };
+
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(file_path) = rfd::FileDialog::new()
+                .set_file_name("products.csv")
+                .add_filter("CSV", &["csv"])
+                .save_file()
+            {
+                match std::fs::write(&file_path, csv) {
+                    Ok(_) => message.set(format!("Catalog exported to {}", file_path.display())),
+                    Err(e) => error_msg.set(format!("Failed to write file: {}", e)),
+                }
+            }
+        });
+    };
+
+    let handle_choose_file = move |_| {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(file_path) = rfd::FileDialog::new()
+            .add_filter("CSV", &["csv", "txt"])
+            .pick_file()
+        {
+            let parsed = std::fs::read_to_string(&file_path)
+                .map_err(|e| format!("Failed to read file: {}", e))
+                .and_then(|content| parse_csv(&content));
+
+            match parsed {
+                Ok(parsed) => {
+                    mapping.set(guess_mapping(&parsed.headers));
+                    table.set(Some(parsed));
+                    preview.set(None);
+                    summary.set(None);
+                    error_msg.set(String::new());
+                    message.set(String::new());
+                }
+                Err(err) => error_msg.set(err),
+            }
+        }
+    };
+
+    let preview_handler = app_state.inventory_handler.clone();
+    let handle_preview = move |_| {
+        let handler = preview_handler.clone();
+        let Some(current_table) = table() else {
+            return;
+        };
+
+        spawn(async move {
+            match handler.preview_import(current_table, mapping()).await {
+                Ok(rows) => {
+                    error_msg.set(String::new());
+                    preview.set(Some(rows));
+                }
+                Err(err) => error_msg.set(err),
+            }
+        });
+    };
+
+    let import_handler = app_state.inventory_handler.clone();
+    let handle_import = move |_| {
+        let handler = import_handler.clone();
+        let Some(current_table) = table() else {
+            return;
+        };
+
+        spawn(async move {
+            match handler.import_products(current_table, mapping()).await {
+                Ok(result) => {
+                    preview.set(None);
+                    summary.set(Some(result));
+                    on_imported.call(());
+                }
+                Err(err) => error_msg.set(err),
+            }
+        });
+    };
+
+    let preview_rows = preview();
+    let counts = preview_rows.as_ref().map(|rows| {
+        let count = |f: fn(&ImportAction) -> bool| rows.iter().filter(|r| f(&r.action)).count();
+        (
+            count(|a| matches!(a, ImportAction::Create(_))),
+            count(|a| matches!(a, ImportAction::Update { .. })),
+            count(|a| matches!(a, ImportAction::Error(_))),
+        )
+    });
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.5); display: flex; justify-content: center; align-items: center; z-index: 1000;",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background: white; padding: 2rem; border-radius: 0.5rem; width: 900px; max-width: 95%; max-height: 90vh; overflow-y: auto;",
+                onclick: move |evt| evt.stop_propagation(),
+
+                // Header
+                div {
+                    style: "display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;",
+                    h3 { style: "margin: 0; font-size: 1.25rem;", "📄 Import / Export Products" }
+                    div {
+                        style: "display: flex; gap: 0.5rem;",
+                        button {
+                            style: SECONDARY_BUTTON_STYLE,
+                            onclick: handle_export,
+                            "⬇️ Export CSV"
+                        }
+                        button {
+                            style: SECONDARY_BUTTON_STYLE,
+                            onclick: move |_| on_close.call(()),
+                            "Close"
+                        }
+                    }
+                }
+
+                if !error_msg().is_empty() {
+                    div {
+                        style: "background: #fff5f5; color: #c53030; padding: 0.75rem; border-radius: 0.25rem; margin-bottom: 1rem;",
+                        "{error_msg}"
+                    }
+                }
+                if !message().is_empty() {
+                    div {
+                        style: "background: #f0fff4; color: #276749; padding: 0.75rem; border-radius: 0.25rem; margin-bottom: 1rem;",
+                        "{message}"
+                    }
+                }
+
+                // Import: file selection
+                div {
+                    style: "display: flex; align-items: center; gap: 1rem; margin-bottom: 1rem;",
+                    button {
+                        style: PRIMARY_BUTTON_STYLE,
+                        onclick: handle_choose_file,
+                        "Choose CSV File..."
+                    }
+                    if let Some(current) = table() {
+                        span { style: "color: #4a5568; font-size: 0.875rem;", "{current.rows.len()} rows found" }
+                    } else {
+                        span {
+                            style: "color: #718096; font-size: 0.875rem;",
+                            "Products are matched by barcode: existing products are updated, the rest are created."
+                        }
+                    }
+                }
+
+                // Import: column mapping
+                if let Some(current) = table() {
+                    h4 { style: "margin: 1rem 0 0.5rem 0; color: #4a5568;", "Column Mapping" }
+                    table {
+                        style: "width: 100%; border-collapse: collapse; margin-bottom: 1rem;",
+                        thead {
+                            tr {
+                                style: "background: #f7fafc; border-bottom: 2px solid #e2e8f0;",
+                                th { style: HEADER_STYLE, "Column" }
+                                th { style: HEADER_STYLE, "Example" }
+                                th { style: HEADER_STYLE, "Import As" }
+                            }
+                        }
+                        tbody {
+                            for (index, header) in current.headers.iter().enumerate() {
+                                tr {
+                                    key: "{index}",
+                                    style: "border-bottom: 1px solid #e2e8f0; font-size: 0.875rem;",
+                                    td { style: "padding: 0.5rem; font-weight: 500;", "{header}" }
+                                    td {
+                                        style: "padding: 0.5rem; color: #718096;",
+                                        {current.rows.first().and_then(|row| row.get(index)).cloned().unwrap_or_default()}
+                                    }
+                                    td {
+                                        style: "padding: 0.5rem;",
+                                        select {
+                                            style: "width: 100%; padding: 0.375rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; background: white;",
+                                            onchange: move |evt| {
+                                                let column = ProductColumn::from_key(&evt.value());
+                                                let mut updated = mapping();
+                                                // Each field can only come from one column
+                                                for mapped in updated.iter_mut() {
+                                                    if column.is_some() && *mapped == column {
+                                                        *mapped = None;
+                                                    }
+                                                }
+                                                if let Some(slot) = updated.get_mut(index) {
+                                                    *slot = column;
+                                                }
+                                                mapping.set(updated);
+                                                preview.set(None);
+                                            },
+                                            option {
+                                                value: "",
+                                                selected: mapping().get(index).copied().flatten().is_none(),
+                                                "Ignore"
+                                            }
+                                            for column in ProductColumn::ALL {
+                                                option {
+                                                    value: column.key(),
+                                                    selected: mapping().get(index).copied().flatten() == Some(column),
+                                                    {column.label()}
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    div {
+                        style: "display: flex; justify-content: flex-end; gap: 0.5rem;",
+                        button {
+                            style: SECONDARY_BUTTON_STYLE,
+                            onclick: handle_preview,
+                            "Preview"
+                        }
+                        if let Some((creates, updates, _)) = counts {
+                            if creates + updates > 0 {
+                                button {
+                                    style: PRIMARY_BUTTON_STYLE,
+                                    onclick: handle_import,
+                                    "Import {creates + updates} Products"
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Import: dry-run preview
+                if let (Some(rows), Some((creates, updates, errors))) = (preview_rows, counts) {
+                    div {
+                        style: "display: flex; gap: 1.5rem; margin: 1rem 0 0.5rem 0; font-size: 0.875rem;",
+                        span { style: "color: #38a169; font-weight: 500;", "{creates} new" }
+                        span { style: "color: #667eea; font-weight: 500;", "{updates} updates" }
+                        span { style: "color: #c53030; font-weight: 500;", "{errors} errors" }
+                    }
+                    if updates > 0 {
+                        p {
+                            style: "color: #718096; font-size: 0.875rem; margin: 0 0 0.5rem 0;",
+                            "Stock is only imported for new products; updated products keep their current stock."
+                        }
+                    }
+                    table {
+                        style: "width: 100%; border-collapse: collapse;",
+                        thead {
+                            tr {
+                                style: "background: #f7fafc; border-bottom: 2px solid #e2e8f0;",
+                                th { style: HEADER_STYLE, "Line" }
+                                th { style: HEADER_STYLE, "Barcode" }
+                                th { style: HEADER_STYLE, "Name" }
+                                th { style: HEADER_STYLE, "Action" }
+                            }
+                        }
+                        tbody {
+                            for row in rows {
+                                tr {
+                                    key: "{row.line}",
+                                    style: "border-bottom: 1px solid #e2e8f0; font-size: 0.875rem;",
+                                    td { style: "padding: 0.5rem; color: #718096;", "{row.line}" }
+                                    td { style: "padding: 0.5rem; font-family: monospace;", {row.barcode.clone().unwrap_or_default()} }
+                                    td { style: "padding: 0.5rem;", "{row.name}" }
+                                    td {
+                                        style: "padding: 0.5rem;",
+                                        match &row.action {
+                                            ImportAction::Create(_) => rsx! {
+                                                span { style: "color: #38a169; font-weight: 500;", "Create" }
+                                            },
+                                            ImportAction::Update { .. } => rsx! {
+                                                span { style: "color: #667eea; font-weight: 500;", "Update" }
+                                            },
+                                            ImportAction::Error(err) => rsx! {
+                                                span { style: "color: #c53030;", "{err}" }
+                                            },
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Import: result
+                if let Some(result) = summary() {
+                    div {
+                        style: "margin-top: 1rem; padding: 1rem; background: #f7fafc; border-radius: 0.5rem; font-size: 0.875rem;",
+                        div {
+                            style: "font-weight: 600; color: #2d3748; margin-bottom: 0.5rem;",
+                            "Imported: {result.created} created, {result.updated} updated, {result.errors.len()} skipped"
+                        }
+                        for (line, err) in result.errors {
+                            div { style: "color: #c53030;", "Line {line}: {err}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+const HEADER_STYLE: &str = "padding: 0.5rem; text-align: left; font-weight: 600; color: #4a5568; font-size: 0.75rem; text-transform: uppercase; letter-spacing: 0.05em;";
+const PRIMARY_BUTTON_STYLE: &str = "padding: 0.5rem 1rem; background: #667eea; color: white; border: none; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem; font-weight: 500;";
+const SECONDARY_BUTTON_STYLE: &str = "padding: 0.5rem 1rem; border: 1px solid #e2e8f0; background: white; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem;";
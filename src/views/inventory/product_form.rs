@@ -3,7 +3,15 @@
 //! Modal form for creating and editing products.
 
 use crate::handlers::AppState;
-use crate::models::{Product, ProductInput};
+use crate::models::kit::kit_availability;
+use crate::models::{CategoryPath, KitComponent, Product, ProductInput};
+use crate::views::inventory::kit_components::KitComponents;
+use crate::views::inventory::price_history::PriceHistory;
+use crate::views::inventory::product_barcodes::ProductBarcodes;
+use crate::views::inventory::product_lots::ProductLots;
+use crate::views::inventory::product_price_tiers::ProductPriceTiers;
+use crate::views::inventory::product_tax_codes::ProductTaxCodes;
+use crate::views::inventory::CategorySelect;
 use dioxus::prelude::*;
 use rust_decimal::Decimal;
 use std::str::FromStr;
@@ -12,19 +20,20 @@ use std::str::FromStr;
 pub fn ProductForm(
     on_close: EventHandler<()>,
     on_save: EventHandler<ProductInput>,
-    on_delete: EventHandler<String>,
+    on_archive: EventHandler<String>,
     initial_product: Option<Product>,
+    categories: Vec<CategoryPath>,
 ) -> Element {
     // Clone initial_product to avoid lifetime issues
     let product_clone = initial_product.clone();
 
     let full_name = use_signal(|| extract_field(&product_clone, |p| p.full_name.clone()));
-    let barcode = use_signal(|| extract_optional_field(&product_clone, |p| p.barcode.clone()));
+    let mut barcode = use_signal(|| extract_optional_field(&product_clone, |p| p.barcode.clone()));
     let price = use_signal(|| extract_field(&product_clone, |p| p.user_price.to_string()));
     let cost = use_signal(|| {
         extract_optional_field(&product_clone, |p| p.cost_price.map(|c| c.to_string()))
     });
-    let stock = use_signal(|| extract_field(&product_clone, |p| p.current_amount.to_string()));
+    let mut stock = use_signal(|| extract_field(&product_clone, |p| p.current_amount.to_string()));
     let min_stock = use_signal(|| extract_field(&product_clone, |p| p.min_amount.to_string()));
     let mut unit_id = use_signal(|| {
         product_clone
@@ -32,6 +41,17 @@ pub fn ProductForm(
             .map(|p| p.unit_measurement_id)
             .unwrap_or(3)
     });
+    let mut purchase_unit_id =
+        use_signal(|| product_clone.as_ref().and_then(|p| p.purchase_unit_id));
+    let units_per_purchase = use_signal(|| {
+        product_clone
+            .as_ref()
+            .map(|p| p.units_per_purchase.to_string())
+            .unwrap_or_else(|| "1".to_string())
+    });
+    let mut category_id = use_signal(|| product_clone.as_ref().and_then(|p| p.category_id.clone()));
+    let mut supplier_id = use_signal(|| product_clone.as_ref().and_then(|p| p.supplier_id.clone()));
+    let mut is_kit = use_signal(|| product_clone.as_ref().is_some_and(|p| p.is_kit));
     let mut error_msg = use_signal(String::new);
 
     let units_resource = use_resource(move || async move {
@@ -39,26 +59,46 @@ pub fn ProductForm(
         app_state.inventory_handler.get_units().await
     });
 
-    let handle_submit = move |_| match validate_and_build_product_input(
-        &full_name(),
-        &barcode(),
-        &price(),
-        &cost(),
-        &stock(),
-        &min_stock(),
-        unit_id(),
-    ) {
-        Ok(input) => {
-            error_msg.set(String::new());
-            on_save.call(input);
-        }
-        Err(err) => {
-            error_msg.set(err);
+    let suppliers_resource = use_resource(move || async move {
+        let app_state = use_context::<AppState>();
+        app_state.inventory_handler.get_suppliers().await
+    });
+
+    let lots_handler = use_context::<AppState>().inventory_handler;
+    let barcode_handler = lots_handler.clone();
+
+    let handle_submit = move |_| {
+        let result = validate_and_build_product_input(
+            &full_name(),
+            &barcode(),
+            &price(),
+            &cost(),
+            &stock(),
+            &min_stock(),
+            unit_id(),
+        )
+        .and_then(|mut input| {
+            input.category_id = category_id();
+            input.supplier_id = supplier_id();
+            input.purchase_unit_id = purchase_unit_id();
+            input.units_per_purchase =
+                parse_units_per_purchase(purchase_unit_id(), &units_per_purchase())?;
+            Ok(input)
+        });
+
+        match result {
+            Ok(input) => {
+                error_msg.set(String::new());
+                on_save.call(input);
+            }
+            Err(err) => {
+                error_msg.set(err);
+            }
         }
     };
 
     let (title, save_label) = get_form_labels(&initial_product);
-    let product_id_for_delete = initial_product.as_ref().map(|p| p.id.clone());
+    let product_id_for_edit = initial_product.as_ref().map(|p| p.id.clone());
 
     rsx! {
         div {
@@ -74,11 +114,11 @@ pub fn ProductForm(
                     style: "display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;",
                     h3 { style: "margin: 0; font-size: 1.25rem;", "{title}" }
 
-                    if let Some(product_id) = product_id_for_delete.clone() {
+                    if let Some(product_id) = product_id_for_edit.clone() {
                         button {
-                            style: "background: #e53e3e; color: white; border: none; padding: 0.5rem 1rem; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem; font-weight: 500;",
-                            onclick: move |_| on_delete.call(product_id.clone()),
-                            "Delete Product"
+                            style: "background: #ed8936; color: white; border: none; padding: 0.5rem 1rem; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem; font-weight: 500;",
+                            onclick: move |_| on_archive.call(product_id.clone()),
+                            "Archive Product"
                         }
                     }
                 }
@@ -96,6 +136,36 @@ pub fn ProductForm(
 
                 // Barcode
                 { form_input("Barcode", "text", barcode, None, None) }
+                if barcode().trim().is_empty() {
+                    button {
+                        style: "background: none; border: none; color: #667eea; font-weight: 500; cursor: pointer; padding: 0; margin: -0.5rem 0 1rem; font-size: 0.875rem;",
+                        onclick: move |_| {
+                            let handler = barcode_handler.clone();
+                            spawn(async move {
+                                match handler.next_internal_barcode().await {
+                                    Ok(code) => barcode.set(code),
+                                    Err(err) => error_msg.set(err),
+                                }
+                            });
+                        },
+                        "Generate internal EAN-13"
+                    }
+                }
+
+                // Additional barcodes can only be attached to saved products
+                if let Some(product_id) = product_id_for_edit.clone() {
+                    ProductBarcodes { product_id }
+                }
+
+                // SAT codes for invoicing, also only for saved products
+                if let Some(product_id) = product_id_for_edit.clone() {
+                    ProductTaxCodes { product_id }
+                }
+
+                // Quantity breaks, also only for saved products
+                if let Some(product_id) = product_id_for_edit.clone() {
+                    ProductPriceTiers { product_id }
+                }
 
                 // Price and Cost
                 div {
@@ -107,10 +177,72 @@ pub fn ProductForm(
                 // Stock Amount and Min Stock
                 div {
                     style: "display: grid; grid-template-columns: 1fr 1fr; gap: 1rem; margin-bottom: 1.5rem;",
-                    { form_input("Stock Amount", "number", stock, None, None) }
+                    if is_kit() {
+                        div {
+                            style: "margin-bottom: 1rem;",
+                            label { style: "display: block; margin-bottom: 0.5rem; font-weight: 500; font-size: 0.875rem; color: #4a5568;", "Available Kits" }
+                            div { style: "padding: 0.625rem 0; font-size: 1rem; color: #4a5568;", "{stock} (from components)" }
+                        }
+                    } else {
+                        { form_input("Stock Amount", "number", stock, None, None) }
+                    }
                     { form_input("Min Stock", "number", min_stock, None, None) }
                 }
 
+                // Components of saved products, which turn them into kits
+                if let Some(product_id) = product_id_for_edit.clone() {
+                    KitComponents {
+                        product_id,
+                        on_change: move |components: Vec<KitComponent>| {
+                            is_kit.set(!components.is_empty());
+                            stock.set(if components.is_empty() {
+                                "0".to_string()
+                            } else {
+                                kit_availability(&components).to_string()
+                            });
+                        },
+                    }
+                }
+
+                // Category
+                div {
+                    style: "margin-bottom: 1rem;",
+                    label { style: "display: block; margin-bottom: 0.5rem; font-weight: 500; font-size: 0.875rem; color: #4a5568;", "Category" }
+                    CategorySelect {
+                        categories: categories.clone(),
+                        selected: category_id(),
+                        empty_label: "Uncategorized",
+                        on_change: move |id| category_id.set(id),
+                    }
+                }
+
+                // Supplier
+                div {
+                    style: "margin-bottom: 1rem;",
+                    label { style: "display: block; margin-bottom: 0.5rem; font-weight: 500; font-size: 0.875rem; color: #4a5568;", "Supplier" }
+                    select {
+                        style: "width: 100%; padding: 0.625rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; background: white; font-size: 1rem;",
+                        onchange: move |evt| {
+                            let value = evt.value();
+                            supplier_id.set(if value.is_empty() { None } else { Some(value) });
+                        },
+                        option {
+                            value: "",
+                            selected: supplier_id().is_none(),
+                            "No supplier"
+                        }
+                        if let Some(Ok(suppliers)) = suppliers_resource.read().as_ref() {
+                            for supplier in suppliers {
+                                option {
+                                    value: "{supplier.id}",
+                                    selected: supplier_id().as_deref() == Some(supplier.id.as_str()),
+                                    "{supplier.name}"
+                                }
+                            }
+                        }
+                    }
+                }
+
                 // Unit of Measurement
                 div {
                     style: "margin-bottom: 1.5rem;",
@@ -134,6 +266,57 @@ pub fn ProductForm(
                     }
                 }
 
+                // Purchase Unit and Conversion Factor
+                div {
+                    style: "display: grid; grid-template-columns: 1fr 1fr; gap: 1rem; margin-bottom: 1.5rem;",
+                    div {
+                        label { style: "display: block; margin-bottom: 0.5rem; font-weight: 500; font-size: 0.875rem; color: #4a5568;", "Purchase Unit" }
+                        select {
+                            style: "width: 100%; padding: 0.625rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; background: white; font-size: 1rem;",
+                            onchange: move |evt| purchase_unit_id.set(evt.value().parse::<i32>().ok()),
+                            option {
+                                value: "",
+                                selected: purchase_unit_id().is_none(),
+                                "Same as sale unit"
+                            }
+                            if let Some(Ok(units)) = units_resource.read().as_ref() {
+                                for unit in units {
+                                    option {
+                                        value: "{unit.id}",
+                                        selected: Some(unit.id) == purchase_unit_id(),
+                                        "{unit.description} ({unit.abbreviation})"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    if purchase_unit_id().is_some() {
+                        { form_input("Sale Units per Purchase Unit", "number", units_per_purchase, Some("0.001"), Some("")) }
+                    }
+                }
+
+                // Price and cost changes of saved products
+                if let Some(product_id) = product_id_for_edit.clone() {
+                    PriceHistory { product_id }
+                }
+
+                // Lots of saved products, with expiry and condition
+                if let Some(product_id) = product_id_for_edit.clone() {
+                    ProductLots {
+                        product_id: product_id.clone(),
+                        on_change: move |_| {
+                            // Keep the stock field in sync after stock leaves sellable lots
+                            let handler = lots_handler.clone();
+                            let product_id = product_id.clone();
+                            spawn(async move {
+                                if let Ok(product) = handler.get_product(product_id).await {
+                                    stock.set(product.current_amount.to_string());
+                                }
+                            });
+                        },
+                    }
+                }
+
                 // Action Buttons
                 div {
                     style: "display: flex; justify-content: flex-end; gap: 0.5rem;",
@@ -221,9 +404,25 @@ fn validate_and_build_product_input(
         current_amount,
         min_amount,
         unit_measurement_id: unit_id,
+        purchase_unit_id: None,
+        units_per_purchase: 1.0,
+        category_id: None,
+        supplier_id: None,
     })
 }
 
+/// Parse the conversion factor, which only applies when a purchase unit is set
+fn parse_units_per_purchase(purchase_unit_id: Option<i32>, value: &str) -> Result<f64, String> {
+    if purchase_unit_id.is_none() {
+        return Ok(1.0);
+    }
+
+    match value.parse::<f64>() {
+        Ok(factor) if factor > 0.0 => Ok(factor),
+        _ => Err("Units per purchase must be a number greater than zero".to_string()),
+    }
+}
+
 /// Helper component for form input fields
 fn form_input(
     label: &str,
@@ -0,0 +1,76 @@
+//! Product Lots Component
+//!
+//! Lists the received lots of a product with their expiry and condition.
+
+use crate::handlers::AppState;
+use crate::views::inventory::lot_row::{condition_name, LotRow};
+use dioxus::prelude::*;
+
+#[component]
+pub fn ProductLots(product_id: String, on_change: EventHandler<()>) -> Element {
+    let app_state = use_context::<AppState>();
+    let mut refresh_trigger = use_signal(|| 0);
+
+    let mut lots_resource = use_resource({
+        let handler = app_state.inventory_handler.clone();
+        move || {
+            let handler = handler.clone();
+            let product_id = product_id.clone();
+            async move { handler.get_lots(product_id).await }
+        }
+    });
+
+    let conditions_resource = use_resource({
+        let handler = app_state.inventory_handler.clone();
+        move || {
+            let handler = handler.clone();
+            async move { handler.get_conditions().await }
+        }
+    });
+
+    use_effect(move || {
+        let _ = refresh_trigger();
+        lots_resource.restart();
+    });
+
+    let conditions = match &*conditions_resource.read_unchecked() {
+        Some(Ok(conditions)) => conditions.clone(),
+        _ => Vec::new(),
+    };
+
+    rsx! {
+        div {
+            style: "margin-bottom: 1.5rem;",
+            label { style: "display: block; margin-bottom: 0.5rem; font-weight: 500; font-size: 0.875rem; color: #4a5568;", "Lots" }
+
+            match &*lots_resource.read_unchecked() {
+                Some(Ok(lots)) if lots.is_empty() => rsx! {
+                    div { style: "color: #718096; font-size: 0.875rem;", "No lots received yet." }
+                },
+                Some(Ok(lots)) => rsx! {
+                    table {
+                        style: "width: 100%; border-collapse: collapse;",
+                        for lot in lots.iter().cloned() {
+                            LotRow {
+                                key: "{lot.id}",
+                                condition: condition_name(&conditions, lot.condition_id),
+                                lot,
+                                product_name: None,
+                                on_moved: move |_| {
+                                    refresh_trigger.set(refresh_trigger() + 1);
+                                    on_change.call(());
+                                },
+                            }
+                        }
+                    }
+                },
+                Some(Err(err)) => rsx! {
+                    div { style: "color: #e53e3e; font-size: 0.875rem;", "Error loading lots: {err}" }
+                },
+                None => rsx! {
+                    div { style: "color: #718096; font-size: 0.875rem;", "Loading lots..." }
+                },
+            }
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Product Price Tiers Component
+//!
+//! Manages the quantity breaks of a product, e.g. a lower unit price when
+//! buying a full box.
+
+use crate::handlers::AppState;
+use crate::models::PriceTier;
+use crate::utils::formatting::format_currency;
+use dioxus::prelude::*;
+use rust_decimal::Decimal;
+
+#[component]
+pub fn ProductPriceTiers(product_id: String) -> Element {
+    let app_state = use_context::<AppState>();
+
+    let mut min_quantity = use_signal(String::new);
+    let mut unit_price = use_signal(String::new);
+    let mut tiers = use_signal(Vec::<PriceTier>::new);
+    let mut error_msg = use_signal(String::new);
+
+    // Load the current tiers
+    let load_handler = app_state.price_lists_handler.clone();
+    let load_id = product_id.clone();
+    use_hook(move || {
+        spawn(async move {
+            match load_handler.get_tiers(load_id).await {
+                Ok(loaded) => tiers.set(loaded),
+                Err(err) => error_msg.set(err),
+            }
+        });
+    });
+
+    // Save the whole set of tiers, keeping the shown ones only if it succeeds
+    let save_handler = app_state.price_lists_handler.clone();
+    let save_tiers = move |product_id: String, updated: Vec<PriceTier>| {
+        let handler = save_handler.clone();
+        spawn(async move {
+            match handler.save_tiers(product_id.clone(), updated).await {
+                Ok(()) => {
+                    min_quantity.set(String::new());
+                    unit_price.set(String::new());
+                    error_msg.set(String::new());
+                    if let Ok(saved) = handler.get_tiers(product_id).await {
+                        tiers.set(saved);
+                    }
+                }
+                Err(err) => error_msg.set(err),
+            }
+        });
+    };
+
+    let add_product_id = product_id.clone();
+    let add_save = save_tiers.clone();
+    let handle_add = move |_| {
+        let min_quantity = match min_quantity().trim().parse::<f64>() {
+            Ok(quantity) => quantity,
+            Err(_) => {
+                error_msg.set("Invalid tier quantity".to_string());
+                return;
+            }
+        };
+        let unit_price = match unit_price().trim().parse::<Decimal>() {
+            Ok(price) => price,
+            Err(_) => {
+                error_msg.set("Invalid tier price".to_string());
+                return;
+            }
+        };
+
+        // Adding a tier for an existing quantity replaces its price
+        let mut updated: Vec<PriceTier> = tiers()
+            .into_iter()
+            .filter(|t| t.min_quantity != min_quantity)
+            .collect();
+        updated.push(PriceTier {
+            product_id: add_product_id.clone(),
+            min_quantity,
+            unit_price,
+        });
+        add_save(add_product_id.clone(), updated);
+    };
+
+    rsx! {
+        div {
+            style: "margin-bottom: 1.5rem;",
+            label { style: "display: block; margin-bottom: 0.5rem; font-weight: 500; font-size: 0.875rem; color: #4a5568;", "Quantity Price Tiers" }
+
+            if !error_msg().is_empty() {
+                div {
+                    style: "background: #fff5f5; color: #c53030; padding: 0.5rem; border-radius: 0.25rem; margin-bottom: 0.5rem; font-size: 0.875rem;",
+                    "{error_msg}"
+                }
+            }
+
+            if tiers().is_empty() {
+                div {
+                    style: "color: #718096; font-size: 0.75rem; margin-bottom: 0.5rem;",
+                    "Lower unit prices for larger quantities, e.g. when buying a full box. They apply to every customer."
+                }
+            }
+
+            for tier in tiers() {
+                {
+                    let save = save_tiers.clone();
+                    let product_id = product_id.clone();
+                    let removed = tier.min_quantity;
+
+                    rsx! {
+                        div {
+                            key: "{tier.min_quantity}",
+                            style: "display: flex; justify-content: space-between; align-items: center; padding: 0.375rem 0; border-bottom: 1px solid #e2e8f0; font-size: 0.875rem;",
+                            span { "From {tier.min_quantity}" }
+                            span { style: "font-family: monospace;", "{format_currency(tier.unit_price)} each" }
+                            button {
+                                style: "background: none; border: none; color: #e53e3e; font-weight: 500; cursor: pointer; padding: 0.25rem 0.5rem; font-size: 0.875rem;",
+                                onclick: move |_| {
+                                    let updated = tiers()
+                                        .into_iter()
+                                        .filter(|t| t.min_quantity != removed)
+                                        .collect();
+                                    save(product_id.clone(), updated);
+                                },
+                                "Remove"
+                            }
+                        }
+                    }
+                }
+            }
+
+            div {
+                style: "display: grid; grid-template-columns: 1fr 1fr auto; gap: 0.5rem; margin-top: 0.5rem;",
+                input {
+                    r#type: "number",
+                    min: "2",
+                    step: "1",
+                    placeholder: "From quantity",
+                    style: "width: 100%; padding: 0.5rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 0.875rem; box-sizing: border-box;",
+                    value: "{min_quantity}",
+                    oninput: move |e| min_quantity.set(e.value())
+                }
+                input {
+                    r#type: "number",
+                    min: "0",
+                    step: "0.01",
+                    placeholder: "Unit price",
+                    style: "width: 100%; padding: 0.5rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 0.875rem; box-sizing: border-box;",
+                    value: "{unit_price}",
+                    oninput: move |e| unit_price.set(e.value())
+                }
+                button {
+                    style: "padding: 0.5rem 0.75rem; background: #667eea; color: white; border: none; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem; font-weight: 500;",
+                    onclick: handle_add,
+                    "Add"
+                }
+            }
+        }
+    }
+}
@@ -7,9 +7,20 @@ use crate::utils::formatting::format_currency;
 use dioxus::prelude::*;
 
 #[component]
-pub fn ProductRow(product: Product, on_edit: EventHandler<Product>) -> Element {
+pub fn ProductRow(
+    product: Product,
+    category_path: String,
+    sale_unit: String,
+    purchase_unit: String,
+    on_edit: EventHandler<Product>,
+    on_receive: EventHandler<Product>,
+    on_write_off: EventHandler<Product>,
+) -> Element {
     let is_low_stock = product.is_low_stock();
     let stock_style = get_stock_style(is_low_stock);
+    let stock_in_purchase_units = product.stock_in_purchase_units();
+    let product_for_receive = product.clone();
+    let product_for_write_off = product.clone();
 
     rsx! {
         tr {
@@ -24,20 +35,49 @@ pub fn ProductRow(product: Product, on_edit: EventHandler<Product>) -> Element {
                 style: "padding: 0.75rem; color: #718096; font-family: monospace; font-size: 1rem;",
                 "{product.barcode.as_deref().unwrap_or(\"-\")}"
             }
+            td {
+                style: "padding: 0.75rem; color: #4a5568; font-size: 0.875rem;",
+                "{category_path}"
+            }
             td {
                 style: "padding: 0.75rem; text-align: center; font-weight: 500; font-size: 1rem;",
                 "{format_currency(product.user_price)}"
             }
             td {
                 style: "padding: 0.75rem; text-align: center; font-size: 1rem; {stock_style}",
-                "{product.current_amount:.2}"
+                "{product.current_amount:.2} {sale_unit}"
+                if product.is_kit {
+                    div {
+                        style: "color: #718096; font-size: 0.75rem; font-weight: normal;",
+                        "kit, from components"
+                    }
+                }
+                if let Some(purchase_amount) = stock_in_purchase_units {
+                    div {
+                        style: "color: #718096; font-size: 0.75rem; font-weight: normal;",
+                        "≈ {purchase_amount:.2} {purchase_unit}"
+                    }
+                }
             }
             td {
                 style: "padding: 0.75rem; text-align: center;",
                 { stock_status_badge(is_low_stock) }
             }
             td {
-                style: "padding: 0.75rem; text-align: right;",
+                style: "padding: 0.75rem; text-align: right; white-space: nowrap;",
+                // Kits are stocked through their components
+                if !product.is_kit {
+                    button {
+                        style: "background: none; border: none; color: #48bb78; font-weight: 500; cursor: pointer; padding: 0.25rem 0.5rem; font-size: 0.875rem;",
+                        onclick: move |_| on_receive.call(product_for_receive.clone()),
+                        "Receive"
+                    }
+                    button {
+                        style: "background: none; border: none; color: #e53e3e; font-weight: 500; cursor: pointer; padding: 0.25rem 0.5rem; font-size: 0.875rem;",
+                        onclick: move |_| on_write_off.call(product_for_write_off.clone()),
+                        "Write Off"
+                    }
+                }
                 button {
                     style: "background: none; border: none; color: #667eea; font-weight: 500; cursor: pointer; padding: 0.25rem 0.5rem; font-size: 0.875rem;",
                     onclick: move |_| on_edit.call(product.clone()),
@@ -0,0 +1,112 @@
+//! Product Tax Codes Component
+//!
+//! Edits the SAT catalog codes and tax treatment used when invoicing a product.
+
+use crate::handlers::AppState;
+use crate::models::invoice::DEFAULT_PRODUCT_CODE;
+use crate::models::{ProductTaxCode, TaxTreatment};
+use dioxus::prelude::*;
+
+#[component]
+pub fn ProductTaxCodes(product_id: String) -> Element {
+    let app_state = use_context::<AppState>();
+
+    let mut product_code = use_signal(String::new);
+    let mut unit_code = use_signal(String::new);
+    let mut tax = use_signal(|| TaxTreatment::Iva16);
+    let mut message = use_signal(|| Option::<(bool, String)>::None);
+
+    // Load the saved codes
+    let load_handler = app_state.invoicing_handler.clone();
+    let load_product_id = product_id.clone();
+    use_hook(move || {
+        spawn(async move {
+            match load_handler.get_tax_code(load_product_id).await {
+                Ok(Some(code)) => {
+                    product_code.set(code.sat_product_code);
+                    unit_code.set(code.sat_unit_code.unwrap_or_default());
+                    tax.set(code.tax);
+                }
+                Ok(None) => {}
+                Err(err) => message.set(Some((false, err))),
+            }
+        });
+    });
+
+    let save_handler = app_state.invoicing_handler.clone();
+    let handle_save = move |_| {
+        let handler = save_handler.clone();
+        let code = ProductTaxCode {
+            product_id: product_id.clone(),
+            sat_product_code: product_code(),
+            sat_unit_code: Some(unit_code()),
+            tax: tax(),
+        };
+
+        spawn(async move {
+            match handler.save_tax_code(code).await {
+                Ok(_) => message.set(Some((true, "Tax codes saved".to_string()))),
+                Err(err) => message.set(Some((false, err))),
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            style: "margin-bottom: 1.5rem;",
+            label { style: "display: block; margin-bottom: 0.5rem; font-weight: 500; font-size: 0.875rem; color: #4a5568;", "Invoicing (SAT)" }
+
+            if let Some((success, text)) = message() {
+                div {
+                    style: if success {
+                        "background: #f0fff4; color: #276749; padding: 0.5rem; border-radius: 0.25rem; margin-bottom: 0.5rem; font-size: 0.875rem;"
+                    } else {
+                        "background: #fff5f5; color: #c53030; padding: 0.5rem; border-radius: 0.25rem; margin-bottom: 0.5rem; font-size: 0.875rem;"
+                    },
+                    "{text}"
+                }
+            }
+
+            div {
+                style: "display: grid; grid-template-columns: 1fr 90px 110px auto; gap: 0.5rem;",
+                input {
+                    r#type: "text",
+                    maxlength: "8",
+                    placeholder: "Product code ({DEFAULT_PRODUCT_CODE})",
+                    title: "c_ClaveProdServ",
+                    style: INPUT_STYLE,
+                    value: "{product_code}",
+                    oninput: move |e| product_code.set(e.value())
+                }
+                input {
+                    r#type: "text",
+                    maxlength: "3",
+                    placeholder: "Unit",
+                    title: "c_ClaveUnidad; leave empty to use the sale unit",
+                    style: INPUT_STYLE,
+                    value: "{unit_code}",
+                    oninput: move |e| unit_code.set(e.value().to_uppercase())
+                }
+                select {
+                    style: INPUT_STYLE,
+                    onchange: move |e| tax.set(TaxTreatment::from_code(&e.value()).unwrap_or(TaxTreatment::Iva16)),
+                    for treatment in TaxTreatment::ALL {
+                        option {
+                            key: "{treatment.code()}",
+                            value: "{treatment.code()}",
+                            selected: tax() == treatment,
+                            "{treatment.label()}"
+                        }
+                    }
+                }
+                button {
+                    style: "padding: 0.5rem 0.75rem; background: #667eea; color: white; border: none; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem; font-weight: 500;",
+                    onclick: handle_save,
+                    "Save"
+                }
+            }
+        }
+    }
+}
+
+const INPUT_STYLE: &str = "width: 100%; padding: 0.5rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 0.875rem; box-sizing: border-box; background: white;";
@@ -2,15 +2,19 @@
 //!
 //! Displays the table of products with headers and rows.
 
-use crate::models::Product;
+use crate::models::{CategoryPath, Product, UnitMeasurement};
 use crate::views::inventory::product_row::ProductRow;
 use dioxus::prelude::*;
 
 #[component]
 pub fn ProductsTable(
     products: Vec<Product>,
+    categories: Vec<CategoryPath>,
+    units: Vec<UnitMeasurement>,
     is_search_mode: bool,
     on_edit: EventHandler<Product>,
+    on_receive: EventHandler<Product>,
+    on_write_off: EventHandler<Product>,
 ) -> Element {
     rsx! {
         div {
@@ -30,7 +34,12 @@ pub fn ProductsTable(
                         for product in products.iter() {
                             ProductRow {
                                 product: product.clone(),
+                                category_path: category_path(&categories, product.category_id.as_deref()),
+                                sale_unit: unit_abbreviation(&units, Some(product.unit_measurement_id)),
+                                purchase_unit: unit_abbreviation(&units, product.purchase_unit_id),
                                 on_edit: move |p| on_edit.call(p),
+                                on_receive: move |p| on_receive.call(p),
+                                on_write_off: move |p| on_write_off.call(p),
                             }
                         }
                     }
@@ -48,6 +57,7 @@ fn table_header() -> Element {
 
             th { style: HEADER_STYLE, "Product Name" }
             th { style: HEADER_STYLE, "Barcode" }
+            th { style: HEADER_STYLE, "Category" }
             th { style: "{HEADER_STYLE} text-align: center;", "Price" }
             th { style: "{HEADER_STYLE} text-align: center;", "Stock" }
             th { style: "{HEADER_STYLE} text-align: center;", "Status" }
@@ -56,6 +66,22 @@ fn table_header() -> Element {
     }
 }
 
+/// Resolve the display path of a product's category
+fn category_path(categories: &[CategoryPath], category_id: Option<&str>) -> String {
+    category_id
+        .and_then(|id| categories.iter().find(|c| c.category.id == id))
+        .map(|c| c.path.clone())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// Resolve a unit's abbreviation (empty when unknown or not set)
+fn unit_abbreviation(units: &[UnitMeasurement], unit_id: Option<i32>) -> String {
+    unit_id
+        .and_then(|id| units.iter().find(|u| u.id == id))
+        .map(|u| u.abbreviation.clone())
+        .unwrap_or_default()
+}
+
 /// Render empty state row
 fn empty_row(is_search_mode: bool) -> Element {
     let message = if is_search_mode {
@@ -67,7 +93,7 @@ fn empty_row(is_search_mode: bool) -> Element {
     rsx! {
         tr {
             td {
-                colspan: "6",
+                colspan: "7",
                 style: "padding: 2rem; text-align: center; color: #718096;",
                 "{message}"
             }
@@ -0,0 +1,201 @@
+//! Receive Stock Modal Component
+//!
+//! Modal for receiving a batch of stock in the product's purchase unit,
+//! showing the converted quantity in sale units before saving. The batch is
+//! recorded as a lot with optional lot code and expiry date. An optional cost
+//! per purchase unit updates the product's cost.
+
+use crate::handlers::AppState;
+use crate::models::{Product, StockReceiptInput, UnitMeasurement};
+use chrono::NaiveDate;
+use dioxus::prelude::*;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+#[component]
+pub fn ReceiveStockModal(
+    product: Product,
+    units: Vec<UnitMeasurement>,
+    on_close: EventHandler<()>,
+    on_received: EventHandler<()>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+
+    let mut quantity = use_signal(String::new);
+    let mut lot_code = use_signal(String::new);
+    let mut expires_at = use_signal(String::new);
+    let mut unit_cost = use_signal(String::new);
+    let mut error_msg = use_signal(String::new);
+
+    let abbreviation = |unit_id: i32| {
+        units
+            .iter()
+            .find(|u| u.id == unit_id)
+            .map(|u| u.abbreviation.clone())
+            .unwrap_or_default()
+    };
+    let sale_unit = abbreviation(product.unit_measurement_id);
+    let purchase_unit = product
+        .purchase_unit_id
+        .map(abbreviation)
+        .unwrap_or_else(|| sale_unit.clone());
+
+    let converted = quantity()
+        .parse::<f64>()
+        .ok()
+        .filter(|q| *q > 0.0)
+        .map(|q| product.to_sale_units(q));
+
+    let handler = app_state.inventory_handler.clone();
+    let product_id = product.id.clone();
+    let handle_receive = move |_| {
+        let handler = handler.clone();
+        let product_id = product_id.clone();
+
+        let purchase_quantity = match quantity().parse::<f64>() {
+            Ok(q) if q > 0.0 => q,
+            _ => {
+                error_msg.set("Quantity must be a positive number".to_string());
+                return;
+            }
+        };
+
+        let expires_at = match expires_at().as_str() {
+            "" => None,
+            value => match NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+                Ok(date) => Some(date),
+                Err(_) => {
+                    error_msg.set("Invalid expiry date".to_string());
+                    return;
+                }
+            },
+        };
+        let unit_cost = match unit_cost().trim() {
+            "" => None,
+            value => match Decimal::from_str(value) {
+                Ok(cost) => Some(cost),
+                Err(_) => {
+                    error_msg.set("Invalid cost".to_string());
+                    return;
+                }
+            },
+        };
+        let input = StockReceiptInput {
+            purchase_quantity,
+            lot_code: Some(lot_code()),
+            expires_at,
+            unit_cost,
+        };
+
+        spawn(async move {
+            match handler.receive_stock(product_id, input).await {
+                Ok(_) => on_received.call(()),
+                Err(err) => error_msg.set(err),
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.5); display: flex; justify-content: center; align-items: center; z-index: 1000;",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background: white; padding: 2rem; border-radius: 0.5rem; width: 400px; max-width: 90%;",
+                onclick: move |evt| evt.stop_propagation(),
+
+                h3 { style: "margin: 0 0 0.5rem 0; font-size: 1.25rem;", "Receive Stock" }
+                p {
+                    style: "margin: 0 0 1.5rem 0; color: #718096;",
+                    "{product.full_name} — current stock {product.current_amount:.2} {sale_unit}"
+                }
+
+                if !error_msg().is_empty() {
+                    div {
+                        style: "background: #fff5f5; color: #c53030; padding: 0.75rem; border-radius: 0.25rem; margin-bottom: 1rem;",
+                        "{error_msg}"
+                    }
+                }
+
+                label {
+                    style: "display: block; margin-bottom: 0.5rem; font-weight: 500; font-size: 0.875rem; color: #4a5568;",
+                    "Quantity ({purchase_unit})"
+                }
+                input {
+                    r#type: "number",
+                    step: "0.001",
+                    autofocus: true,
+                    style: "width: 100%; padding: 0.625rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 1rem; box-sizing: border-box;",
+                    value: "{quantity}",
+                    oninput: move |e| quantity.set(e.value())
+                }
+
+                if let Some(sale_quantity) = converted {
+                    if product.purchase_unit_id.is_some() {
+                        div {
+                            style: "margin-top: 0.5rem; color: #4a5568; font-size: 0.875rem;",
+                            "= {sale_quantity:.2} {sale_unit} ({product.units_per_purchase} {sale_unit} per {purchase_unit})"
+                        }
+                    }
+                }
+
+                div {
+                    style: "display: grid; grid-template-columns: 1fr 1fr; gap: 1rem; margin-top: 1rem;",
+                    div {
+                        label {
+                            style: "display: block; margin-bottom: 0.5rem; font-weight: 500; font-size: 0.875rem; color: #4a5568;",
+                            "Lot Code"
+                        }
+                        input {
+                            r#type: "text",
+                            style: "width: 100%; padding: 0.625rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 1rem; box-sizing: border-box;",
+                            value: "{lot_code}",
+                            oninput: move |e| lot_code.set(e.value())
+                        }
+                    }
+                    div {
+                        label {
+                            style: "display: block; margin-bottom: 0.5rem; font-weight: 500; font-size: 0.875rem; color: #4a5568;",
+                            "Expiry Date"
+                        }
+                        input {
+                            r#type: "date",
+                            style: "width: 100%; padding: 0.625rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 1rem; box-sizing: border-box;",
+                            value: "{expires_at}",
+                            oninput: move |e| expires_at.set(e.value())
+                        }
+                    }
+                }
+
+                div {
+                    style: "margin-top: 1rem;",
+                    label {
+                        style: "display: block; margin-bottom: 0.5rem; font-weight: 500; font-size: 0.875rem; color: #4a5568;",
+                        "Cost per {purchase_unit} (optional)"
+                    }
+                    input {
+                        r#type: "number",
+                        step: "0.01",
+                        style: "width: 100%; padding: 0.625rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 1rem; box-sizing: border-box;",
+                        value: "{unit_cost}",
+                        oninput: move |e| unit_cost.set(e.value())
+                    }
+                }
+
+                div {
+                    style: "display: flex; justify-content: flex-end; gap: 0.5rem; margin-top: 1.5rem;",
+                    button {
+                        style: "padding: 0.5rem 1rem; border: 1px solid #e2e8f0; background: white; border-radius: 0.25rem; cursor: pointer; font-size: 1rem; font-weight: 500;",
+                        onclick: move |_| on_close.call(()),
+                        "Cancel"
+                    }
+                    button {
+                        style: "padding: 0.5rem 1rem; background: #48bb78; color: white; border: none; border-radius: 0.25rem; cursor: pointer; font-size: 1rem; font-weight: 500;",
+                        onclick: handle_receive,
+                        "Receive"
+                    }
+                }
+            }
+        }
+    }
+}
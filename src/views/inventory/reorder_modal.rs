@@ -0,0 +1,345 @@
+//! Reorder Modal Component
+//!
+//! Reorder suggestions from recent sales, supplier lead time and safety
+//! stock, turned into draft purchase orders (one per supplier).
+
+use crate::handlers::AppState;
+use crate::models::{PurchaseOrder, ReorderSuggestion};
+use crate::utils::formatting::{format_currency, format_date};
+use crate::views::app::use_store_clock;
+use dioxus::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+#[component]
+pub fn ReorderModal(on_close: EventHandler<()>) -> Element {
+    let app_state = use_context::<AppState>();
+    let clock = use_store_clock();
+
+    let mut suggestions = use_signal(|| None::<Vec<ReorderSuggestion>>);
+    let mut selected = use_signal(HashSet::<String>::new);
+    let mut expanded_order = use_signal(|| None::<String>);
+    let mut error_msg = use_signal(String::new);
+    let mut message = use_signal(String::new);
+
+    let suppliers_resource = use_resource({
+        let handler = app_state.inventory_handler.clone();
+        move || {
+            let handler = handler.clone();
+            async move { handler.get_suppliers().await }
+        }
+    });
+
+    let mut orders_resource = use_resource({
+        let handler = app_state.purchasing_handler.clone();
+        move || {
+            let handler = handler.clone();
+            async move { handler.load_purchase_orders().await }
+        }
+    });
+
+    let lines_resource = use_resource({
+        let handler = app_state.purchasing_handler.clone();
+        move || {
+            let handler = handler.clone();
+            let order_id = expanded_order();
+            async move {
+                match order_id {
+                    Some(id) => handler.get_purchase_order_lines(id).await.map(Some),
+                    None => Ok(None),
+                }
+            }
+        }
+    });
+
+    // Compute the suggestions with the configured policy
+    let settings_handler = app_state.settings_handler.clone();
+    let suggestions_handler = app_state.purchasing_handler.clone();
+    use_hook(move || {
+        spawn(async move {
+            let result = match settings_handler.get_reorder_policy().await {
+                Ok(policy) => suggestions_handler.get_reorder_suggestions(policy).await,
+                Err(err) => Err(err),
+            };
+
+            match result {
+                Ok(loaded) => {
+                    selected.set(loaded.iter().map(|s| s.product_id.clone()).collect());
+                    suggestions.set(Some(loaded));
+                }
+                Err(err) => error_msg.set(err),
+            }
+        });
+    });
+
+    let create_handler = app_state.purchasing_handler.clone();
+    let handle_create = move |_| {
+        let handler = create_handler.clone();
+        let chosen: Vec<ReorderSuggestion> = suggestions()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|s| selected().contains(&s.product_id))
+            .collect();
+
+        spawn(async move {
+            match handler.create_purchase_orders(chosen).await {
+                Ok(orders) => {
+                    error_msg.set(String::new());
+                    message.set(format!("{} draft purchase orders created", orders.len()));
+                    orders_resource.restart();
+                }
+                Err(err) => error_msg.set(err),
+            }
+        });
+    };
+
+    let supplier_names: HashMap<String, String> = match &*suppliers_resource.read_unchecked() {
+        Some(Ok(suppliers)) => suppliers
+            .iter()
+            .map(|s| (s.id.clone(), s.name.clone()))
+            .collect(),
+        _ => HashMap::new(),
+    };
+    let selected_count = selected().len();
+    let delete_handler = app_state.purchasing_handler.clone();
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.5); display: flex; justify-content: center; align-items: center; z-index: 1000;",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background: white; padding: 2rem; border-radius: 0.5rem; width: 1000px; max-width: 95%; max-height: 90vh; overflow-y: auto;",
+                onclick: move |evt| evt.stop_propagation(),
+
+                // Header
+                div {
+                    style: "display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;",
+                    h3 { style: "margin: 0; font-size: 1.25rem;", "🛒 Reorder Suggestions" }
+                    button {
+                        style: SECONDARY_BUTTON_STYLE,
+                        onclick: move |_| on_close.call(()),
+                        "Close"
+                    }
+                }
+
+                if !error_msg().is_empty() {
+                    div {
+                        style: "background: #fff5f5; color: #c53030; padding: 0.75rem; border-radius: 0.25rem; margin-bottom: 1rem;",
+                        "{error_msg}"
+                    }
+                }
+                if !message().is_empty() {
+                    div {
+                        style: "background: #f0fff4; color: #276749; padding: 0.75rem; border-radius: 0.25rem; margin-bottom: 1rem;",
+                        "{message}"
+                    }
+                }
+
+                // Suggestions
+                match suggestions() {
+                    None => rsx! {
+                        div { style: "color: #718096;", "Calculating suggestions..." }
+                    },
+                    Some(lines) if lines.is_empty() => rsx! {
+                        div {
+                            style: "padding: 1rem; text-align: center; color: #718096;",
+                            "Nothing needs reordering."
+                        }
+                    },
+                    Some(lines) => rsx! {
+                        table {
+                            style: "width: 100%; border-collapse: collapse; margin-bottom: 1rem;",
+                            thead {
+                                tr {
+                                    style: "background: #f7fafc; border-bottom: 2px solid #e2e8f0;",
+                                    th { style: HEADER_STYLE, "" }
+                                    th { style: HEADER_STYLE, "Product" }
+                                    th { style: HEADER_STYLE, "Supplier" }
+                                    th { style: HEADER_STYLE, "Stock" }
+                                    th { style: HEADER_STYLE, "Sold / Day" }
+                                    th { style: HEADER_STYLE, "Reorder Point" }
+                                    th { style: HEADER_STYLE, "Order (Purchase Units)" }
+                                    th { style: HEADER_STYLE, "Units" }
+                                }
+                            }
+                            tbody {
+                                for (index, line) in lines.into_iter().enumerate() {
+                                    {
+                                        let toggle_id = line.product_id.clone();
+                                        let is_selected = selected().contains(&line.product_id);
+                                        let supplier_name = line
+                                            .supplier_id
+                                            .as_ref()
+                                            .and_then(|id| supplier_names.get(id).cloned())
+                                            .unwrap_or_else(|| "No supplier".to_string());
+
+                                        rsx! {
+                                            tr {
+                                                key: "{line.product_id}",
+                                                style: "border-bottom: 1px solid #e2e8f0; font-size: 0.875rem;",
+                                                td {
+                                                    style: "padding: 0.5rem;",
+                                                    input {
+                                                        r#type: "checkbox",
+                                                        checked: is_selected,
+                                                        onchange: move |_| {
+                                                            let mut ids = selected();
+                                                            if !ids.remove(&toggle_id) {
+                                                                ids.insert(toggle_id.clone());
+                                                            }
+                                                            selected.set(ids);
+                                                        }
+                                                    }
+                                                }
+                                                td { style: "padding: 0.5rem; font-weight: 500;", "{line.product_name}" }
+                                                td { style: "padding: 0.5rem; color: #718096;", "{supplier_name}" }
+                                                td { style: "padding: 0.5rem;", {format!("{:.2}", line.current_stock)} }
+                                                td { style: "padding: 0.5rem;", {format!("{:.2}", line.avg_daily_sales)} }
+                                                td { style: "padding: 0.5rem;", {format!("{:.2}", line.reorder_point)} }
+                                                td {
+                                                    style: "padding: 0.5rem;",
+                                                    input {
+                                                        r#type: "number",
+                                                        min: "1",
+                                                        step: "1",
+                                                        style: "width: 80px; padding: 0.375rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 0.875rem;",
+                                                        value: "{line.purchase_quantity}",
+                                                        oninput: move |e| {
+                                                            let quantity = e.value().parse::<f64>().unwrap_or(0.0);
+                                                            if let Some(mut current) = suggestions() {
+                                                                if let Some(item) = current.get_mut(index) {
+                                                                    item.set_purchase_quantity(quantity);
+                                                                }
+                                                                suggestions.set(Some(current));
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                                td { style: "padding: 0.5rem; color: #718096;", {format!("{:.2}", line.suggested_quantity)} }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+
+                        div {
+                            style: "display: flex; justify-content: flex-end; margin-bottom: 1.5rem;",
+                            button {
+                                style: PRIMARY_BUTTON_STYLE,
+                                disabled: selected_count == 0,
+                                onclick: handle_create,
+                                "Create Draft Orders ({selected_count} Products)"
+                            }
+                        }
+                    },
+                }
+
+                // Draft purchase orders
+                h4 { style: "margin: 0 0 0.75rem 0; font-size: 1rem; color: #4a5568;", "Draft Purchase Orders" }
+                match &*orders_resource.read_unchecked() {
+                    Some(Ok(orders)) if orders.is_empty() => rsx! {
+                        div {
+                            style: "padding: 1rem; text-align: center; color: #718096;",
+                            "No purchase orders yet."
+                        }
+                    },
+                    Some(Ok(orders)) => rsx! {
+                        for order in orders.iter().cloned() {
+                            {
+                                let toggle_id = order.id.clone();
+                                let delete_id = order.id.clone();
+                                let handler = delete_handler.clone();
+                                let is_expanded = expanded_order().as_deref() == Some(order.id.as_str());
+
+                                rsx! {
+                                    div {
+                                        key: "{order.id}",
+                                        style: "border-bottom: 1px solid #e2e8f0; padding: 0.5rem 0;",
+                                        div {
+                                            style: "display: flex; justify-content: space-between; align-items: center; font-size: 0.875rem;",
+                                            div {
+                                                span { style: "font-weight: 500;", {order_title(&order)} }
+                                                span { style: "margin-left: 0.75rem; color: #718096;", {format_date(&order.created_at, &clock)} }
+                                                span { style: "margin-left: 0.75rem; color: #718096;", "{order.line_count} products" }
+                                                span { style: "margin-left: 0.75rem;", {format_currency(order.total_cost)} }
+                                            }
+                                            div {
+                                                button {
+                                                    style: "background: none; border: none; color: #667eea; font-weight: 500; cursor: pointer; padding: 0.25rem 0.5rem; font-size: 0.875rem;",
+                                                    onclick: move |_| {
+                                                        if is_expanded {
+                                                            expanded_order.set(None);
+                                                        } else {
+                                                            expanded_order.set(Some(toggle_id.clone()));
+                                                        }
+                                                    },
+                                                    if is_expanded { "Hide" } else { "View" }
+                                                }
+                                                button {
+                                                    style: "background: none; border: none; color: #e53e3e; font-weight: 500; cursor: pointer; padding: 0.25rem 0.5rem; font-size: 0.875rem;",
+                                                    onclick: move |_| {
+                                                        let handler = handler.clone();
+                                                        let id = delete_id.clone();
+                                                        spawn(async move {
+                                                            match handler.delete_purchase_order(id).await {
+                                                                Ok(()) => {
+                                                                    error_msg.set(String::new());
+                                                                    orders_resource.restart();
+                                                                }
+                                                                Err(err) => error_msg.set(err),
+                                                            }
+                                                        });
+                                                    },
+                                                    "Delete"
+                                                }
+                                            }
+                                        }
+                                        if is_expanded {
+                                            if let Some(Ok(Some(lines))) = &*lines_resource.read_unchecked() {
+                                                table {
+                                                    style: "width: 100%; border-collapse: collapse; margin-top: 0.5rem; font-size: 0.875rem;",
+                                                    tbody {
+                                                        for line in lines.iter() {
+                                                            tr {
+                                                                key: "{line.product_id}",
+                                                                td { style: "padding: 0.25rem 0.5rem;", "{line.product_name}" }
+                                                                td { style: "padding: 0.25rem 0.5rem;", "{line.purchase_quantity} ({line.quantity} units)" }
+                                                                td {
+                                                                    style: "padding: 0.25rem 0.5rem; text-align: right;",
+                                                                    {line.total_cost().map(format_currency).unwrap_or_else(|| "-".to_string())}
+                                                                }
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    Some(Err(err)) => rsx! {
+                        div { style: "color: #e53e3e;", "Error loading purchase orders: {err}" }
+                    },
+                    None => rsx! {
+                        div { style: "color: #718096;", "Loading purchase orders..." }
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// Supplier name of an order
+fn order_title(order: &PurchaseOrder) -> String {
+    order
+        .supplier_name
+        .clone()
+        .unwrap_or_else(|| "No supplier".to_string())
+}
+
+const HEADER_STYLE: &str = "padding: 0.5rem; text-align: left; font-weight: 600; color: #4a5568; font-size: 0.75rem; text-transform: uppercase; letter-spacing: 0.05em;";
+const PRIMARY_BUTTON_STYLE: &str = "padding: 0.5rem 1rem; background: #667eea; color: white; border: none; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem; font-weight: 500;";
+const SECONDARY_BUTTON_STYLE: &str = "padding: 0.5rem 1rem; border: 1px solid #e2e8f0; background: white; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem;";
@@ -0,0 +1,360 @@
+//! Reprice Modal Component
+//!
+//! Bulk price updates: pick products by category, supplier or search, choose
+//! a percentage, fixed amount or target margin, preview old vs new prices and
+//! apply them all at once.
+
+use crate::api::RepriceLine;
+use crate::handlers::AppState;
+use crate::models::pricing::{format_price_endings, parse_price_endings};
+use crate::models::{CategoryPath, PriceRule, RepriceInput};
+use crate::utils::formatting::format_currency;
+use crate::views::inventory::CategorySelect;
+use dioxus::prelude::*;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+#[component]
+pub fn RepriceModal(
+    categories: Vec<CategoryPath>,
+    on_close: EventHandler<()>,
+    on_applied: EventHandler<()>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+
+    let mut category_id = use_signal(|| None::<String>);
+    let mut supplier_id = use_signal(|| None::<String>);
+    let mut query = use_signal(String::new);
+    let mut rule_kind = use_signal(|| "percentage".to_string());
+    let mut rule_value = use_signal(String::new);
+    let mut endings = use_signal(String::new);
+    let mut preview = use_signal(|| None::<Vec<RepriceLine>>);
+    let mut error_msg = use_signal(String::new);
+    let mut message = use_signal(String::new);
+
+    let suppliers_resource = use_resource({
+        let handler = app_state.inventory_handler.clone();
+        move || {
+            let handler = handler.clone();
+            async move { handler.get_suppliers().await }
+        }
+    });
+
+    // Prefill the price endings from settings
+    let settings_handler = app_state.settings_handler.clone();
+    use_hook(move || {
+        spawn(async move {
+            if let Ok(configured) = settings_handler.get_price_endings().await {
+                endings.set(format_price_endings(&configured));
+            }
+        });
+    });
+
+    // Any change to the selection or rule invalidates the preview
+    use_effect(move || {
+        let _ = (
+            category_id(),
+            supplier_id(),
+            query(),
+            rule_kind(),
+            rule_value(),
+            endings(),
+        );
+        preview.set(None);
+    });
+
+    let preview_handler = app_state.inventory_handler.clone();
+    let handle_preview = move |_| {
+        let handler = preview_handler.clone();
+        let input = build_reprice_input(
+            category_id(),
+            supplier_id(),
+            &query(),
+            &rule_kind(),
+            &rule_value(),
+            &endings(),
+        );
+
+        spawn(async move {
+            let result = match input {
+                Ok(input) => handler.preview_reprice(input).await,
+                Err(err) => Err(err),
+            };
+
+            match result {
+                Ok(lines) => {
+                    error_msg.set(String::new());
+                    message.set(String::new());
+                    preview.set(Some(lines));
+                }
+                Err(err) => error_msg.set(err),
+            }
+        });
+    };
+
+    let apply_handler = app_state.inventory_handler.clone();
+    let handle_apply = move |_| {
+        let handler = apply_handler.clone();
+        let input = build_reprice_input(
+            category_id(),
+            supplier_id(),
+            &query(),
+            &rule_kind(),
+            &rule_value(),
+            &endings(),
+        );
+
+        spawn(async move {
+            let result = match input {
+                Ok(input) => handler.apply_reprice(input).await,
+                Err(err) => Err(err),
+            };
+
+            match result {
+                Ok(changed) => {
+                    preview.set(None);
+                    error_msg.set(String::new());
+                    message.set(format!("{} prices updated", changed));
+                    on_applied.call(());
+                }
+                Err(err) => error_msg.set(err),
+            }
+        });
+    };
+
+    let preview_lines = preview();
+    let changes = preview_lines
+        .as_ref()
+        .map(|lines| lines.iter().filter(|line| line.is_change()).count());
+    let value_label = match rule_kind().as_str() {
+        "fixed" => "Amount ($)",
+        "margin" => "Margin over cost (%)",
+        _ => "Change (%)",
+    };
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.5); display: flex; justify-content: center; align-items: center; z-index: 1000;",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background: white; padding: 2rem; border-radius: 0.5rem; width: 900px; max-width: 95%; max-height: 90vh; overflow-y: auto;",
+                onclick: move |evt| evt.stop_propagation(),
+
+                // Header
+                div {
+                    style: "display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;",
+                    h3 { style: "margin: 0; font-size: 1.25rem;", "🏷️ Bulk Price Update" }
+                    button {
+                        style: SECONDARY_BUTTON_STYLE,
+                        onclick: move |_| on_close.call(()),
+                        "Close"
+                    }
+                }
+
+                if !error_msg().is_empty() {
+                    div {
+                        style: "background: #fff5f5; color: #c53030; padding: 0.75rem; border-radius: 0.25rem; margin-bottom: 1rem;",
+                        "{error_msg}"
+                    }
+                }
+                if !message().is_empty() {
+                    div {
+                        style: "background: #f0fff4; color: #276749; padding: 0.75rem; border-radius: 0.25rem; margin-bottom: 1rem;",
+                        "{message}"
+                    }
+                }
+
+                // Product selection
+                div {
+                    style: "display: grid; grid-template-columns: 1fr 1fr 1fr; gap: 0.75rem; margin-bottom: 1rem;",
+                    div {
+                        label { style: LABEL_STYLE, "Category" }
+                        CategorySelect {
+                            categories: categories.clone(),
+                            selected: category_id(),
+                            empty_label: "All categories",
+                            on_change: move |id| category_id.set(id),
+                        }
+                    }
+                    div {
+                        label { style: LABEL_STYLE, "Supplier" }
+                        select {
+                            style: INPUT_STYLE,
+                            onchange: move |evt| {
+                                let value = evt.value();
+                                supplier_id.set(if value.is_empty() { None } else { Some(value) });
+                            },
+                            option { value: "", selected: supplier_id().is_none(), "All suppliers" }
+                            if let Some(Ok(suppliers)) = suppliers_resource.read().as_ref() {
+                                for supplier in suppliers {
+                                    option {
+                                        value: "{supplier.id}",
+                                        selected: supplier_id().as_deref() == Some(supplier.id.as_str()),
+                                        "{supplier.name}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    div {
+                        label { style: LABEL_STYLE, "Search" }
+                        input {
+                            r#type: "text",
+                            placeholder: "Name or barcode",
+                            style: INPUT_STYLE,
+                            value: "{query}",
+                            oninput: move |e| query.set(e.value())
+                        }
+                    }
+                }
+
+                // Price rule
+                div {
+                    style: "display: grid; grid-template-columns: 1fr 1fr 1fr; gap: 0.75rem; margin-bottom: 1rem;",
+                    div {
+                        label { style: LABEL_STYLE, "Rule" }
+                        select {
+                            style: INPUT_STYLE,
+                            onchange: move |evt| rule_kind.set(evt.value()),
+                            option { value: "percentage", selected: rule_kind() == "percentage", "Percentage change" }
+                            option { value: "fixed", selected: rule_kind() == "fixed", "Fixed amount" }
+                            option { value: "margin", selected: rule_kind() == "margin", "Target margin over cost" }
+                        }
+                    }
+                    div {
+                        label { style: LABEL_STYLE, "{value_label}" }
+                        input {
+                            r#type: "number",
+                            step: "0.01",
+                            style: INPUT_STYLE,
+                            value: "{rule_value}",
+                            oninput: move |e| rule_value.set(e.value())
+                        }
+                    }
+                    div {
+                        label { style: LABEL_STYLE, "Price endings" }
+                        input {
+                            r#type: "text",
+                            placeholder: ".50, .90",
+                            style: INPUT_STYLE,
+                            value: "{endings}",
+                            oninput: move |e| endings.set(e.value())
+                        }
+                    }
+                }
+
+                div {
+                    style: "display: flex; justify-content: flex-end; gap: 0.5rem; margin-bottom: 1rem;",
+                    button {
+                        style: SECONDARY_BUTTON_STYLE,
+                        onclick: handle_preview,
+                        "Preview"
+                    }
+                    if let Some(changes) = changes {
+                        if changes > 0 {
+                            button {
+                                style: PRIMARY_BUTTON_STYLE,
+                                onclick: handle_apply,
+                                "Apply to {changes} Products"
+                            }
+                        }
+                    }
+                }
+
+                // Old vs new prices
+                if let Some(lines) = preview_lines {
+                    if lines.is_empty() {
+                        div {
+                            style: "padding: 1rem; text-align: center; color: #718096;",
+                            "No products match the selection."
+                        }
+                    } else {
+                        table {
+                            style: "width: 100%; border-collapse: collapse;",
+                            thead {
+                                tr {
+                                    style: "background: #f7fafc; border-bottom: 2px solid #e2e8f0;",
+                                    th { style: HEADER_STYLE, "Product" }
+                                    th { style: HEADER_STYLE, "Cost" }
+                                    th { style: HEADER_STYLE, "Current Price" }
+                                    th { style: HEADER_STYLE, "New Price" }
+                                }
+                            }
+                            tbody {
+                                for line in lines {
+                                    tr {
+                                        key: "{line.product_id}",
+                                        style: "border-bottom: 1px solid #e2e8f0; font-size: 0.875rem;",
+                                        td { style: "padding: 0.5rem;", "{line.product_name}" }
+                                        td {
+                                            style: "padding: 0.5rem; color: #718096;",
+                                            {line.cost_price.map(format_currency).unwrap_or_else(|| "-".to_string())}
+                                        }
+                                        td { style: "padding: 0.5rem;", {format_currency(line.old_price)} }
+                                        td {
+                                            style: "padding: 0.5rem;",
+                                            match line.new_price {
+                                                Some(new_price) if line.is_change() => rsx! {
+                                                    span { style: "font-weight: 600; color: {change_color(line.old_price, new_price)};", {format_currency(new_price)} }
+                                                },
+                                                Some(_) => rsx! {
+                                                    span { style: "color: #718096;", "No change" }
+                                                },
+                                                None => rsx! {
+                                                    span { style: "color: #c53030;", "No cost, skipped" }
+                                                },
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Build the reprice request from the form fields
+fn build_reprice_input(
+    category_id: Option<String>,
+    supplier_id: Option<String>,
+    query: &str,
+    rule_kind: &str,
+    rule_value: &str,
+    endings: &str,
+) -> Result<RepriceInput, String> {
+    let value =
+        Decimal::from_str(rule_value.trim()).map_err(|_| "Enter a valid number".to_string())?;
+
+    let rule = match rule_kind {
+        "fixed" => PriceRule::FixedAmount(value),
+        "margin" => PriceRule::TargetMargin(value),
+        _ => PriceRule::Percentage(value),
+    };
+
+    Ok(RepriceInput {
+        category_id,
+        supplier_id,
+        query: query.trim().to_string(),
+        rule,
+        endings: parse_price_endings(endings)?,
+    })
+}
+
+/// Green for increases, red for decreases
+fn change_color(old_price: Decimal, new_price: Decimal) -> &'static str {
+    if new_price > old_price {
+        "#38a169"
+    } else {
+        "#c53030"
+    }
+}
+
+const LABEL_STYLE: &str = "display: block; margin-bottom: 0.25rem; font-weight: 500; font-size: 0.875rem; color: #4a5568;";
+const INPUT_STYLE: &str = "width: 100%; padding: 0.5rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 0.875rem; box-sizing: border-box; background: white;";
+const HEADER_STYLE: &str = "padding: 0.5rem; text-align: left; font-weight: 600; color: #4a5568; font-size: 0.75rem; text-transform: uppercase; letter-spacing: 0.05em;";
+const PRIMARY_BUTTON_STYLE: &str = "padding: 0.5rem 1rem; background: #667eea; color: white; border: none; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem; font-weight: 500;";
+const SECONDARY_BUTTON_STYLE: &str = "padding: 0.5rem 1rem; border: 1px solid #e2e8f0; background: white; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem;";
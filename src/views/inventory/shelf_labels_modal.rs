@@ -0,0 +1,330 @@
+//! Shelf Labels Modal Component
+//!
+//! Pick products and a label layout and save a PDF sheet of shelf labels
+//! (name, price, unit and barcode). Products without a barcode can get an
+//! internal EAN-13 code first.
+
+use crate::handlers::AppState;
+use crate::models::CategoryPath;
+use crate::utils::formatting::format_currency;
+use crate::views::inventory::label_template::{generate_labels_pdf, LabelLayout, ShelfLabel};
+use crate::views::inventory::CategorySelect;
+use dioxus::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+#[component]
+pub fn ShelfLabelsModal(
+    categories: Vec<CategoryPath>,
+    on_close: EventHandler<()>,
+    on_change: EventHandler<()>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+
+    let mut category_id = use_signal(|| None::<String>);
+    let mut query = use_signal(String::new);
+    let mut selected = use_signal(HashSet::<String>::new);
+    let mut copies = use_signal(|| "1".to_string());
+    let mut layout = use_signal(|| LabelLayout::Small);
+    let mut error_msg = use_signal(String::new);
+    let mut message = use_signal(String::new);
+
+    let mut products_resource = use_resource({
+        let handler = app_state.inventory_handler.clone();
+        move || {
+            let handler = handler.clone();
+            let category_id = category_id();
+            async move {
+                match category_id {
+                    Some(id) => handler.load_products_by_category(Some(id)).await,
+                    None => handler.load_products().await,
+                }
+            }
+        }
+    });
+
+    let units_resource = use_resource({
+        let handler = app_state.inventory_handler.clone();
+        move || {
+            let handler = handler.clone();
+            async move { handler.get_units().await }
+        }
+    });
+
+    let generate_handler = app_state.inventory_handler.clone();
+    let handle_generate = move |_| {
+        let handler = generate_handler.clone();
+        spawn(async move {
+            match handler.generate_missing_barcodes().await {
+                Ok(count) => {
+                    error_msg.set(String::new());
+                    message.set(format!("{} barcodes generated", count));
+                    products_resource.restart();
+                    on_change.call(());
+                }
+                Err(err) => error_msg.set(err),
+            }
+        });
+    };
+
+    let products = match &*products_resource.read_unchecked() {
+        Some(Ok(products)) => products.clone(),
+        _ => Vec::new(),
+    };
+    let search = query().trim().to_lowercase();
+    let visible: Vec<_> = products
+        .iter()
+        .filter(|p| {
+            search.is_empty()
+                || p.full_name.to_lowercase().contains(&search)
+                || p.barcode.as_deref().is_some_and(|b| b.contains(&search))
+        })
+        .cloned()
+        .collect();
+    let visible_ids: Vec<String> = visible.iter().map(|p| p.id.clone()).collect();
+    let missing_barcodes = products.iter().filter(|p| p.barcode.is_none()).count();
+    let selected_count = selected().len();
+
+    let print_products = products.clone();
+    let handle_print = move |_| {
+        let copies = match copies().trim().parse::<usize>() {
+            Ok(copies) if copies > 0 => copies,
+            _ => {
+                error_msg.set("Copies must be a whole number greater than zero".to_string());
+                return;
+            }
+        };
+
+        let units: HashMap<i32, String> = match &*units_resource.read_unchecked() {
+            Some(Ok(units)) => units
+                .iter()
+                .map(|u| (u.id, u.abbreviation.clone()))
+                .collect(),
+            _ => HashMap::new(),
+        };
+        let chosen = selected();
+        let labels: Vec<ShelfLabel> = print_products
+            .iter()
+            .filter(|p| chosen.contains(&p.id))
+            .flat_map(|p| {
+                let label = ShelfLabel {
+                    name: p.full_name.clone(),
+                    price: p.user_price,
+                    unit: units
+                        .get(&p.unit_measurement_id)
+                        .cloned()
+                        .unwrap_or_default(),
+                    barcode: p.barcode.clone(),
+                };
+                std::iter::repeat_n(label, copies)
+            })
+            .collect();
+
+        if labels.is_empty() {
+            error_msg.set("Select at least one product".to_string());
+            return;
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            // Show file save dialog
+            if let Some(file_path) = rfd::FileDialog::new()
+                .set_file_name("shelf_labels.pdf")
+                .add_filter("PDF", &["pdf"])
+                .save_file()
+            {
+                match generate_labels_pdf(&labels, layout(), file_path) {
+                    Ok(()) => {
+                        error_msg.set(String::new());
+                        message.set(format!("{} labels saved", labels.len()));
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to generate labels: {}", e);
+                        error_msg.set(format!("Failed to generate labels: {}", e));
+                    }
+                }
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.5); display: flex; justify-content: center; align-items: center; z-index: 1000;",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background: white; padding: 2rem; border-radius: 0.5rem; width: 800px; max-width: 95%; max-height: 90vh; overflow-y: auto;",
+                onclick: move |evt| evt.stop_propagation(),
+
+                // Header
+                div {
+                    style: "display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;",
+                    h3 { style: "margin: 0; font-size: 1.25rem;", "🔖 Shelf Labels" }
+                    button {
+                        style: SECONDARY_BUTTON_STYLE,
+                        onclick: move |_| on_close.call(()),
+                        "Close"
+                    }
+                }
+
+                if !error_msg().is_empty() {
+                    div {
+                        style: "background: #fff5f5; color: #c53030; padding: 0.75rem; border-radius: 0.25rem; margin-bottom: 1rem;",
+                        "{error_msg}"
+                    }
+                }
+                if !message().is_empty() {
+                    div {
+                        style: "background: #f0fff4; color: #276749; padding: 0.75rem; border-radius: 0.25rem; margin-bottom: 1rem;",
+                        "{message}"
+                    }
+                }
+
+                // Products without barcode
+                if missing_barcodes > 0 {
+                    div {
+                        style: "display: flex; justify-content: space-between; align-items: center; background: #fffaf0; padding: 0.75rem; border-radius: 0.25rem; margin-bottom: 1rem; font-size: 0.875rem;",
+                        span { "{missing_barcodes} products have no barcode." }
+                        button {
+                            style: SECONDARY_BUTTON_STYLE,
+                            onclick: handle_generate,
+                            "Generate EAN-13 Codes"
+                        }
+                    }
+                }
+
+                // Product selection and layout
+                div {
+                    style: "display: grid; grid-template-columns: 1fr 1fr 1fr 90px; gap: 0.75rem; margin-bottom: 1rem;",
+                    div {
+                        label { style: LABEL_STYLE, "Category" }
+                        CategorySelect {
+                            categories: categories.clone(),
+                            selected: category_id(),
+                            empty_label: "All categories",
+                            on_change: move |id| category_id.set(id),
+                        }
+                    }
+                    div {
+                        label { style: LABEL_STYLE, "Search" }
+                        input {
+                            r#type: "text",
+                            placeholder: "Name or barcode",
+                            style: INPUT_STYLE,
+                            value: "{query}",
+                            oninput: move |e| query.set(e.value())
+                        }
+                    }
+                    div {
+                        label { style: LABEL_STYLE, "Layout" }
+                        select {
+                            style: INPUT_STYLE,
+                            onchange: move |evt| {
+                                if let Some(choice) = evt.value().parse::<usize>().ok().and_then(|i| LabelLayout::ALL.get(i)) {
+                                    layout.set(*choice);
+                                }
+                            },
+                            for (index, option_layout) in LabelLayout::ALL.iter().enumerate() {
+                                option {
+                                    value: "{index}",
+                                    selected: *option_layout == layout(),
+                                    {option_layout.label()}
+                                }
+                            }
+                        }
+                    }
+                    div {
+                        label { style: LABEL_STYLE, "Copies" }
+                        input {
+                            r#type: "number",
+                            min: "1",
+                            style: INPUT_STYLE,
+                            value: "{copies}",
+                            oninput: move |e| copies.set(e.value())
+                        }
+                    }
+                }
+
+                div {
+                    style: "display: flex; justify-content: space-between; align-items: center; margin-bottom: 0.5rem;",
+                    div {
+                        button {
+                            style: "background: none; border: none; color: #667eea; font-weight: 500; cursor: pointer; padding: 0.25rem 0.5rem 0.25rem 0; font-size: 0.875rem;",
+                            onclick: move |_| {
+                                let mut current = selected();
+                                current.extend(visible_ids.iter().cloned());
+                                selected.set(current);
+                            },
+                            "Select All"
+                        }
+                        button {
+                            style: "background: none; border: none; color: #667eea; font-weight: 500; cursor: pointer; padding: 0.25rem 0.5rem; font-size: 0.875rem;",
+                            onclick: move |_| selected.set(HashSet::new()),
+                            "Clear"
+                        }
+                    }
+                    button {
+                        style: PRIMARY_BUTTON_STYLE,
+                        disabled: selected_count == 0,
+                        onclick: handle_print,
+                        "Save PDF ({selected_count} Products)"
+                    }
+                }
+
+                // Product list
+                table {
+                    style: "width: 100%; border-collapse: collapse;",
+                    thead {
+                        tr {
+                            style: "background: #f7fafc; border-bottom: 2px solid #e2e8f0;",
+                            th { style: HEADER_STYLE, "" }
+                            th { style: HEADER_STYLE, "Product" }
+                            th { style: HEADER_STYLE, "Barcode" }
+                            th { style: HEADER_STYLE, "Price" }
+                        }
+                    }
+                    tbody {
+                        for product in visible {
+                            {
+                                let toggle_id = product.id.clone();
+                                let is_selected = selected().contains(&product.id);
+
+                                rsx! {
+                                    tr {
+                                        key: "{product.id}",
+                                        style: "border-bottom: 1px solid #e2e8f0; font-size: 0.875rem;",
+                                        td {
+                                            style: "padding: 0.5rem;",
+                                            input {
+                                                r#type: "checkbox",
+                                                checked: is_selected,
+                                                onchange: move |_| {
+                                                    let mut ids = selected();
+                                                    if !ids.remove(&toggle_id) {
+                                                        ids.insert(toggle_id.clone());
+                                                    }
+                                                    selected.set(ids);
+                                                }
+                                            }
+                                        }
+                                        td { style: "padding: 0.5rem; font-weight: 500;", "{product.full_name}" }
+                                        td {
+                                            style: "padding: 0.5rem; color: #718096; font-family: monospace;",
+                                            {product.barcode.clone().unwrap_or_else(|| "-".to_string())}
+                                        }
+                                        td { style: "padding: 0.5rem;", {format_currency(product.user_price)} }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+const LABEL_STYLE: &str = "display: block; margin-bottom: 0.25rem; font-weight: 500; font-size: 0.875rem; color: #4a5568;";
+const INPUT_STYLE: &str = "width: 100%; padding: 0.5rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 0.875rem; box-sizing: border-box; background: white;";
+const HEADER_STYLE: &str = "padding: 0.5rem; text-align: left; font-weight: 600; color: #4a5568; font-size: 0.75rem; text-transform: uppercase; letter-spacing: 0.05em;";
+const PRIMARY_BUTTON_STYLE: &str = "padding: 0.5rem 1rem; background: #667eea; color: white; border: none; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem; font-weight: 500;";
+const SECONDARY_BUTTON_STYLE: &str = "padding: 0.5rem 1rem; border: 1px solid #e2e8f0; background: white; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem;";
@@ -0,0 +1,186 @@
+//! Shrinkage Report Modal Component
+//!
+//! Stock adjustments for a period, totaled by reason and valued at cost.
+
+use crate::handlers::AppState;
+use crate::utils::formatting::{format_currency, format_datetime};
+use crate::views::app::use_store_clock;
+use chrono::{Duration, NaiveDate};
+use dioxus::prelude::*;
+
+/// Default report period in days
+const DEFAULT_PERIOD_DAYS: i64 = 30;
+
+#[component]
+pub fn ShrinkageReportModal(on_close: EventHandler<()>) -> Element {
+    let app_state = use_context::<AppState>();
+
+    let clock = use_store_clock();
+    let today = clock.today();
+    let mut start_date = use_signal(|| (today - Duration::days(DEFAULT_PERIOD_DAYS)).to_string());
+    let mut end_date = use_signal(|| today.to_string());
+
+    let report_resource = use_resource({
+        let handler = app_state.inventory_handler.clone();
+        move || {
+            let handler = handler.clone();
+            let start = NaiveDate::parse_from_str(&start_date(), "%Y-%m-%d");
+            let end = NaiveDate::parse_from_str(&end_date(), "%Y-%m-%d");
+
+            async move {
+                match (start, end) {
+                    (Ok(start), Ok(end)) => handler.get_shrinkage_report(start, end).await,
+                    _ => Err("Select a valid period".to_string()),
+                }
+            }
+        }
+    });
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.5); display: flex; justify-content: center; align-items: center; z-index: 1000;",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background: white; padding: 2rem; border-radius: 0.5rem; width: 800px; max-width: 95%; max-height: 90vh; overflow-y: auto;",
+                onclick: move |evt| evt.stop_propagation(),
+
+                // Header
+                div {
+                    style: "display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;",
+                    h3 { style: "margin: 0; font-size: 1.25rem;", "📉 Shrinkage Report" }
+                    div {
+                        style: "display: flex; gap: 0.5rem; align-items: center;",
+                        input {
+                            r#type: "date",
+                            style: DATE_STYLE,
+                            value: "{start_date}",
+                            oninput: move |e| start_date.set(e.value())
+                        }
+                        span { style: "color: #718096;", "to" }
+                        input {
+                            r#type: "date",
+                            style: DATE_STYLE,
+                            value: "{end_date}",
+                            oninput: move |e| end_date.set(e.value())
+                        }
+                        button {
+                            style: "padding: 0.5rem 1rem; border: 1px solid #e2e8f0; background: white; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem;",
+                            onclick: move |_| on_close.call(()),
+                            "Close"
+                        }
+                    }
+                }
+
+                match &*report_resource.read_unchecked() {
+                    Some(Ok(report)) if report.adjustments.is_empty() => rsx! {
+                        div {
+                            style: "padding: 2rem; text-align: center; color: #718096;",
+                            "No stock adjustments in this period."
+                        }
+                    },
+                    Some(Ok(report)) => rsx! {
+                        // Totals by reason
+                        table {
+                            style: "width: 100%; border-collapse: collapse; margin-bottom: 1.5rem;",
+                            thead {
+                                tr {
+                                    style: "background: #f7fafc; border-bottom: 2px solid #e2e8f0;",
+                                    th { style: HEADER_STYLE, "Reason" }
+                                    th { style: "{HEADER_STYLE} text-align: center;", "Adjustments" }
+                                    th { style: "{HEADER_STYLE} text-align: center;", "Quantity" }
+                                    th { style: "{HEADER_STYLE} text-align: right;", "Value at Cost" }
+                                }
+                            }
+                            tbody {
+                                for summary in report.by_reason.iter() {
+                                    tr {
+                                        style: "border-bottom: 1px solid #e2e8f0;",
+                                        td { style: CELL_STYLE, "{summary.reason}" }
+                                        td { style: "{CELL_STYLE} text-align: center;", "{summary.adjustment_count}" }
+                                        td { style: "{CELL_STYLE} text-align: center;", "{summary.total_quantity:.2}" }
+                                        td { style: "{CELL_STYLE} text-align: right; {value_style(summary.total_cost)}", "{format_currency(summary.total_cost)}" }
+                                    }
+                                }
+                                tr {
+                                    style: "font-weight: 600;",
+                                    td { style: CELL_STYLE, "Total" }
+                                    td { style: CELL_STYLE }
+                                    td { style: CELL_STYLE }
+                                    td { style: "{CELL_STYLE} text-align: right; {value_style(report.total_cost)}", "{format_currency(report.total_cost)}" }
+                                }
+                            }
+                        }
+
+                        // Individual adjustments
+                        h4 { style: "margin: 0 0 0.5rem 0; color: #4a5568;", "Adjustments" }
+                        table {
+                            style: "width: 100%; border-collapse: collapse;",
+                            thead {
+                                tr {
+                                    style: "background: #f7fafc; border-bottom: 2px solid #e2e8f0;",
+                                    th { style: HEADER_STYLE, "Date" }
+                                    th { style: HEADER_STYLE, "Product" }
+                                    th { style: HEADER_STYLE, "Reason" }
+                                    th { style: "{HEADER_STYLE} text-align: center;", "Quantity" }
+                                    th { style: "{HEADER_STYLE} text-align: right;", "Value" }
+                                }
+                            }
+                            tbody {
+                                for adjustment in report.adjustments.iter() {
+                                    tr {
+                                        style: "border-bottom: 1px solid #e2e8f0;",
+                                        td { style: CELL_STYLE, "{format_datetime(&adjustment.adjusted_at, &clock)}" }
+                                        td { style: CELL_STYLE, "{adjustment.product_name}" }
+                                        td {
+                                            style: CELL_STYLE,
+                                            {
+                                                report
+                                                    .by_reason
+                                                    .iter()
+                                                    .find(|s| s.reason_id == adjustment.reason_id)
+                                                    .map(|s| s.reason.clone())
+                                                    .unwrap_or_default()
+                                            }
+                                            if let Some(notes) = &adjustment.notes {
+                                                div { style: "color: #718096; font-size: 0.75rem;", "{notes}" }
+                                            }
+                                        }
+                                        td { style: "{CELL_STYLE} text-align: center;", "{adjustment.quantity:.2}" }
+                                        td { style: "{CELL_STYLE} text-align: right; {value_style(adjustment.total_cost)}", "{format_currency(adjustment.total_cost)}" }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    Some(Err(err)) => rsx! {
+                        div {
+                            style: "padding: 2rem; text-align: center; color: #e53e3e; background: #fff5f5; border-radius: 0.5rem;",
+                            "❌ {err}"
+                        }
+                    },
+                    None => rsx! {
+                        div {
+                            style: "padding: 2rem; text-align: center; color: #718096;",
+                            "⏳ Loading report..."
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// Show losses in red
+fn value_style(value: rust_decimal::Decimal) -> &'static str {
+    if value < rust_decimal::Decimal::ZERO {
+        "color: #c53030;"
+    } else {
+        "color: #2d3748;"
+    }
+}
+
+const HEADER_STYLE: &str = "padding: 0.5rem; text-align: left; font-weight: 600; color: #4a5568; font-size: 0.75rem; text-transform: uppercase; letter-spacing: 0.05em;";
+const CELL_STYLE: &str = "padding: 0.5rem; font-size: 0.875rem;";
+const DATE_STYLE: &str =
+    "padding: 0.5rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 0.875rem;";
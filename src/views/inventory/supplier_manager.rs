@@ -0,0 +1,206 @@
+//! Supplier Manager Component
+//!
+//! Modal for creating, editing and deleting suppliers.
+
+use crate::handlers::AppState;
+use crate::models::SupplierInput;
+use dioxus::prelude::*;
+
+#[component]
+pub fn SupplierManager(on_close: EventHandler<()>, on_change: EventHandler<()>) -> Element {
+    let app_state = use_context::<AppState>();
+
+    let mut name = use_signal(String::new);
+    let mut phone = use_signal(String::new);
+    let mut lead_time = use_signal(String::new);
+    let mut editing_id = use_signal(|| None::<String>);
+    let mut error_msg = use_signal(String::new);
+
+    let mut suppliers_resource = use_resource({
+        let handler = app_state.inventory_handler.clone();
+        move || {
+            let handler = handler.clone();
+            async move { handler.get_suppliers().await }
+        }
+    });
+
+    let save_handler = app_state.inventory_handler.clone();
+    let delete_handler = app_state.inventory_handler.clone();
+
+    let handle_save = move |_| {
+        let handler = save_handler.clone();
+        let lead_time_days = match lead_time().trim() {
+            "" => 0,
+            value => match value.parse::<i64>() {
+                Ok(days) => days,
+                Err(_) => {
+                    error_msg.set("Lead time must be a whole number of days".to_string());
+                    return;
+                }
+            },
+        };
+        let input = SupplierInput {
+            name: name(),
+            phone: Some(phone()),
+            lead_time_days,
+        };
+        let edit_id = editing_id();
+
+        spawn(async move {
+            let result = match edit_id {
+                Some(id) => handler.update_supplier(id, input).await.map(|_| ()),
+                None => handler.create_supplier(input).await.map(|_| ()),
+            };
+
+            match result {
+                Ok(()) => {
+                    name.set(String::new());
+                    phone.set(String::new());
+                    lead_time.set(String::new());
+                    editing_id.set(None);
+                    error_msg.set(String::new());
+                    suppliers_resource.restart();
+                    on_change.call(());
+                }
+                Err(err) => error_msg.set(err),
+            }
+        });
+    };
+
+    let save_label = if editing_id().is_some() {
+        "Update Supplier"
+    } else {
+        "Add Supplier"
+    };
+
+    let suppliers = match &*suppliers_resource.read_unchecked() {
+        Some(Ok(suppliers)) => suppliers.clone(),
+        _ => Vec::new(),
+    };
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.5); display: flex; justify-content: center; align-items: center; z-index: 1000;",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background: white; padding: 2rem; border-radius: 0.5rem; width: 600px; max-width: 90%; max-height: 90vh; overflow-y: auto;",
+                onclick: move |evt| evt.stop_propagation(),
+
+                // Header
+                div {
+                    style: "display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;",
+                    h3 { style: "margin: 0; font-size: 1.25rem;", "Suppliers" }
+                    button {
+                        style: "padding: 0.5rem 1rem; border: 1px solid #e2e8f0; background: white; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem;",
+                        onclick: move |_| on_close.call(()),
+                        "Close"
+                    }
+                }
+
+                // Error message
+                if !error_msg().is_empty() {
+                    div {
+                        style: "background: #fff5f5; color: #c53030; padding: 0.75rem; border-radius: 0.25rem; margin-bottom: 1rem;",
+                        "{error_msg}"
+                    }
+                }
+
+                // Create / edit form
+                div {
+                    style: "display: grid; grid-template-columns: 1fr 1fr 110px auto; gap: 0.5rem; margin-bottom: 1.5rem; align-items: center;",
+                    input {
+                        r#type: "text",
+                        placeholder: "Supplier name",
+                        style: "width: 100%; padding: 0.625rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 1rem; box-sizing: border-box;",
+                        value: "{name}",
+                        oninput: move |e| name.set(e.value())
+                    }
+                    input {
+                        r#type: "tel",
+                        placeholder: "Phone (optional)",
+                        style: "width: 100%; padding: 0.625rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 1rem; box-sizing: border-box;",
+                        value: "{phone}",
+                        oninput: move |e| phone.set(e.value())
+                    }
+                    input {
+                        r#type: "number",
+                        min: "0",
+                        placeholder: "Lead days",
+                        title: "Days from ordering to receiving",
+                        style: "width: 100%; padding: 0.625rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 1rem; box-sizing: border-box;",
+                        value: "{lead_time}",
+                        oninput: move |e| lead_time.set(e.value())
+                    }
+                    button {
+                        style: "padding: 0.625rem 1rem; background: #667eea; color: white; border: none; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem; font-weight: 500; white-space: nowrap;",
+                        onclick: handle_save,
+                        "{save_label}"
+                    }
+                }
+
+                // Supplier list
+                if suppliers.is_empty() {
+                    div {
+                        style: "padding: 1rem; text-align: center; color: #718096;",
+                        "No suppliers yet."
+                    }
+                } else {
+                    for supplier in suppliers {
+                        {
+                            let edit_supplier = supplier.clone();
+                            let delete_id = supplier.id.clone();
+                            let handler = delete_handler.clone();
+
+                            rsx! {
+                                div {
+                                    key: "{supplier.id}",
+                                    style: "display: flex; justify-content: space-between; align-items: center; padding: 0.5rem 0; border-bottom: 1px solid #e2e8f0;",
+                                    div {
+                                        span { style: "font-size: 1rem;", "{supplier.name}" }
+                                        if let Some(ref phone) = supplier.phone {
+                                            span { style: "margin-left: 0.75rem; color: #718096; font-size: 0.875rem;", "{phone}" }
+                                        }
+                                        if supplier.lead_time_days > 0 {
+                                            span { style: "margin-left: 0.75rem; color: #718096; font-size: 0.875rem;", "{supplier.lead_time_days} days lead time" }
+                                        }
+                                    }
+                                    div {
+                                        button {
+                                            style: "background: none; border: none; color: #667eea; font-weight: 500; cursor: pointer; padding: 0.25rem 0.5rem; font-size: 0.875rem;",
+                                            onclick: move |_| {
+                                                name.set(edit_supplier.name.clone());
+                                                phone.set(edit_supplier.phone.clone().unwrap_or_default());
+                                                lead_time.set(edit_supplier.lead_time_days.to_string());
+                                                editing_id.set(Some(edit_supplier.id.clone()));
+                                            },
+                                            "Edit"
+                                        }
+                                        button {
+                                            style: "background: none; border: none; color: #e53e3e; font-weight: 500; cursor: pointer; padding: 0.25rem 0.5rem; font-size: 0.875rem;",
+                                            onclick: move |_| {
+                                                let handler = handler.clone();
+                                                let id = delete_id.clone();
+                                                spawn(async move {
+                                                    match handler.delete_supplier(id).await {
+                                                        Ok(()) => {
+                                                            error_msg.set(String::new());
+                                                            suppliers_resource.restart();
+                                                            on_change.call(());
+                                                        }
+                                                        Err(err) => error_msg.set(err),
+                                                    }
+                                                });
+                                            },
+                                            "Delete"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,218 @@
+//! Unit Manager Component
+//!
+//! Modal for creating, editing and deleting units of measurement and their
+//! fraction rules.
+
+use crate::handlers::AppState;
+use crate::models::UnitMeasurementInput;
+use dioxus::prelude::*;
+
+#[component]
+pub fn UnitManager(on_close: EventHandler<()>, on_change: EventHandler<()>) -> Element {
+    let app_state = use_context::<AppState>();
+
+    let mut description = use_signal(String::new);
+    let mut abbreviation = use_signal(String::new);
+    let mut allows_fraction = use_signal(|| false);
+    let mut decimals = use_signal(|| 3);
+    let mut editing_id = use_signal(|| None::<i32>);
+    let mut error_msg = use_signal(String::new);
+
+    let mut units_resource = use_resource({
+        let handler = app_state.inventory_handler.clone();
+        move || {
+            let handler = handler.clone();
+            async move { handler.get_units().await }
+        }
+    });
+
+    let save_handler = app_state.inventory_handler.clone();
+    let delete_handler = app_state.inventory_handler.clone();
+
+    let mut reset_form = move || {
+        description.set(String::new());
+        abbreviation.set(String::new());
+        allows_fraction.set(false);
+        decimals.set(3);
+        editing_id.set(None);
+    };
+
+    let handle_save = move |_| {
+        let handler = save_handler.clone();
+        let input = UnitMeasurementInput {
+            description: description(),
+            abbreviation: abbreviation(),
+            allows_fraction: allows_fraction(),
+            decimals: decimals(),
+        };
+        let edit_id = editing_id();
+
+        spawn(async move {
+            let result = match edit_id {
+                Some(id) => handler.update_unit(id, input).await.map(|_| ()),
+                None => handler.create_unit(input).await.map(|_| ()),
+            };
+
+            match result {
+                Ok(()) => {
+                    reset_form();
+                    error_msg.set(String::new());
+                    units_resource.restart();
+                    on_change.call(());
+                }
+                Err(err) => error_msg.set(err),
+            }
+        });
+    };
+
+    let save_label = if editing_id().is_some() {
+        "Update Unit"
+    } else {
+        "Add Unit"
+    };
+
+    let units = match &*units_resource.read_unchecked() {
+        Some(Ok(units)) => units.clone(),
+        _ => Vec::new(),
+    };
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.5); display: flex; justify-content: center; align-items: center; z-index: 1000;",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background: white; padding: 2rem; border-radius: 0.5rem; width: 650px; max-width: 90%; max-height: 90vh; overflow-y: auto;",
+                onclick: move |evt| evt.stop_propagation(),
+
+                // Header
+                div {
+                    style: "display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;",
+                    h3 { style: "margin: 0; font-size: 1.25rem;", "Units of Measurement" }
+                    button {
+                        style: "padding: 0.5rem 1rem; border: 1px solid #e2e8f0; background: white; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem;",
+                        onclick: move |_| on_close.call(()),
+                        "Close"
+                    }
+                }
+
+                // Error message
+                if !error_msg().is_empty() {
+                    div {
+                        style: "background: #fff5f5; color: #c53030; padding: 0.75rem; border-radius: 0.25rem; margin-bottom: 1rem;",
+                        "{error_msg}"
+                    }
+                }
+
+                // Create / edit form
+                div {
+                    style: "display: grid; grid-template-columns: 1fr 90px auto 90px auto; gap: 0.5rem; margin-bottom: 1.5rem; align-items: center;",
+                    input {
+                        r#type: "text",
+                        placeholder: "Unit name",
+                        style: "width: 100%; padding: 0.625rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 1rem; box-sizing: border-box;",
+                        value: "{description}",
+                        oninput: move |e| description.set(e.value())
+                    }
+                    input {
+                        r#type: "text",
+                        placeholder: "Abbr.",
+                        style: "width: 100%; padding: 0.625rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 1rem; box-sizing: border-box;",
+                        value: "{abbreviation}",
+                        oninput: move |e| abbreviation.set(e.value())
+                    }
+                    label {
+                        style: "display: flex; align-items: center; gap: 0.25rem; font-size: 0.875rem; color: #4a5568; white-space: nowrap;",
+                        input {
+                            r#type: "checkbox",
+                            checked: allows_fraction(),
+                            onchange: move |e| allows_fraction.set(e.checked())
+                        }
+                        "Fractions"
+                    }
+                    select {
+                        style: "width: 100%; padding: 0.625rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 0.875rem; background: white;",
+                        disabled: !allows_fraction(),
+                        title: "Decimal places",
+                        onchange: move |e| decimals.set(e.value().parse().unwrap_or(3)),
+                        for places in 1..=3 {
+                            option {
+                                value: "{places}",
+                                selected: decimals() == places,
+                                "{places} dec."
+                            }
+                        }
+                    }
+                    button {
+                        style: "padding: 0.625rem 1rem; background: #667eea; color: white; border: none; border-radius: 0.25rem; cursor: pointer; font-size: 0.875rem; font-weight: 500; white-space: nowrap;",
+                        onclick: handle_save,
+                        "{save_label}"
+                    }
+                }
+
+                // Unit list
+                if units.is_empty() {
+                    div {
+                        style: "padding: 1rem; text-align: center; color: #718096;",
+                        "No units yet."
+                    }
+                } else {
+                    for unit in units {
+                        {
+                            let edit_unit = unit.clone();
+                            let delete_id = unit.id;
+                            let handler = delete_handler.clone();
+                            let rule = if unit.allows_fraction {
+                                format!("up to {} decimals", unit.decimals)
+                            } else {
+                                "whole numbers".to_string()
+                            };
+
+                            rsx! {
+                                div {
+                                    key: "{unit.id}",
+                                    style: "display: flex; justify-content: space-between; align-items: center; padding: 0.5rem 0; border-bottom: 1px solid #e2e8f0;",
+                                    div {
+                                        span { style: "font-size: 1rem;", "{unit.description}" }
+                                        span { style: "margin-left: 0.75rem; color: #718096; font-size: 0.875rem; font-family: monospace;", "{unit.abbreviation}" }
+                                        span { style: "margin-left: 0.75rem; color: #718096; font-size: 0.875rem;", "{rule}" }
+                                    }
+                                    div {
+                                        button {
+                                            style: "background: none; border: none; color: #667eea; font-weight: 500; cursor: pointer; padding: 0.25rem 0.5rem; font-size: 0.875rem;",
+                                            onclick: move |_| {
+                                                description.set(edit_unit.description.clone());
+                                                abbreviation.set(edit_unit.abbreviation.clone());
+                                                allows_fraction.set(edit_unit.allows_fraction);
+                                                decimals.set(if edit_unit.allows_fraction { edit_unit.decimals } else { 3 });
+                                                editing_id.set(Some(edit_unit.id));
+                                            },
+                                            "Edit"
+                                        }
+                                        button {
+                                            style: "background: none; border: none; color: #e53e3e; font-weight: 500; cursor: pointer; padding: 0.25rem 0.5rem; font-size: 0.875rem;",
+                                            onclick: move |_| {
+                                                let handler = handler.clone();
+                                                spawn(async move {
+                                                    match handler.delete_unit(delete_id).await {
+                                                        Ok(()) => {
+                                                            error_msg.set(String::new());
+                                                            units_resource.restart();
+                                                            on_change.call(());
+                                                        }
+                                                        Err(err) => error_msg.set(err),
+                                                    }
+                                                });
+                                            },
+                                            "Delete"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
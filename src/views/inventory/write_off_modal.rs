@@ -0,0 +1,160 @@
+//! Write-Off Modal Component
+//!
+//! Modal for removing stock with a reason (damaged, theft, internal use, ...),
+//! showing the loss valued at the product's cost before saving.
+
+use crate::handlers::AppState;
+use crate::models::{Product, WriteOffInput};
+use crate::utils::formatting::format_currency;
+use dioxus::prelude::*;
+use rust_decimal::Decimal;
+
+#[component]
+pub fn WriteOffModal(
+    product: Product,
+    on_close: EventHandler<()>,
+    on_saved: EventHandler<()>,
+) -> Element {
+    let app_state = use_context::<AppState>();
+
+    let mut quantity = use_signal(String::new);
+    let mut reason_id = use_signal(|| None::<i32>);
+    let mut notes = use_signal(String::new);
+    let mut error_msg = use_signal(String::new);
+
+    let reasons_resource = use_resource({
+        let handler = app_state.inventory_handler.clone();
+        move || {
+            let handler = handler.clone();
+            async move { handler.get_adjustment_reasons().await }
+        }
+    });
+
+    // Loss valued at cost, for the preview
+    let loss_value = quantity()
+        .parse::<f64>()
+        .ok()
+        .filter(|q| *q > 0.0)
+        .and_then(|q| {
+            product
+                .cost_price
+                .map(|cost| cost * Decimal::from_f64_retain(q).unwrap_or_default())
+        });
+
+    let handler = app_state.inventory_handler.clone();
+    let product_id = product.id.clone();
+    let handle_save = move |_| {
+        let handler = handler.clone();
+
+        let amount = match quantity().parse::<f64>() {
+            Ok(q) if q > 0.0 => q,
+            _ => {
+                error_msg.set("Quantity must be a positive number".to_string());
+                return;
+            }
+        };
+        let Some(reason_id) = reason_id() else {
+            error_msg.set("Select a reason".to_string());
+            return;
+        };
+        let input = WriteOffInput {
+            product_id: product_id.clone(),
+            quantity: amount,
+            reason_id,
+            notes: Some(notes()),
+        };
+
+        spawn(async move {
+            match handler.write_off_stock(input).await {
+                Ok(_) => on_saved.call(()),
+                Err(err) => error_msg.set(err),
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            style: "position: fixed; top: 0; left: 0; width: 100%; height: 100%; background: rgba(0,0,0,0.5); display: flex; justify-content: center; align-items: center; z-index: 1000;",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                style: "background: white; padding: 2rem; border-radius: 0.5rem; width: 400px; max-width: 90%;",
+                onclick: move |evt| evt.stop_propagation(),
+
+                h3 { style: "margin: 0 0 0.5rem 0; font-size: 1.25rem;", "Write Off Stock" }
+                p {
+                    style: "margin: 0 0 1.5rem 0; color: #718096;",
+                    "{product.full_name} — current stock {product.current_amount:.2}"
+                }
+
+                if !error_msg().is_empty() {
+                    div {
+                        style: "background: #fff5f5; color: #c53030; padding: 0.75rem; border-radius: 0.25rem; margin-bottom: 1rem;",
+                        "{error_msg}"
+                    }
+                }
+
+                label { style: LABEL_STYLE, "Quantity" }
+                input {
+                    r#type: "number",
+                    step: "0.001",
+                    autofocus: true,
+                    style: INPUT_STYLE,
+                    value: "{quantity}",
+                    oninput: move |e| quantity.set(e.value())
+                }
+
+                label { style: "{LABEL_STYLE} margin-top: 1rem;", "Reason" }
+                select {
+                    style: INPUT_STYLE,
+                    onchange: move |evt| reason_id.set(evt.value().parse::<i32>().ok()),
+                    option { value: "", selected: reason_id().is_none(), "Select a reason..." }
+                    if let Some(Ok(reasons)) = reasons_resource.read().as_ref() {
+                        for reason in reasons {
+                            option {
+                                value: "{reason.id}",
+                                selected: Some(reason.id) == reason_id(),
+                                "{reason.description}"
+                            }
+                        }
+                    }
+                }
+
+                label { style: "{LABEL_STYLE} margin-top: 1rem;", "Notes" }
+                input {
+                    r#type: "text",
+                    style: INPUT_STYLE,
+                    value: "{notes}",
+                    oninput: move |e| notes.set(e.value())
+                }
+
+                div {
+                    style: "margin-top: 1rem; color: #4a5568; font-size: 0.875rem;",
+                    match loss_value {
+                        Some(value) => rsx! { "Loss at cost: {format_currency(value)}" },
+                        None if product.cost_price.is_none() => rsx! { "No cost price set; the loss will be valued at $0.00" },
+                        None => rsx! {},
+                    }
+                }
+
+                div {
+                    style: "display: flex; justify-content: flex-end; gap: 0.5rem; margin-top: 1.5rem;",
+                    button {
+                        style: "padding: 0.5rem 1rem; border: 1px solid #e2e8f0; background: white; border-radius: 0.25rem; cursor: pointer; font-size: 1rem; font-weight: 500;",
+                        onclick: move |_| on_close.call(()),
+                        "Cancel"
+                    }
+                    button {
+                        style: "padding: 0.5rem 1rem; background: #e53e3e; color: white; border: none; border-radius: 0.25rem; cursor: pointer; font-size: 1rem; font-weight: 500;",
+                        onclick: handle_save,
+                        "Write Off"
+                    }
+                }
+            }
+        }
+    }
+}
+
+const LABEL_STYLE: &str =
+    "display: block; margin-bottom: 0.5rem; font-weight: 500; font-size: 0.875rem; color: #4a5568;";
+const INPUT_STYLE: &str = "width: 100%; padding: 0.625rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 1rem; box-sizing: border-box; background: white;";
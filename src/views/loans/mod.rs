@@ -21,7 +21,9 @@ use stat_card::StatCard;
 
 use crate::handlers::AppState;
 use crate::models::{Loan, LoanPayment, LoanPaymentInput, Operation, Sale};
+use crate::utils::export::ExportDataset;
 use crate::utils::formatting::format_currency;
+use crate::views::export_modal::ExportModal;
 use crate::views::pagination_nav::PaginationNav;
 use dioxus::prelude::*;
 use rust_decimal::Decimal;
@@ -44,6 +46,7 @@ pub fn LoansView() -> Element {
     });
     let mut selected_payment_history = use_signal(|| Option::<(String, Vec<LoanPayment>)>::None);
     let mut current_page = use_signal(|| 1i64);
+    let mut show_export = use_signal(|| false);
 
     // Load loans with pagination (always paginated, whether searching or not)
     let mut loans_resource = use_resource({
@@ -278,6 +281,11 @@ pub fn LoansView() -> Element {
                                     style: "font-size: 1.5rem; font-weight: 600; color: #2d3748; margin: 0;",
                                     "💰 Customer Loans"
                                 }
+                                button {
+                                    style: "background: white; color: #667eea; padding: 0.5rem 1rem; border: 2px solid #667eea; border-radius: 0.5rem; font-weight: 500; cursor: pointer; font-size: 0.875rem;",
+                                    onclick: move |_| show_export.set(true),
+                                    "⬇️ Export"
+                                }
                             }
 
                             // Payment message
@@ -411,6 +419,13 @@ pub fn LoansView() -> Element {
                     on_close: move |_| selected_payment_history.set(None),
                 }
             }
+
+            if show_export() {
+                ExportModal {
+                    datasets: vec![ExportDataset::Loans, ExportDataset::LoanPayments],
+                    on_close: move |_| show_export.set(false),
+                }
+            }
         }
     }
 }
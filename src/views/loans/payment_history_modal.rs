@@ -4,7 +4,7 @@
 
 use crate::models::LoanPayment;
 use crate::utils::formatting::format_currency;
-use chrono_tz::America::Mexico_City;
+use crate::views::app::use_store_clock;
 use dioxus::prelude::*;
 
 #[component]
@@ -13,6 +13,8 @@ pub fn PaymentHistoryModal(
     payments: Vec<LoanPayment>,
     on_close: EventHandler<()>,
 ) -> Element {
+    let clock = use_store_clock();
+
     rsx! {
         // Modal overlay
         div {
@@ -74,10 +76,7 @@ pub fn PaymentHistoryModal(
                             tbody {
                                 for payment in &payments {
                                     {
-                                        let formatted_date = payment.payment_date
-                                            .with_timezone(&Mexico_City)
-                                            .format("%d-%b-%Y %H:%M")
-                                            .to_string();
+                                        let formatted_date = clock.format(&payment.payment_date, "%d-%b-%Y %H:%M");
                                         rsx! {
                                             tr {
                                                 style: "border-bottom: 1px solid #e2e8f0;",
@@ -4,7 +4,7 @@
 
 use crate::models::{Loan, LoanPayment, Operation, Sale};
 use crate::utils::formatting::format_currency;
-use chrono_tz::America::Mexico_City;
+use crate::views::app::use_store_clock;
 use dioxus::prelude::*;
 
 #[component]
@@ -15,11 +15,8 @@ pub fn ReceiptModal(
     payments: Option<Vec<LoanPayment>>,
     on_close: EventHandler<()>,
 ) -> Element {
-    let formatted_date = sale
-        .sold_at
-        .with_timezone(&Mexico_City)
-        .format("%d-%b-%Y %H:%M")
-        .to_string();
+    let clock = use_store_clock();
+    let formatted_date = clock.format(&sale.sold_at, "%d-%b-%Y %H:%M");
 
     // Clone values for print handler
     let sale_clone = sale.clone();
@@ -45,6 +42,7 @@ pub fn ReceiptModal(
                         &sale_clone,
                         &operations_clone,
                         payments_ref,
+                        &clock,
                         file_path,
                     ) {
                         tracing::error!("Failed to generate loan receipt: {}", e);
@@ -131,7 +129,7 @@ pub fn ReceiptModal(
                                 tr {
                                     style: "border-bottom: 1px solid #e2e8f0;",
                                     td { style: "padding: 0.75rem;", "{operation.product_name}" }
-                                    td { style: "padding: 0.75rem; text-align: right; font-family: monospace;", "{operation.quantity:.3}" }
+                                    td { style: "padding: 0.75rem; text-align: right; font-family: monospace;", {operation.formatted_quantity()} }
                                     td { style: "padding: 0.75rem; text-align: right; font-family: monospace;", "{format_currency(operation.unit_price)}" }
                                     td { style: "padding: 0.75rem; text-align: right; font-weight: 500; font-family: monospace;", "{format_currency(operation.subtotal)}" }
                                 }
@@ -1,9 +1,8 @@
 //! Loan Receipt PDF generation with Typst templates
 
-use crate::models::{Loan, LoanPayment, Operation, Sale};
+use crate::models::{Loan, LoanPayment, Operation, Sale, StoreClock};
 use crate::utils::formatting::format_currency;
 use crate::utils::pdf::{compile_typst_to_pdf, escape_typst};
-use chrono_tz::America::Mexico_City;
 use sailfish::TemplateOnce;
 
 #[derive(TemplateOnce)]
@@ -40,21 +39,18 @@ pub fn generate_loan_receipt_pdf(
     sale: &Sale,
     operations: &[Operation],
     payments: &[LoanPayment],
+    clock: &StoreClock,
     file_path: std::path::PathBuf,
 ) -> Result<(), Box<dyn std::error::Error>> {
     // Format sale date
-    let formatted_date = sale
-        .sold_at
-        .with_timezone(&Mexico_City)
-        .format("%d-%b-%Y %H:%M")
-        .to_string();
+    let formatted_date = clock.format(&sale.sold_at, "%d-%b-%Y %H:%M");
 
     // Prepare items data
     let items: Vec<ReceiptItem> = operations
         .iter()
         .map(|op| ReceiptItem {
             product_name: escape_typst(&op.product_name),
-            quantity: format!("{:.3}", op.quantity),
+            quantity: escape_typst(&op.formatted_quantity()),
             price: escape_typst(&format_currency(op.unit_price)),
             subtotal: escape_typst(&format_currency(op.subtotal)),
         })
@@ -64,11 +60,7 @@ pub fn generate_loan_receipt_pdf(
     let payment_items: Vec<PaymentItem> = payments
         .iter()
         .map(|payment| {
-            let payment_date = payment
-                .payment_date
-                .with_timezone(&Mexico_City)
-                .format("%d-%b-%Y %H:%M")
-                .to_string();
+            let payment_date = clock.format(&payment.payment_date, "%d-%b-%Y %H:%M");
 
             PaymentItem {
                 date: escape_typst(&payment_date),
@@ -3,7 +3,12 @@
 //! This module contains all Dioxus components for the POS application.
 
 pub mod app;
+pub mod customers;
+pub mod export_modal;
+pub mod history;
 pub mod inventory;
 pub mod loans;
 pub mod pagination_nav;
+pub mod reports;
 pub mod sales;
+pub mod settings;
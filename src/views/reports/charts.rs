@@ -0,0 +1,140 @@
+//! Chart Components
+//!
+//! Small bar and line charts drawn as inline SVG.
+
+use dioxus::prelude::*;
+
+const WIDTH: f64 = 560.0;
+const HEIGHT: f64 = 180.0;
+const LABEL_HEIGHT: f64 = 20.0;
+const TOP_PADDING: f64 = 8.0;
+
+/// One value on a chart
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartPoint {
+    pub label: String,   // Axis label under the value
+    pub value: f64,      // Value that sets the bar height
+    pub tooltip: String, // Shown on hover
+}
+
+/// Plot height of a value relative to the largest one
+fn scaled_height(value: f64, max: f64) -> f64 {
+    let plot_height = HEIGHT - LABEL_HEIGHT - TOP_PADDING;
+    if max <= 0.0 {
+        0.0
+    } else {
+        (value / max * plot_height).max(0.0)
+    }
+}
+
+fn max_value(points: &[ChartPoint]) -> f64 {
+    points.iter().map(|p| p.value).fold(0.0, f64::max)
+}
+
+#[component]
+pub fn BarChart(points: Vec<ChartPoint>, color: &'static str) -> Element {
+    let max = max_value(&points);
+    let slot = WIDTH / points.len().max(1) as f64;
+    let bar_width = slot * 0.7;
+    let baseline = HEIGHT - LABEL_HEIGHT;
+
+    rsx! {
+        svg {
+            view_box: "0 0 {WIDTH} {HEIGHT}",
+            width: "100%",
+            height: "{HEIGHT}",
+            line {
+                x1: "0",
+                y1: "{baseline}",
+                x2: "{WIDTH}",
+                y2: "{baseline}",
+                stroke: "#e2e8f0",
+            }
+            for (i, point) in points.iter().enumerate() {
+                g {
+                    key: "{i}",
+                    rect {
+                        x: "{i as f64 * slot + (slot - bar_width) / 2.0}",
+                        y: "{baseline - scaled_height(point.value, max)}",
+                        width: "{bar_width}",
+                        height: "{scaled_height(point.value, max)}",
+                        rx: "2",
+                        fill: color,
+                        title { "{point.tooltip}" }
+                    }
+                    text {
+                        x: "{i as f64 * slot + slot / 2.0}",
+                        y: "{HEIGHT - 6.0}",
+                        text_anchor: "middle",
+                        font_size: "10",
+                        fill: "#718096",
+                        "{point.label}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn LineChart(points: Vec<ChartPoint>, color: &'static str) -> Element {
+    let max = max_value(&points);
+    let slot = WIDTH / points.len().max(1) as f64;
+    let baseline = HEIGHT - LABEL_HEIGHT;
+    let coordinates: Vec<(f64, f64)> = points
+        .iter()
+        .enumerate()
+        .map(|(i, point)| {
+            (
+                i as f64 * slot + slot / 2.0,
+                baseline - scaled_height(point.value, max),
+            )
+        })
+        .collect();
+    let polyline = coordinates
+        .iter()
+        .map(|(x, y)| format!("{:.1},{:.1}", x, y))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    rsx! {
+        svg {
+            view_box: "0 0 {WIDTH} {HEIGHT}",
+            width: "100%",
+            height: "{HEIGHT}",
+            line {
+                x1: "0",
+                y1: "{baseline}",
+                x2: "{WIDTH}",
+                y2: "{baseline}",
+                stroke: "#e2e8f0",
+            }
+            polyline {
+                points: "{polyline}",
+                fill: "none",
+                stroke: color,
+                stroke_width: "2",
+            }
+            for (i, (point, (x, y))) in points.iter().zip(coordinates.iter()).enumerate() {
+                g {
+                    key: "{i}",
+                    circle {
+                        cx: "{x}",
+                        cy: "{y}",
+                        r: "3.5",
+                        fill: color,
+                        title { "{point.tooltip}" }
+                    }
+                    text {
+                        x: "{x}",
+                        y: "{HEIGHT - 6.0}",
+                        text_anchor: "middle",
+                        font_size: "10",
+                        fill: "#718096",
+                        "{point.label}"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! Daily Summary PDF generation with Typst templates
+
+use crate::models::DailyReport;
+use crate::utils::formatting::{format_currency, format_quantity};
+use crate::utils::pdf::{compile_typst_to_pdf, escape_typst};
+use sailfish::TemplateOnce;
+
+/// Low stock rows that fit on the page
+const MAX_LOW_STOCK_ROWS: usize = 15;
+
+#[derive(TemplateOnce)]
+#[template(path = "daily_report.typ.stpl")]
+struct DailyReportTemplate {
+    date: String,
+    headline: Vec<(String, String)>,
+    payments: Vec<PaymentLine>,
+    top_products: Vec<ProductLine>,
+    hours: Vec<HourLine>,
+    low_stock: Vec<LowStockLine>,
+    low_stock_count: usize,
+}
+
+struct PaymentLine {
+    label: String,
+    count: String,
+    amount: String,
+}
+
+struct ProductLine {
+    name: String,
+    quantity: String,
+    revenue: String,
+}
+
+struct HourLine {
+    label: String,
+    tickets: usize,
+    bar_percent: u32,
+    revenue: String,
+}
+
+struct LowStockLine {
+    name: String,
+    stock: String,
+    minimum: String,
+}
+
+/// Whole quantities without decimals, fractional ones with three
+pub fn format_amount(quantity: f64) -> String {
+    let decimals = if quantity.fract() == 0.0 { 0 } else { 3 };
+    format_quantity(quantity, decimals)
+}
+
+pub fn generate_daily_report_pdf(
+    report: &DailyReport,
+    file_path: std::path::PathBuf,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let money = |amount| escape_typst(&format_currency(amount));
+
+    let headline = vec![
+        ("Tickets".to_string(), report.ticket_count.to_string()),
+        ("Revenue".to_string(), money(report.revenue)),
+        ("Average Ticket".to_string(), money(report.average_ticket())),
+        ("Cash Collected".to_string(), money(report.cash_collected())),
+    ];
+
+    let payments = vec![
+        PaymentLine {
+            label: "Cash sales".to_string(),
+            count: report.cash_sales.to_string(),
+            amount: money(report.cash_revenue),
+        },
+        PaymentLine {
+            label: "Credit sales".to_string(),
+            count: report.credit_sales.to_string(),
+            amount: money(report.credit_revenue),
+        },
+        PaymentLine {
+            label: "Credit left unpaid at the till".to_string(),
+            count: String::new(),
+            amount: money(report.credit_outstanding),
+        },
+        PaymentLine {
+            label: "Loan payments collected".to_string(),
+            count: report.payment_count.to_string(),
+            amount: money(report.payments_collected),
+        },
+        PaymentLine {
+            label: "Outstanding loans (all customers)".to_string(),
+            count: String::new(),
+            amount: money(report.total_outstanding),
+        },
+    ];
+
+    let top_products = report
+        .top_products
+        .iter()
+        .map(|product| ProductLine {
+            name: escape_typst(&product.product_name),
+            quantity: escape_typst(&format_amount(product.quantity)),
+            revenue: money(product.revenue),
+        })
+        .collect();
+
+    let max_revenue = report
+        .hourly
+        .iter()
+        .map(|hour| hour.revenue)
+        .max()
+        .unwrap_or_default();
+    let hours = report
+        .hourly
+        .iter()
+        .map(|hour| HourLine {
+            label: format!("{:02}:00", hour.hour),
+            tickets: hour.tickets,
+            bar_percent: bar_percent(hour.revenue, max_revenue),
+            revenue: money(hour.revenue),
+        })
+        .collect();
+
+    let low_stock = report
+        .low_stock
+        .iter()
+        .take(MAX_LOW_STOCK_ROWS)
+        .map(|product| LowStockLine {
+            name: escape_typst(&product.full_name),
+            stock: escape_typst(&format_amount(product.current_amount)),
+            minimum: escape_typst(&format_amount(product.min_amount)),
+        })
+        .collect();
+
+    let template = DailyReportTemplate {
+        date: escape_typst(&report.date.format("%d-%b-%Y").to_string()),
+        headline,
+        payments,
+        top_products,
+        hours,
+        low_stock,
+        low_stock_count: report.low_stock.len(),
+    };
+
+    let typst_content = template.render_once()?;
+
+    // Compile Typst to PDF and save
+    compile_typst_to_pdf(typst_content, file_path)?;
+
+    Ok(())
+}
+
+/// Bar length as a percentage of the busiest hour, at least 1 so it shows
+fn bar_percent(revenue: rust_decimal::Decimal, max: rust_decimal::Decimal) -> u32 {
+    use rust_decimal::prelude::ToPrimitive;
+
+    if max.is_zero() {
+        return 1;
+    }
+    ((revenue / max * rust_decimal::Decimal::from(100))
+        .round()
+        .to_u32()
+        .unwrap_or(0))
+    .max(1)
+}
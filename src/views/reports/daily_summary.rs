@@ -0,0 +1,258 @@
+//! Daily Summary Component
+//!
+//! End-of-day figures for any date with PDF export.
+
+use super::daily_report_template::{self, format_amount};
+use crate::handlers::AppState;
+use crate::utils::formatting::format_currency;
+use crate::views::app::use_store_clock;
+use dioxus::prelude::*;
+
+#[component]
+pub fn DailySummary(category_id: ReadSignal<Option<String>>) -> Element {
+    let app_state = use_context::<AppState>();
+
+    let today = use_store_clock().today().format("%Y-%m-%d").to_string();
+    let mut report_date = use_signal(|| today);
+    let mut message = use_signal(|| Option::<(bool, String)>::None);
+
+    // Load the summary for the selected date
+    let report_resource = use_resource({
+        let handler = app_state.reports_handler.clone();
+        move || {
+            let handler = handler.clone();
+            let (date, category_id) = (report_date(), category_id());
+            async move { handler.get_daily_report(date, category_id).await }
+        }
+    });
+
+    let report = match &*report_resource.read_unchecked() {
+        Some(Ok(report)) => Some(report.clone()),
+        _ => None,
+    };
+
+    let save_report = {
+        let report = report.clone();
+        move |_| {
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(report) = &report {
+                if let Some(file_path) = rfd::FileDialog::new()
+                    .set_file_name(format!("daily_summary_{}.pdf", report.date))
+                    .add_filter("PDF", &["pdf"])
+                    .save_file()
+                {
+                    match daily_report_template::generate_daily_report_pdf(report, file_path) {
+                        Ok(()) => message.set(Some((true, "Daily summary saved".to_string()))),
+                        Err(e) => {
+                            tracing::error!("Failed to generate daily summary: {}", e);
+                            message.set(Some((
+                                false,
+                                format!("Failed to generate daily summary: {}", e),
+                            )));
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            style: "background: white; border-radius: 0.5rem; padding: 1.5rem; box-shadow: 0 1px 3px rgba(0,0,0,0.1);",
+
+            // Header
+            div {
+                style: "display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem; gap: 1rem;",
+                h2 {
+                    style: "font-size: 1.5rem; font-weight: 600; color: #2d3748; margin: 0;",
+                    "📊 Daily Summary"
+                }
+                div {
+                    style: "display: flex; gap: 0.75rem; align-items: center;",
+                    input {
+                        r#type: "date",
+                        style: "padding: 0.625rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 1rem;",
+                        value: "{report_date}",
+                        oninput: move |e| report_date.set(e.value())
+                    }
+                    button {
+                        style: "background: #48bb78; color: white; padding: 0.625rem 1.25rem; border: none; border-radius: 0.5rem; font-weight: 500; cursor: pointer; font-size: 1rem;",
+                        disabled: report.is_none(),
+                        onclick: save_report,
+                        "💾 Save PDF"
+                    }
+                }
+            }
+
+            if let Some((success, text)) = message() {
+                div {
+                    style: if success {
+                        "background: #f0fff4; color: #276749; padding: 0.75rem; border-radius: 0.25rem; margin-bottom: 1rem;"
+                    } else {
+                        "background: #fff5f5; color: #c53030; padding: 0.75rem; border-radius: 0.25rem; margin-bottom: 1rem;"
+                    },
+                    "{text}"
+                }
+            }
+
+            match &*report_resource.read_unchecked() {
+                Some(Ok(report)) => rsx! {
+                    // Headline figures
+                    div {
+                        style: "display: grid; grid-template-columns: repeat(4, 1fr); gap: 1rem; margin-bottom: 1.5rem;",
+                        SummaryCard { label: "Tickets", value: report.ticket_count.to_string() }
+                        SummaryCard { label: "Revenue", value: format_currency(report.revenue) }
+                        SummaryCard { label: "Average Ticket", value: format_currency(report.average_ticket()) }
+                        SummaryCard { label: "Cash Collected", value: format_currency(report.cash_collected()) }
+                    }
+
+                    // Payments
+                    h3 { style: SECTION_TITLE_STYLE, "Payments" }
+                    table {
+                        style: "width: 100%; border-collapse: collapse; margin-bottom: 1.5rem;",
+                        tbody {
+                            PaymentRow { label: "Cash sales", count: report.cash_sales.to_string(), amount: format_currency(report.cash_revenue) }
+                            PaymentRow { label: "Credit sales", count: report.credit_sales.to_string(), amount: format_currency(report.credit_revenue) }
+                            PaymentRow { label: "Credit left unpaid at the till", count: String::new(), amount: format_currency(report.credit_outstanding) }
+                            PaymentRow { label: "Loan payments collected", count: report.payment_count.to_string(), amount: format_currency(report.payments_collected) }
+                            PaymentRow { label: "Outstanding loans (all customers)", count: String::new(), amount: format_currency(report.total_outstanding) }
+                        }
+                    }
+
+                    div {
+                        style: "display: grid; grid-template-columns: 3fr 2fr; gap: 1.5rem; margin-bottom: 1.5rem;",
+
+                        // Top products
+                        div {
+                            h3 { style: SECTION_TITLE_STYLE, "Top Products" }
+                            if report.top_products.is_empty() {
+                                div { style: EMPTY_STYLE, "No products sold" }
+                            } else {
+                                table {
+                                    style: "width: 100%; border-collapse: collapse;",
+                                    thead {
+                                        tr {
+                                            style: "background: #f7fafc; border-bottom: 2px solid #e2e8f0;",
+                                            th { style: HEADER_CELL_STYLE, "Product" }
+                                            th { style: "{HEADER_CELL_STYLE} text-align: right;", "Qty" }
+                                            th { style: "{HEADER_CELL_STYLE} text-align: right;", "Revenue" }
+                                        }
+                                    }
+                                    tbody {
+                                        for product in report.top_products.iter() {
+                                            tr {
+                                                key: "{product.product_id}",
+                                                style: "border-bottom: 1px solid #e2e8f0;",
+                                                td { style: "padding: 0.5rem;", "{product.product_name}" }
+                                                td { style: "padding: 0.5rem; text-align: right; font-family: monospace;", "{format_amount(product.quantity)}" }
+                                                td { style: "padding: 0.5rem; text-align: right; font-family: monospace;", "{format_currency(product.revenue)}" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+
+                        // Hourly distribution
+                        div {
+                            h3 { style: SECTION_TITLE_STYLE, "Sales by Hour" }
+                            if report.hourly.is_empty() {
+                                div { style: EMPTY_STYLE, "No sales" }
+                            } else {
+                                {
+                                    let max_tickets = report.hourly.iter().map(|h| h.tickets).max().unwrap_or(1);
+                                    rsx! {
+                                        for hour in report.hourly.iter() {
+                                            div {
+                                                key: "{hour.hour}",
+                                                style: "display: flex; align-items: center; gap: 0.5rem; margin-bottom: 0.375rem; font-size: 0.875rem;",
+                                                span { style: "width: 3rem; color: #4a5568; font-family: monospace;", "{hour.hour:02}:00" }
+                                                div {
+                                                    style: "flex: 1; background: #edf2f7; border-radius: 0.25rem; height: 0.75rem;",
+                                                    div {
+                                                        style: "background: #667eea; border-radius: 0.25rem; height: 100%; width: {hour.tickets * 100 / max_tickets}%;",
+                                                    }
+                                                }
+                                                span { style: "width: 5.5rem; text-align: right; font-family: monospace;", "{format_currency(hour.revenue)}" }
+                                                span { style: "width: 2rem; text-align: right; color: #718096;", "{hour.tickets}" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    // Low stock
+                    h3 { style: SECTION_TITLE_STYLE, "Low Stock ({report.low_stock.len()})" }
+                    if report.low_stock.is_empty() {
+                        div { style: EMPTY_STYLE, "All products are above their minimum stock" }
+                    } else {
+                        table {
+                            style: "width: 100%; border-collapse: collapse;",
+                            thead {
+                                tr {
+                                    style: "background: #f7fafc; border-bottom: 2px solid #e2e8f0;",
+                                    th { style: HEADER_CELL_STYLE, "Product" }
+                                    th { style: "{HEADER_CELL_STYLE} text-align: right;", "Stock" }
+                                    th { style: "{HEADER_CELL_STYLE} text-align: right;", "Minimum" }
+                                }
+                            }
+                            tbody {
+                                for product in report.low_stock.iter() {
+                                    tr {
+                                        key: "{product.id}",
+                                        style: "border-bottom: 1px solid #e2e8f0;",
+                                        td { style: "padding: 0.5rem;", "{product.full_name}" }
+                                        td { style: "padding: 0.5rem; text-align: right; font-family: monospace; color: #c53030;", "{format_amount(product.current_amount)}" }
+                                        td { style: "padding: 0.5rem; text-align: right; font-family: monospace;", "{format_amount(product.min_amount)}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                Some(Err(err)) => rsx! {
+                    div {
+                        style: "padding: 2rem; text-align: center; color: #e53e3e; background: #fff5f5; border-radius: 0.5rem;",
+                        "❌ {err}"
+                    }
+                },
+                None => rsx! {
+                    div {
+                        style: "padding: 2rem; text-align: center; color: #718096;",
+                        "⏳ Loading summary..."
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn SummaryCard(label: &'static str, value: String) -> Element {
+    rsx! {
+        div {
+            style: "background: #f7fafc; border: 1px solid #e2e8f0; border-radius: 0.5rem; padding: 1rem;",
+            div { style: "color: #718096; font-size: 0.875rem; margin-bottom: 0.25rem;", "{label}" }
+            div { style: "color: #2d3748; font-size: 1.5rem; font-weight: 700;", "{value}" }
+        }
+    }
+}
+
+#[component]
+fn PaymentRow(label: &'static str, count: String, amount: String) -> Element {
+    rsx! {
+        tr {
+            style: "border-bottom: 1px solid #e2e8f0;",
+            td { style: "padding: 0.5rem;", "{label}" }
+            td { style: "padding: 0.5rem; text-align: right; color: #718096;", "{count}" }
+            td { style: "padding: 0.5rem; text-align: right; font-family: monospace; font-weight: 500;", "{amount}" }
+        }
+    }
+}
+
+const SECTION_TITLE_STYLE: &str = "font-size: 1.125rem; color: #4a5568; margin: 0 0 0.75rem 0;";
+const HEADER_CELL_STYLE: &str =
+    "padding: 0.5rem; text-align: left; font-weight: 600; color: #4a5568; font-size: 0.875rem;";
+const EMPTY_STYLE: &str = "padding: 1rem; text-align: center; color: #a0aec0;";
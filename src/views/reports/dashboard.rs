@@ -0,0 +1,200 @@
+//! Sales Dashboard Component
+//!
+//! Revenue, tickets and average ticket per day, week or month against the
+//! previous period, with trend charts.
+
+use super::charts::{BarChart, ChartPoint, LineChart};
+use crate::handlers::AppState;
+use crate::models::{DashboardPeriod, PeriodTotals};
+use crate::utils::formatting::{format_currency, format_percentage};
+use chrono::Duration;
+use dioxus::prelude::*;
+use rust_decimal::prelude::ToPrimitive;
+
+#[component]
+pub fn SalesDashboard(category_id: ReadSignal<Option<String>>) -> Element {
+    let app_state = use_context::<AppState>();
+    let mut period = use_signal(|| DashboardPeriod::Day);
+
+    let dashboard_resource = use_resource({
+        let handler = app_state.reports_handler.clone();
+        move || {
+            let handler = handler.clone();
+            let (period, category_id) = (period(), category_id());
+            async move { handler.get_dashboard(period, category_id).await }
+        }
+    });
+
+    rsx! {
+        div {
+            style: "background: white; border-radius: 0.5rem; padding: 1.5rem; box-shadow: 0 1px 3px rgba(0,0,0,0.1);",
+
+            // Header
+            div {
+                style: "display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem; gap: 1rem;",
+                h2 {
+                    style: "font-size: 1.5rem; font-weight: 600; color: #2d3748; margin: 0;",
+                    "📉 Sales Dashboard"
+                }
+                div {
+                    style: "display: flex; gap: 0.25rem; background: #edf2f7; padding: 0.25rem; border-radius: 0.5rem;",
+                    for option in [DashboardPeriod::Day, DashboardPeriod::Week, DashboardPeriod::Month] {
+                        button {
+                            key: "{option.label()}",
+                            style: if period() == option { PERIOD_ACTIVE_STYLE } else { PERIOD_STYLE },
+                            onclick: move |_| period.set(option),
+                            "{option.label()}"
+                        }
+                    }
+                }
+            }
+
+            match &*dashboard_resource.read_unchecked() {
+                Some(Ok(dashboard)) => {
+                    let current = &dashboard.current;
+                    let previous = &dashboard.previous;
+                    let compared_to = format!("vs {}", range_label(previous));
+
+                    rsx! {
+                        div {
+                            style: "color: #718096; font-size: 0.875rem; margin-bottom: 1rem;",
+                            "{range_label(current)}"
+                        }
+
+                        // Headline figures
+                        div {
+                            style: "display: grid; grid-template-columns: repeat(3, 1fr); gap: 1rem; margin-bottom: 1.5rem;",
+                            KpiCard {
+                                label: "Revenue",
+                                value: format_currency(current.revenue),
+                                previous: format!("{} {}", format_currency(previous.revenue), compared_to),
+                                change: dashboard.revenue_change(),
+                                color: "#48bb78",
+                            }
+                            KpiCard {
+                                label: "Tickets",
+                                value: current.tickets.to_string(),
+                                previous: format!("{} {}", previous.tickets, compared_to),
+                                change: dashboard.tickets_change(),
+                                color: "#667eea",
+                            }
+                            KpiCard {
+                                label: "Average Ticket",
+                                value: format_currency(current.average_ticket()),
+                                previous: format!("{} {}", format_currency(previous.average_ticket()), compared_to),
+                                change: dashboard.average_ticket_change(),
+                                color: "#ed8936",
+                            }
+                        }
+
+                        // Trend charts
+                        h3 { style: SECTION_TITLE_STYLE, "Revenue per {dashboard.period.label().to_lowercase()}" }
+                        BarChart {
+                            points: chart_points(dashboard.period, &dashboard.trend, |t| {
+                                (t.revenue.to_f64().unwrap_or(0.0), format_currency(t.revenue))
+                            }),
+                            color: "#48bb78",
+                        }
+                        div {
+                            style: "display: grid; grid-template-columns: 1fr 1fr; gap: 1.5rem; margin-top: 1.5rem;",
+                            div {
+                                h3 { style: SECTION_TITLE_STYLE, "Tickets" }
+                                LineChart {
+                                    points: chart_points(dashboard.period, &dashboard.trend, |t| {
+                                        (t.tickets as f64, t.tickets.to_string())
+                                    }),
+                                    color: "#667eea",
+                                }
+                            }
+                            div {
+                                h3 { style: SECTION_TITLE_STYLE, "Average Ticket" }
+                                LineChart {
+                                    points: chart_points(dashboard.period, &dashboard.trend, |t| {
+                                        let average = t.average_ticket();
+                                        (average.to_f64().unwrap_or(0.0), format_currency(average))
+                                    }),
+                                    color: "#ed8936",
+                                }
+                            }
+                        }
+                    }
+                },
+                Some(Err(err)) => rsx! {
+                    div {
+                        style: "padding: 2rem; text-align: center; color: #e53e3e; background: #fff5f5; border-radius: 0.5rem;",
+                        "❌ {err}"
+                    }
+                },
+                None => rsx! {
+                    div {
+                        style: "padding: 2rem; text-align: center; color: #a0aec0;",
+                        "⏳ Loading dashboard..."
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn KpiCard(
+    label: &'static str,
+    value: String,
+    previous: String,
+    change: Option<f64>,
+    color: &'static str,
+) -> Element {
+    let (change_text, change_color) = match change {
+        Some(change) if change > 0.0 => (format!("▲ {}", format_percentage(change)), "#38a169"),
+        Some(change) if change < 0.0 => (format!("▼ {}", format_percentage(-change)), "#e53e3e"),
+        Some(_) => ("No change".to_string(), "#718096"),
+        None => ("No previous sales".to_string(), "#a0aec0"),
+    };
+
+    rsx! {
+        div {
+            style: "padding: 1rem; border-radius: 0.5rem; background: #f7fafc; border-left: 4px solid {color};",
+            div { style: "font-size: 0.875rem; color: #718096; font-weight: 500;", "{label}" }
+            div { style: "font-size: 1.75rem; font-weight: 700; color: {color}; margin: 0.25rem 0;", "{value}" }
+            div { style: "font-size: 0.875rem; font-weight: 600; color: {change_color};", "{change_text}" }
+            div { style: "font-size: 0.75rem; color: #a0aec0; margin-top: 0.25rem;", "{previous}" }
+        }
+    }
+}
+
+/// Chart points for the trend, with the value and its display text from `measure`
+fn chart_points(
+    period: DashboardPeriod,
+    trend: &[PeriodTotals],
+    measure: impl Fn(&PeriodTotals) -> (f64, String),
+) -> Vec<ChartPoint> {
+    trend
+        .iter()
+        .map(|totals| {
+            let (value, display) = measure(totals);
+            ChartPoint {
+                label: period.short_label(totals.start),
+                value,
+                tooltip: format!("{}: {}", range_label(totals), display),
+            }
+        })
+        .collect()
+}
+
+/// Human-readable span of a period, e.g. "12-Oct-2026 – 14-Oct-2026"
+fn range_label(totals: &PeriodTotals) -> String {
+    let last_day = totals.end - Duration::days(1);
+    if last_day <= totals.start {
+        totals.start.format("%d-%b-%Y").to_string()
+    } else {
+        format!(
+            "{} – {}",
+            totals.start.format("%d-%b-%Y"),
+            last_day.format("%d-%b-%Y")
+        )
+    }
+}
+
+const PERIOD_STYLE: &str = "padding: 0.375rem 1rem; border: none; border-radius: 0.375rem; background: transparent; color: #4a5568; font-weight: 500; cursor: pointer;";
+const PERIOD_ACTIVE_STYLE: &str = "padding: 0.375rem 1rem; border: none; border-radius: 0.375rem; background: white; color: #667eea; font-weight: 600; cursor: pointer; box-shadow: 0 1px 2px rgba(0,0,0,0.1);";
+const SECTION_TITLE_STYLE: &str = "font-size: 1.125rem; color: #4a5568; margin: 0 0 0.5rem 0;";
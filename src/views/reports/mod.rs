@@ -0,0 +1,113 @@
+//! Reports Module
+//!
+//! UI components for the sales dashboard, end-of-day summaries and product
+//! analytics.
+
+mod charts;
+mod daily_report_template;
+mod daily_summary;
+mod dashboard;
+mod product_analytics;
+
+use daily_summary::DailySummary;
+use dashboard::SalesDashboard;
+use product_analytics::ProductAnalytics;
+
+use crate::handlers::AppState;
+use crate::views::inventory::CategorySelect;
+use dioxus::prelude::*;
+
+#[derive(Clone, Copy, PartialEq)]
+enum ReportSection {
+    Dashboard,
+    DailySummary,
+    ProductAnalytics,
+}
+
+#[component]
+pub fn ReportsView() -> Element {
+    let app_state = use_context::<AppState>();
+    let mut section = use_signal(|| ReportSection::Dashboard);
+    // Shared by every section: None reports on the whole store
+    let mut category_id = use_signal(|| Option::<String>::None);
+
+    let categories_resource = use_resource({
+        let inventory_handler = app_state.inventory_handler.clone();
+        move || {
+            let handler = inventory_handler.clone();
+            async move { handler.get_categories().await }
+        }
+    });
+
+    rsx! {
+        div {
+            class: "reports-view",
+
+            // Section switcher
+            div {
+                style: "display: flex; gap: 0.5rem; margin-bottom: 1rem;",
+                SectionButton {
+                    label: "📉 Dashboard",
+                    is_active: section() == ReportSection::Dashboard,
+                    onclick: move |_| section.set(ReportSection::Dashboard),
+                }
+                SectionButton {
+                    label: "📊 Daily Summary",
+                    is_active: section() == ReportSection::DailySummary,
+                    onclick: move |_| section.set(ReportSection::DailySummary),
+                }
+                SectionButton {
+                    label: "📈 Product Performance",
+                    is_active: section() == ReportSection::ProductAnalytics,
+                    onclick: move |_| section.set(ReportSection::ProductAnalytics),
+                }
+
+                // Category scope
+                div {
+                    style: "margin-left: auto; width: 260px;",
+                    {
+                        let categories = match &*categories_resource.read_unchecked() {
+                            Some(Ok(categories)) => categories.clone(),
+                            _ => Vec::new(),
+                        };
+                        rsx! {
+                            CategorySelect {
+                                categories,
+                                selected: category_id(),
+                                empty_label: "All categories",
+                                on_change: move |id| category_id.set(id),
+                            }
+                        }
+                    }
+                }
+            }
+
+            match section() {
+                ReportSection::Dashboard => rsx! { SalesDashboard { category_id } },
+                ReportSection::DailySummary => rsx! { DailySummary { category_id } },
+                ReportSection::ProductAnalytics => rsx! { ProductAnalytics { category_id } },
+            }
+        }
+    }
+}
+
+#[component]
+fn SectionButton(
+    label: &'static str,
+    is_active: bool,
+    onclick: EventHandler<MouseEvent>,
+) -> Element {
+    let style = if is_active {
+        "background: #667eea; color: white; padding: 0.5rem 1rem; border: 2px solid #667eea; border-radius: 0.5rem; font-weight: 500; cursor: pointer; font-size: 0.875rem;"
+    } else {
+        "background: white; color: #667eea; padding: 0.5rem 1rem; border: 2px solid #667eea; border-radius: 0.5rem; font-weight: 500; cursor: pointer; font-size: 0.875rem;"
+    };
+
+    rsx! {
+        button {
+            style: "{style}",
+            onclick: move |evt| onclick.call(evt),
+            "{label}"
+        }
+    }
+}
@@ -0,0 +1,305 @@
+//! Product Analytics Component
+//!
+//! Sortable product performance with ABC classes, slow movers and products
+//! that stopped selling.
+
+use super::daily_report_template::format_amount;
+use crate::handlers::AppState;
+use crate::models::analytics::sort_performance;
+use crate::models::{AbcClass, PerformanceSort};
+use crate::utils::formatting::{format_currency, format_percentage};
+use crate::views::app::use_store_clock;
+use chrono::Duration;
+use dioxus::prelude::*;
+
+#[component]
+pub fn ProductAnalytics(category_id: ReadSignal<Option<String>>) -> Element {
+    let app_state = use_context::<AppState>();
+    let clock = use_store_clock();
+
+    let today = clock.today();
+    let mut from_date = use_signal(|| (today - Duration::days(29)).format("%Y-%m-%d").to_string());
+    let mut to_date = use_signal(|| today.format("%Y-%m-%d").to_string());
+    let sort = use_signal(|| (PerformanceSort::Revenue, true));
+    let mut idle_days = use_signal(|| "30".to_string());
+
+    let analytics_resource = use_resource({
+        let handler = app_state.reports_handler.clone();
+        move || {
+            let handler = handler.clone();
+            let (from, to, category_id) = (from_date(), to_date(), category_id());
+            async move { handler.get_product_analytics(from, to, category_id).await }
+        }
+    });
+
+    let idle_resource = use_resource({
+        let handler = app_state.reports_handler.clone();
+        move || {
+            let handler = handler.clone();
+            let (days, category_id) = (idle_days(), category_id());
+            async move {
+                let days = days
+                    .trim()
+                    .parse::<i64>()
+                    .map_err(|_| "Days must be a whole number".to_string())?;
+                handler.get_idle_products(days, category_id).await
+            }
+        }
+    });
+
+    rsx! {
+        div {
+            style: "background: white; border-radius: 0.5rem; padding: 1.5rem; box-shadow: 0 1px 3px rgba(0,0,0,0.1);",
+
+            // Header
+            div {
+                style: "display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem; gap: 1rem;",
+                h2 {
+                    style: "font-size: 1.5rem; font-weight: 600; color: #2d3748; margin: 0;",
+                    "📈 Product Performance"
+                }
+                div {
+                    style: "display: flex; gap: 0.5rem; align-items: center; color: #4a5568;",
+                    input {
+                        r#type: "date",
+                        style: DATE_INPUT_STYLE,
+                        value: "{from_date}",
+                        oninput: move |e| from_date.set(e.value())
+                    }
+                    "to"
+                    input {
+                        r#type: "date",
+                        style: DATE_INPUT_STYLE,
+                        value: "{to_date}",
+                        oninput: move |e| to_date.set(e.value())
+                    }
+                }
+            }
+
+            match &*analytics_resource.read_unchecked() {
+                Some(Ok(analytics)) => {
+                    let mut lines = analytics.products.clone();
+                    let (sort_by, descending) = sort();
+                    sort_performance(&mut lines, sort_by, descending);
+                    let class_counts = [AbcClass::A, AbcClass::B, AbcClass::C]
+                        .map(|class| lines.iter().filter(|l| l.abc_class == class).count());
+
+                    rsx! {
+                        div {
+                            style: "display: flex; gap: 1.5rem; margin-bottom: 1rem; color: #4a5568; font-size: 0.875rem;",
+                            span { "{lines.len()} products sold" }
+                            span { "{format_amount(analytics.total_units)} units" }
+                            span { "{format_currency(analytics.total_revenue)} revenue" }
+                            span { "A: {class_counts[0]} · B: {class_counts[1]} · C: {class_counts[2]}" }
+                        }
+
+                        if lines.is_empty() {
+                            div { style: EMPTY_STYLE, "No sales in this period" }
+                        } else {
+                            div {
+                                style: "overflow-x: auto; max-height: 480px; overflow-y: auto; margin-bottom: 1.5rem;",
+                                table {
+                                    style: "width: 100%; border-collapse: collapse;",
+                                    thead {
+                                        tr {
+                                            style: "background: #f7fafc; border-bottom: 2px solid #e2e8f0;",
+                                            SortHeader { label: "Product", column: PerformanceSort::Name, sort, align_right: false }
+                                            th { style: HEADER_CELL_STYLE, "Class" }
+                                            SortHeader { label: "Units", column: PerformanceSort::Units, sort, align_right: true }
+                                            SortHeader { label: "Revenue", column: PerformanceSort::Revenue, sort, align_right: true }
+                                            th { style: "{HEADER_CELL_STYLE} text-align: right;", "Share" }
+                                            SortHeader { label: "Margin", column: PerformanceSort::Margin, sort, align_right: true }
+                                            SortHeader { label: "Margin %", column: PerformanceSort::MarginPercent, sort, align_right: true }
+                                        }
+                                    }
+                                    tbody {
+                                        for line in lines.iter() {
+                                            tr {
+                                                key: "{line.product_id}",
+                                                style: "border-bottom: 1px solid #e2e8f0;",
+                                                td { style: "padding: 0.5rem;", "{line.product_name}" }
+                                                td {
+                                                    style: "padding: 0.5rem;",
+                                                    span {
+                                                        style: "padding: 0.125rem 0.5rem; border-radius: 9999px; font-size: 0.75rem; font-weight: 600; {class_style(line.abc_class)}",
+                                                        "{line.abc_class.label()}"
+                                                    }
+                                                }
+                                                td { style: NUMBER_CELL_STYLE, "{format_amount(line.quantity)}" }
+                                                td { style: NUMBER_CELL_STYLE, "{format_currency(line.revenue)}" }
+                                                td { style: NUMBER_CELL_STYLE, "{format_percentage(line.revenue_share)}" }
+                                                td {
+                                                    style: NUMBER_CELL_STYLE,
+                                                    {line.margin().map(format_currency).unwrap_or_else(|| "-".to_string())}
+                                                }
+                                                td {
+                                                    style: NUMBER_CELL_STYLE,
+                                                    {line.margin_percent().map(format_percentage).unwrap_or_else(|| "-".to_string())}
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+
+                        // Slow movers
+                        h3 { style: SECTION_TITLE_STYLE, "Slow Movers" }
+                        div {
+                            style: "color: #718096; font-size: 0.75rem; margin-bottom: 0.5rem;",
+                            "Products in stock whose stock would last longest at this period's sales pace."
+                        }
+                        if analytics.slow_movers.is_empty() {
+                            div { style: EMPTY_STYLE, "No products in stock" }
+                        } else {
+                            table {
+                                style: "width: 100%; border-collapse: collapse; margin-bottom: 1.5rem;",
+                                thead {
+                                    tr {
+                                        style: "background: #f7fafc; border-bottom: 2px solid #e2e8f0;",
+                                        th { style: HEADER_CELL_STYLE, "Product" }
+                                        th { style: "{HEADER_CELL_STYLE} text-align: right;", "Stock" }
+                                        th { style: "{HEADER_CELL_STYLE} text-align: right;", "Sold" }
+                                        th { style: "{HEADER_CELL_STYLE} text-align: right;", "Days of stock" }
+                                    }
+                                }
+                                tbody {
+                                    for mover in analytics.slow_movers.iter() {
+                                        tr {
+                                            key: "{mover.product_id}",
+                                            style: "border-bottom: 1px solid #e2e8f0;",
+                                            td { style: "padding: 0.5rem;", "{mover.product_name}" }
+                                            td { style: NUMBER_CELL_STYLE, "{format_amount(mover.stock)}" }
+                                            td { style: NUMBER_CELL_STYLE, "{format_amount(mover.units_sold)}" }
+                                            td {
+                                                style: NUMBER_CELL_STYLE,
+                                                {mover.days_of_stock.map(|d| format!("{:.0}", d)).unwrap_or_else(|| "No sales".to_string())}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                Some(Err(err)) => rsx! {
+                    div {
+                        style: "padding: 2rem; text-align: center; color: #e53e3e; background: #fff5f5; border-radius: 0.5rem; margin-bottom: 1.5rem;",
+                        "❌ {err}"
+                    }
+                },
+                None => rsx! {
+                    div { style: EMPTY_STYLE, "⏳ Loading analytics..." }
+                }
+            }
+
+            // Products not sold recently
+            div {
+                style: "display: flex; align-items: center; gap: 0.5rem; margin-bottom: 0.75rem;",
+                h3 { style: "font-size: 1.125rem; color: #4a5568; margin: 0;", "Not Sold in" }
+                input {
+                    r#type: "number",
+                    min: "1",
+                    style: "width: 5rem; padding: 0.375rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 1rem;",
+                    value: "{idle_days}",
+                    oninput: move |e| idle_days.set(e.value())
+                }
+                h3 { style: "font-size: 1.125rem; color: #4a5568; margin: 0;", "days" }
+            }
+            match &*idle_resource.read_unchecked() {
+                Some(Ok(idle)) if idle.is_empty() => rsx! {
+                    div { style: EMPTY_STYLE, "Every product sold within these days" }
+                },
+                Some(Ok(idle)) => rsx! {
+                    table {
+                        style: "width: 100%; border-collapse: collapse;",
+                        thead {
+                            tr {
+                                style: "background: #f7fafc; border-bottom: 2px solid #e2e8f0;",
+                                th { style: HEADER_CELL_STYLE, "Product" }
+                                th { style: "{HEADER_CELL_STYLE} text-align: right;", "Stock" }
+                                th { style: "{HEADER_CELL_STYLE} text-align: right;", "Last sold" }
+                            }
+                        }
+                        tbody {
+                            for product in idle.iter() {
+                                tr {
+                                    key: "{product.product_id}",
+                                    style: "border-bottom: 1px solid #e2e8f0;",
+                                    td { style: "padding: 0.5rem;", "{product.product_name}" }
+                                    td { style: NUMBER_CELL_STYLE, "{format_amount(product.stock)}" }
+                                    td {
+                                        style: NUMBER_CELL_STYLE,
+                                        {
+                                            product
+                                                .last_sold_at
+                                                .map(|sold_at| clock.format(&sold_at, "%d-%b-%Y"))
+                                                .unwrap_or_else(|| "Never".to_string())
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                Some(Err(err)) => rsx! {
+                    div {
+                        style: "padding: 1rem; text-align: center; color: #e53e3e; background: #fff5f5; border-radius: 0.5rem;",
+                        "❌ {err}"
+                    }
+                },
+                None => rsx! {
+                    div { style: EMPTY_STYLE, "⏳ Loading..." }
+                }
+            }
+        }
+    }
+}
+
+/// Column header that sorts the table; clicking the active column flips the order
+#[component]
+fn SortHeader(
+    label: &'static str,
+    column: PerformanceSort,
+    sort: Signal<(PerformanceSort, bool)>,
+    align_right: bool,
+) -> Element {
+    let (active, descending) = sort();
+    let arrow = match (active == column, descending) {
+        (true, true) => " ▼",
+        (true, false) => " ▲",
+        (false, _) => "",
+    };
+    let align = if align_right { "right" } else { "left" };
+
+    rsx! {
+        th {
+            style: "{HEADER_CELL_STYLE} text-align: {align}; cursor: pointer; user-select: none;",
+            onclick: move |_| {
+                if active == column {
+                    sort.set((column, !descending));
+                } else {
+                    // Names read best A-Z, figures largest first
+                    sort.set((column, column != PerformanceSort::Name));
+                }
+            },
+            "{label}{arrow}"
+        }
+    }
+}
+
+fn class_style(class: AbcClass) -> &'static str {
+    match class {
+        AbcClass::A => "background: #c6f6d5; color: #22543d;",
+        AbcClass::B => "background: #fefcbf; color: #744210;",
+        AbcClass::C => "background: #fed7d7; color: #c53030;",
+    }
+}
+
+const DATE_INPUT_STYLE: &str =
+    "padding: 0.625rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 1rem;";
+const SECTION_TITLE_STYLE: &str = "font-size: 1.125rem; color: #4a5568; margin: 0 0 0.5rem 0;";
+const HEADER_CELL_STYLE: &str =
+    "padding: 0.5rem; text-align: left; font-weight: 600; color: #4a5568; font-size: 0.875rem;";
+const NUMBER_CELL_STYLE: &str = "padding: 0.5rem; text-align: right; font-family: monospace;";
+const EMPTY_STYLE: &str = "padding: 1rem; text-align: center; color: #a0aec0;";
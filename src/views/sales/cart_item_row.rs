@@ -2,16 +2,29 @@
 //!
 //! Displays a single cart item with product details, quantity, and remove button.
 
-use crate::utils::formatting::format_currency;
+use crate::models::UnitMeasurement;
+use crate::utils::formatting::{format_currency, format_quantity};
 use dioxus::prelude::*;
 use rust_decimal::Decimal;
 
 use super::CartItem;
 
 #[component]
-pub fn CartItemRow(item: CartItem, on_remove: EventHandler<String>) -> Element {
-    let subtotal =
-        item.product.user_price * Decimal::from_f64_retain(item.quantity).unwrap_or_default();
+pub fn CartItemRow(
+    item: CartItem,
+    unit_price: Decimal, // Resolved for the customer and quantity
+    subtotal: Decimal,
+    unit: Option<UnitMeasurement>,
+    on_remove: EventHandler<String>,
+) -> Element {
+    let quantity = match &unit {
+        Some(unit) => format!(
+            "{} {}",
+            format_quantity(item.quantity, unit.quantity_decimals()),
+            unit.abbreviation
+        ),
+        None => item.quantity.to_string(),
+    };
 
     rsx! {
         div {
@@ -25,7 +38,13 @@ pub fn CartItemRow(item: CartItem, on_remove: EventHandler<String>) -> Element {
                 }
                 div {
                     style: "font-size: 0.875rem; color: #718096;",
-                    "{item.quantity} × {format_currency(item.product.user_price)}"
+                    "{quantity} × {format_currency(unit_price)}"
+                    if unit_price < item.product.user_price {
+                        span {
+                            style: "margin-left: 0.5rem; text-decoration: line-through; color: #a0aec0;",
+                            "{format_currency(item.product.user_price)}"
+                        }
+                    }
                 }
             }
 
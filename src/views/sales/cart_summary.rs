@@ -11,6 +11,7 @@ use super::validations::is_valid_payment_amount;
 #[component]
 pub fn CartSummary(
     cart_total: Decimal,
+    points_amount: Decimal, // Part of the total paid with loyalty points
     change_amount: Decimal,
     payment_amount: ReadSignal<String>,
     cart_is_empty: bool,
@@ -30,6 +31,15 @@ pub fn CartSummary(
                 span { style: "font-weight: 700; color: #667eea;", "{format_currency(cart_total)}" }
             }
 
+            // Loyalty points tender
+            if points_amount > Decimal::ZERO {
+                div {
+                    style: "display: flex; justify-content: space-between; margin-bottom: 1rem; font-size: 1rem; color: #b7791f;",
+                    span { style: "font-weight: 500;", "⭐ Paid with points:" }
+                    span { style: "font-weight: 600;", "−{format_currency(points_amount)}" }
+                }
+            }
+
             // Payment input
             div {
                 style: "margin-bottom: 1rem;",
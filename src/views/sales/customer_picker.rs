@@ -0,0 +1,78 @@
+//! Customer Picker Component
+//!
+//! Selects the customer of a sale, whether to attach their tax profile so
+//! the sale can be invoiced to them, and the loyalty points they redeem.
+
+use crate::models::Customer;
+use crate::utils::formatting::format_currency;
+use dioxus::prelude::*;
+use rust_decimal::Decimal;
+
+#[component]
+pub fn CustomerPicker(
+    customers: Vec<Customer>,
+    selected: Signal<Option<Customer>>,
+    attach_tax_profile: Signal<bool>,
+    points_balance: Option<i64>, // None for walk-in customers or with the program off
+    point_value: Decimal,
+    redeem_points: Signal<String>,
+) -> Element {
+    let mut selected = selected;
+    let mut attach_tax_profile = attach_tax_profile;
+    let mut redeem_points = redeem_points;
+    let selected_id = selected().map(|c| c.id).unwrap_or_default();
+    let tax_profile = selected().and_then(|c| c.tax_profile);
+
+    rsx! {
+        div {
+            style: "margin-bottom: 1rem;",
+            select {
+                style: "width: 100%; padding: 0.5rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 0.875rem; background: white;",
+                onchange: move |e| {
+                    let id = e.value();
+                    let customer = customers.iter().find(|c| c.id == id).cloned();
+                    attach_tax_profile.set(false);
+                    redeem_points.set(String::new());
+                    selected.set(customer);
+                },
+                option { value: "", selected: selected_id.is_empty(), "👤 Walk-in customer" }
+                for customer in customers.iter() {
+                    option {
+                        key: "{customer.id}",
+                        value: "{customer.id}",
+                        selected: customer.id == selected_id,
+                        "{customer.name}"
+                    }
+                }
+            }
+            if let Some(profile) = tax_profile {
+                label {
+                    style: "display: flex; align-items: center; gap: 0.5rem; margin-top: 0.5rem; font-size: 0.875rem; color: #4a5568;",
+                    input {
+                        r#type: "checkbox",
+                        checked: attach_tax_profile(),
+                        onchange: move |e| attach_tax_profile.set(e.checked())
+                    }
+                    "Invoice to {profile.rfc}"
+                }
+            }
+            if let Some(balance) = points_balance {
+                div {
+                    style: "display: flex; align-items: center; justify-content: space-between; gap: 0.5rem; margin-top: 0.5rem; font-size: 0.875rem; color: #4a5568;",
+                    span { "⭐ {balance} points ({format_currency(point_value * Decimal::from(balance))})" }
+                    if balance > 0 {
+                        input {
+                            r#type: "number",
+                            min: "0",
+                            max: "{balance}",
+                            placeholder: "Redeem",
+                            style: "width: 90px; padding: 0.375rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 0.875rem;",
+                            value: "{redeem_points}",
+                            oninput: move |e| redeem_points.set(e.value())
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -4,6 +4,7 @@
 
 mod cart_item_row;
 mod cart_summary;
+mod customer_picker;
 mod product_card;
 mod products_list;
 mod quantity_modal;
@@ -14,14 +15,19 @@ mod validations;
 
 pub use cart_item_row::CartItemRow;
 pub use cart_summary::CartSummary;
+pub use customer_picker::CustomerPicker;
 pub use product_card::ProductCard;
 pub use products_list::ProductsList;
 pub use quantity_modal::QuantityModal;
 pub use sale_message::SaleMessage;
-use sale_receipt_modal::SaleReceiptModal;
+pub use sale_receipt_modal::SaleReceiptModal;
 
 use crate::handlers::AppState;
-use crate::models::{LoanInput, Operation, Product, Sale, SaleInput, SaleItemInput};
+use crate::models::{
+    Customer, LoanInput, LoyaltySettings, Operation, PriceBook, Product, Sale, SaleInput,
+    SaleItemInput, UnitMeasurement,
+};
+use crate::views::inventory::CategorySelect;
 use crate::views::loans::LoanForm;
 use dioxus::prelude::*;
 use rust_decimal::Decimal;
@@ -31,23 +37,31 @@ use std::rc::Rc;
 pub struct CartItem {
     pub product: Rc<Product>,
     pub quantity: f64,
+    pub line_total: Option<Decimal>, // Amount printed on a scale label
 }
 
-/// Get unit measurement abbreviation from ID
-fn get_unit_abbreviation(unit_id: i32) -> &'static str {
-    use crate::models::UnitMeasurement;
-    match unit_id {
-        UnitMeasurement::KILOGRAM => "kg",
-        UnitMeasurement::LITER => "lt",
-        UnitMeasurement::UNIT => "unit",
-        UnitMeasurement::PIECE => "pcs",
-        UnitMeasurement::BOX => "box",
-        UnitMeasurement::CAN => "can",
-        UnitMeasurement::BOTTLE => "btl",
-        _ => "unit",
+impl CartItem {
+    /// Unit price for the cart quantity, after price lists and quantity tiers.
+    /// Scale labels keep the regular price they were printed with.
+    pub fn unit_price(&self, prices: &PriceBook) -> Decimal {
+        match self.line_total {
+            Some(_) => self.product.user_price,
+            None => prices.unit_price(&self.product, self.quantity),
+        }
+    }
+
+    pub fn subtotal(&self, prices: &PriceBook) -> Decimal {
+        self.line_total.unwrap_or_else(|| {
+            self.unit_price(prices) * Decimal::from_f64_retain(self.quantity).unwrap_or_default()
+        })
     }
 }
 
+/// Find a unit measurement by ID
+fn find_unit(units: &[UnitMeasurement], unit_id: i32) -> Option<UnitMeasurement> {
+    units.iter().find(|u| u.id == unit_id).cloned()
+}
+
 /// Parse payment amount from string input
 fn parse_payment_amount(payment: &str) -> Result<Decimal, String> {
     if payment.is_empty() {
@@ -60,14 +74,15 @@ fn parse_payment_amount(payment: &str) -> Result<Decimal, String> {
 }
 
 /// Convert cart items to sale item inputs
-fn cart_to_sale_items(cart_items: &[CartItem]) -> Vec<SaleItemInput> {
+fn cart_to_sale_items(cart_items: &[CartItem], prices: &PriceBook) -> Vec<SaleItemInput> {
     cart_items
         .iter()
         .map(|item| SaleItemInput {
             product_id: item.product.id.clone(),
             product_name: item.product.full_name.clone(),
             quantity: item.quantity,
-            unit_price: item.product.user_price,
+            unit_price: item.unit_price(prices),
+            line_total: item.line_total,
         })
         .collect()
 }
@@ -79,6 +94,40 @@ struct SaleSignals {
     cart: Signal<Vec<CartItem>>,
     payment_amount: Signal<String>,
     refresh_trigger: Signal<i32>,
+    customer: Signal<Option<Customer>>,
+    attach_tax_profile: Signal<bool>,
+    redeem_points: Signal<String>,
+    points_tender: Memo<(i64, Decimal)>, // Points redeemed and their value
+    prices: Memo<PriceBook>,
+}
+
+impl SaleSignals {
+    /// Sale input for the cart, with the selected customer and tax profile
+    fn sale_input(&self, cart_items: &[CartItem], paid_amount: Decimal) -> SaleInput {
+        let customer = self.customer.read();
+        SaleInput {
+            items: cart_to_sale_items(cart_items, &self.prices.read()),
+            paid_amount,
+            customer_id: customer.as_ref().map(|c| c.id.clone()),
+            tax_profile: customer
+                .as_ref()
+                .filter(|_| *self.attach_tax_profile.read())
+                .and_then(|c| c.tax_profile.clone()),
+            redeemed_points: self.points_tender.read().0,
+            points_amount: self.points_tender.read().1,
+        }
+    }
+
+    /// Reset the checkout after a completed sale
+    fn clear(&mut self) {
+        self.cart.write().clear();
+        self.payment_amount.set(String::new());
+        self.customer.set(None);
+        self.attach_tax_profile.set(false);
+        self.redeem_points.set(String::new());
+        let current_trigger = *self.refresh_trigger.read();
+        self.refresh_trigger.set(current_trigger + 1);
+    }
 }
 
 /// Additional signals for loan sale processing
@@ -96,18 +145,12 @@ fn process_cash_sale(
     mut signals: SaleSignals,
 ) {
     spawn(async move {
-        let sale_input = SaleInput {
-            items: cart_to_sale_items(&cart_items),
-            paid_amount,
-        };
+        let sale_input = signals.sale_input(&cart_items, paid_amount);
 
         match execute_sale_transaction(app_state, sale_input, None).await {
             Ok((sale, operations)) => {
                 signals.completed_sale.set(Some((sale, operations)));
-                signals.cart.write().clear();
-                signals.payment_amount.set(String::new());
-                let current_trigger = *signals.refresh_trigger.read();
-                signals.refresh_trigger.set(current_trigger + 1);
+                signals.clear();
             }
             Err(err) => {
                 signals.sale_message.set(Some((false, err)));
@@ -126,21 +169,15 @@ fn process_loan_sale(
     mut loan_signals: LoanSaleSignals,
 ) {
     spawn(async move {
-        let sale_input = SaleInput {
-            items: cart_to_sale_items(&cart_items),
-            paid_amount,
-        };
+        let sale_input = sale_signals.sale_input(&cart_items, paid_amount);
 
         match execute_sale_transaction(app_state, sale_input, Some(loan_input)).await {
             Ok((sale, operations)) => {
                 sale_signals.completed_sale.set(Some((sale, operations)));
-                sale_signals.cart.write().clear();
-                sale_signals.payment_amount.set(String::new());
+                sale_signals.clear();
                 loan_signals.debtor_name.set(String::new());
                 loan_signals.debtor_phone.set(String::new());
                 loan_signals.show_loan_form.set(false);
-                let current_trigger = *sale_signals.refresh_trigger.read();
-                sale_signals.refresh_trigger.set(current_trigger + 1);
             }
             Err(err) => {
                 sale_signals.sale_message.set(Some((false, err)));
@@ -197,15 +234,95 @@ pub fn SalesView() -> Element {
     let mut show_loan_form = use_signal(|| false);
     let mut debtor_name = use_signal(String::new);
     let mut debtor_phone = use_signal(String::new);
+    let mut selected_category = use_signal(|| None::<String>);
+    let customer = use_signal(|| None::<Customer>);
+    let attach_tax_profile = use_signal(|| false);
+    let redeem_points = use_signal(String::new);
 
-    // Load products from database
+    // Load products from database (scoped to the selected category)
     let mut products_resource = use_resource({
         let inventory_handler = app_state.inventory_handler.clone();
         move || {
             let handler = inventory_handler.clone();
-            async move { handler.load_products().await }
+            let category_id = selected_category();
+            async move { handler.load_products_by_category(category_id).await }
+        }
+    });
+
+    // Load categories for browsing
+    let categories_resource = use_resource({
+        let inventory_handler = app_state.inventory_handler.clone();
+        move || {
+            let handler = inventory_handler.clone();
+            async move { handler.get_categories().await }
+        }
+    });
+
+    // Load customers for the checkout picker
+    let customers_resource = use_resource({
+        let customers_handler = app_state.customers_handler.clone();
+        move || {
+            let handler = customers_handler.clone();
+            async move { handler.load_customers().await }
+        }
+    });
+
+    // Load the loyalty program rules and the selected customer's points
+    let loyalty_resource = use_resource({
+        let settings_handler = app_state.settings_handler.clone();
+        move || {
+            let handler = settings_handler.clone();
+            async move { handler.get_loyalty_settings().await }
+        }
+    });
+    let loyalty = match &*loyalty_resource.read_unchecked() {
+        Some(Ok(loyalty)) => loyalty.clone(),
+        _ => LoyaltySettings::default(),
+    };
+    let points_resource = use_resource({
+        let loyalty_handler = app_state.loyalty_handler.clone();
+        move || {
+            let handler = loyalty_handler.clone();
+            let customer_id = customer().map(|c| c.id);
+            async move {
+                match customer_id {
+                    Some(id) => handler.get_points_balance(id).await.map(Some),
+                    None => Ok(None),
+                }
+            }
+        }
+    });
+    let points_balance = match &*points_resource.read_unchecked() {
+        Some(Ok(balance)) if loyalty.is_enabled() => *balance,
+        _ => None,
+    };
+
+    // Load the selected customer's price list and the quantity tiers
+    let prices_resource = use_resource({
+        let price_lists_handler = app_state.price_lists_handler.clone();
+        move || {
+            let handler = price_lists_handler.clone();
+            let customer_id = customer().map(|c| c.id);
+            async move { handler.get_price_book(customer_id).await }
+        }
+    });
+    let prices = use_memo(move || match &*prices_resource.read() {
+        Some(Ok(prices)) => prices.clone(),
+        _ => PriceBook::default(),
+    });
+
+    // Load units for abbreviations and fraction rules
+    let units_resource = use_resource({
+        let inventory_handler = app_state.inventory_handler.clone();
+        move || {
+            let handler = inventory_handler.clone();
+            async move { handler.get_units().await }
         }
     });
+    let units = match &*units_resource.read_unchecked() {
+        Some(Ok(units)) => units.clone(),
+        _ => Vec::new(),
+    };
 
     // Refresh products when trigger changes
     use_effect(move || {
@@ -215,19 +332,28 @@ pub fn SalesView() -> Element {
 
     // Calculate cart total (reactive)
     let cart_total = use_memo(move || {
+        let prices = prices.read();
         cart.read()
             .iter()
-            .map(|item| {
-                item.product.user_price
-                    * Decimal::from_f64_retain(item.quantity).unwrap_or_default()
-            })
+            .map(|item| item.subtotal(&prices))
             .sum::<Decimal>()
     });
 
+    // Points the customer is redeeming and what they are worth
+    let points_tender = use_memo(move || {
+        let points = redeem_points.read().trim().parse::<i64>().unwrap_or(0);
+        match &*loyalty_resource.read() {
+            Some(Ok(loyalty)) if customer.read().is_some() && points > 0 => {
+                (points, loyalty.value_of(points))
+            }
+            _ => (0, Decimal::ZERO),
+        }
+    });
+
     // Calculate change (subtotal to return to buyer) - reactive
     let change_amount = use_memo(move || {
         let payment = payment_amount.read();
-        let total = *cart_total.read();
+        let total = *cart_total.read() - points_tender.read().1;
 
         if payment.is_empty() {
             Decimal::ZERO
@@ -250,47 +376,89 @@ pub fn SalesView() -> Element {
         show_quantity_modal.set(Some(product));
     };
 
-    // Add product to cart with specified quantity
-    let add_to_cart_with_quantity = move |(product, quantity): (Product, f64)| {
-        let cart_items_read = cart.read();
+    // Add a cart line; scale labels carry their printed amount and stay on a
+    // line of their own
+    let mut add_to_cart_line =
+        move |product: Product, quantity: f64, line_total: Option<Decimal>| {
+            let cart_items_read = cart.read();
+
+            // Check if quantity exceeds available stock
+            let existing_quantity: f64 = cart_items_read
+                .iter()
+                .filter(|item| item.product.id == product.id)
+                .map(|item| item.quantity)
+                .sum();
+
+            let total_quantity = existing_quantity + quantity;
+
+            // Scanned quantities must also follow the unit's fraction rules,
+            // except labels, whose quantity comes from their printed amount
+            if let Some(Ok(units)) = &*units_resource.read_unchecked() {
+                let unit =
+                    find_unit(units, product.unit_measurement_id).filter(|_| line_total.is_none());
+                if let Some(unit) = unit {
+                    if let Err(err) = unit.check_quantity(quantity) {
+                        sale_message.set(Some((false, format!("{}: {}", product.full_name, err))));
+                        show_quantity_modal.set(None);
+                        return;
+                    }
+                }
+            }
 
-        // Check if quantity exceeds available stock
-        let existing_quantity = cart_items_read
-            .iter()
-            .find(|item| item.product.id == product.id)
-            .map(|item| item.quantity)
-            .unwrap_or(0.0);
+            if total_quantity > product.current_amount {
+                sale_message.set(Some((
+                    false,
+                    format!(
+                        "Cannot add {}. Only {} available (already have {} in cart)",
+                        quantity, product.current_amount, existing_quantity
+                    ),
+                )));
+                show_quantity_modal.set(None);
+                return;
+            }
 
-        let total_quantity = existing_quantity + quantity;
+            // Drop the read guard before writing
+            drop(cart_items_read);
+            let mut cart_items = cart.write();
+
+            // Add or update cart item
+            if let Some(item) = cart_items
+                .iter_mut()
+                .find(|i| i.product.id == product.id && i.line_total.is_none())
+                .filter(|_| line_total.is_none())
+            {
+                item.quantity += quantity;
+            } else {
+                cart_items.push(CartItem {
+                    product: Rc::new(product),
+                    quantity,
+                    line_total,
+                });
+            }
 
-        if total_quantity > product.current_amount {
-            sale_message.set(Some((
-                false,
-                format!(
-                    "Cannot add {}. Only {} available (already have {} in cart)",
-                    quantity, product.current_amount, existing_quantity
-                ),
-            )));
+            // Close modal
             show_quantity_modal.set(None);
-            return;
-        }
-
-        // Drop the read guard before writing
-        drop(cart_items_read);
-        let mut cart_items = cart.write();
+        };
 
-        // Add or update cart item
-        if let Some(item) = cart_items.iter_mut().find(|i| i.product.id == product.id) {
-            item.quantity += quantity;
-        } else {
-            cart_items.push(CartItem {
-                product: Rc::new(product),
-                quantity,
-            });
-        }
+    // Add product to cart with specified quantity
+    let add_to_cart_with_quantity = move |(product, quantity): (Product, f64)| {
+        add_to_cart_line(product, quantity, None);
+    };
 
-        // Close modal
-        show_quantity_modal.set(None);
+    // Scan a barcode: add the product with its pack quantity straight to the cart
+    let scan_handler = app_state.inventory_handler.clone();
+    let scan_barcode = move |barcode: String| {
+        let handler = scan_handler.clone();
+        let mut add_to_cart = add_to_cart_line;
+        spawn(async move {
+            match handler.scan_barcode(barcode).await {
+                Ok(scanned) => {
+                    add_to_cart(scanned.product, scanned.pack_quantity, scanned.line_total);
+                    search_query.set(String::new());
+                }
+                Err(err) => sale_message.set(Some((false, err))),
+            }
+        });
     };
 
     // Remove from cart
@@ -310,6 +478,14 @@ pub fn SalesView() -> Element {
         }
 
         let total = *cart_total.read();
+        let points_amount = points_tender.read().1;
+        if points_amount > total {
+            sale_message.set(Some((
+                false,
+                "Points cannot pay more than the sale total".to_string(),
+            )));
+            return;
+        }
 
         let paid_amount = match parse_payment_amount(&payment_amount.read()) {
             Ok(amount) => amount,
@@ -320,7 +496,14 @@ pub fn SalesView() -> Element {
         };
 
         // Check if this is a loan (payment < total)
-        if paid_amount < total {
+        if paid_amount + points_amount < total {
+            // Known customers don't need their details typed again
+            if let Some(selected) = customer() {
+                if debtor_name.read().trim().is_empty() {
+                    debtor_name.set(selected.name);
+                    debtor_phone.set(selected.phone.unwrap_or_default());
+                }
+            }
             show_loan_form.set(true);
         } else {
             process_cash_sale(
@@ -333,6 +516,11 @@ pub fn SalesView() -> Element {
                     cart,
                     payment_amount,
                     refresh_trigger,
+                    customer,
+                    attach_tax_profile,
+                    redeem_points,
+                    points_tender,
+                    prices,
                 },
             );
         }
@@ -372,6 +560,11 @@ pub fn SalesView() -> Element {
                 cart,
                 payment_amount,
                 refresh_trigger,
+                customer,
+                attach_tax_profile,
+                redeem_points,
+                points_tender,
+                prices,
             },
             LoanSaleSignals {
                 debtor_name,
@@ -395,16 +588,41 @@ pub fn SalesView() -> Element {
                     "💼 New Sale"
                 }
 
-                // Product search
-                input {
-                    r#type: "text",
-                    placeholder: "🔍 Search products or scan barcode...",
-                    value: "{search_query}",
-                    oninput: move |evt| search_query.set(evt.value()),
-                    style: "width: 100%; padding: 0.75rem; border: 2px solid #e2e8f0; border-radius: 0.5rem; font-size: 1rem; margin-bottom: 1.5rem; box-sizing: border-box;",
+                // Product search and category browsing
+                div {
+                    style: "display: grid; grid-template-columns: 1fr 220px; gap: 1rem; margin-bottom: 1.5rem;",
+
+                    input {
+                        r#type: "text",
+                        placeholder: "🔍 Search products or scan barcode...",
+                        value: "{search_query}",
+                        oninput: move |evt| search_query.set(evt.value()),
+                        onkeydown: move |evt| {
+                            let query = search_query().trim().to_string();
+                            if evt.key() == Key::Enter && !query.is_empty() {
+                                scan_barcode(query);
+                            }
+                        },
+                        style: "width: 100%; padding: 0.75rem; border: 2px solid #e2e8f0; border-radius: 0.5rem; font-size: 1rem; box-sizing: border-box;",
+                    }
+
+                    {
+                        let categories = match &*categories_resource.read_unchecked() {
+                            Some(Ok(categories)) => categories.clone(),
+                            _ => Vec::new(),
+                        };
+                        rsx! {
+                            CategorySelect {
+                                categories,
+                                selected: selected_category(),
+                                empty_label: "All categories",
+                                on_change: move |category_id| selected_category.set(category_id),
+                            }
+                        }
+                    }
                 }
 
-                // Products list - only shows when search is active
+                // Products list - shows when searching or browsing a category
                 {
                     let products = match &*products_resource.read_unchecked() {
                         Some(Ok(products)) => products.clone(),
@@ -413,8 +631,10 @@ pub fn SalesView() -> Element {
                     rsx! {
                         ProductsList {
                             products: Signal::new(products),
+                            units: units.clone(),
                             cart_items: cart,
                             search_query: search_query,
+                            is_browsing: selected_category().is_some(),
                             on_add: move |p: Product| show_product_modal(p),
                         }
                     }
@@ -453,15 +673,37 @@ pub fn SalesView() -> Element {
                         for item in cart.read().iter() {
                             CartItemRow {
                                 item: item.clone(),
+                                unit_price: item.unit_price(&prices.read()),
+                                subtotal: item.subtotal(&prices.read()),
+                                unit: find_unit(&units, item.product.unit_measurement_id),
                                 on_remove: move |id: String| remove_from_cart(id),
                             }
                         }
                     }
                 }
 
+                // Customer
+                {
+                    let customers = match &*customers_resource.read_unchecked() {
+                        Some(Ok(customers)) => customers.clone(),
+                        _ => Vec::new(),
+                    };
+                    rsx! {
+                        CustomerPicker {
+                            customers,
+                            selected: customer,
+                            attach_tax_profile,
+                            points_balance,
+                            point_value: loyalty.point_value,
+                            redeem_points,
+                        }
+                    }
+                }
+
                 // Cart summary
                 CartSummary {
                     cart_total: *cart_total.read(),
+                    points_amount: points_tender.read().1,
                     change_amount: *change_amount.read(),
                     payment_amount: payment_amount,
                     cart_is_empty: cart.read().is_empty(),
@@ -475,7 +717,7 @@ pub fn SalesView() -> Element {
         if let Some(product) = show_quantity_modal.read().as_ref() {
             QuantityModal {
                 product: product.clone(),
-                unit_abbreviation: get_unit_abbreviation(product.unit_measurement_id).to_string(),
+                unit: find_unit(&units, product.unit_measurement_id),
                 on_confirm: add_to_cart_with_quantity,
                 on_cancel: move |_| show_quantity_modal.set(None),
             }
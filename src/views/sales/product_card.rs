@@ -2,29 +2,34 @@
 //!
 //! Displays a product card in the sales view with product information and add to cart functionality.
 
-use crate::models::Product;
-use crate::utils::formatting::format_currency;
+use crate::models::{Product, UnitMeasurement};
+use crate::utils::formatting::{format_currency, format_quantity};
 use dioxus::prelude::*;
 
-use super::{get_unit_abbreviation, CartItem};
+use super::CartItem;
 
 #[component]
 pub fn ProductCard(
     product: Product,
+    unit: Option<UnitMeasurement>,
     cart_items: ReadSignal<Vec<CartItem>>,
     on_add: EventHandler<Product>,
 ) -> Element {
     // Calculate remaining stock (current stock - quantity in cart)
-    let quantity_in_cart = cart_items
+    let quantity_in_cart: f64 = cart_items
         .read()
         .iter()
-        .find(|item| item.product.id == product.id)
+        .filter(|item| item.product.id == product.id)
         .map(|item| item.quantity)
-        .unwrap_or(0.0);
+        .sum();
 
     let remaining_stock = product.current_amount - quantity_in_cart;
     let is_low_stock = remaining_stock <= product.min_amount;
-    let unit_abbr = get_unit_abbreviation(product.unit_measurement_id);
+    let unit_abbr = unit.as_ref().map_or("unit", |u| u.abbreviation.as_str());
+    let stock_label = format_quantity(
+        remaining_stock,
+        unit.as_ref().map_or(2, |u| u.quantity_decimals()),
+    );
 
     rsx! {
         div {
@@ -43,7 +48,7 @@ pub fn ProductCard(
 
             div {
                 style: "font-size: 0.875rem; color: #718096;",
-                "Stock: {remaining_stock:.2} {unit_abbr}"
+                "Stock: {stock_label} {unit_abbr}"
             }
 
             if is_low_stock {
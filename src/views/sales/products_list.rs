@@ -1,25 +1,30 @@
 //! Products List Component
 //!
-//! Displays a grid of available products with search functionality.
+//! Displays a grid of available products with search and category browsing.
 
-use crate::models::Product;
+use crate::models::{Product, UnitMeasurement};
 use dioxus::prelude::*;
 
-use super::{CartItem, ProductCard};
+use super::{find_unit, CartItem, ProductCard};
 
 #[component]
 pub fn ProductsList(
     products: ReadSignal<Vec<Product>>,
+    units: Vec<UnitMeasurement>,
     cart_items: ReadSignal<Vec<CartItem>>,
     search_query: ReadSignal<String>,
+    is_browsing: bool,
     on_add: EventHandler<Product>,
 ) -> Element {
     // Filter products based on search query - use memo to avoid re-filtering
-    let filtered_products = use_memo(move || {
+    let filtered_products = use_memo(use_reactive!(|is_browsing| {
         let query = search_query.read();
         let products_read = products.read();
 
-        if query.is_empty() {
+        if query.is_empty() && is_browsing {
+            // Browsing a category: show all of its products
+            products_read.clone()
+        } else if query.is_empty() {
             Vec::new()
         } else {
             let query_lower = query.to_lowercase();
@@ -33,13 +38,13 @@ pub fn ProductsList(
                 .cloned()
                 .collect()
         }
-    });
+    }));
 
     rsx! {
         div {
             style: "display: grid; grid-template-columns: repeat(auto-fill, minmax(200px, 1fr)); gap: 1rem; max-height: 600px; overflow-y: auto;",
 
-            if !search_query.read().is_empty() && filtered_products.read().is_empty() {
+            if (is_browsing || !search_query.read().is_empty()) && filtered_products.read().is_empty() {
                 div {
                     style: "grid-column: 1 / -1; padding: 2rem; text-align: center; color: #718096;",
                     "No products found"
@@ -48,6 +53,7 @@ pub fn ProductsList(
                 for product in filtered_products.read().iter() {
                     ProductCard {
                         product: product.clone(),
+                        unit: find_unit(&units, product.unit_measurement_id),
                         cart_items: cart_items,
                         on_add: move |p: Product| on_add.call(p),
                     }
@@ -2,8 +2,8 @@
 //!
 //! Modal dialog for entering product quantity when adding to cart.
 
-use crate::models::Product;
-use crate::utils::formatting::format_currency;
+use crate::models::{Product, UnitMeasurement};
+use crate::utils::formatting::{format_currency, format_quantity};
 use dioxus::prelude::*;
 
 use super::validations::is_valid_quantity;
@@ -11,10 +11,26 @@ use super::validations::is_valid_quantity;
 #[component]
 pub fn QuantityModal(
     product: Product,
-    unit_abbreviation: String,
+    unit: Option<UnitMeasurement>,
     on_confirm: EventHandler<(Product, f64)>,
     on_cancel: EventHandler<()>,
 ) -> Element {
+    // Unknown units keep the previous 3-decimal limit
+    let decimals = unit.as_ref().map_or(3, |u| u.quantity_decimals());
+    let unit_abbreviation = unit
+        .as_ref()
+        .map_or("unit".to_string(), |u| u.abbreviation.clone());
+    let available = format_quantity(product.current_amount, decimals);
+    let placeholder = format_quantity(1.0, decimals);
+    let invalid_message = if decimals == 0 {
+        "⚠️ Enter a whole quantity not exceeding stock".to_string()
+    } else {
+        format!(
+            "⚠️ Enter a valid quantity (max {} decimals, not exceeding stock)",
+            decimals
+        )
+    };
+
     let mut quantity_input = use_signal(|| String::from("1"));
     let mut has_invalid_input = use_signal(|| false);
 
@@ -51,7 +67,7 @@ pub fn QuantityModal(
                     }
                     div {
                         style: "color: #718096; font-size: 0.875rem; margin-top: 0.5rem;",
-                        "Available: {product.current_amount:.3} {unit_abbreviation}"
+                        "Available: {available} {unit_abbreviation}"
                     }
                 }
 
@@ -64,14 +80,15 @@ pub fn QuantityModal(
                     }
                     input {
                         r#type: "text",
-                        inputmode: "decimal",
-                        placeholder: "1.000",
+                        inputmode: if decimals == 0 { "numeric" } else { "decimal" },
+                        placeholder: "{placeholder}",
                         value: "{quantity_input}",
                         autofocus: true,
                         oninput: move |evt| {
                             let value = evt.value();
                             // Allow partial input for better UX
-                            if value.is_empty() || value == "." || value.ends_with('.') || is_valid_quantity(&value) {
+                            let partial = decimals > 0 && (value == "." || value.ends_with('.'));
+                            if value.is_empty() || partial || is_valid_quantity(&value, decimals) {
                                 has_invalid_input.set(false);
                                 quantity_input.set(value);
                             } else {
@@ -82,7 +99,7 @@ pub fn QuantityModal(
                             if evt.key() == Key::Enter {
                                 let quantity_str = quantity_input.read();
                                 if let Ok(quantity) = quantity_str.parse::<f64>() {
-                                    if is_valid_quantity(&quantity_str) {
+                                    if is_valid_quantity(&quantity_str, decimals) {
                                         on_confirm.call((product_for_keydown.clone(), quantity));
                                     } else {
                                         has_invalid_input.set(true);
@@ -101,7 +118,7 @@ pub fn QuantityModal(
                     if *has_invalid_input.read() {
                         div {
                             style: "margin-top: 0.5rem; font-size: 0.875rem; color: #c53030;",
-                            "⚠️ Enter a valid quantity (max 3 decimals, not exceeding stock)"
+                            "{invalid_message}"
                         }
                     }
                 }
@@ -119,7 +136,7 @@ pub fn QuantityModal(
                         onclick: move |_| {
                             let quantity_str = quantity_input.read();
                             if let Ok(quantity) = quantity_str.parse::<f64>() {
-                                if is_valid_quantity(&quantity_str) {
+                                if is_valid_quantity(&quantity_str, decimals) {
                                     on_confirm.call((product_for_button.clone(), quantity));
                                 } else {
                                     has_invalid_input.set(true);
@@ -1,6 +1,6 @@
 //! Receipt PDF generation with Typst templates
 
-use crate::models::{Operation, Sale};
+use crate::models::{Operation, Sale, SalePoints};
 use crate::utils::formatting::format_currency;
 use crate::utils::pdf::{compile_typst_to_pdf, escape_typst};
 use sailfish::TemplateOnce;
@@ -14,8 +14,10 @@ struct ReceiptTemplate {
     items: Vec<ReceiptItem>,
     total: String,
     paid: String,
+    points_paid: Option<String>,
     change_amount: Option<String>,
     amount_owed: Option<String>,
+    points: Option<SalePoints>,
 }
 
 struct ReceiptItem {
@@ -29,6 +31,7 @@ pub fn generate_receipt_pdf(
     sale: &Sale,
     operations: &[Operation],
     formatted_date: &str,
+    points: Option<&SalePoints>,
     file_path: std::path::PathBuf,
 ) -> Result<(), Box<dyn std::error::Error>> {
     // Prepare template data
@@ -36,7 +39,7 @@ pub fn generate_receipt_pdf(
         .iter()
         .map(|op| ReceiptItem {
             product_name: escape_typst(&op.product_name),
-            quantity: format!("{:.3}", op.quantity),
+            quantity: escape_typst(&op.formatted_quantity()),
             price: escape_typst(&format_currency(op.unit_price)),
             subtotal: escape_typst(&format_currency(op.subtotal)),
         })
@@ -56,6 +59,12 @@ pub fn generate_receipt_pdf(
         None
     };
 
+    let points_paid = if sale.points_amount > rust_decimal::Decimal::ZERO {
+        Some(escape_typst(&format_currency(sale.points_amount)))
+    } else {
+        None
+    };
+
     let template = ReceiptTemplate {
         receipt_id: escape_typst(&sale.id),
         date: escape_typst(formatted_date),
@@ -63,8 +72,10 @@ pub fn generate_receipt_pdf(
         items,
         total: escape_typst(&format_currency(sale.total_amount)),
         paid: escape_typst(&format_currency(sale.paid_amount)),
+        points_paid,
         change_amount,
         amount_owed,
+        points: points.cloned(),
     };
 
     let typst_content = template.render_once()?;
@@ -2,9 +2,10 @@
 //!
 //! Modal dialog for displaying sale receipts with print functionality.
 
+use crate::handlers::AppState;
 use crate::models::{Operation, Sale};
 use crate::utils::formatting::format_currency;
-use chrono_tz::America::Mexico_City;
+use crate::views::app::use_store_clock;
 use dioxus::prelude::*;
 
 #[component]
@@ -12,17 +13,33 @@ pub fn SaleReceiptModal(
     sale: Sale,
     operations: Vec<Operation>,
     on_close: EventHandler<()>,
+    // Past sale opened from the history rather than one just completed
+    #[props(default)] reprint: bool,
 ) -> Element {
-    let formatted_date = sale
-        .sold_at
-        .with_timezone(&Mexico_City)
-        .format("%d-%b-%Y %H:%M")
-        .to_string();
+    let clock = use_store_clock();
+    let formatted_date = clock.format(&sale.sold_at, "%d-%b-%Y %H:%M");
+    let app_state = use_context::<AppState>();
+
+    // Loyalty points earned and redeemed on this sale
+    let points_resource = use_resource({
+        let loyalty_handler = app_state.loyalty_handler.clone();
+        let sale = sale.clone();
+        move || {
+            let handler = loyalty_handler.clone();
+            let sale = sale.clone();
+            async move { handler.get_sale_points(sale).await }
+        }
+    });
+    let points = match &*points_resource.read_unchecked() {
+        Some(Ok(points)) => points.clone(),
+        _ => None,
+    };
 
     // Clone values for print handler
     let sale_clone = sale.clone();
     let operations_clone = operations.clone();
     let date_clone = formatted_date.clone();
+    let points_clone = points.clone();
 
     // Print handler
     let print_receipt = move |_| {
@@ -40,7 +57,11 @@ pub fn SaleReceiptModal(
                 .save_file()
             {
                 if let Err(e) = super::receipt_template::generate_receipt_pdf(
-                    sale_ref, ops_ref, date_ref, file_path,
+                    sale_ref,
+                    ops_ref,
+                    date_ref,
+                    points_clone.as_ref(),
+                    file_path,
                 ) {
                     tracing::error!("Failed to generate receipt: {}", e);
                 }
@@ -75,15 +96,17 @@ pub fn SaleReceiptModal(
                 }
 
                 // Success message
-                div {
-                    style: "background: #f0fff4; color: #22543d; padding: 1rem; border-radius: 0.5rem; margin-bottom: 1.5rem; border: 1px solid #48bb78; text-align: center;",
+                if !reprint {
                     div {
-                        style: "font-size: 2rem; margin-bottom: 0.5rem;",
-                        "✅"
-                    }
-                    div {
-                        style: "font-weight: 600; font-size: 1.125rem;",
-                        "Sale Completed Successfully!"
+                        style: "background: #f0fff4; color: #22543d; padding: 1rem; border-radius: 0.5rem; margin-bottom: 1.5rem; border: 1px solid #48bb78; text-align: center;",
+                        div {
+                            style: "font-size: 2rem; margin-bottom: 0.5rem;",
+                            "✅"
+                        }
+                        div {
+                            style: "font-weight: 600; font-size: 1.125rem;",
+                            "Sale Completed Successfully!"
+                        }
                     }
                 }
 
@@ -139,7 +162,7 @@ pub fn SaleReceiptModal(
                                 tr {
                                     style: "border-bottom: 1px solid #e2e8f0;",
                                     td { style: "padding: 0.75rem;", "{operation.product_name}" }
-                                    td { style: "padding: 0.75rem; text-align: right; font-family: monospace;", "{operation.quantity:.3}" }
+                                    td { style: "padding: 0.75rem; text-align: right; font-family: monospace;", {operation.formatted_quantity()} }
                                     td { style: "padding: 0.75rem; text-align: right; font-family: monospace;", "{format_currency(operation.unit_price)}" }
                                     td { style: "padding: 0.75rem; text-align: right; font-weight: 500; font-family: monospace;", "{format_currency(operation.subtotal)}" }
                                 }
@@ -161,6 +184,13 @@ pub fn SaleReceiptModal(
                         span { style: "font-weight: 500; color: #4a5568;", "Paid:" }
                         span { style: "color: #48bb78; font-weight: 600; font-family: monospace;", "{format_currency(sale.paid_amount)}" }
                     }
+                    if sale.points_amount > rust_decimal::Decimal::ZERO {
+                        div {
+                            style: "display: flex; justify-content: space-between; margin-bottom: 0.5rem;",
+                            span { style: "font-weight: 500; color: #4a5568;", "Paid with points:" }
+                            span { style: "color: #b7791f; font-weight: 600; font-family: monospace;", "{format_currency(sale.points_amount)}" }
+                        }
+                    }
                     if sale.change_amount > rust_decimal::Decimal::ZERO {
                         div {
                             style: "display: flex; justify-content: space-between; margin-bottom: 0.5rem;",
@@ -177,6 +207,16 @@ pub fn SaleReceiptModal(
                     }
                 }
 
+                // Loyalty points
+                if let Some(points) = points {
+                    div {
+                        style: "background: #fffff0; border: 1px solid #ecc94b; padding: 1rem; border-radius: 0.5rem; margin-bottom: 1.5rem; display: flex; justify-content: space-between; font-size: 0.875rem; color: #4a5568;",
+                        span { "⭐ Earned: {points.earned}" }
+                        span { "Redeemed: {points.redeemed}" }
+                        span { style: "font-weight: 600;", "Balance: {points.balance}" }
+                    }
+                }
+
                 // Action buttons
                 div {
                     style: "display: flex; gap: 1rem;",
@@ -57,17 +57,17 @@ pub fn is_valid_payment_amount(value: &str) -> bool {
     }
 }
 
-/// Validates if a quantity string is valid.
+/// Validates if a quantity string is valid for a unit.
 ///
 /// Valid formats:
-/// - Positive numbers with max 3 decimal places
-/// - Examples: "1", "0.5", "2.250", "10.125"
+/// - Positive numbers with at most `max_decimals` decimal places
+/// - Examples with 3 decimals: "1", "0.5", "2.250", "10.125"
 ///
 /// Invalid formats:
 /// - Zero or negative: "0", "-1"
-/// - More than 3 decimals: "1.2345"
+/// - Too many decimals: "1.2345" (or "1.5" for whole units)
 /// - Non-numeric: "abc"
-pub fn is_valid_quantity(value: &str) -> bool {
+pub fn is_valid_quantity(value: &str, max_decimals: u32) -> bool {
     // Empty string not allowed for quantity
     if value.is_empty() {
         return false;
@@ -90,10 +90,10 @@ pub fn is_valid_quantity(value: &str) -> bool {
         return false;
     }
 
-    // Check decimal places (max 3)
+    // Check decimal places (whole units allow no decimal point at all)
     if let Some(decimal_pos) = value.find('.') {
         let decimal_part = &value[decimal_pos + 1..];
-        if decimal_part.len() > 3 {
+        if max_decimals == 0 || decimal_part.len() > max_decimals as usize {
             return false;
         }
     }
@@ -139,22 +139,27 @@ mod tests {
 
     #[test]
     fn test_valid_quantities() {
-        assert!(is_valid_quantity("1"));
-        assert!(is_valid_quantity("0.5"));
-        assert!(is_valid_quantity("2.250"));
-        assert!(is_valid_quantity("10.125"));
-        assert!(is_valid_quantity("100"));
-        assert!(is_valid_quantity("0.001"));
+        assert!(is_valid_quantity("1", 3));
+        assert!(is_valid_quantity("0.5", 3));
+        assert!(is_valid_quantity("2.250", 3));
+        assert!(is_valid_quantity("10.125", 3));
+        assert!(is_valid_quantity("100", 3));
+        assert!(is_valid_quantity("0.001", 3));
+        assert!(is_valid_quantity("2", 0)); // Whole units
+        assert!(is_valid_quantity("1.5", 1));
     }
 
     #[test]
     fn test_invalid_quantities() {
-        assert!(!is_valid_quantity("")); // Empty
-        assert!(!is_valid_quantity("0")); // Zero
-        assert!(!is_valid_quantity("-1")); // Negative
-        assert!(!is_valid_quantity("1.2345")); // More than 3 decimals
-        assert!(!is_valid_quantity("abc")); // Non-numeric
-        assert!(!is_valid_quantity(".")); // Just decimal
-        assert!(!is_valid_quantity("-0.5")); // Negative
+        assert!(!is_valid_quantity("", 3)); // Empty
+        assert!(!is_valid_quantity("0", 3)); // Zero
+        assert!(!is_valid_quantity("-1", 3)); // Negative
+        assert!(!is_valid_quantity("1.2345", 3)); // More than 3 decimals
+        assert!(!is_valid_quantity("abc", 3)); // Non-numeric
+        assert!(!is_valid_quantity(".", 3)); // Just decimal
+        assert!(!is_valid_quantity("-0.5", 3)); // Negative
+        assert!(!is_valid_quantity("1.5", 0)); // Fraction of a whole unit
+        assert!(!is_valid_quantity("2.", 0)); // Decimal point on a whole unit
+        assert!(!is_valid_quantity("1.25", 1)); // More decimals than the unit
     }
 }
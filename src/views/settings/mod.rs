@@ -0,0 +1,483 @@
+//! Settings Module
+//!
+//! UI components for store-wide configuration.
+
+use crate::handlers::AppState;
+use crate::models::invoice::TAX_REGIMES;
+use crate::models::pricing::format_price_endings;
+use crate::models::scale_barcode::format_scale_formats;
+use crate::models::{CategoryPath, InvoiceIssuer, LoyaltySettings, ReorderPolicy, StoreClock};
+use dioxus::prelude::*;
+
+#[component]
+pub fn SettingsView() -> Element {
+    let app_state = use_context::<AppState>();
+
+    let mut price_endings = use_signal(String::new);
+    let mut scale_formats = use_signal(String::new);
+    let mut window_days = use_signal(String::new);
+    let mut coverage_days = use_signal(String::new);
+    let mut safety_factor = use_signal(String::new);
+    let mut timezone = use_signal(String::new);
+    let mut day_cutoff_hour = use_signal(|| 0u32);
+    let mut store_clock = use_context::<Signal<StoreClock>>();
+    let mut issuer = use_signal(InvoiceIssuer::default);
+    let mut amount_per_point = use_signal(String::new);
+    let mut point_value = use_signal(String::new);
+    let mut expiry_days = use_signal(String::new);
+    let mut excluded_categories = use_signal(Vec::<String>::new);
+    let mut categories = use_signal(Vec::<CategoryPath>::new);
+    let mut message = use_signal(|| Option::<(bool, String)>::None);
+
+    // Load current settings
+    let load_handler = app_state.settings_handler.clone();
+    let inventory_handler = app_state.inventory_handler.clone();
+    use_hook(move || {
+        spawn(async move {
+            match load_handler.get_price_endings().await {
+                Ok(endings) => price_endings.set(format_price_endings(&endings)),
+                Err(err) => message.set(Some((false, err))),
+            }
+            match load_handler.get_scale_formats().await {
+                Ok(formats) => scale_formats.set(format_scale_formats(&formats)),
+                Err(err) => message.set(Some((false, err))),
+            }
+            match load_handler.get_reorder_policy().await {
+                Ok(policy) => {
+                    window_days.set(policy.window_days.to_string());
+                    coverage_days.set(policy.coverage_days.to_string());
+                    safety_factor.set(policy.safety_factor.to_string());
+                }
+                Err(err) => message.set(Some((false, err))),
+            }
+            match load_handler.get_store_clock().await {
+                Ok(clock) => {
+                    timezone.set(clock.timezone_name().to_string());
+                    day_cutoff_hour.set(clock.day_cutoff_hour);
+                }
+                Err(err) => message.set(Some((false, err))),
+            }
+            match load_handler.get_invoice_issuer().await {
+                Ok(loaded) => issuer.set(loaded),
+                Err(err) => message.set(Some((false, err))),
+            }
+            match load_handler.get_loyalty_settings().await {
+                Ok(loyalty) => {
+                    amount_per_point.set(loyalty.amount_per_point.to_string());
+                    point_value.set(loyalty.point_value.to_string());
+                    expiry_days.set(loyalty.expiry_days.to_string());
+                    excluded_categories.set(loyalty.excluded_category_ids);
+                }
+                Err(err) => message.set(Some((false, err))),
+            }
+            match inventory_handler.get_categories().await {
+                Ok(loaded) => categories.set(loaded),
+                Err(err) => message.set(Some((false, err))),
+            }
+        });
+    });
+
+    let save_handler = app_state.settings_handler.clone();
+    let handle_save = move |_| {
+        let handler = save_handler.clone();
+        let value = price_endings();
+        let formats = scale_formats();
+        let policy = parse_reorder_policy(&window_days(), &coverage_days(), &safety_factor());
+        let clock = StoreClock::new(&timezone(), day_cutoff_hour());
+        let invoice_issuer = issuer();
+        let loyalty = parse_loyalty_settings(
+            &amount_per_point(),
+            &point_value(),
+            &expiry_days(),
+            excluded_categories(),
+        );
+
+        spawn(async move {
+            let result = async {
+                let (policy, clock, loyalty) = (policy?, clock?, loyalty?);
+                let endings = handler.save_price_endings(value).await?;
+                let formats = handler.save_scale_formats(formats).await?;
+                handler.save_reorder_policy(policy).await?;
+                let clock = handler.save_store_clock(clock).await?;
+                let invoice_issuer = handler.save_invoice_issuer(invoice_issuer).await?;
+                handler.save_loyalty_settings(loyalty).await?;
+                Ok::<_, String>((endings, formats, clock, invoice_issuer))
+            }
+            .await;
+
+            match result {
+                Ok((endings, formats, clock, invoice_issuer)) => {
+                    price_endings.set(format_price_endings(&endings));
+                    scale_formats.set(format_scale_formats(&formats));
+                    store_clock.set(clock);
+                    issuer.set(invoice_issuer);
+                    message.set(Some((true, "Settings saved".to_string())));
+                }
+                Err(err) => message.set(Some((false, err))),
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            class: "settings-view",
+            style: "background: white; border-radius: 0.5rem; padding: 1.5rem; box-shadow: 0 1px 3px rgba(0,0,0,0.1);",
+
+            h2 {
+                style: "font-size: 1.5rem; font-weight: 600; color: #2d3748; margin: 0 0 1.5rem 0;",
+                "⚙️ Settings"
+            }
+
+            if let Some((success, text)) = message() {
+                div {
+                    style: if success {
+                        "background: #f0fff4; color: #276749; padding: 0.75rem; border-radius: 0.25rem; margin-bottom: 1rem;"
+                    } else {
+                        "background: #fff5f5; color: #c53030; padding: 0.75rem; border-radius: 0.25rem; margin-bottom: 1rem;"
+                    },
+                    "{text}"
+                }
+            }
+
+            // Pricing
+            h3 { style: "font-size: 1.125rem; color: #4a5568; margin: 0 0 1rem 0;", "Pricing" }
+            div {
+                style: "max-width: 400px; margin-bottom: 1.5rem;",
+                label { style: LABEL_STYLE, "Price endings" }
+                input {
+                    r#type: "text",
+                    placeholder: ".50, .90",
+                    style: INPUT_STYLE,
+                    value: "{price_endings}",
+                    oninput: move |e| price_endings.set(e.value())
+                }
+                div {
+                    style: "margin-top: 0.25rem; color: #718096; font-size: 0.75rem;",
+                    "Bulk price updates round up to the closest ending. Leave empty to round to cents."
+                }
+            }
+
+            // Scanning
+            h3 { style: "font-size: 1.125rem; color: #4a5568; margin: 0 0 1rem 0;", "Scanning" }
+            div {
+                style: "max-width: 400px; margin-bottom: 1.5rem;",
+                label { style: LABEL_STYLE, "Scale barcode formats" }
+                input {
+                    r#type: "text",
+                    placeholder: "20PPPPPWWWWWC, 22PPPPP$$$$$C",
+                    style: INPUT_STYLE,
+                    value: "{scale_formats}",
+                    oninput: move |e| scale_formats.set(e.value())
+                }
+                div {
+                    style: "margin-top: 0.25rem; color: #718096; font-size: 0.75rem;",
+                    "EAN-13 codes printed by the scale: prefix digits, P for the PLU, W for grams or $ for cents, C for the check digit. The PLU is looked up as the product's barcode."
+                }
+            }
+
+            // Reordering
+            h3 { style: "font-size: 1.125rem; color: #4a5568; margin: 0 0 1rem 0;", "Reordering" }
+            div {
+                style: "display: grid; grid-template-columns: repeat(3, 1fr); gap: 1rem; max-width: 600px; margin-bottom: 0.25rem;",
+                div {
+                    label { style: LABEL_STYLE, "Sales window (days)" }
+                    input {
+                        r#type: "number",
+                        min: "1",
+                        style: INPUT_STYLE,
+                        value: "{window_days}",
+                        oninput: move |e| window_days.set(e.value())
+                    }
+                }
+                div {
+                    label { style: LABEL_STYLE, "Coverage (days)" }
+                    input {
+                        r#type: "number",
+                        min: "0",
+                        style: INPUT_STYLE,
+                        value: "{coverage_days}",
+                        oninput: move |e| coverage_days.set(e.value())
+                    }
+                }
+                div {
+                    label { style: LABEL_STYLE, "Safety factor" }
+                    input {
+                        r#type: "number",
+                        min: "1",
+                        step: "0.1",
+                        style: INPUT_STYLE,
+                        value: "{safety_factor}",
+                        oninput: move |e| safety_factor.set(e.value())
+                    }
+                }
+            }
+            div {
+                style: "max-width: 600px; margin-bottom: 1.5rem; color: #718096; font-size: 0.75rem;",
+                "Reorder suggestions average sales over the window and order enough for the supplier's lead time plus the coverage days, multiplied by the safety factor."
+            }
+
+            // Business day
+            h3 { style: "font-size: 1.125rem; color: #4a5568; margin: 0 0 1rem 0;", "Business Day" }
+            div {
+                style: "display: grid; grid-template-columns: 2fr 1fr; gap: 1rem; max-width: 600px; margin-bottom: 0.25rem;",
+                div {
+                    label { style: LABEL_STYLE, "Store timezone" }
+                    input {
+                        r#type: "text",
+                        list: "timezone-options",
+                        placeholder: "America/Mexico_City",
+                        style: INPUT_STYLE,
+                        value: "{timezone}",
+                        oninput: move |e| timezone.set(e.value())
+                    }
+                    datalist {
+                        id: "timezone-options",
+                        for zone in chrono_tz::TZ_VARIANTS.iter() {
+                            option { key: "{zone.name()}", value: "{zone.name()}" }
+                        }
+                    }
+                }
+                div {
+                    label { style: LABEL_STYLE, "Day ends at" }
+                    select {
+                        style: INPUT_STYLE,
+                        value: "{day_cutoff_hour}",
+                        onchange: move |e| day_cutoff_hour.set(e.value().parse().unwrap_or(0)),
+                        for hour in 0..=StoreClock::MAX_CUTOFF_HOUR {
+                            option {
+                                key: "{hour}",
+                                value: "{hour}",
+                                selected: hour == day_cutoff_hour(),
+                                {cutoff_label(hour)}
+                            }
+                        }
+                    }
+                }
+            }
+            div {
+                style: "max-width: 600px; margin-bottom: 1.5rem; color: #718096; font-size: 0.75rem;",
+                "Times on screen and on receipts use the store timezone. Sales made after midnight but before the day ends count toward the previous day in reports, the dashboard and today's totals."
+            }
+
+            // Invoicing
+            h3 { style: "font-size: 1.125rem; color: #4a5568; margin: 0 0 1rem 0;", "Invoicing" }
+            div {
+                style: "display: grid; grid-template-columns: 1fr 2fr; gap: 1rem; max-width: 600px; margin-bottom: 1rem;",
+                div {
+                    label { style: LABEL_STYLE, "RFC" }
+                    input {
+                        r#type: "text",
+                        style: INPUT_STYLE,
+                        value: "{issuer.read().rfc}",
+                        oninput: move |e| issuer.write().rfc = e.value().to_uppercase()
+                    }
+                }
+                div {
+                    label { style: LABEL_STYLE, "Legal name" }
+                    input {
+                        r#type: "text",
+                        style: INPUT_STYLE,
+                        value: "{issuer.read().legal_name}",
+                        oninput: move |e| issuer.write().legal_name = e.value()
+                    }
+                }
+            }
+            div {
+                style: "display: grid; grid-template-columns: 2fr 1fr; gap: 1rem; max-width: 600px; margin-bottom: 1rem;",
+                div {
+                    label { style: LABEL_STYLE, "Tax regime" }
+                    select {
+                        style: INPUT_STYLE,
+                        onchange: move |e| issuer.write().tax_regime = e.value(),
+                        option { value: "", selected: issuer.read().tax_regime.is_empty(), "Select..." }
+                        for (code, description) in TAX_REGIMES.iter() {
+                            option {
+                                key: "{code}",
+                                value: "{code}",
+                                selected: issuer.read().tax_regime == *code,
+                                "{code} - {description}"
+                            }
+                        }
+                    }
+                }
+                div {
+                    label { style: LABEL_STYLE, "ZIP code of issue" }
+                    input {
+                        r#type: "text",
+                        maxlength: "5",
+                        style: INPUT_STYLE,
+                        value: "{issuer.read().zip_code}",
+                        oninput: move |e| issuer.write().zip_code = e.value()
+                    }
+                }
+            }
+            div {
+                style: "display: grid; grid-template-columns: 1fr 2fr; gap: 1rem; max-width: 600px; margin-bottom: 0.25rem;",
+                div {
+                    label { style: LABEL_STYLE, "Series" }
+                    input {
+                        r#type: "text",
+                        placeholder: "A",
+                        style: INPUT_STYLE,
+                        value: "{issuer.read().series}",
+                        oninput: move |e| issuer.write().series = e.value().to_uppercase()
+                    }
+                }
+                div {
+                    label { style: LABEL_STYLE, "Certificate number (CSD)" }
+                    input {
+                        r#type: "text",
+                        maxlength: "20",
+                        style: INPUT_STYLE,
+                        value: "{issuer.read().certificate_number}",
+                        oninput: move |e| issuer.write().certificate_number = e.value()
+                    }
+                }
+            }
+            div {
+                style: "max-width: 600px; margin-bottom: 1.5rem; color: #718096; font-size: 0.75rem;",
+                "Data printed as the issuer of CFDI invoices. Leave the RFC empty if the store does not invoice. Invoices are stamped by the local test provider and have no fiscal validity until a PAC is connected."
+            }
+
+            // Loyalty
+            h3 { style: "font-size: 1.125rem; color: #4a5568; margin: 0 0 1rem 0;", "Loyalty Points" }
+            div {
+                style: "display: grid; grid-template-columns: repeat(3, 1fr); gap: 1rem; max-width: 600px; margin-bottom: 1rem;",
+                div {
+                    label { style: LABEL_STYLE, "Amount per point" }
+                    input {
+                        r#type: "number",
+                        min: "0",
+                        step: "0.01",
+                        style: INPUT_STYLE,
+                        value: "{amount_per_point}",
+                        oninput: move |e| amount_per_point.set(e.value())
+                    }
+                }
+                div {
+                    label { style: LABEL_STYLE, "Point value" }
+                    input {
+                        r#type: "number",
+                        min: "0",
+                        step: "0.01",
+                        style: INPUT_STYLE,
+                        value: "{point_value}",
+                        oninput: move |e| point_value.set(e.value())
+                    }
+                }
+                div {
+                    label { style: LABEL_STYLE, "Points expire after (days)" }
+                    input {
+                        r#type: "number",
+                        min: "0",
+                        style: INPUT_STYLE,
+                        value: "{expiry_days}",
+                        oninput: move |e| expiry_days.set(e.value())
+                    }
+                }
+            }
+            if !categories.read().is_empty() {
+                div {
+                    style: "max-width: 600px; margin-bottom: 0.25rem;",
+                    label { style: LABEL_STYLE, "Categories that don't earn points" }
+                    div {
+                        style: "display: grid; grid-template-columns: repeat(2, 1fr); gap: 0.25rem; max-height: 160px; overflow-y: auto; border: 1px solid #e2e8f0; border-radius: 0.25rem; padding: 0.5rem;",
+                        for path in categories.read().iter() {
+                            label {
+                                key: "{path.category.id}",
+                                style: "display: flex; align-items: center; gap: 0.5rem; font-size: 0.875rem; color: #4a5568;",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: excluded_categories.read().contains(&path.category.id),
+                                    onchange: {
+                                        let id = path.category.id.clone();
+                                        move |e: Event<FormData>| {
+                                            let mut excluded = excluded_categories.write();
+                                            excluded.retain(|c| *c != id);
+                                            if e.checked() {
+                                                excluded.push(id.clone());
+                                            }
+                                        }
+                                    }
+                                }
+                                "{path.path}"
+                            }
+                        }
+                    }
+                }
+            }
+            div {
+                style: "max-width: 600px; margin-bottom: 1.5rem; color: #718096; font-size: 0.75rem;",
+                "Customers earn one point for each amount spent, except on excluded categories and their subcategories, and redeem points at checkout for their value. Set the amount per point to 0 to turn the program off, or the expiry to 0 so points never expire."
+            }
+
+            button {
+                style: "background: #667eea; color: white; padding: 0.75rem 1.5rem; border: none; border-radius: 0.5rem; font-weight: 500; cursor: pointer; font-size: 1rem;",
+                onclick: handle_save,
+                "Save Settings"
+            }
+        }
+    }
+}
+
+/// Label for a business-day cutoff hour
+fn cutoff_label(hour: u32) -> String {
+    match hour {
+        0 => "Midnight".to_string(),
+        hour => format!("{}:00 am", hour),
+    }
+}
+
+/// Parse the reorder policy fields
+fn parse_reorder_policy(
+    window_days: &str,
+    coverage_days: &str,
+    safety_factor: &str,
+) -> Result<ReorderPolicy, String> {
+    let policy = ReorderPolicy {
+        window_days: window_days
+            .trim()
+            .parse()
+            .map_err(|_| "Sales window must be a whole number of days".to_string())?,
+        coverage_days: coverage_days
+            .trim()
+            .parse()
+            .map_err(|_| "Coverage must be a whole number of days".to_string())?,
+        safety_factor: safety_factor
+            .trim()
+            .parse()
+            .map_err(|_| "Safety factor must be a number".to_string())?,
+    };
+    policy.validate()?;
+    Ok(policy)
+}
+
+/// Parse the loyalty program fields
+fn parse_loyalty_settings(
+    amount_per_point: &str,
+    point_value: &str,
+    expiry_days: &str,
+    excluded_category_ids: Vec<String>,
+) -> Result<LoyaltySettings, String> {
+    let settings = LoyaltySettings {
+        amount_per_point: amount_per_point
+            .trim()
+            .parse()
+            .map_err(|_| "Amount per point must be a number".to_string())?,
+        point_value: point_value
+            .trim()
+            .parse()
+            .map_err(|_| "Point value must be a number".to_string())?,
+        expiry_days: expiry_days
+            .trim()
+            .parse()
+            .map_err(|_| "Points expiry must be a whole number of days".to_string())?,
+        excluded_category_ids,
+    };
+    settings.validate()?;
+    Ok(settings)
+}
+
+const LABEL_STYLE: &str =
+    "display: block; margin-bottom: 0.5rem; font-weight: 500; font-size: 0.875rem; color: #4a5568;";
+const INPUT_STYLE: &str = "width: 100%; padding: 0.625rem; border: 1px solid #e2e8f0; border-radius: 0.25rem; font-size: 1rem; box-sizing: border-box;";